@@ -19,6 +19,8 @@ bitflags! {
         const USER_CURRENT = 1 << 8;
         const USER = 1 << 9;
         const VOICE_STATE = 1 << 10;
+        const STICKER = 1 << 11;
+        const SCHEDULED_EVENT = 1 << 12;
     }
 }
 
@@ -79,6 +81,8 @@ mod tests {
         assert_eq!(1 << 8, ResourceType::USER_CURRENT.bits());
         assert_eq!(1 << 9, ResourceType::USER.bits());
         assert_eq!(1 << 10, ResourceType::VOICE_STATE.bits());
+        assert_eq!(1 << 11, ResourceType::STICKER.bits());
+        assert_eq!(1 << 12, ResourceType::SCHEDULED_EVENT.bits());
     }
 
     #[test]
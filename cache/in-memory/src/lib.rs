@@ -70,10 +70,14 @@ use std::{
     sync::{Arc, Mutex},
 };
 use twilight_model::{
-    channel::{Group, GuildChannel, PrivateChannel},
+    channel::{
+        message::sticker::{Sticker, StickerId},
+        Group, GuildChannel, PrivateChannel,
+    },
     gateway::presence::{Presence, UserOrId},
     guild::{Emoji, Guild, Member, PartialMember, Role},
-    id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
+    id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, ScheduledEventId, UserId},
+    scheduled_event::GuildScheduledEvent,
     user::{CurrentUser, User},
     voice::VoiceState,
 };
@@ -146,10 +150,14 @@ struct InMemoryCacheRef {
     guild_members: DashMap<GuildId, HashSet<UserId>>,
     guild_presences: DashMap<GuildId, HashSet<UserId>>,
     guild_roles: DashMap<GuildId, HashSet<RoleId>>,
+    guild_scheduled_events: DashMap<GuildId, HashSet<ScheduledEventId>>,
+    guild_stickers: DashMap<GuildId, HashSet<StickerId>>,
     members: DashMap<(GuildId, UserId), Arc<CachedMember>>,
     messages: DashMap<ChannelId, BTreeMap<MessageId, Arc<CachedMessage>>>,
     presences: DashMap<(GuildId, UserId), Arc<CachedPresence>>,
     roles: DashMap<RoleId, GuildItem<Role>>,
+    scheduled_events: DashMap<ScheduledEventId, GuildItem<GuildScheduledEvent>>,
+    stickers: DashMap<StickerId, GuildItem<CachedSticker>>,
     unavailable_guilds: DashSet<GuildId>,
     users: DashMap<UserId, (Arc<User>, BTreeSet<GuildId>)>,
     /// Mapping of channels and the users currently connected.
@@ -359,6 +367,35 @@ impl InMemoryCache {
         self.0.guild_roles.get(&guild_id).map(|r| r.value().clone())
     }
 
+    /// Gets the set of scheduled events in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of scheduled events in
+    /// the guild. This requires both the [`GUILDS`] and
+    /// [`GUILD_SCHEDULED_EVENTS`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_SCHEDULED_EVENTS`]: ::twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn guild_scheduled_events(&self, guild_id: GuildId) -> Option<HashSet<ScheduledEventId>> {
+        self.0
+            .guild_scheduled_events
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
+    /// Gets the set of stickers in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of stickers in the
+    /// guild. This requires both the [`GUILDS`] and [`GUILD_EMOJIS`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_EMOJIS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS
+    pub fn guild_stickers(&self, guild_id: GuildId) -> Option<HashSet<StickerId>> {
+        self.0
+            .guild_stickers
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
     /// Gets a member by guild ID and user ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -424,6 +461,34 @@ impl InMemoryCache {
             .map(|role| Arc::clone(&role.data))
     }
 
+    /// Gets a scheduled event by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_SCHEDULED_EVENTS`]
+    /// intent.
+    ///
+    /// [`GUILD_SCHEDULED_EVENTS`]: ::twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn scheduled_event(
+        &self,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Option<Arc<GuildScheduledEvent>> {
+        self.0
+            .scheduled_events
+            .get(&scheduled_event_id)
+            .map(|event| Arc::clone(&event.data))
+    }
+
+    /// Gets a guild sticker by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_EMOJIS`] intent.
+    ///
+    /// [`GUILD_EMOJIS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS
+    pub fn sticker(&self, sticker_id: StickerId) -> Option<Arc<CachedSticker>> {
+        self.0
+            .stickers
+            .get(&sticker_id)
+            .map(|sticker| Arc::clone(&sticker.data))
+    }
+
     /// Gets a user by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -483,10 +548,14 @@ impl InMemoryCache {
         self.0.guild_members.clear();
         self.0.guild_presences.clear();
         self.0.guild_roles.clear();
+        self.0.guild_scheduled_events.clear();
+        self.0.guild_stickers.clear();
         self.0.members.clear();
         self.0.messages.clear();
         self.0.presences.clear();
         self.0.roles.clear();
+        self.0.scheduled_events.clear();
+        self.0.stickers.clear();
         self.0.unavailable_guilds.clear();
         self.0.users.clear();
         self.0.voice_state_channels.clear();
@@ -622,6 +691,16 @@ impl InMemoryCache {
             self.cache_roles(guild.id, guild.roles);
         }
 
+        if self.wants(ResourceType::SCHEDULED_EVENT) {
+            self.0.guild_scheduled_events.insert(guild.id, HashSet::new());
+            self.cache_scheduled_events(guild.id, guild.guild_scheduled_events);
+        }
+
+        if self.wants(ResourceType::STICKER) {
+            self.0.guild_stickers.insert(guild.id, HashSet::new());
+            self.cache_stickers(guild.id, guild.stickers);
+        }
+
         if self.wants(ResourceType::VOICE_STATE) {
             self.0.voice_state_guilds.insert(guild.id, HashSet::new());
             self.cache_voice_states(guild.voice_states);
@@ -684,6 +763,7 @@ impl InMemoryCache {
             joined_at: member.joined_at,
             mute: member.mute,
             nick: member.nick,
+            pending: member.pending,
             premium_since: member.premium_since,
             roles: member.roles,
             user,
@@ -721,6 +801,7 @@ impl InMemoryCache {
             joined_at: member.joined_at.to_owned(),
             mute: member.mute,
             nick: member.nick.to_owned(),
+            pending: false,
             premium_since: None,
             roles: member.roles.to_owned(),
             user,
@@ -788,6 +869,78 @@ impl InMemoryCache {
         upsert_guild_item(&self.0.roles, guild_id, role.id, role)
     }
 
+    fn cache_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        event: GuildScheduledEvent,
+    ) -> Arc<GuildScheduledEvent> {
+        self.0
+            .guild_scheduled_events
+            .entry(guild_id)
+            .or_default()
+            .insert(event.id);
+
+        upsert_guild_item(&self.0.scheduled_events, guild_id, event.id, event)
+    }
+
+    fn cache_scheduled_events(
+        &self,
+        guild_id: GuildId,
+        events: impl IntoIterator<Item = GuildScheduledEvent>,
+    ) {
+        for event in events {
+            self.cache_scheduled_event(guild_id, event);
+        }
+    }
+
+    fn cache_sticker(&self, guild_id: GuildId, sticker: Sticker) -> Arc<CachedSticker> {
+        match self.0.stickers.get(&sticker.id) {
+            Some(s) if *s.data == sticker => return Arc::clone(&s.data),
+            Some(_) | None => {}
+        }
+
+        let user = match sticker.user {
+            Some(u) => Some(self.cache_user(Cow::Owned(u), Some(guild_id))),
+            None => None,
+        };
+
+        let cached = Arc::new(CachedSticker {
+            id: sticker.id,
+            available: sticker.available,
+            description: sticker.description,
+            format_type: sticker.format_type,
+            guild_id: sticker.guild_id,
+            kind: sticker.kind,
+            name: sticker.name,
+            pack_id: sticker.pack_id,
+            sort_value: sticker.sort_value,
+            tags: sticker.tags,
+            user,
+        });
+
+        self.0.stickers.insert(
+            cached.id,
+            GuildItem {
+                data: Arc::clone(&cached),
+                guild_id,
+            },
+        );
+
+        self.0
+            .guild_stickers
+            .entry(guild_id)
+            .or_default()
+            .insert(sticker.id);
+
+        cached
+    }
+
+    fn cache_stickers(&self, guild_id: GuildId, stickers: impl IntoIterator<Item = Sticker>) {
+        for sticker in stickers {
+            self.cache_sticker(guild_id, sticker);
+        }
+    }
+
     fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<GuildId>) -> Arc<User> {
         match self.0.users.get_mut(&user.id) {
             Some(mut u) if *u.0 == *user => {
@@ -926,6 +1079,23 @@ impl InMemoryCache {
         Some(role.data)
     }
 
+    fn delete_scheduled_event(
+        &self,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Option<Arc<GuildScheduledEvent>> {
+        let event = self
+            .0
+            .scheduled_events
+            .remove(&scheduled_event_id)
+            .map(|(_, v)| v)?;
+
+        if let Some(mut events) = self.0.guild_scheduled_events.get_mut(&event.guild_id) {
+            events.remove(&scheduled_event_id);
+        }
+
+        Some(event.data)
+    }
+
     /// Determine whether the configured cache wants a specific resource to be
     /// processed.
     fn wants(&self, resource_type: ResourceType) -> bool {
@@ -994,6 +1164,7 @@ mod tests {
             joined_at: None,
             mute: false,
             nick: None,
+            pending: false,
             premium_since: None,
             roles: Vec::new(),
             user: user(id),
@@ -1095,6 +1266,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::AllMembers,
             features: vec![],
+            guild_scheduled_events: Vec::new(),
             icon: None,
             joined_at: Some("".to_owned()),
             large: false,
@@ -1115,6 +1287,7 @@ mod tests {
             region: "us-east".to_owned(),
             roles: Vec::new(),
             splash: None,
+            stickers: Vec::new(),
             system_channel_id: None,
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
             rules_channel_id: None,
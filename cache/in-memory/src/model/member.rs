@@ -13,6 +13,8 @@ pub struct CachedMember {
     pub joined_at: Option<String>,
     pub mute: bool,
     pub nick: Option<String>,
+    /// Whether the member has yet to pass the guild's membership screening.
+    pub pending: bool,
     pub premium_since: Option<String>,
     pub roles: Vec<RoleId>,
     pub user: Arc<User>,
@@ -25,6 +27,7 @@ impl PartialEq<Member> for CachedMember {
             self.joined_at.as_ref(),
             self.mute,
             &self.nick,
+            self.pending,
             self.premium_since.as_ref(),
             &self.roles,
         ) == (
@@ -32,6 +35,7 @@ impl PartialEq<Member> for CachedMember {
             other.joined_at.as_ref(),
             other.mute,
             &other.nick,
+            other.pending,
             other.premium_since.as_ref(),
             &other.roles,
         )
@@ -75,6 +79,7 @@ mod tests {
             joined_at: None,
             mute: true,
             nick: Some("member nick".to_owned()),
+            pending: false,
             premium_since: None,
             roles: Vec::new(),
             user: Arc::new(user()),
@@ -108,6 +113,7 @@ mod tests {
             joined_at: None,
             mute: true,
             nick: Some("member nick".to_owned()),
+            pending: false,
             premium_since: None,
             roles: Vec::new(),
             user: user(),
@@ -0,0 +1,88 @@
+use serde::Serialize;
+use std::sync::Arc;
+use twilight_model::{
+    channel::message::sticker::{
+        Sticker, StickerFormatType, StickerId, StickerPackId, StickerType,
+    },
+    id::GuildId,
+    user::User,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedSticker {
+    pub id: StickerId,
+    pub available: Option<bool>,
+    pub description: Option<String>,
+    pub format_type: StickerFormatType,
+    pub guild_id: Option<GuildId>,
+    pub kind: Option<StickerType>,
+    pub name: String,
+    pub pack_id: Option<StickerPackId>,
+    pub sort_value: Option<u64>,
+    pub tags: Option<String>,
+    pub user: Option<Arc<User>>,
+}
+
+impl PartialEq<Sticker> for CachedSticker {
+    fn eq(&self, other: &Sticker) -> bool {
+        self.id == other.id
+            && self.available == other.available
+            && self.description == other.description
+            && self.format_type == other.format_type
+            && self.guild_id == other.guild_id
+            && self.kind == other.kind
+            && self.name == other.name
+            && self.pack_id == other.pack_id
+            && self.sort_value == other.sort_value
+            && self.tags == other.tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedSticker;
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::message::sticker::{Sticker, StickerFormatType, StickerId, StickerType},
+        id::GuildId,
+    };
+
+    #[test]
+    fn test_eq_sticker() {
+        let sticker = Sticker {
+            asset: String::new(),
+            available: Some(true),
+            description: Some("a sticker".to_owned()),
+            format_type: StickerFormatType::Png,
+            guild_id: Some(GuildId(1)),
+            id: StickerId(2),
+            kind: Some(StickerType::Guild),
+            name: "sticker".to_owned(),
+            pack_id: None,
+            preview_asset: None,
+            sort_value: None,
+            tags: Some("cat".to_owned()),
+            user: None,
+        };
+        let cached = CachedSticker {
+            id: StickerId(2),
+            available: Some(true),
+            description: Some("a sticker".to_owned()),
+            format_type: StickerFormatType::Png,
+            guild_id: Some(GuildId(1)),
+            kind: Some(StickerType::Guild),
+            name: "sticker".to_owned(),
+            pack_id: None,
+            sort_value: None,
+            tags: Some("cat".to_owned()),
+            user: None,
+        };
+
+        assert_eq!(cached, sticker);
+    }
+
+    #[test]
+    fn test_impls() {
+        static_assertions::assert_impl_all!(CachedSticker: Clone, Debug, Eq, PartialEq);
+    }
+}
@@ -20,6 +20,10 @@ impl UpdateCache for Event {
         use Event::*;
 
         match self {
+            AutoModerationActionExecution(_) => {}
+            AutoModerationRuleCreate(_) => {}
+            AutoModerationRuleDelete(_) => {}
+            AutoModerationRuleUpdate(_) => {}
             BanAdd(_) => {}
             BanRemove(_) => {}
             ChannelCreate(v) => c.update(v),
@@ -36,6 +40,12 @@ impl UpdateCache for Event {
             GuildDelete(v) => c.update(v.deref()),
             GuildEmojisUpdate(v) => c.update(v),
             GuildIntegrationsUpdate(v) => c.update(v),
+            GuildScheduledEventCreate(v) => c.update(v.deref()),
+            GuildScheduledEventDelete(v) => c.update(v.deref()),
+            GuildScheduledEventUpdate(v) => c.update(v.deref()),
+            GuildScheduledEventUserAdd(v) => c.update(v),
+            GuildScheduledEventUserRemove(v) => c.update(v),
+            GuildStickersUpdate(v) => c.update(v),
             GuildUpdate(v) => c.update(v.deref()),
             InviteCreate(_) => {}
             InviteDelete(_) => {}
@@ -65,6 +75,7 @@ impl UpdateCache for Event {
             ShardReconnecting(_) => {}
             ShardPayload(_) => {}
             ShardResuming(_) => {}
+            ShardStopped(_) => {}
             TypingStart(v) => c.update(v.deref()),
             UnavailableGuild(v) => c.update(v),
             UserUpdate(v) => c.update(v),
@@ -215,6 +226,18 @@ impl UpdateCache for GuildDelete {
             remove_ids(&cache.0.guild_roles, &cache.0.roles, id);
         }
 
+        if cache.wants(ResourceType::SCHEDULED_EVENT) {
+            remove_ids(
+                &cache.0.guild_scheduled_events,
+                &cache.0.scheduled_events,
+                id,
+            );
+        }
+
+        if cache.wants(ResourceType::STICKER) {
+            remove_ids(&cache.0.guild_stickers, &cache.0.stickers, id);
+        }
+
         if cache.wants(ResourceType::VOICE_STATE) {
             // Clear out a guilds voice states when a guild leaves
             cache.0.voice_state_guilds.remove(&id);
@@ -250,6 +273,112 @@ impl UpdateCache for GuildEmojisUpdate {
 
 impl UpdateCache for GuildIntegrationsUpdate {}
 
+impl UpdateCache for GuildScheduledEventCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.cache_scheduled_event(self.guild_id, self.0.clone());
+    }
+}
+
+impl UpdateCache for GuildScheduledEventDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.delete_scheduled_event(self.id);
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        let mut event = self.0.clone();
+
+        // Updates don't include the user count, so keep the cached one.
+        if event.user_count.is_none() {
+            event.user_count = cache
+                .0
+                .scheduled_events
+                .get(&event.id)
+                .and_then(|cached| cached.data.user_count);
+        }
+
+        cache.cache_scheduled_event(self.guild_id, event);
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUserAdd {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        // The user count is only known if the event was fetched with it.
+        if let Some(mut event) = cache
+            .0
+            .scheduled_events
+            .get_mut(&self.guild_scheduled_event_id)
+        {
+            let event = Arc::make_mut(&mut event.data);
+
+            if let Some(user_count) = event.user_count.as_mut() {
+                *user_count += 1;
+            }
+        }
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUserRemove {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        if let Some(mut event) = cache
+            .0
+            .scheduled_events
+            .get_mut(&self.guild_scheduled_event_id)
+        {
+            let event = Arc::make_mut(&mut event.data);
+
+            if let Some(user_count) = event.user_count.as_mut() {
+                *user_count = user_count.saturating_sub(1);
+            }
+        }
+    }
+}
+
+impl UpdateCache for GuildStickersUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::STICKER) {
+            return;
+        }
+
+        // The event contains every sticker of the guild, so remove the ones
+        // that are no longer in it.
+        if let Some(mut ids) = cache.0.guild_stickers.get_mut(&self.guild_id) {
+            ids.retain(|id| {
+                let keep = self.stickers.iter().any(|sticker| sticker.id == *id);
+
+                if !keep {
+                    cache.0.stickers.remove(id);
+                }
+
+                keep
+            });
+        }
+
+        cache.cache_stickers(self.guild_id, self.stickers.clone());
+    }
+}
+
 impl UpdateCache for GuildUpdate {
     fn update(&self, cache: &InMemoryCache) {
         if !cache.wants(ResourceType::GUILD) {
@@ -365,6 +494,7 @@ impl UpdateCache for MemberUpdate {
         let mut member = Arc::make_mut(&mut member);
 
         member.nick = self.nick.clone();
+        member.pending = self.pending;
         member.roles = self.roles.clone();
         member.joined_at.replace(self.joined_at.clone());
     }
@@ -715,7 +845,10 @@ mod tests {
     use crate::config::ResourceType;
     use twilight_model::{
         channel::{
-            message::{MessageFlags, MessageType},
+            message::{
+                sticker::{Sticker, StickerFormatType, StickerId, StickerType},
+                MessageFlags, MessageType,
+            },
             ChannelType, GuildChannel, Message, Reaction, TextChannel,
         },
         gateway::payload::{reaction_remove_emoji::PartialEmoji, ChannelDelete},
@@ -723,7 +856,8 @@ mod tests {
             DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, Member, MfaLevel,
             PartialGuild, PartialMember, PremiumTier, SystemChannelFlags, VerificationLevel,
         },
-        id::{ChannelId, GuildId, MessageId, UserId},
+        id::{ChannelId, GuildId, MessageId, ScheduledEventId, UserId},
+        scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, Status},
         user::User,
         voice::VoiceState,
     };
@@ -816,6 +950,7 @@ mod tests {
                 joined_at: None,
                 mute: false,
                 nick: Some("member nick".to_owned()),
+                pending: false,
                 premium_since: None,
                 roles: Vec::new(),
                 user: User {
@@ -847,6 +982,7 @@ mod tests {
             joined_at: None,
             mute: false,
             nick: None,
+            pending: false,
             premium_since: None,
             roles: Vec::new(),
             user: User {
@@ -895,6 +1031,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::None,
             features: Vec::new(),
+            guild_scheduled_events: Vec::new(),
             icon: None,
             id: GuildId(1),
             joined_at: None,
@@ -918,6 +1055,7 @@ mod tests {
             roles: Vec::new(),
             rules_channel_id: None,
             splash: None,
+            stickers: Vec::new(),
             system_channel_flags: SystemChannelFlags::empty(),
             system_channel_id: None,
             unavailable: false,
@@ -1046,6 +1184,7 @@ mod tests {
                 joined_at: None,
                 mute: false,
                 nick: None,
+                pending: false,
                 premium_since: None,
                 roles: Vec::new(),
                 user: User {
@@ -1257,4 +1396,194 @@ mod tests {
 
         assert_eq!(msg.reactions.len(), 0);
     }
+
+    #[test]
+    fn test_member_update_pending() {
+        let cache = InMemoryCache::new();
+        let user = User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(2),
+            locale: None,
+            mfa_enabled: None,
+            name: "user".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+
+        cache.update(&MemberAdd(Member {
+            deaf: false,
+            guild_id: GuildId(1),
+            hoisted_role: None,
+            joined_at: Some("timestamp".to_owned()),
+            mute: false,
+            nick: None,
+            pending: true,
+            premium_since: None,
+            roles: Vec::new(),
+            user: user.clone(),
+        }));
+        assert!(cache.member(GuildId(1), UserId(2)).unwrap().pending);
+
+        cache.update(&MemberUpdate {
+            guild_id: GuildId(1),
+            joined_at: "timestamp".to_owned(),
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: Vec::new(),
+            user,
+        });
+        assert!(!cache.member(GuildId(1), UserId(2)).unwrap().pending);
+    }
+
+    #[test]
+    fn test_guild_stickers_update() {
+        fn sticker(id: u64) -> Sticker {
+            Sticker {
+                asset: String::new(),
+                available: Some(true),
+                description: None,
+                format_type: StickerFormatType::Png,
+                guild_id: Some(GuildId(1)),
+                id: StickerId(id),
+                kind: Some(StickerType::Guild),
+                name: "sticker".to_owned(),
+                pack_id: None,
+                preview_asset: None,
+                sort_value: None,
+                tags: Some("cat".to_owned()),
+                user: None,
+            }
+        }
+
+        let cache = InMemoryCache::new();
+        cache.update(&GuildStickersUpdate {
+            guild_id: GuildId(1),
+            stickers: vec![sticker(2), sticker(3)],
+        });
+
+        assert_eq!(2, cache.guild_stickers(GuildId(1)).unwrap().len());
+        assert!(cache.sticker(StickerId(3)).is_some());
+
+        cache.update(&GuildStickersUpdate {
+            guild_id: GuildId(1),
+            stickers: vec![sticker(2)],
+        });
+
+        let stickers = cache.guild_stickers(GuildId(1)).unwrap();
+        assert_eq!(1, stickers.len());
+        assert!(stickers.contains(&StickerId(2)));
+        assert!(cache.sticker(StickerId(2)).is_some());
+        assert!(cache.sticker(StickerId(3)).is_none());
+    }
+
+    #[test]
+    fn test_guild_scheduled_events() {
+        let event = GuildScheduledEvent {
+            channel_id: Some(ChannelId(2)),
+            creator: None,
+            creator_id: None,
+            description: None,
+            entity_id: None,
+            entity_metadata: None,
+            entity_type: EntityType::Voice,
+            guild_id: GuildId(1),
+            id: ScheduledEventId(3),
+            image: None,
+            name: "movie night".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: None,
+            scheduled_start_time: "2021-11-05T18:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: Some(1),
+        };
+
+        let cache = InMemoryCache::new();
+        cache.update(&GuildScheduledEventCreate(event.clone()));
+        assert!(cache
+            .guild_scheduled_events(GuildId(1))
+            .unwrap()
+            .contains(&ScheduledEventId(3)));
+
+        cache.update(&GuildScheduledEventUserAdd {
+            guild_id: GuildId(1),
+            guild_scheduled_event_id: ScheduledEventId(3),
+            user_id: UserId(4),
+        });
+        assert_eq!(
+            Some(2),
+            cache
+                .scheduled_event(ScheduledEventId(3))
+                .unwrap()
+                .user_count
+        );
+
+        cache.update(&GuildScheduledEventUpdate(GuildScheduledEvent {
+            status: Status::Active,
+            ..event.clone()
+        }));
+        assert_eq!(
+            Status::Active,
+            cache.scheduled_event(ScheduledEventId(3)).unwrap().status
+        );
+
+        cache.update(&GuildScheduledEventDelete(event));
+        assert!(cache.scheduled_event(ScheduledEventId(3)).is_none());
+        assert!(cache.guild_scheduled_events(GuildId(1)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_guild_scheduled_event_update_keeps_user_count() {
+        let event = GuildScheduledEvent {
+            channel_id: Some(ChannelId(2)),
+            creator: None,
+            creator_id: None,
+            description: None,
+            entity_id: None,
+            entity_metadata: None,
+            entity_type: EntityType::Voice,
+            guild_id: GuildId(1),
+            id: ScheduledEventId(3),
+            image: None,
+            name: "movie night".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: None,
+            scheduled_start_time: "2021-11-05T18:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: Some(5),
+        };
+
+        let cache = InMemoryCache::new();
+        cache.update(&GuildScheduledEventCreate(event.clone()));
+
+        cache.update(&GuildScheduledEventUpdate(GuildScheduledEvent {
+            name: "movie marathon".to_owned(),
+            user_count: None,
+            ..event.clone()
+        }));
+        let cached = cache.scheduled_event(ScheduledEventId(3)).unwrap();
+        assert_eq!("movie marathon", cached.name);
+        assert_eq!(Some(5), cached.user_count);
+
+        // A count included in the update replaces the cached one.
+        cache.update(&GuildScheduledEventUpdate(GuildScheduledEvent {
+            user_count: Some(7),
+            ..event
+        }));
+        assert_eq!(
+            Some(7),
+            cache
+                .scheduled_event(ScheduledEventId(3))
+                .unwrap()
+                .user_count
+        );
+    }
 }
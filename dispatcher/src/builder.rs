@@ -0,0 +1,149 @@
+use crate::{
+    dispatcher::{Dispatcher, DispatcherRef},
+    error::DispatchError,
+    handler::{BoxedHandler, Handler},
+    middleware::Middleware,
+};
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicU64, Mutex},
+};
+use tokio::sync::Semaphore;
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_model::gateway::event::EventType;
+
+/// Builder to configure and construct a [`Dispatcher`].
+///
+/// # Examples
+///
+/// Create a dispatcher processing at most 10 events at a time and replying
+/// to messages:
+///
+/// ```rust,no_run
+/// use twilight_dispatcher::{Dispatcher, HandlerError, ShardId};
+/// use twilight_model::gateway::payload::MessageCreate;
+///
+/// async fn message(msg: MessageCreate, shard: ShardId) -> Result<(), HandlerError> {
+///     println!("shard {} received message: {}", shard.0, msg.content);
+///
+///     Ok(())
+/// }
+///
+/// let dispatcher = Dispatcher::builder()
+///     .concurrency_limit(Some(10))
+///     .on(message)
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct DispatcherBuilder(DispatcherRef);
+
+impl DispatcherBuilder {
+    /// Create a new builder with the default configuration.
+    pub fn new() -> Self {
+        Self(DispatcherRef {
+            cache: None,
+            concurrency: None,
+            guild_ordering: false,
+            guilds: Mutex::new(HashMap::new()),
+            guild_sequence: AtomicU64::new(0),
+            handlers: Vec::new(),
+            middleware: Vec::new(),
+            on_error: Box::new(|error| tracing::warn!("{}", error)),
+        })
+    }
+
+    /// Consume the builder, returning the configured dispatcher.
+    pub fn build(self) -> Dispatcher {
+        Dispatcher::from_ref(self.0)
+    }
+
+    /// Set the cache to update with every event.
+    ///
+    /// The cache is updated before any handler is called, and can be
+    /// extracted by handlers via [`Cache`].
+    ///
+    /// Defaults to no cache.
+    ///
+    /// [`Cache`]: crate::Cache
+    pub fn cache(mut self, cache: InMemoryCache) -> Self {
+        self.0.cache.replace(cache);
+
+        self
+    }
+
+    /// Set the maximum number of events to process at the same time.
+    ///
+    /// Events received while the limit is reached wait until another event
+    /// has been processed.
+    ///
+    /// Defaults to `None`, processing every event as soon as it's received.
+    pub fn concurrency_limit(mut self, limit: Option<usize>) -> Self {
+        self.0.concurrency = limit.map(Semaphore::new);
+
+        self
+    }
+
+    /// Set whether to process events of the same guild in the order they
+    /// were received.
+    ///
+    /// When enabled an event of a guild is only processed once all of the
+    /// guild's previous events have been processed. Events of different
+    /// guilds and events not belonging to a guild are still processed
+    /// concurrently.
+    ///
+    /// Defaults to `false`.
+    pub fn guild_ordering(mut self, guild_ordering: bool) -> Self {
+        self.0.guild_ordering = guild_ordering;
+
+        self
+    }
+
+    /// Add middleware called before and after the handlers of each event.
+    ///
+    /// Refer to [`Middleware`] for the order middleware is called in.
+    pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+        self.0.middleware.push(Box::new(middleware));
+
+        self
+    }
+
+    /// Register a handler.
+    ///
+    /// The event type of the handler is taken from its arguments. Refer to
+    /// [`Handler`] for more information.
+    pub fn on<Args>(mut self, handler: impl Handler<Args>) -> Self {
+        let event_type = handler.event_type();
+        self.0.handlers.push(BoxedHandler::new(handler, event_type));
+
+        self
+    }
+
+    /// Register a handler for an event type.
+    ///
+    /// This is useful for handlers that don't take an event payload, such as
+    /// for [`EventType::GatewayReconnect`] or handlers taking an [`Event`].
+    ///
+    /// [`Event`]: twilight_model::gateway::event::Event
+    pub fn on_event<Args>(mut self, event_type: EventType, handler: impl Handler<Args>) -> Self {
+        self.0
+            .handlers
+            .push(BoxedHandler::new(handler, Some(event_type)));
+
+        self
+    }
+
+    /// Set the function reporting errors of handlers.
+    ///
+    /// Defaults to logging errors with a warning.
+    pub fn on_error(mut self, on_error: impl Fn(DispatchError) + Send + Sync + 'static) -> Self {
+        self.0.on_error = Box::new(on_error);
+
+        self
+    }
+}
+
+impl Default for DispatcherBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,254 @@
+//! Context of a dispatched event and extractors for handler arguments.
+
+#![allow(clippy::wildcard_imports)]
+
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_model::gateway::{
+    event::{shard::*, Event, EventType},
+    payload::*,
+};
+
+/// Event being dispatched along with information about where it came from.
+///
+/// A context is shared by every handler and middleware processing the same
+/// event.
+#[derive(Debug)]
+pub struct Context {
+    pub(crate) cache: Option<InMemoryCache>,
+    pub(crate) event: Event,
+    pub(crate) shard_id: u64,
+}
+
+impl Context {
+    /// Create a new context for an event received by a shard.
+    pub fn new(shard_id: u64, event: Event, cache: Option<InMemoryCache>) -> Self {
+        Self {
+            cache,
+            event,
+            shard_id,
+        }
+    }
+
+    /// Return an immutable reference to the cache of the dispatcher, if one
+    /// is configured.
+    ///
+    /// The cache has already been updated with the event.
+    pub fn cache(&self) -> Option<&InMemoryCache> {
+        self.cache.as_ref()
+    }
+
+    /// Return an immutable reference to the event.
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    /// Return the ID of the shard that received the event.
+    pub fn shard_id(&self) -> u64 {
+        self.shard_id
+    }
+}
+
+/// Type that can be extracted from the context of an event and passed to a
+/// handler as an argument.
+///
+/// Extractors for each event payload, such as [`MessageCreate`], restrict the
+/// handler to the event type of the payload. Other extractors, such as
+/// [`ShardId`] and [`Cache`], don't restrict the event type.
+///
+/// [`MessageCreate`]: twilight_model::gateway::payload::MessageCreate
+pub trait FromContext: Sized {
+    /// Event type the extractor is limited to.
+    ///
+    /// Handlers taking an argument with an event type are only called for
+    /// events of that type.
+    const EVENT_TYPE: Option<EventType> = None;
+
+    /// Extract the value from the context.
+    ///
+    /// Returns `None` if the value isn't available, in which case the handler
+    /// isn't called and a [`DispatchError::Extracting`] is reported.
+    ///
+    /// [`DispatchError::Extracting`]: crate::DispatchError::Extracting
+    fn from_context(context: &Context) -> Option<Self>;
+}
+
+/// ID of the shard that received the event.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ShardId(pub u64);
+
+impl FromContext for ShardId {
+    fn from_context(context: &Context) -> Option<Self> {
+        Some(Self(context.shard_id))
+    }
+}
+
+/// Handle to the cache of the dispatcher.
+///
+/// Extracting fails if the dispatcher doesn't have a cache.
+#[derive(Clone, Debug)]
+pub struct Cache(pub InMemoryCache);
+
+impl FromContext for Cache {
+    fn from_context(context: &Context) -> Option<Self> {
+        context.cache.clone().map(Self)
+    }
+}
+
+impl FromContext for Event {
+    fn from_context(context: &Context) -> Option<Self> {
+        Some(context.event.clone())
+    }
+}
+
+macro_rules! impl_from_context {
+    ($($variant:ident($payload:ty)),* $(,)?) => {
+        $(
+            impl FromContext for $payload {
+                const EVENT_TYPE: Option<EventType> = Some(EventType::$variant);
+
+                fn from_context(context: &Context) -> Option<Self> {
+                    match &context.event {
+                        Event::$variant(payload) => Some(Clone::clone(payload)),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_context_boxed {
+    ($($variant:ident($payload:ty)),* $(,)?) => {
+        $(
+            impl FromContext for $payload {
+                const EVENT_TYPE: Option<EventType> = Some(EventType::$variant);
+
+                fn from_context(context: &Context) -> Option<Self> {
+                    match &context.event {
+                        Event::$variant(payload) => Some(payload.as_ref().clone()),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_context! {
+    AutoModerationActionExecution(AutoModerationActionExecution),
+    BanAdd(BanAdd),
+    BanRemove(BanRemove),
+    ChannelCreate(ChannelCreate),
+    ChannelDelete(ChannelDelete),
+    ChannelPinsUpdate(ChannelPinsUpdate),
+    ChannelUpdate(ChannelUpdate),
+    GuildEmojisUpdate(GuildEmojisUpdate),
+    GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+    GuildStickersUpdate(GuildStickersUpdate),
+    InviteDelete(InviteDelete),
+    MemberChunk(MemberChunk),
+    MemberRemove(MemberRemove),
+    MessageDelete(MessageDelete),
+    MessageDeleteBulk(MessageDeleteBulk),
+    ReactionRemoveAll(ReactionRemoveAll),
+    ReactionRemoveEmoji(ReactionRemoveEmoji),
+    RoleCreate(RoleCreate),
+    RoleDelete(RoleDelete),
+    RoleUpdate(RoleUpdate),
+    ShardConnected(Connected),
+    ShardConnecting(Connecting),
+    ShardDisconnected(Disconnected),
+    ShardIdentifying(Identifying),
+    ShardPayload(Payload),
+    ShardReconnecting(Reconnecting),
+    ShardResuming(Resuming),
+    ShardStopped(Stopped),
+    UnavailableGuild(UnavailableGuild),
+    UserUpdate(UserUpdate),
+    VoiceServerUpdate(VoiceServerUpdate),
+    WebhooksUpdate(WebhooksUpdate),
+}
+
+impl_from_context_boxed! {
+    AutoModerationRuleCreate(AutoModerationRuleCreate),
+    AutoModerationRuleDelete(AutoModerationRuleDelete),
+    AutoModerationRuleUpdate(AutoModerationRuleUpdate),
+    GuildCreate(GuildCreate),
+    GuildDelete(GuildDelete),
+    GuildScheduledEventCreate(GuildScheduledEventCreate),
+    GuildScheduledEventDelete(GuildScheduledEventDelete),
+    GuildScheduledEventUpdate(GuildScheduledEventUpdate),
+    GuildUpdate(GuildUpdate),
+    InviteCreate(InviteCreate),
+    MemberAdd(MemberAdd),
+    MemberUpdate(MemberUpdate),
+    MessageCreate(MessageCreate),
+    MessageUpdate(MessageUpdate),
+    PresenceUpdate(PresenceUpdate),
+    ReactionAdd(ReactionAdd),
+    ReactionRemove(ReactionRemove),
+    Ready(Ready),
+    TypingStart(TypingStart),
+    VoiceStateUpdate(VoiceStateUpdate),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cache, Context, FromContext, ShardId};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_cache_inmemory::InMemoryCache;
+    use twilight_model::{
+        gateway::{
+            event::{Event, EventType},
+            payload::{MessageCreate, RoleDelete},
+        },
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(Cache: Clone, Debug, FromContext, Send, Sync);
+    assert_impl_all!(Context: Debug, Send, Sync);
+    assert_impl_all!(ShardId: Clone, Copy, Debug, FromContext, Send, Sync);
+
+    fn role_delete() -> Event {
+        Event::RoleDelete(RoleDelete {
+            guild_id: GuildId(1),
+            role_id: RoleId(2),
+        })
+    }
+
+    #[test]
+    fn test_event_types() {
+        assert_eq!(Some(EventType::RoleDelete), RoleDelete::EVENT_TYPE);
+        assert_eq!(Some(EventType::MessageCreate), MessageCreate::EVENT_TYPE);
+        assert!(ShardId::EVENT_TYPE.is_none());
+        assert!(Cache::EVENT_TYPE.is_none());
+        assert!(Event::EVENT_TYPE.is_none());
+    }
+
+    #[test]
+    fn test_extract_payload() {
+        let context = Context::new(3, role_delete(), None);
+
+        assert_eq!(
+            Some(RoleDelete {
+                guild_id: GuildId(1),
+                role_id: RoleId(2),
+            }),
+            RoleDelete::from_context(&context)
+        );
+        assert!(MessageCreate::from_context(&context).is_none());
+    }
+
+    #[test]
+    fn test_extract_shard_id_and_cache() {
+        let context = Context::new(3, role_delete(), None);
+        assert_eq!(Some(ShardId(3)), ShardId::from_context(&context));
+        assert!(Cache::from_context(&context).is_none());
+
+        let context = Context::new(3, role_delete(), Some(InMemoryCache::new()));
+        assert!(Cache::from_context(&context).is_some());
+    }
+}
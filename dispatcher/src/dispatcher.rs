@@ -0,0 +1,639 @@
+use crate::{
+    builder::DispatcherBuilder, context::Context, error::DispatchError, guild,
+    handler::BoxedHandler, middleware::Middleware,
+};
+use futures_util::stream::{Stream, StreamExt};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{
+    sync::{oneshot, Semaphore},
+    task::JoinHandle,
+};
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_gateway::EventTypeFlags;
+use twilight_model::{
+    gateway::event::{Event, EventType},
+    id::GuildId,
+};
+
+pub(crate) struct DispatcherRef {
+    pub cache: Option<InMemoryCache>,
+    pub concurrency: Option<Semaphore>,
+    pub guild_ordering: bool,
+    /// Sequence number of the last event of each guild still being processed,
+    /// and a receiver notified once it was processed.
+    pub guilds: Mutex<HashMap<GuildId, (u64, oneshot::Receiver<()>)>>,
+    /// Sequence number of the next event with guild ordering.
+    pub guild_sequence: AtomicU64,
+    pub handlers: Vec<BoxedHandler>,
+    pub middleware: Vec<Box<dyn Middleware>>,
+    pub on_error: Box<dyn Fn(DispatchError) + Send + Sync>,
+}
+
+impl Debug for DispatcherRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("DispatcherRef")
+            .field("cache", &self.cache)
+            .field("concurrency", &self.concurrency)
+            .field("guild_ordering", &self.guild_ordering)
+            .field("guilds", &self.guilds)
+            .field("guild_sequence", &self.guild_sequence)
+            .field("handlers", &self.handlers)
+            .field("middleware", &self.middleware)
+            .field("on_error", &"<fn>")
+            .finish()
+    }
+}
+
+/// Dispatcher routing events to the handlers registered for them.
+///
+/// Each event is processed in a new task, so the dispatcher must be used
+/// within a Tokio runtime. The dispatcher is cheap to clone, and clones share
+/// the same handlers and state.
+///
+/// Refer to the [crate-level documentation] for an example.
+///
+/// [crate-level documentation]: crate
+#[derive(Clone, Debug)]
+pub struct Dispatcher(Arc<DispatcherRef>);
+
+impl Dispatcher {
+    /// Create a builder to configure and construct a dispatcher.
+    pub fn builder() -> DispatcherBuilder {
+        DispatcherBuilder::new()
+    }
+
+    pub(crate) fn from_ref(inner: DispatcherRef) -> Self {
+        Self(Arc::new(inner))
+    }
+
+    /// Return the event types the registered handlers process.
+    ///
+    /// This can be passed to [`Cluster::some_events`] to only receive the
+    /// events the dispatcher needs. If a cache is configured or a handler
+    /// processes every event, then the [default event types] are returned.
+    ///
+    /// [`Cluster::some_events`]: twilight_gateway::Cluster::some_events
+    /// [default event types]: EventTypeFlags::default
+    pub fn event_types(&self) -> EventTypeFlags {
+        if self.0.cache.is_some() {
+            return EventTypeFlags::default();
+        }
+
+        let mut flags = EventTypeFlags::empty();
+
+        for handler in &self.0.handlers {
+            match handler.event_type() {
+                Some(event_type) => flags |= EventTypeFlags::from(event_type),
+                None => return EventTypeFlags::default(),
+            }
+        }
+
+        flags
+    }
+
+    /// Dispatch an event received by a shard to its handlers.
+    ///
+    /// The cache, if any, is updated immediately. The handlers are then called
+    /// in a new task, which is returned. Returns `None` if no handler processes
+    /// the event.
+    pub fn dispatch(&self, shard_id: u64, event: Event) -> Option<JoinHandle<()>> {
+        if let Some(cache) = self.0.cache.as_ref() {
+            cache.update(&event);
+        }
+
+        let event_type = event.kind();
+
+        if !self.0.handles(event_type) {
+            if let Event::GuildDelete(guild) = &event {
+                self.0.forget_guild(guild.id);
+            }
+
+            return None;
+        }
+
+        let mut order = None;
+
+        if self.0.guild_ordering {
+            if let Some(guild_id) = guild::event_guild_id(&event) {
+                let (tx, rx) = oneshot::channel();
+                let sequence = self.0.guild_sequence.fetch_add(1, Ordering::Relaxed);
+                let previous = self
+                    .0
+                    .guilds
+                    .lock()
+                    .expect("guilds poisoned")
+                    .insert(guild_id, (sequence, rx));
+
+                order = Some((previous, guild_id, sequence, tx));
+            }
+        }
+
+        let context = Context::new(shard_id, event, self.0.cache.clone());
+        let inner = Arc::clone(&self.0);
+
+        Some(tokio::spawn(async move {
+            let done = match order {
+                Some((previous, guild_id, sequence, done)) => {
+                    if let Some((_, previous)) = previous {
+                        // The sender is dropped without sending if the previous
+                        // task was aborted, in which case we can continue too.
+                        let _ = previous.await;
+                    }
+
+                    Some((guild_id, sequence, done))
+                }
+                None => None,
+            };
+
+            let _permit = match inner.concurrency.as_ref() {
+                Some(semaphore) => semaphore.acquire().await.ok(),
+                None => None,
+            };
+
+            inner.process(context, event_type).await;
+
+            if let Some((guild_id, sequence, done)) = done {
+                let _ = done.send(());
+                inner.finish_guild_event(guild_id, sequence);
+            }
+        }))
+    }
+
+    /// Dispatch every event of a stream, such as [`Cluster::events`].
+    ///
+    /// Returns once the stream ends, without waiting for the events to be
+    /// processed.
+    ///
+    /// [`Cluster::events`]: twilight_gateway::Cluster::events
+    pub async fn run(&self, events: impl Stream<Item = (u64, Event)>) {
+        futures_util::pin_mut!(events);
+
+        while let Some((shard_id, event)) = events.next().await {
+            self.dispatch(shard_id, event);
+        }
+    }
+}
+
+impl DispatcherRef {
+    /// Remove the guild's entry if the event with the sequence number is
+    /// still its last one, as no event of the guild is left to wait for.
+    fn finish_guild_event(&self, guild_id: GuildId, sequence: u64) {
+        let mut guilds = self.guilds.lock().expect("guilds poisoned");
+
+        if guilds
+            .get(&guild_id)
+            .map_or(false, |(last, _)| *last == sequence)
+        {
+            guilds.remove(&guild_id);
+        }
+    }
+
+    /// Remove the entry of a guild the bot left.
+    fn forget_guild(&self, guild_id: GuildId) {
+        self.guilds
+            .lock()
+            .expect("guilds poisoned")
+            .remove(&guild_id);
+    }
+
+    fn handles(&self, event_type: EventType) -> bool {
+        self.handlers.iter().any(|handler| {
+            handler
+                .event_type()
+                .map_or(true, |handler_type| handler_type == event_type)
+        })
+    }
+
+    async fn process(&self, context: Context, event_type: EventType) {
+        if !self.middleware.iter().all(|m| m.before(&context)) {
+            return;
+        }
+
+        let shard_id = context.shard_id();
+        let mut tasks = Vec::new();
+
+        for handler in &self.handlers {
+            if handler
+                .event_type()
+                .map_or(false, |handler_type| handler_type != event_type)
+            {
+                continue;
+            }
+
+            match handler.call(&context) {
+                Ok(future) => tasks.push(tokio::spawn(future)),
+                Err(extractor) => (self.on_error)(DispatchError::Extracting {
+                    event_type,
+                    extractor,
+                    shard_id,
+                }),
+            }
+        }
+
+        for task in tasks {
+            match task.await {
+                Ok(Err(source)) => (self.on_error)(DispatchError::Handler {
+                    event_type,
+                    shard_id,
+                    source,
+                }),
+                Err(source) if source.is_panic() => (self.on_error)(DispatchError::Panicked {
+                    event_type,
+                    shard_id,
+                }),
+                // Handlers can't be cancelled, since their tasks aren't
+                // exposed.
+                Ok(Ok(())) | Err(_) => {}
+            }
+        }
+
+        for middleware in self.middleware.iter().rev() {
+            middleware.after(&context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dispatcher;
+    use crate::{
+        context::{Context, ShardId},
+        error::{DispatchError, HandlerError},
+        middleware::Middleware,
+        Cache,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+    use tokio::sync::oneshot;
+    use twilight_gateway::EventTypeFlags;
+    use twilight_model::{
+        channel::{message::MessageType, Message},
+        gateway::{
+            event::{Event, EventType},
+            payload::{GuildDelete, MessageCreate, MessageDelete, RoleDelete, UnavailableGuild},
+        },
+        id::{ChannelId, GuildId, MessageId, RoleId, UserId},
+        user::User,
+    };
+
+    assert_impl_all!(Dispatcher: Clone, Debug, Send, Sync);
+
+    fn role_delete(guild_id: u64, role_id: u64) -> Event {
+        Event::RoleDelete(RoleDelete {
+            guild_id: GuildId(guild_id),
+            role_id: RoleId(role_id),
+        })
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl Recorder {
+        fn push(&self, value: impl Into<String>) {
+            self.0.lock().unwrap().push(value.into());
+        }
+
+        fn take(&self) -> Vec<String> {
+            std::mem::take(&mut self.0.lock().unwrap())
+        }
+    }
+
+    async fn panics(_: RoleDelete) -> Result<(), HandlerError> {
+        panic!("handler panicked")
+    }
+
+    #[test]
+    fn test_event_types() {
+        let dispatcher = Dispatcher::builder()
+            .on(|_: RoleDelete| async { Ok::<_, HandlerError>(()) })
+            .on_event(EventType::GatewayReconnect, || async {
+                Ok::<_, HandlerError>(())
+            })
+            .build();
+        assert_eq!(
+            EventTypeFlags::ROLE_DELETE | EventTypeFlags::GATEWAY_RECONNECT,
+            dispatcher.event_types()
+        );
+
+        let dispatcher = Dispatcher::builder()
+            .on(|_: RoleDelete| async { Ok::<_, HandlerError>(()) })
+            .on(|_: ShardId| async { Ok::<_, HandlerError>(()) })
+            .build();
+        assert_eq!(EventTypeFlags::default(), dispatcher.event_types());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_to_matching_handlers() {
+        let recorder = Arc::new(Recorder::default());
+        let (r1, r2) = (Arc::clone(&recorder), Arc::clone(&recorder));
+
+        let dispatcher = Dispatcher::builder()
+            .on(move |event: RoleDelete, shard: ShardId| {
+                r1.push(format!("role {} on {}", event.role_id, shard.0));
+
+                async { Ok::<_, HandlerError>(()) }
+            })
+            .on(move |event: UnavailableGuild| {
+                r2.push(format!("guild {}", event.id));
+
+                async { Ok::<_, HandlerError>(()) }
+            })
+            .build();
+
+        dispatcher
+            .dispatch(3, role_delete(1, 2))
+            .unwrap()
+            .await
+            .unwrap();
+        assert!(dispatcher.dispatch(0, Event::GatewayReconnect).is_none());
+
+        assert_eq!(vec!["role 2 on 3".to_owned()], recorder.take());
+    }
+
+    #[tokio::test]
+    async fn test_errors_reported() {
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let reported = Arc::clone(&errors);
+
+        let dispatcher = Dispatcher::builder()
+            .on(|_: RoleDelete| async { Err(HandlerError::from("failed")) })
+            .on(panics)
+            .on(|_: RoleDelete, _: Cache| async { Ok::<_, HandlerError>(()) })
+            .on_error(move |error| reported.lock().unwrap().push(error))
+            .build();
+
+        dispatcher
+            .dispatch(1, role_delete(1, 2))
+            .unwrap()
+            .await
+            .unwrap();
+
+        let errors = errors.lock().unwrap();
+        assert_eq!(3, errors.len());
+        assert!(errors
+            .iter()
+            .all(|error| error.shard_id() == 1 && error.event_type() == EventType::RoleDelete));
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            DispatchError::Extracting { extractor, .. } if extractor.ends_with("Cache")
+        )));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, DispatchError::Handler { .. })));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, DispatchError::Panicked { .. })));
+    }
+
+    #[derive(Debug)]
+    struct Named(&'static str, bool, Arc<Recorder>);
+
+    impl Middleware for Named {
+        fn before(&self, _: &Context) -> bool {
+            self.2.push(format!("before {}", self.0));
+
+            self.1
+        }
+
+        fn after(&self, _: &Context) {
+            self.2.push(format!("after {}", self.0));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware() {
+        let recorder = Arc::new(Recorder::default());
+        let handled = Arc::clone(&recorder);
+
+        let dispatcher = Dispatcher::builder()
+            .middleware(Named("a", true, Arc::clone(&recorder)))
+            .middleware(Named("b", true, Arc::clone(&recorder)))
+            .on(move |_: RoleDelete| {
+                handled.push("handler");
+
+                async { Ok::<_, HandlerError>(()) }
+            })
+            .build();
+        dispatcher
+            .dispatch(0, role_delete(1, 2))
+            .unwrap()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec!["before a", "before b", "handler", "after b", "after a"],
+            recorder.take()
+        );
+
+        let dispatcher = Dispatcher::builder()
+            .middleware(Named("a", false, Arc::clone(&recorder)))
+            .on(panics)
+            .build();
+        dispatcher
+            .dispatch(0, role_delete(1, 2))
+            .unwrap()
+            .await
+            .unwrap();
+
+        assert_eq!(vec!["before a"], recorder.take());
+    }
+
+    #[tokio::test]
+    async fn test_guild_ordering() {
+        let recorder = Arc::new(Recorder::default());
+        let handled = Arc::clone(&recorder);
+
+        let dispatcher = Dispatcher::builder()
+            .guild_ordering(true)
+            .on(move |event: RoleDelete| {
+                let handled = Arc::clone(&handled);
+
+                async move {
+                    if event.role_id == RoleId(1) {
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                    }
+
+                    handled.push(format!("{} {}", event.guild_id, event.role_id));
+
+                    Ok::<_, HandlerError>(())
+                }
+            })
+            .build();
+
+        let first = dispatcher.dispatch(0, role_delete(1, 1)).unwrap();
+        let second = dispatcher.dispatch(0, role_delete(1, 2)).unwrap();
+        let other_guild = dispatcher.dispatch(0, role_delete(2, 3)).unwrap();
+
+        other_guild.await.unwrap();
+        second.await.unwrap();
+        first.await.unwrap();
+
+        assert_eq!(vec!["2 3", "1 1", "1 2"], recorder.take());
+    }
+
+    fn message_create(guild_id: u64, message_id: u64) -> Event {
+        Event::MessageCreate(Box::new(MessageCreate(Message {
+            activity: None,
+            application: None,
+            attachments: Vec::new(),
+            author: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(1),
+                locale: None,
+                mfa_enabled: None,
+                name: "user".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            channel_id: ChannelId(2),
+            content: "content".to_owned(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: None,
+            guild_id: Some(GuildId(guild_id)),
+            id: MessageId(message_id),
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            referenced_message: None,
+            stickers: Vec::new(),
+            timestamp: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+            tts: false,
+            webhook_id: None,
+        })))
+    }
+
+    #[tokio::test]
+    async fn test_guild_ordering_messages() {
+        let recorder = Arc::new(Recorder::default());
+        let (created, deleted) = (Arc::clone(&recorder), Arc::clone(&recorder));
+
+        let dispatcher = Dispatcher::builder()
+            .guild_ordering(true)
+            .on(move |event: MessageCreate| {
+                let created = Arc::clone(&created);
+
+                async move {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    created.push(format!("create {}", event.id));
+
+                    Ok::<_, HandlerError>(())
+                }
+            })
+            .on(move |event: MessageDelete| {
+                deleted.push(format!("delete {}", event.id));
+
+                async { Ok::<_, HandlerError>(()) }
+            })
+            .build();
+
+        let create = dispatcher.dispatch(0, message_create(1, 3)).unwrap();
+        let delete = dispatcher
+            .dispatch(
+                0,
+                Event::MessageDelete(MessageDelete {
+                    channel_id: ChannelId(2),
+                    guild_id: Some(GuildId(1)),
+                    id: MessageId(3),
+                }),
+            )
+            .unwrap();
+
+        delete.await.unwrap();
+        create.await.unwrap();
+
+        assert_eq!(vec!["create 3", "delete 3"], recorder.take());
+    }
+
+    #[tokio::test]
+    async fn test_guild_ordering_forgets_guilds() {
+        let dispatcher = Dispatcher::builder()
+            .guild_ordering(true)
+            .on(|_: RoleDelete| async { Ok::<_, HandlerError>(()) })
+            .build();
+
+        let first = dispatcher.dispatch(0, role_delete(1, 1)).unwrap();
+        let second = dispatcher.dispatch(0, role_delete(1, 2)).unwrap();
+        first.await.unwrap();
+        second.await.unwrap();
+        assert!(dispatcher.0.guilds.lock().unwrap().is_empty());
+
+        // Guilds are forgotten once the bot leaves them, even if their
+        // deletion isn't handled.
+        dispatcher
+            .0
+            .guilds
+            .lock()
+            .unwrap()
+            .insert(GuildId(2), (u64::MAX, oneshot::channel().1));
+        assert!(dispatcher
+            .dispatch(
+                0,
+                Event::GuildDelete(Box::new(GuildDelete {
+                    id: GuildId(2),
+                    unavailable: false,
+                })),
+            )
+            .is_none());
+        assert!(dispatcher.0.guilds.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit() {
+        let current = Arc::new(AtomicUsize::new(0));
+        let max = Arc::new(AtomicUsize::new(0));
+        let (c, m) = (Arc::clone(&current), Arc::clone(&max));
+
+        let dispatcher = Dispatcher::builder()
+            .concurrency_limit(Some(2))
+            .on(move |_: RoleDelete| {
+                let (current, max) = (Arc::clone(&c), Arc::clone(&m));
+
+                async move {
+                    let now = current.fetch_add(1, Ordering::SeqCst) + 1;
+                    max.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    current.fetch_sub(1, Ordering::SeqCst);
+
+                    Ok::<_, HandlerError>(())
+                }
+            })
+            .build();
+
+        let tasks = (0..6)
+            .map(|id| dispatcher.dispatch(0, role_delete(id, id)).unwrap())
+            .collect::<Vec<_>>();
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(2, max.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::gateway::event::EventType;
+
+/// Error returned by a handler.
+pub type HandlerError = Box<dyn Error + Send + Sync>;
+
+/// Processing an event with a handler failed.
+///
+/// Errors are passed to the error reporter of the dispatcher, configured via
+/// [`DispatcherBuilder::on_error`].
+///
+/// [`DispatcherBuilder::on_error`]: crate::DispatcherBuilder::on_error
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DispatchError {
+    /// An argument of the handler couldn't be extracted from the event.
+    ///
+    /// This happens when using the [`Cache`] extractor without configuring a
+    /// cache.
+    ///
+    /// [`Cache`]: crate::Cache
+    Extracting {
+        /// Type of the event.
+        event_type: EventType,
+        /// Name of the type that couldn't be extracted.
+        extractor: &'static str,
+        /// ID of the shard that received the event.
+        shard_id: u64,
+    },
+    /// The handler returned an error.
+    Handler {
+        /// Type of the event.
+        event_type: EventType,
+        /// ID of the shard that received the event.
+        shard_id: u64,
+        /// Error returned by the handler.
+        source: HandlerError,
+    },
+    /// The handler panicked.
+    Panicked {
+        /// Type of the event.
+        event_type: EventType,
+        /// ID of the shard that received the event.
+        shard_id: u64,
+    },
+}
+
+impl DispatchError {
+    /// Return the type of the event that failed to be processed.
+    pub fn event_type(&self) -> EventType {
+        match self {
+            Self::Extracting { event_type, .. }
+            | Self::Handler { event_type, .. }
+            | Self::Panicked { event_type, .. } => *event_type,
+        }
+    }
+
+    /// Return the ID of the shard that received the event.
+    pub fn shard_id(&self) -> u64 {
+        match self {
+            Self::Extracting { shard_id, .. }
+            | Self::Handler { shard_id, .. }
+            | Self::Panicked { shard_id, .. } => *shard_id,
+        }
+    }
+}
+
+impl Display for DispatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Extracting {
+                event_type,
+                extractor,
+                shard_id,
+            } => f.write_fmt(format_args!(
+                "failed to extract {} from {:?} event on shard {}",
+                extractor, event_type, shard_id
+            )),
+            Self::Handler {
+                event_type,
+                shard_id,
+                ..
+            } => f.write_fmt(format_args!(
+                "handler for {:?} event on shard {} failed",
+                event_type, shard_id
+            )),
+            Self::Panicked {
+                event_type,
+                shard_id,
+            } => f.write_fmt(format_args!(
+                "handler for {:?} event on shard {} panicked",
+                event_type, shard_id
+            )),
+        }
+    }
+}
+
+impl Error for DispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Handler { source, .. } => Some(&**source),
+            Self::Extracting { .. } | Self::Panicked { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DispatchError;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::gateway::event::EventType;
+
+    assert_impl_all!(DispatchError: Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_accessors() {
+        let error = DispatchError::Panicked {
+            event_type: EventType::MessageCreate,
+            shard_id: 2,
+        };
+
+        assert_eq!(EventType::MessageCreate, error.event_type());
+        assert_eq!(2, error.shard_id());
+        assert_eq!(
+            "handler for MessageCreate event on shard 2 panicked",
+            error.to_string()
+        );
+    }
+}
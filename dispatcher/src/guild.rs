@@ -0,0 +1,76 @@
+use twilight_model::{channel::Channel, gateway::event::Event, id::GuildId};
+
+/// Return the ID of the guild an event happened in, if any.
+pub fn event_guild_id(event: &Event) -> Option<GuildId> {
+    match event {
+        Event::AutoModerationActionExecution(e) => Some(e.guild_id),
+        Event::AutoModerationRuleCreate(e) => Some(e.guild_id),
+        Event::AutoModerationRuleDelete(e) => Some(e.guild_id),
+        Event::AutoModerationRuleUpdate(e) => Some(e.guild_id),
+        Event::BanAdd(e) => Some(e.guild_id),
+        Event::BanRemove(e) => Some(e.guild_id),
+        Event::ChannelCreate(e) => channel_guild_id(e),
+        Event::ChannelDelete(e) => channel_guild_id(e),
+        Event::ChannelPinsUpdate(e) => e.guild_id,
+        Event::ChannelUpdate(e) => channel_guild_id(e),
+        Event::GatewayHeartbeatAck => None,
+        Event::GatewayHeartbeat(_) => None,
+        Event::GatewayHello(_) => None,
+        Event::GatewayInvalidateSession(_) => None,
+        Event::GatewayReconnect => None,
+        Event::GiftCodeUpdate => None,
+        Event::GuildCreate(e) => Some(e.id),
+        Event::GuildDelete(e) => Some(e.id),
+        Event::GuildEmojisUpdate(e) => Some(e.guild_id),
+        Event::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventCreate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventDelete(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
+        Event::GuildStickersUpdate(e) => Some(e.guild_id),
+        Event::GuildUpdate(e) => Some(e.id),
+        Event::InviteCreate(e) => Some(e.guild_id),
+        Event::InviteDelete(e) => Some(e.guild_id),
+        Event::MemberAdd(e) => Some(e.guild_id),
+        Event::MemberChunk(e) => Some(e.guild_id),
+        Event::MemberRemove(e) => Some(e.guild_id),
+        Event::MemberUpdate(e) => Some(e.guild_id),
+        Event::MessageCreate(e) => e.guild_id,
+        Event::MessageDelete(e) => e.guild_id,
+        Event::MessageDeleteBulk(e) => e.guild_id,
+        Event::MessageUpdate(e) => e.guild_id,
+        Event::PresenceUpdate(e) => Some(e.guild_id),
+        Event::PresencesReplace => None,
+        Event::ReactionAdd(e) => e.guild_id,
+        Event::ReactionRemove(e) => e.guild_id,
+        Event::ReactionRemoveAll(e) => e.guild_id,
+        Event::ReactionRemoveEmoji(e) => Some(e.guild_id),
+        Event::Ready(_) => None,
+        Event::Resumed => None,
+        Event::RoleCreate(e) => Some(e.guild_id),
+        Event::RoleDelete(e) => Some(e.guild_id),
+        Event::RoleUpdate(e) => Some(e.guild_id),
+        Event::ShardConnected(_) => None,
+        Event::ShardConnecting(_) => None,
+        Event::ShardDisconnected(_) => None,
+        Event::ShardIdentifying(_) => None,
+        Event::ShardPayload(_) => None,
+        Event::ShardReconnecting(_) => None,
+        Event::ShardResuming(_) => None,
+        Event::ShardStopped(_) => None,
+        Event::TypingStart(e) => e.guild_id,
+        Event::UnavailableGuild(e) => Some(e.id),
+        Event::UserUpdate(_) => None,
+        Event::VoiceServerUpdate(e) => e.guild_id,
+        Event::VoiceStateUpdate(e) => e.0.guild_id,
+        Event::WebhooksUpdate(e) => Some(e.guild_id),
+    }
+}
+
+fn channel_guild_id(channel: &Channel) -> Option<GuildId> {
+    match channel {
+        Channel::Guild(c) => c.guild_id(),
+        Channel::Group(_) | Channel::Private(_) => None,
+    }
+}
@@ -0,0 +1,162 @@
+//! Handlers processing dispatched events.
+
+use crate::{
+    context::{Context, FromContext},
+    error::HandlerError,
+};
+use futures_util::future::{BoxFuture, FutureExt};
+use std::{
+    any,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+};
+use twilight_model::gateway::event::EventType;
+
+/// Future returned by calling a handler.
+pub(crate) type HandlerFuture = BoxFuture<'static, Result<(), HandlerError>>;
+
+/// Handler with its argument types erased.
+type HandlerFn = dyn Fn(&Context) -> Result<HandlerFuture, &'static str> + Send + Sync;
+
+/// Function that processes events.
+///
+/// Handlers are implemented for async functions and closures returning a
+/// `Result<(), HandlerError>` and taking up to 4 arguments implementing
+/// [`FromContext`], such as event payloads, [`ShardId`], [`Cache`], and
+/// [`Event`].
+///
+/// The event type of a handler is taken from its first argument that is an
+/// event payload. Handlers without a payload argument are called for every
+/// event.
+///
+/// [`Cache`]: crate::Cache
+/// [`Event`]: twilight_model::gateway::event::Event
+/// [`ShardId`]: crate::ShardId
+pub trait Handler<Args>: Send + Sync + 'static {
+    /// Return the event type the handler processes, or `None` if it processes
+    /// every event.
+    fn event_type(&self) -> Option<EventType>;
+
+    /// Extract the arguments of the handler from the context and call it.
+    ///
+    /// Returns the name of the type of the first argument that couldn't be
+    /// extracted if extracting failed.
+    #[doc(hidden)]
+    fn call(&self, context: &Context) -> Result<HandlerFuture, &'static str>;
+}
+
+macro_rules! impl_handler {
+    ($($arg:ident),*) => {
+        impl<F, Fut, $($arg,)*> Handler<($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+            $($arg: FromContext + Send + 'static,)*
+        {
+            fn event_type(&self) -> Option<EventType> {
+                #[allow(unused_mut)]
+                let mut event_type = None;
+                $(event_type = event_type.or($arg::EVENT_TYPE);)*
+
+                event_type
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            fn call(&self, context: &Context) -> Result<HandlerFuture, &'static str> {
+                $(
+                    let $arg = $arg::from_context(context)
+                        .ok_or_else(any::type_name::<$arg>)?;
+                )*
+
+                Ok((self)($($arg),*).boxed())
+            }
+        }
+    };
+}
+
+impl_handler!();
+impl_handler!(A);
+impl_handler!(A, B);
+impl_handler!(A, B, C);
+impl_handler!(A, B, C, D);
+
+/// Handler with its argument types erased, so that handlers with different
+/// arguments can be stored together.
+pub(crate) struct BoxedHandler {
+    call: Box<HandlerFn>,
+    event_type: Option<EventType>,
+}
+
+impl BoxedHandler {
+    pub fn new<Args>(handler: impl Handler<Args>, event_type: Option<EventType>) -> Self {
+        Self {
+            call: Box::new(move |context| handler.call(context)),
+            event_type,
+        }
+    }
+
+    pub fn call(&self, context: &Context) -> Result<HandlerFuture, &'static str> {
+        (self.call)(context)
+    }
+
+    pub fn event_type(&self) -> Option<EventType> {
+        self.event_type
+    }
+}
+
+impl Debug for BoxedHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("BoxedHandler")
+            .field("call", &"<fn>")
+            .field("event_type", &self.event_type)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Handler;
+    use crate::{
+        context::{Cache, Context, ShardId},
+        error::HandlerError,
+    };
+    use twilight_model::gateway::{
+        event::{Event, EventType},
+        payload::{MessageCreate, RoleDelete},
+    };
+
+    async fn catch_all(_: Event) -> Result<(), HandlerError> {
+        Ok(())
+    }
+
+    async fn role_delete(_: ShardId, _: RoleDelete) -> Result<(), HandlerError> {
+        Ok(())
+    }
+
+    async fn message_create(_: MessageCreate, _: Cache) -> Result<(), HandlerError> {
+        Ok(())
+    }
+
+    fn event_type<Args>(handler: &impl Handler<Args>) -> Option<EventType> {
+        handler.event_type()
+    }
+
+    #[test]
+    fn test_event_type() {
+        assert!(event_type(&catch_all).is_none());
+        assert!(event_type(&|| async { Ok::<_, HandlerError>(()) }).is_none());
+        assert_eq!(Some(EventType::RoleDelete), event_type(&role_delete));
+        assert_eq!(Some(EventType::MessageCreate), event_type(&message_create));
+    }
+
+    #[test]
+    fn test_call_extraction_failed() {
+        let context = Context::new(0, Event::GatewayReconnect, None);
+
+        assert!(Handler::call(&catch_all, &context).is_ok());
+        assert_eq!(
+            Some("twilight_dispatcher::context::Cache"),
+            Handler::call(&|_: Cache| async { Ok::<_, HandlerError>(()) }, &context).err()
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! # twilight-dispatcher
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-dispatcher` routes the events received by a cluster or shard to
+//! handlers registered for each event type, instead of matching on every
+//! event in one large event loop.
+//!
+//! Handlers are async functions taking arguments that are extracted from the
+//! event, such as the event payload, the ID of the shard that received the
+//! event, or a handle to the cache. The event type a handler processes is
+//! taken from its payload argument.
+//!
+//! Each event is processed in its own task. The dispatcher can limit how many
+//! events are processed at the same time, process the events of each guild in
+//! the order they were received, run middleware before and after handlers,
+//! and report handler errors and panics to a function of your choice.
+//!
+//! ## Examples
+//!
+//! Handle messages and ready events from a cluster, processing events of each
+//! guild in order:
+//!
+//! ```rust,no_run
+//! use std::{env, error::Error};
+//! use twilight_cache_inmemory::InMemoryCache;
+//! use twilight_dispatcher::{Cache, Dispatcher, HandlerError, ShardId};
+//! use twilight_gateway::{Cluster, Intents};
+//! use twilight_model::gateway::payload::{MessageCreate, Ready};
+//!
+//! async fn message(msg: MessageCreate, Cache(cache): Cache) -> Result<(), HandlerError> {
+//!     if let Some(channel) = cache.guild_channel(msg.channel_id) {
+//!         println!("message in {}: {}", channel.name(), msg.content);
+//!     }
+//!
+//!     Ok(())
+//! }
+//!
+//! async fn ready(ready: Ready, shard: ShardId) -> Result<(), HandlerError> {
+//!     println!("shard {} ready as {}", shard.0, ready.user.name);
+//!
+//!     Ok(())
+//! }
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn Error>> {
+//! let dispatcher = Dispatcher::builder()
+//!     .cache(InMemoryCache::new())
+//!     .guild_ordering(true)
+//!     .on(message)
+//!     .on(ready)
+//!     .on_error(|error| eprintln!("error processing event: {}", error))
+//!     .build();
+//!
+//! let intents = Intents::GUILDS | Intents::GUILD_MESSAGES;
+//! let cluster = Cluster::new(env::var("DISCORD_TOKEN")?, intents).await?;
+//! cluster.up().await;
+//!
+//! dispatcher.run(cluster.some_events(dispatcher.event_types())).await;
+//! # Ok(()) }
+//! ```
+//!
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+pub mod context;
+pub mod handler;
+pub mod middleware;
+
+mod builder;
+mod dispatcher;
+mod error;
+mod guild;
+
+pub use self::{
+    builder::DispatcherBuilder,
+    context::{Cache, Context, FromContext, ShardId},
+    dispatcher::Dispatcher,
+    error::{DispatchError, HandlerError},
+    handler::Handler,
+    middleware::Middleware,
+};
@@ -0,0 +1,51 @@
+//! Hooks running before and after handlers.
+
+use crate::context::Context;
+use std::fmt::Debug;
+
+/// Hooks called before and after the handlers of each event.
+///
+/// Middleware is called in the order it was added before handlers, and in
+/// the reverse order after handlers. Middleware is only called for events
+/// that have at least one handler.
+///
+/// # Examples
+///
+/// Ignore events from shard 0 and log when handlers finish:
+///
+/// ```rust
+/// use twilight_dispatcher::{Context, Middleware};
+///
+/// #[derive(Debug)]
+/// struct SkipFirstShard;
+///
+/// impl Middleware for SkipFirstShard {
+///     fn before(&self, context: &Context) -> bool {
+///         context.shard_id() != 0
+///     }
+///
+///     fn after(&self, context: &Context) {
+///         println!("processed {:?}", context.event().kind());
+///     }
+/// }
+/// ```
+pub trait Middleware: Debug + Send + Sync + 'static {
+    /// Hook called before the handlers of an event are called.
+    ///
+    /// Return `false` to skip the event, in which case no handlers, later
+    /// middleware, or `after` hooks are called.
+    ///
+    /// Defaults to returning `true`.
+    fn before(&self, context: &Context) -> bool {
+        let _ = context;
+
+        true
+    }
+
+    /// Hook called after every handler of an event has finished.
+    ///
+    /// Defaults to doing nothing.
+    fn after(&self, context: &Context) {
+        let _ = context;
+    }
+}
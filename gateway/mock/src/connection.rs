@@ -0,0 +1,437 @@
+use super::{
+    deflater::Deflater,
+    script::{Action, Script},
+    server::{Command, State},
+};
+use async_tungstenite::{
+    tokio::{accept_async, TokioAdapter},
+    tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    },
+    WebSocketStream,
+};
+use futures_channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures_util::{
+    sink::SinkExt,
+    stream::{SplitSink, SplitStream, StreamExt},
+};
+use serde_json::Value;
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::net::TcpStream;
+use twilight_model::gateway::OpCode;
+
+type Stream = WebSocketStream<TokioAdapter<TcpStream>>;
+
+/// Message to send to the client.
+#[derive(Debug)]
+enum Outgoing {
+    Close { code: u16, reason: String },
+    Payload(Payload),
+}
+
+/// Gateway payload to send to the client.
+#[derive(Debug)]
+struct Payload {
+    data: Value,
+    kind: Option<String>,
+    op: u8,
+    seq: Option<u64>,
+}
+
+impl Payload {
+    fn new(op: OpCode, data: Value) -> Self {
+        Self {
+            data,
+            kind: None,
+            op: op as u8,
+            seq: None,
+        }
+    }
+
+    fn dispatch(seq: u64, kind: String, data: Value) -> Self {
+        Self {
+            data,
+            kind: Some(kind),
+            op: OpCode::Event as u8,
+            seq: Some(seq),
+        }
+    }
+
+    /// Serialize the payload.
+    ///
+    /// Clients look for the opcode, sequence, and event type before the data,
+    /// so the fields are written in the same order Discord sends them.
+    fn to_bytes(&self) -> Vec<u8> {
+        let kind = self
+            .kind
+            .as_ref()
+            .map_or(Value::Null, |kind| Value::String(kind.clone()));
+        let seq = self.seq.map_or(Value::Null, Value::from);
+
+        format!(
+            r#"{{"t":{},"s":{},"op":{},"d":{}}}"#,
+            kind, seq, self.op, self.data
+        )
+        .into_bytes()
+    }
+}
+
+/// The client disconnected before the script finished.
+#[derive(Debug)]
+struct Disconnected;
+
+/// Connection to the mock gateway performing a script.
+pub struct Connection {
+    acks: Arc<AtomicBool>,
+    commands: UnboundedReceiver<Command>,
+    id: u64,
+    outgoing: UnboundedSender<Outgoing>,
+    /// Sequence used for dispatches sent before a session was established.
+    seq: u64,
+    session: Option<String>,
+    state: Arc<State>,
+}
+
+impl Connection {
+    pub async fn run(id: u64, stream: TcpStream, script: Script, state: Arc<State>) {
+        let stream = match accept_async(stream).await {
+            Ok(stream) => stream,
+            Err(source) => {
+                tracing::warn!(connection = id, "websocket handshake failed: {}", source);
+
+                return;
+            }
+        };
+
+        let (sink, stream) = stream.split();
+        let (outgoing_tx, outgoing_rx) = mpsc::unbounded();
+        let (commands_tx, commands_rx) = mpsc::unbounded();
+        let acks = Arc::new(AtomicBool::new(true));
+
+        tokio::spawn(write(id, sink, outgoing_rx));
+        tokio::spawn(read(
+            id,
+            stream,
+            commands_tx,
+            outgoing_tx.clone(),
+            Arc::clone(&acks),
+            Arc::clone(&state),
+        ));
+
+        let mut connection = Self {
+            acks,
+            commands: commands_rx,
+            id,
+            outgoing: outgoing_tx,
+            seq: 0,
+            session: None,
+            state,
+        };
+
+        if connection.perform(script).await.is_err() {
+            tracing::debug!(connection = id, "client disconnected during script");
+
+            return;
+        }
+
+        // Keep the connection open until the client disconnects.
+        while connection.commands.next().await.is_some() {}
+
+        tracing::debug!(connection = id, "client disconnected");
+    }
+
+    async fn perform(&mut self, script: Script) -> Result<(), Disconnected> {
+        for action in script.actions {
+            tracing::trace!(connection = self.id, ?action, "performing action");
+
+            match action {
+                Action::AwaitCommand(op) => while self.next_command().await?.op != op {},
+                Action::AwaitSession => self.await_session().await?,
+                Action::Close { code, reason } => {
+                    let _ = self
+                        .outgoing
+                        .unbounded_send(Outgoing::Close { code, reason });
+
+                    return Ok(());
+                }
+                Action::Dispatch { kind, data } => self.dispatch(kind, data),
+                Action::Heartbeat => {
+                    let seq = self.current_seq();
+
+                    self.send(Payload::new(OpCode::Heartbeat, Value::from(seq)));
+                }
+                Action::HeartbeatAcks(enabled) => self.acks.store(enabled, Ordering::Relaxed),
+                Action::Hello => {
+                    let data = serde_json::json!({
+                        "heartbeat_interval": self.state.heartbeat_interval,
+                    });
+
+                    self.send(Payload::new(OpCode::Hello, data));
+                }
+                Action::InvalidSession(resumable) => {
+                    self.send(Payload::new(OpCode::InvalidSession, Value::Bool(resumable)));
+                }
+                Action::Raw(payload) => {
+                    let op = payload
+                        .get("op")
+                        .and_then(Value::as_u64)
+                        .and_then(|op| u8::try_from(op).ok())
+                        .unwrap_or_default();
+
+                    self.send(Payload {
+                        data: payload.get("d").cloned().unwrap_or(Value::Null),
+                        kind: payload
+                            .get("t")
+                            .and_then(Value::as_str)
+                            .map(ToOwned::to_owned),
+                        op,
+                        seq: payload.get("s").and_then(Value::as_u64),
+                    });
+                }
+                Action::Reconnect => self.send(Payload::new(OpCode::Reconnect, Value::Null)),
+                Action::Sleep(duration) => tokio::time::sleep(duration).await,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Wait for an IDENTIFY or RESUME and reply to it.
+    async fn await_session(&mut self) -> Result<(), Disconnected> {
+        loop {
+            let command = self.next_command().await?;
+
+            match command.op {
+                OpCode::Identify => {
+                    self.identify(&command.data);
+
+                    return Ok(());
+                }
+                OpCode::Resume => {
+                    self.resume(&command.data);
+
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn identify(&mut self, data: &Value) {
+        let id = self.state.start_session();
+        let mut ready = self.state.ready.clone();
+
+        if let Some(ready) = ready.as_object_mut() {
+            ready.insert("session_id".to_owned(), Value::String(id.clone()));
+
+            if let Some(shard) = data.get("shard") {
+                ready.insert("shard".to_owned(), shard.clone());
+            }
+        }
+
+        tracing::debug!(connection = self.id, session_id = %id, "client identified");
+
+        self.session.replace(id);
+        self.dispatch("READY".to_owned(), ready);
+    }
+
+    fn resume(&mut self, data: &Value) {
+        let session_id = data.get("session_id").and_then(Value::as_str);
+        let seq = data.get("seq").and_then(Value::as_u64).unwrap_or_default();
+
+        let replay = session_id.and_then(|id| {
+            let sessions = self.state.sessions.lock().expect("sessions poisoned");
+
+            sessions.get(id).map(|session| {
+                session
+                    .dispatches
+                    .iter()
+                    .filter(|dispatch| dispatch.seq > seq)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        });
+
+        let replay = if let Some(replay) = replay {
+            replay
+        } else {
+            tracing::debug!(connection = self.id, ?session_id, "unknown session resumed");
+
+            self.send(Payload::new(OpCode::InvalidSession, Value::Bool(false)));
+
+            return;
+        };
+
+        tracing::debug!(
+            connection = self.id,
+            ?session_id,
+            replayed = replay.len(),
+            "client resumed",
+        );
+
+        self.session = session_id.map(ToOwned::to_owned);
+
+        for dispatch in replay {
+            self.send(Payload::dispatch(
+                dispatch.seq,
+                dispatch.kind,
+                dispatch.data,
+            ));
+        }
+
+        self.dispatch("RESUMED".to_owned(), Value::Null);
+    }
+
+    /// Send a dispatch event, recording it in the session if there is one.
+    fn dispatch(&mut self, kind: String, data: Value) {
+        let seq = if let Some(id) = self.session.as_ref() {
+            let mut sessions = self.state.sessions.lock().expect("sessions poisoned");
+
+            sessions
+                .get_mut(id)
+                .map(|session| session.dispatch(kind.clone(), data.clone()))
+        } else {
+            None
+        };
+
+        let seq = seq.unwrap_or_else(|| {
+            self.seq += 1;
+
+            self.seq
+        });
+
+        self.send(Payload::dispatch(seq, kind, data));
+    }
+
+    fn current_seq(&self) -> u64 {
+        self.session
+            .as_ref()
+            .and_then(|id| {
+                let sessions = self.state.sessions.lock().expect("sessions poisoned");
+
+                sessions.get(id).map(|session| session.seq)
+            })
+            .unwrap_or(self.seq)
+    }
+
+    async fn next_command(&mut self) -> Result<Command, Disconnected> {
+        self.commands.next().await.ok_or(Disconnected)
+    }
+
+    fn send(&self, payload: Payload) {
+        let _ = self.outgoing.unbounded_send(Outgoing::Payload(payload));
+    }
+}
+
+/// Forward outgoing messages to the client, compressing payloads.
+async fn write(id: u64, mut sink: SplitSink<Stream, Message>, mut rx: UnboundedReceiver<Outgoing>) {
+    let mut deflater = Deflater::new();
+
+    while let Some(outgoing) = rx.next().await {
+        let message = match outgoing {
+            Outgoing::Close { code, reason } => Message::Close(Some(CloseFrame {
+                code: CloseCode::from(code),
+                reason: Cow::Owned(reason),
+            })),
+            Outgoing::Payload(payload) => match deflater.compress(&payload.to_bytes()) {
+                Ok(bytes) => Message::Binary(bytes),
+                Err(source) => {
+                    tracing::warn!(connection = id, "compressing payload failed: {}", source);
+
+                    continue;
+                }
+            },
+        };
+
+        let close = matches!(message, Message::Close(_));
+
+        if let Err(source) = sink.send(message).await {
+            tracing::debug!(connection = id, "sending message failed: {}", source);
+
+            break;
+        }
+
+        if close {
+            break;
+        }
+    }
+}
+
+/// Read commands from the client, recording them and acknowledging
+/// heartbeats.
+async fn read(
+    id: u64,
+    mut stream: SplitStream<Stream>,
+    commands: UnboundedSender<Command>,
+    outgoing: UnboundedSender<Outgoing>,
+    acks: Arc<AtomicBool>,
+    state: Arc<State>,
+) {
+    while let Some(Ok(message)) = stream.next().await {
+        let bytes = match message {
+            Message::Binary(bytes) => bytes,
+            Message::Text(text) => text.into_bytes(),
+            Message::Close(frame) => {
+                tracing::debug!(connection = id, ?frame, "client closed connection");
+
+                break;
+            }
+            Message::Ping(_) | Message::Pong(_) => continue,
+        };
+
+        let mut value = match serde_json::from_slice::<Value>(&bytes) {
+            Ok(value) => value,
+            Err(source) => {
+                tracing::warn!(connection = id, "client sent invalid json: {}", source);
+
+                continue;
+            }
+        };
+
+        let op = if let Some(Ok(op)) = value
+            .get("op")
+            .cloned()
+            .map(serde_json::from_value::<OpCode>)
+        {
+            op
+        } else {
+            tracing::warn!(connection = id, ?value, "client sent unknown opcode");
+
+            continue;
+        };
+
+        let data = value
+            .as_object_mut()
+            .and_then(|object| object.remove("d"))
+            .unwrap_or(Value::Null);
+
+        let command = Command {
+            connection: id,
+            data,
+            op,
+        };
+
+        tracing::trace!(connection = id, ?command, "received command");
+
+        state
+            .commands
+            .lock()
+            .expect("commands poisoned")
+            .push(command.clone());
+
+        if op == OpCode::Heartbeat && acks.load(Ordering::Relaxed) {
+            let ack = Payload::new(OpCode::HeartbeatAck, Value::Null);
+            let _ = outgoing.unbounded_send(Outgoing::Payload(ack));
+        }
+
+        let _ = commands.unbounded_send(command);
+    }
+}
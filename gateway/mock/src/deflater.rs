@@ -0,0 +1,81 @@
+use flate2::{Compress, CompressError, Compression, FlushCompress, Status};
+
+/// Compressor for a connection's `zlib-stream` context.
+///
+/// Discord compresses all payloads of a connection through a single zlib
+/// context, flushing it after every payload so that each message ends with
+/// the `00 00 ff ff` suffix that clients look for.
+#[derive(Debug)]
+pub struct Deflater {
+    compress: Compress,
+}
+
+impl Deflater {
+    /// Size of the chunks the output buffer grows by.
+    const CHUNK_SIZE: usize = 4 * 1024;
+
+    pub fn new() -> Self {
+        Self {
+            compress: Compress::new(Compression::default(), true),
+        }
+    }
+
+    /// Compress a payload, returning the bytes to send in a binary message.
+    pub fn compress(&mut self, payload: &[u8]) -> Result<Vec<u8>, CompressError> {
+        let mut output = Vec::with_capacity(payload.len() + Self::CHUNK_SIZE);
+        let before = self.compress.total_in();
+
+        loop {
+            #[allow(clippy::cast_possible_truncation)]
+            let offset = (self.compress.total_in() - before) as usize;
+
+            if output.len() == output.capacity() {
+                output.reserve(Self::CHUNK_SIZE);
+            }
+
+            let status =
+                self.compress
+                    .compress_vec(&payload[offset..], &mut output, FlushCompress::Sync)?;
+
+            let consumed = self.compress.total_in() - before == payload.len() as u64;
+
+            // The flush is only complete once all input was consumed and
+            // there was space left over in the output buffer.
+            if consumed && output.len() < output.capacity() {
+                break;
+            }
+
+            if let Status::StreamEnd = status {
+                break;
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Deflater;
+    use flate2::{Decompress, FlushDecompress};
+
+    #[test]
+    fn test_sync_flushed_payloads() {
+        let mut deflater = Deflater::new();
+        let mut decompress = Decompress::new(true);
+
+        for payload in &[b"{\"op\":10}".to_vec(), vec![b'a'; 20_000]] {
+            let compressed = deflater.compress(payload).unwrap();
+            assert_eq!(
+                &compressed[compressed.len() - 4..],
+                &[0x00, 0x00, 0xff, 0xff]
+            );
+
+            let mut output = Vec::with_capacity(payload.len() * 2);
+            decompress
+                .decompress_vec(&compressed, &mut output, FlushDecompress::Sync)
+                .unwrap();
+            assert_eq!(&output, payload);
+        }
+    }
+}
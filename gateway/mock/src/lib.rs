@@ -0,0 +1,86 @@
+//! # twilight-gateway-mock
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-gateway-mock` is an in-process mock of Discord's gateway for
+//! testing shards, clusters, and anything built on top of them without
+//! connecting to Discord.
+//!
+//! The [`MockGateway`] listens on a local port and speaks enough of the
+//! gateway protocol for a `Shard` to connect to it: it sends a HELLO, accepts
+//! IDENTIFY and RESUME commands, acknowledges heartbeats, and compresses
+//! everything it sends with a `zlib-stream` context like Discord does.
+//!
+//! Each connection made to the mock is driven by a [`Script`]. Scripts are
+//! lists of [`Action`]s such as sending dispatch events, invalidating the
+//! session, or closing the connection with a [`CloseCode`]. Scripts are used
+//! in the order they're queued; connections made after the queue is empty
+//! use the default script, which performs the handshake and then idles.
+//!
+//! Every command sent by a client is recorded and can be inspected via
+//! [`MockGateway::commands`].
+//!
+//! ## Examples
+//!
+//! Start a mock gateway, queue a script that dispatches an event and then
+//! closes the connection, and connect a shard to it:
+//!
+//! ```rust,ignore
+//! use twilight_gateway::Shard;
+//! use twilight_gateway_mock::{MockGateway, MockQueue, Script};
+//! use twilight_model::gateway::{CloseCode, Intents};
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let gateway = MockGateway::builder().bind().await?;
+//! gateway.push_script(
+//!     Script::new()
+//!         .dispatch("TYPING_START", serde_json::json!({
+//!             "channel_id": "1",
+//!             "timestamp": 1,
+//!             "user_id": "2",
+//!         }))
+//!         .close(CloseCode::UnknownError),
+//! );
+//!
+//! let mut shard = Shard::builder("token", Intents::empty())
+//!     .gateway_url(Some(gateway.url()))
+//!     .queue(Arc::new(Box::new(MockQueue)))
+//!     .build();
+//! shard.start().await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`CloseCode`]: twilight_model::gateway::CloseCode
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+mod connection;
+mod deflater;
+mod queue;
+mod script;
+mod server;
+
+pub use self::{
+    queue::MockQueue,
+    script::{Action, Script},
+    server::{Command, MockGateway, MockGatewayBuilder},
+};
@@ -0,0 +1,16 @@
+use std::{future::Future, pin::Pin};
+use twilight_gateway_queue::Queue;
+
+/// Queue that allows shards to identify immediately.
+///
+/// The default `LocalQueue` waits 6 seconds between identifies to respect
+/// Discord's session start ratelimit. The mock gateway has no such ratelimit,
+/// so tests that reconnect shards can use this to avoid waiting.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MockQueue;
+
+impl Queue for MockQueue {
+    fn request(&'_ self, _: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async {})
+    }
+}
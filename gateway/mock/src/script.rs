@@ -0,0 +1,219 @@
+use serde_json::Value;
+use std::time::Duration;
+use twilight_model::gateway::{CloseCode, OpCode};
+
+/// Step performed by a connection to the mock gateway.
+///
+/// Refer to the [`Script`] methods for what each action does.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Action {
+    /// Wait until the client sends a command with an opcode.
+    AwaitCommand(OpCode),
+    /// Wait for the client to identify or resume and reply accordingly.
+    AwaitSession,
+    /// Close the connection with a close code and end the script.
+    Close {
+        /// Close code to send.
+        code: u16,
+        /// Reason for the close.
+        reason: String,
+    },
+    /// Send a dispatch event.
+    Dispatch {
+        /// Type of the dispatch event, such as `GUILD_CREATE`.
+        kind: String,
+        /// Data of the event.
+        data: Value,
+    },
+    /// Request the client to send a heartbeat.
+    Heartbeat,
+    /// Set whether received heartbeats are acknowledged.
+    HeartbeatAcks(bool),
+    /// Send a HELLO.
+    Hello,
+    /// Send an `INVALID_SESSION`, specifying whether the session is resumable.
+    InvalidSession(bool),
+    /// Send a RECONNECT.
+    Reconnect,
+    /// Send a payload as-is, without assigning it a sequence number.
+    Raw(Value),
+    /// Wait for a duration.
+    Sleep(Duration),
+}
+
+/// Sequence of actions performed by a connection to the mock gateway.
+///
+/// Scripts created via [`Script::new`] start with the usual handshake: a HELLO
+/// is sent and the connection waits for the client to identify or resume.
+/// Use [`Script::empty`] to start without it.
+///
+/// Once all actions have been performed the connection stays open, replying to
+/// heartbeats until the client disconnects.
+///
+/// # Examples
+///
+/// Send a guild create after the session has been established, invalidate
+/// the session, and then close the connection:
+///
+/// ```rust
+/// use twilight_gateway_mock::Script;
+/// use twilight_model::gateway::CloseCode;
+///
+/// let script = Script::new()
+///     .dispatch("GUILD_CREATE", serde_json::json!({ "id": "1", "unavailable": true }))
+///     .invalid_session(false)
+///     .close(CloseCode::SessionTimedOut);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Script {
+    pub(crate) actions: Vec<Action>,
+}
+
+impl Script {
+    /// Create a new script starting with the gateway handshake.
+    pub fn new() -> Self {
+        Self {
+            actions: vec![Action::Hello, Action::AwaitSession],
+        }
+    }
+
+    /// Create a new script with no actions.
+    pub fn empty() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// Add an action to the script.
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+
+        self
+    }
+
+    /// Wait until the client sends a command with the given opcode.
+    ///
+    /// Commands sent before this action is performed aren't considered.
+    pub fn await_command(self, op: OpCode) -> Self {
+        self.action(Action::AwaitCommand(op))
+    }
+
+    /// Wait for the client to send an IDENTIFY or RESUME.
+    ///
+    /// When identifying a new session is started and a READY is sent. When
+    /// resuming a known session the dispatch events the client missed are
+    /// replayed followed by a RESUMED. Resuming an unknown session sends an
+    /// `INVALID_SESSION` that isn't resumable.
+    pub fn await_session(self) -> Self {
+        self.action(Action::AwaitSession)
+    }
+
+    /// Close the connection with a close code and end the script.
+    pub fn close(self, code: CloseCode) -> Self {
+        self.close_raw(code as u16, format!("{:?}", code))
+    }
+
+    /// Close the connection with a raw close code and end the script.
+    ///
+    /// Useful for close codes not known to [`CloseCode`].
+    pub fn close_raw(self, code: u16, reason: impl Into<String>) -> Self {
+        self.action(Action::Close {
+            code,
+            reason: reason.into(),
+        })
+    }
+
+    /// Send a dispatch event with the next sequence number of the session.
+    pub fn dispatch(self, kind: impl Into<String>, data: Value) -> Self {
+        self.action(Action::Dispatch {
+            kind: kind.into(),
+            data,
+        })
+    }
+
+    /// Request the client to send a heartbeat.
+    pub fn heartbeat(self) -> Self {
+        self.action(Action::Heartbeat)
+    }
+
+    /// Set whether heartbeats sent by the client are acknowledged.
+    ///
+    /// Heartbeats are acknowledged by default. Disabling this simulates a
+    /// zombied connection.
+    pub fn heartbeat_acks(self, enabled: bool) -> Self {
+        self.action(Action::HeartbeatAcks(enabled))
+    }
+
+    /// Send a HELLO with the gateway's heartbeat interval.
+    pub fn hello(self) -> Self {
+        self.action(Action::Hello)
+    }
+
+    /// Send an `INVALID_SESSION`.
+    pub fn invalid_session(self, resumable: bool) -> Self {
+        self.action(Action::InvalidSession(resumable))
+    }
+
+    /// Send a payload as-is.
+    ///
+    /// The payload isn't validated and isn't recorded in the session's
+    /// history, so it won't be replayed when resuming.
+    pub fn raw(self, payload: Value) -> Self {
+        self.action(Action::Raw(payload))
+    }
+
+    /// Send a RECONNECT.
+    pub fn reconnect(self) -> Self {
+        self.action(Action::Reconnect)
+    }
+
+    /// Wait for a duration before performing the next action.
+    pub fn sleep(self, duration: Duration) -> Self {
+        self.action(Action::Sleep(duration))
+    }
+
+    /// Return an immutable reference to the actions of the script.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+}
+
+impl Default for Script {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Script};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::gateway::CloseCode;
+
+    assert_impl_all!(Script: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(Action: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_new_starts_with_handshake() {
+        assert_eq!(
+            Script::new().actions(),
+            &[Action::Hello, Action::AwaitSession]
+        );
+        assert!(Script::empty().actions().is_empty());
+    }
+
+    #[test]
+    fn test_close() {
+        let script = Script::empty().close(CloseCode::AuthenticationFailed);
+
+        assert_eq!(
+            script.actions(),
+            &[Action::Close {
+                code: 4004,
+                reason: "AuthenticationFailed".to_owned(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use super::{connection::Connection, script::Script};
+use serde_json::Value;
+use std::{
+    collections::{HashMap, VecDeque},
+    io::Result as IoResult,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{net::TcpListener, task::JoinHandle};
+use twilight_model::gateway::OpCode;
+
+/// Command sent by a client to the mock gateway.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Command {
+    /// Index of the connection that sent the command, starting at 0.
+    pub connection: u64,
+    /// Data of the command, or [`Value::Null`] if there was none.
+    pub data: Value,
+    /// Opcode of the command.
+    pub op: OpCode,
+}
+
+/// Dispatch event sent in a session.
+#[derive(Clone, Debug)]
+pub(crate) struct Dispatch {
+    pub data: Value,
+    pub kind: String,
+    pub seq: u64,
+}
+
+/// Session created by a client identifying.
+#[derive(Debug, Default)]
+pub(crate) struct Session {
+    pub dispatches: Vec<Dispatch>,
+    pub seq: u64,
+}
+
+impl Session {
+    /// Record a dispatch event, returning its sequence number.
+    pub fn dispatch(&mut self, kind: String, data: Value) -> u64 {
+        self.seq += 1;
+        self.dispatches.push(Dispatch {
+            data,
+            kind,
+            seq: self.seq,
+        });
+
+        self.seq
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct State {
+    pub commands: Mutex<Vec<Command>>,
+    pub connections: AtomicU64,
+    pub heartbeat_interval: u64,
+    pub ready: Value,
+    pub scripts: Mutex<VecDeque<Script>>,
+    pub sessions: Mutex<HashMap<String, Session>>,
+}
+
+impl State {
+    /// Start a new session, returning its ID.
+    pub fn start_session(&self) -> String {
+        let mut sessions = self.sessions.lock().expect("sessions poisoned");
+        let id = format!("mock-session-{}", sessions.len());
+        sessions.insert(id.clone(), Session::default());
+
+        id
+    }
+}
+
+/// Builder for a [`MockGateway`].
+#[derive(Debug)]
+pub struct MockGatewayBuilder {
+    heartbeat_interval: u64,
+    ready: Value,
+}
+
+impl MockGatewayBuilder {
+    /// Create a new builder for a mock gateway.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind the mock gateway to a random local port and start accepting
+    /// connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns an IO error if binding the listener failed.
+    pub async fn bind(self) -> IoResult<MockGateway> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        let state = Arc::new(State {
+            commands: Mutex::new(Vec::new()),
+            connections: AtomicU64::new(0),
+            heartbeat_interval: self.heartbeat_interval,
+            ready: self.ready,
+            scripts: Mutex::new(VecDeque::new()),
+            sessions: Mutex::new(HashMap::new()),
+        });
+
+        let handle = tokio::spawn(accept(listener, Arc::clone(&state)));
+
+        Ok(MockGateway {
+            addr,
+            handle,
+            state,
+        })
+    }
+
+    /// Set the heartbeat interval sent in HELLO payloads, in milliseconds.
+    ///
+    /// The default is 41250 milliseconds.
+    pub fn heartbeat_interval(mut self, heartbeat_interval: u64) -> Self {
+        self.heartbeat_interval = heartbeat_interval;
+
+        self
+    }
+
+    /// Set the data of READY dispatch events.
+    ///
+    /// The `session_id` and `shard` fields are replaced with the values of the
+    /// identifying session.
+    ///
+    /// The default is a READY with no guilds and a bot user with an ID of 1.
+    pub fn ready(mut self, ready: Value) -> Self {
+        self.ready = ready;
+
+        self
+    }
+}
+
+impl Default for MockGatewayBuilder {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval: 41250,
+            ready: serde_json::json!({
+                "guilds": [],
+                "session_id": "",
+                "user": {
+                    "avatar": null,
+                    "bot": true,
+                    "discriminator": "0001",
+                    "id": "1",
+                    "mfa_enabled": false,
+                    "username": "twilight",
+                },
+                "v": 8,
+            }),
+        }
+    }
+}
+
+/// In-process mock of Discord's gateway.
+///
+/// Refer to the [crate-level] documentation for more information.
+///
+/// The mock stops accepting connections when dropped.
+///
+/// [crate-level]: crate
+#[derive(Debug)]
+pub struct MockGateway {
+    addr: SocketAddr,
+    handle: JoinHandle<()>,
+    state: Arc<State>,
+}
+
+impl MockGateway {
+    /// Bind a mock gateway with the default configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an IO error if binding the listener failed.
+    pub async fn bind() -> IoResult<Self> {
+        MockGatewayBuilder::new().bind().await
+    }
+
+    /// Create a builder to configure a mock gateway.
+    pub fn builder() -> MockGatewayBuilder {
+        MockGatewayBuilder::new()
+    }
+
+    /// Return the local address the mock gateway is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Return the URL to connect to the mock gateway with.
+    ///
+    /// Pass this to `ShardBuilder::gateway_url` or
+    /// `ClusterBuilder::gateway_url`.
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    /// Queue a script to run for the next connection without a script.
+    pub fn push_script(&self, script: Script) {
+        self.state
+            .scripts
+            .lock()
+            .expect("scripts poisoned")
+            .push_back(script);
+    }
+
+    /// Return a copy of all of the commands clients have sent.
+    pub fn commands(&self) -> Vec<Command> {
+        self.state
+            .commands
+            .lock()
+            .expect("commands poisoned")
+            .clone()
+    }
+
+    /// Return the number of connections that have been accepted.
+    pub fn connections(&self) -> u64 {
+        self.state.connections.load(Ordering::Relaxed)
+    }
+
+    /// Return the IDs of all sessions that have been started.
+    pub fn sessions(&self) -> Vec<String> {
+        let mut ids = self
+            .state
+            .sessions
+            .lock()
+            .expect("sessions poisoned")
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        ids.sort();
+
+        ids
+    }
+
+    /// Stop accepting new connections.
+    pub fn shutdown(&self) {
+        self.handle.abort();
+    }
+}
+
+impl Drop for MockGateway {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+async fn accept(listener: TcpListener, state: Arc<State>) {
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(source) => {
+                tracing::warn!("accepting connection failed: {}", source);
+
+                continue;
+            }
+        };
+
+        let id = state.connections.fetch_add(1, Ordering::Relaxed);
+        let script = state
+            .scripts
+            .lock()
+            .expect("scripts poisoned")
+            .pop_front()
+            .unwrap_or_default();
+
+        tracing::debug!(connection = id, %peer, "accepted connection");
+
+        tokio::spawn(Connection::run(id, stream, script, Arc::clone(&state)));
+    }
+}
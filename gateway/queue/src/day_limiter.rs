@@ -56,6 +56,7 @@ impl DayLimiter {
             .gateway()
             .authed()
             .await
+            .and_then(|response| response.model())
             .map_err(|source| DayLimiterError::RetrievingSessionAvailability { source })?;
 
         let last_check = Instant::now();
@@ -81,7 +82,13 @@ impl DayLimiter {
         } else {
             let wait = lock.last_check + lock.next_reset;
             time::sleep_until(wait).await;
-            if let Ok(info) = lock.http.gateway().authed().await {
+            if let Ok(info) = lock
+                .http
+                .gateway()
+                .authed()
+                .await
+                .and_then(|response| response.model())
+            {
                 let last_check = Instant::now();
                 let next_reset = Duration::from_millis(info.session_start_limit.remaining);
                 tracing::info!("next session start limit reset in: {:.2?}", next_reset);
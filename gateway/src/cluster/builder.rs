@@ -2,7 +2,7 @@ use super::{
     config::Config as ClusterConfig,
     r#impl::{Cluster, ClusterStartError},
 };
-use crate::shard::{LargeThresholdError, ResumeSession, ShardBuilder};
+use crate::shard::{LargeThresholdError, ReconnectStrategy, ResumeSession, ShardBuilder};
 use std::{
     collections::HashMap,
     convert::TryFrom,
@@ -185,6 +185,7 @@ impl ClusterBuilder {
                 .gateway()
                 .authed()
                 .await
+                .and_then(|response| response.model())
                 .ok()
                 .map(|s| s.url);
 
@@ -295,6 +296,19 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the strategy used by shards to reconnect to the gateway.
+    ///
+    /// Refer to [`ShardBuilder::reconnect_strategy`] for the default value and
+    /// more information.
+    pub fn reconnect_strategy(
+        mut self,
+        reconnect_strategy: Arc<Box<dyn ReconnectStrategy>>,
+    ) -> Self {
+        self.1 = self.1.reconnect_strategy(reconnect_strategy);
+
+        self
+    }
+
     /// Set the session information to resume shards with.
     ///
     /// This requires having recovered the resume data when shutting down the
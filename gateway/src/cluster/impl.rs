@@ -18,7 +18,11 @@ use std::{
     sync::{Arc, Mutex},
 };
 use twilight_http::Error as HttpError;
-use twilight_model::gateway::event::Event;
+use twilight_model::gateway::{
+    event::Event,
+    payload::update_status::{UpdateStatus, UpdateStatusInfo},
+    OpCode,
+};
 
 /// Sending a command to a shard failed.
 #[derive(Debug)]
@@ -67,6 +71,43 @@ impl Error for ClusterCommandError {
     }
 }
 
+/// Updating the presence of the shards of a cluster failed.
+///
+/// This is returned from [`Cluster::set_presence`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClusterPresenceError {
+    /// Sending the presence update to at least one shard failed.
+    ///
+    /// The update was still sent to all other shards.
+    Sending {
+        /// IDs of the shards the update failed to be sent to, along with the
+        /// reason.
+        failures: Vec<(u64, CommandError)>,
+    },
+}
+
+impl Display for ClusterPresenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Sending { failures } => f.write_fmt(format_args!(
+                "updating the presence of {} shards failed",
+                failures.len()
+            )),
+        }
+    }
+}
+
+impl Error for ClusterPresenceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Sending { failures } => failures
+                .first()
+                .map(|(_, source)| source as &(dyn Error + 'static)),
+        }
+    }
+}
+
 /// Sending a raw websocket message via a shard failed.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -189,6 +230,7 @@ impl Cluster {
                     .gateway()
                     .authed()
                     .await
+                    .and_then(|response| response.model())
                     .map_err(|source| ClusterStartError::RetrievingGatewayInfo { source })?;
 
                 [0, gateway.shards - 1, gateway.shards]
@@ -378,6 +420,52 @@ impl Cluster {
             .map_err(|source| ClusterCommandError::Sending { source })
     }
 
+    /// Update the presence of all shards in the cluster.
+    ///
+    /// The update is sent to all shards at once, each waiting for its own
+    /// command ratelimit. Create a presence via a [`PresenceBuilder`].
+    ///
+    /// The presence isn't remembered, so shards that need to create a new
+    /// session identify with the presence configured via
+    /// [`ClusterBuilder::presence`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClusterPresenceError::Sending`] if sending the update to at
+    /// least one shard failed, such as when it isn't connected.
+    ///
+    /// [`PresenceBuilder`]: crate::presence::PresenceBuilder
+    pub async fn set_presence(
+        &self,
+        presence: UpdateStatusInfo,
+    ) -> Result<(), ClusterPresenceError> {
+        let update = UpdateStatus {
+            d: presence,
+            op: OpCode::StatusUpdate,
+        };
+
+        let shards = self.0.shards.lock().expect("shards poisoned").clone();
+        let results = future::join_all(shards.into_iter().map(|(id, shard)| {
+            let update = &update;
+
+            async move { (id, shard.command(update).await) }
+        }))
+        .await;
+
+        let mut failures = results
+            .into_iter()
+            .filter_map(|(id, result)| result.err().map(|source| (id, source)))
+            .collect::<Vec<_>>();
+
+        if failures.is_empty() {
+            return Ok(());
+        }
+
+        failures.sort_unstable_by_key(|(id, _)| *id);
+
+        Err(ClusterPresenceError::Sending { failures })
+    }
+
     /// Send a raw command to the specified shard.
     ///
     /// # Errors
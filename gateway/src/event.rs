@@ -6,6 +6,14 @@ bitflags! {
     /// Bitflags representing all of the possible types of events.
     #[non_exhaustive]
     pub struct EventTypeFlags: u64 {
+        /// An auto moderation rule has been triggered and an action executed.
+        const AUTO_MODERATION_ACTION_EXECUTION = 1 << 57;
+        /// An auto moderation rule has been created in a guild.
+        const AUTO_MODERATION_RULE_CREATE = 1 << 58;
+        /// An auto moderation rule has been deleted from a guild.
+        const AUTO_MODERATION_RULE_DELETE = 1 << 59;
+        /// An auto moderation rule in a guild has been updated.
+        const AUTO_MODERATION_RULE_UPDATE = 1 << 60;
         /// User has been banned from a guild.
         const BAN_ADD = 1;
         /// User has been unbanned from a guild.
@@ -41,6 +49,18 @@ bitflags! {
         const GUILD_EMOJIS_UPDATE = 1 << 12;
         /// A guild's integrations have been updated.
         const GUILD_INTEGRATIONS_UPDATE = 1 << 13;
+        /// A scheduled event has been created in a guild.
+        const GUILD_SCHEDULED_EVENT_CREATE = 1 << 52;
+        /// A scheduled event has been deleted from a guild.
+        const GUILD_SCHEDULED_EVENT_DELETE = 1 << 53;
+        /// A scheduled event in a guild has been updated.
+        const GUILD_SCHEDULED_EVENT_UPDATE = 1 << 54;
+        /// A user has subscribed to a scheduled event in a guild.
+        const GUILD_SCHEDULED_EVENT_USER_ADD = 1 << 55;
+        /// A user has unsubscribed from a scheduled event in a guild.
+        const GUILD_SCHEDULED_EVENT_USER_REMOVE = 1 << 56;
+        /// A guild's stickers have been updated.
+        const GUILD_STICKERS_UPDATE = 1 << 51;
         /// A guild has been updated.
         const GUILD_UPDATE = 1 << 14;
         /// Invite for a channel has been created.
@@ -105,6 +125,8 @@ bitflags! {
         const SHARD_RECONNECTING = 1 << 37;
         /// Shard is resuming a session with the gateway.
         const SHARD_RESUMING = 1 << 38;
+        /// Shard has stopped and won't reconnect.
+        const SHARD_STOPPED = 1 << 50;
         /// User has begun typing in a channel.
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
@@ -123,6 +145,12 @@ bitflags! {
 impl From<EventType> for EventTypeFlags {
     fn from(event_type: EventType) -> Self {
         match event_type {
+            EventType::AutoModerationActionExecution => {
+                EventTypeFlags::AUTO_MODERATION_ACTION_EXECUTION
+            }
+            EventType::AutoModerationRuleCreate => EventTypeFlags::AUTO_MODERATION_RULE_CREATE,
+            EventType::AutoModerationRuleDelete => EventTypeFlags::AUTO_MODERATION_RULE_DELETE,
+            EventType::AutoModerationRuleUpdate => EventTypeFlags::AUTO_MODERATION_RULE_UPDATE,
             EventType::BanAdd => EventTypeFlags::BAN_ADD,
             EventType::BanRemove => EventTypeFlags::BAN_REMOVE,
             EventType::ChannelCreate => EventTypeFlags::CHANNEL_CREATE,
@@ -139,6 +167,14 @@ impl From<EventType> for EventTypeFlags {
             EventType::GuildDelete => EventTypeFlags::GUILD_DELETE,
             EventType::GuildEmojisUpdate => EventTypeFlags::GUILD_EMOJIS_UPDATE,
             EventType::GuildIntegrationsUpdate => EventTypeFlags::GUILD_INTEGRATIONS_UPDATE,
+            EventType::GuildScheduledEventCreate => EventTypeFlags::GUILD_SCHEDULED_EVENT_CREATE,
+            EventType::GuildScheduledEventDelete => EventTypeFlags::GUILD_SCHEDULED_EVENT_DELETE,
+            EventType::GuildScheduledEventUpdate => EventTypeFlags::GUILD_SCHEDULED_EVENT_UPDATE,
+            EventType::GuildScheduledEventUserAdd => EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_ADD,
+            EventType::GuildScheduledEventUserRemove => {
+                EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_REMOVE
+            }
+            EventType::GuildStickersUpdate => EventTypeFlags::GUILD_STICKERS_UPDATE,
             EventType::GuildUpdate => EventTypeFlags::GUILD_UPDATE,
             EventType::InviteCreate => EventTypeFlags::INVITE_CREATE,
             EventType::InviteDelete => EventTypeFlags::INVITE_DELETE,
@@ -168,6 +204,7 @@ impl From<EventType> for EventTypeFlags {
             EventType::ShardReconnecting => EventTypeFlags::SHARD_RECONNECTING,
             EventType::ShardPayload => EventTypeFlags::SHARD_PAYLOAD,
             EventType::ShardResuming => EventTypeFlags::SHARD_RESUMING,
+            EventType::ShardStopped => EventTypeFlags::SHARD_STOPPED,
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
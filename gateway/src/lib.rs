@@ -123,6 +123,7 @@
 #![recursion_limit = "256"]
 
 pub mod cluster;
+pub mod presence;
 pub mod shard;
 
 mod event;
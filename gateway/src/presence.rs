@@ -0,0 +1,467 @@
+//! Create presences and activities with builders.
+//!
+//! Bots are only able to set a subset of the fields of an [`Activity`]: its
+//! type, name, and the URL of a stream. Setting any other field is ignored by
+//! Discord, and some combinations are silently rejected. The builders in this
+//! module only expose what bots are able to set and validate the input before
+//! sending it.
+//!
+//! # Examples
+//!
+//! Build a presence with a streaming activity and set it on all shards of a
+//! cluster:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::env;
+//! use twilight_gateway::{
+//!     presence::{ActivityBuilder, PresenceBuilder},
+//!     Cluster, Intents,
+//! };
+//! use twilight_model::gateway::presence::Status;
+//!
+//! let token = env::var("DISCORD_TOKEN")?;
+//! let cluster = Cluster::new(token, Intents::GUILDS).await?;
+//! cluster.up().await;
+//!
+//! let activity = ActivityBuilder::streaming("some stream")?
+//!     .url("https://twitch.tv/twilight")?
+//!     .build();
+//! let presence = PresenceBuilder::new()
+//!     .activity(activity)
+//!     .status(Status::Idle)
+//!     .build();
+//!
+//! cluster.set_presence(presence).await?;
+//! # Ok(()) }
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::gateway::{
+    payload::update_status::UpdateStatusInfo,
+    presence::{Activity, ActivityType, Status},
+};
+
+/// Error creating an activity.
+///
+/// This is returned from the constructors of [`ActivityBuilder`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ActivityNameError {
+    /// Name is empty.
+    Empty {
+        /// Provided name. Although empty, the same owned allocation is
+        /// included.
+        name: String,
+    },
+    /// Name is longer than [`ActivityBuilder::NAME_LENGTH_LIMIT`] characters.
+    TooLong {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for ActivityNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Empty { .. } => f.write_str("the activity name is empty"),
+            Self::TooLong { .. } => f.write_str("the activity name is too long"),
+        }
+    }
+}
+
+impl Error for ActivityNameError {}
+
+/// Error setting the URL of an activity.
+///
+/// This is returned from [`ActivityBuilder::url`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ActivityUrlError {
+    /// The activity isn't a streaming activity, so it can't have a URL.
+    NotStreaming {
+        /// Type of the activity.
+        kind: ActivityType,
+        /// Provided URL.
+        url: String,
+    },
+    /// The URL isn't a Twitch or Youtube URL, which are the only streaming
+    /// services supported by Discord.
+    Unsupported {
+        /// Provided URL.
+        url: String,
+    },
+}
+
+impl Display for ActivityUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NotStreaming { kind, .. } => f.write_fmt(format_args!(
+                "activities of type {:?} can't have a url",
+                kind
+            )),
+            Self::Unsupported { url } => f.write_fmt(format_args!(
+                "the url `{}` isn't a twitch or youtube url",
+                url
+            )),
+        }
+    }
+}
+
+impl Error for ActivityUrlError {}
+
+/// Create an activity for a bot with a builder.
+///
+/// Custom statuses can't be set by bots, so there's no way to create them.
+///
+/// # Examples
+///
+/// Create an activity showing the bot as watching something:
+///
+/// ```rust
+/// use twilight_gateway::presence::ActivityBuilder;
+/// use twilight_model::gateway::presence::ActivityType;
+///
+/// let activity = ActivityBuilder::watching("the stars")?.build();
+/// assert_eq!(ActivityType::Watching, activity.kind);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into an activity"]
+pub struct ActivityBuilder(Activity);
+
+impl ActivityBuilder {
+    /// The maximum number of characters that can be in the name of an
+    /// activity.
+    pub const NAME_LENGTH_LIMIT: usize = 128;
+
+    /// Hosts of streaming services that Discord supports.
+    const STREAM_HOSTS: &'static [&'static str] = &[
+        "twitch.tv",
+        "www.twitch.tv",
+        "youtube.com",
+        "www.youtube.com",
+    ];
+
+    /// Create a "Competing in {name}" activity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActivityNameError`] if the name is empty or too long.
+    pub fn competing(name: impl Into<String>) -> Result<Self, ActivityNameError> {
+        Self::new(ActivityType::Competing, name.into())
+    }
+
+    /// Create a "Listening to {name}" activity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActivityNameError`] if the name is empty or too long.
+    pub fn listening(name: impl Into<String>) -> Result<Self, ActivityNameError> {
+        Self::new(ActivityType::Listening, name.into())
+    }
+
+    /// Create a "Playing {name}" activity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActivityNameError`] if the name is empty or too long.
+    pub fn playing(name: impl Into<String>) -> Result<Self, ActivityNameError> {
+        Self::new(ActivityType::Playing, name.into())
+    }
+
+    /// Create a "Streaming {name}" activity.
+    ///
+    /// Set the URL of the stream via [`url`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActivityNameError`] if the name is empty or too long.
+    ///
+    /// [`url`]: Self::url
+    pub fn streaming(name: impl Into<String>) -> Result<Self, ActivityNameError> {
+        Self::new(ActivityType::Streaming, name.into())
+    }
+
+    /// Create a "Watching {name}" activity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActivityNameError`] if the name is empty or too long.
+    pub fn watching(name: impl Into<String>) -> Result<Self, ActivityNameError> {
+        Self::new(ActivityType::Watching, name.into())
+    }
+
+    fn new(kind: ActivityType, name: String) -> Result<Self, ActivityNameError> {
+        if name.trim().is_empty() {
+            return Err(ActivityNameError::Empty { name });
+        }
+
+        if name.chars().count() > Self::NAME_LENGTH_LIMIT {
+            return Err(ActivityNameError::TooLong { name });
+        }
+
+        Ok(Self(Activity {
+            application_id: None,
+            assets: None,
+            created_at: None,
+            details: None,
+            emoji: None,
+            flags: None,
+            id: None,
+            instance: None,
+            kind,
+            name,
+            party: None,
+            secrets: None,
+            state: None,
+            timestamps: None,
+            url: None,
+        }))
+    }
+
+    /// Build into an activity.
+    pub fn build(self) -> Activity {
+        self.0
+    }
+
+    /// Set the URL of a streaming activity.
+    ///
+    /// Only Twitch and Youtube URLs are supported by Discord.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActivityUrlError::NotStreaming`] if the activity wasn't
+    /// created via [`streaming`].
+    ///
+    /// Returns [`ActivityUrlError::Unsupported`] if the URL isn't a Twitch or
+    /// Youtube URL.
+    ///
+    /// [`streaming`]: Self::streaming
+    pub fn url(mut self, url: impl Into<String>) -> Result<Self, ActivityUrlError> {
+        let url = url.into();
+
+        if self.0.kind != ActivityType::Streaming {
+            return Err(ActivityUrlError::NotStreaming {
+                kind: self.0.kind,
+                url,
+            });
+        }
+
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .and_then(|rest| rest.split('/').next());
+
+        if !host.map_or(false, |host| Self::STREAM_HOSTS.contains(&host)) {
+            return Err(ActivityUrlError::Unsupported { url });
+        }
+
+        self.0.url.replace(url);
+
+        Ok(self)
+    }
+}
+
+/// Create a presence for a bot with a builder.
+///
+/// The presence can be used when identifying via
+/// [`ShardBuilder::presence`] and [`ClusterBuilder::presence`], or to update
+/// the presence of running shards via [`Cluster::set_presence`].
+///
+/// By default the status is online, the bot isn't AFK, and there are no
+/// activities.
+///
+/// # Examples
+///
+/// Refer to the [module-level documentation] for examples.
+///
+/// [`Cluster::set_presence`]: crate::Cluster::set_presence
+/// [`ClusterBuilder::presence`]: crate::cluster::ClusterBuilder::presence
+/// [`ShardBuilder::presence`]: crate::shard::ShardBuilder::presence
+/// [module-level documentation]: self
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a presence"]
+pub struct PresenceBuilder(UpdateStatusInfo);
+
+impl PresenceBuilder {
+    /// Create a new default presence builder.
+    pub fn new() -> Self {
+        Self(UpdateStatusInfo::new(
+            Vec::new(),
+            false,
+            None,
+            Status::Online,
+        ))
+    }
+
+    /// Add an activity to the presence.
+    ///
+    /// Create an activity via [`ActivityBuilder`].
+    pub fn activity(mut self, activity: Activity) -> Self {
+        self.0
+            .activities
+            .get_or_insert_with(Vec::new)
+            .push(activity);
+
+        self
+    }
+
+    /// Set whether the bot is AFK.
+    ///
+    /// Defaults to `false`.
+    pub fn afk(mut self, afk: bool) -> Self {
+        self.0.afk = afk;
+
+        self
+    }
+
+    /// Build into a presence.
+    pub fn build(self) -> UpdateStatusInfo {
+        self.0
+    }
+
+    /// Set the Unix time in milliseconds of when the bot went idle.
+    ///
+    /// Defaults to `None`.
+    pub fn since(mut self, since: u64) -> Self {
+        self.0.since.replace(since);
+
+        self
+    }
+
+    /// Set the status.
+    ///
+    /// Defaults to [`Status::Online`].
+    pub fn status(mut self, status: Status) -> Self {
+        self.0.status = status;
+
+        self
+    }
+}
+
+impl Default for PresenceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActivityBuilder, ActivityNameError, ActivityUrlError, PresenceBuilder};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::gateway::presence::{ActivityType, Status};
+
+    assert_fields!(ActivityNameError::Empty: name);
+    assert_fields!(ActivityNameError::TooLong: name);
+    assert_fields!(ActivityUrlError::NotStreaming: kind, url);
+    assert_fields!(ActivityUrlError::Unsupported: url);
+    assert_impl_all!(ActivityBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ActivityNameError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ActivityUrlError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(PresenceBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_activity_kinds() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            ActivityType::Competing,
+            ActivityBuilder::competing("a")?.build().kind
+        );
+        assert_eq!(
+            ActivityType::Listening,
+            ActivityBuilder::listening("a")?.build().kind
+        );
+        assert_eq!(
+            ActivityType::Playing,
+            ActivityBuilder::playing("a")?.build().kind
+        );
+        assert_eq!(
+            ActivityType::Streaming,
+            ActivityBuilder::streaming("a")?.build().kind
+        );
+        assert_eq!(
+            ActivityType::Watching,
+            ActivityBuilder::watching("a")?.build().kind
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_activity_name() {
+        assert!(matches!(
+            ActivityBuilder::playing(" ").unwrap_err(),
+            ActivityNameError::Empty { name } if name == " "
+        ));
+        assert!(ActivityBuilder::playing("a".repeat(128)).is_ok());
+        assert!(matches!(
+            ActivityBuilder::playing("a".repeat(129)).unwrap_err(),
+            ActivityNameError::TooLong { .. }
+        ));
+    }
+
+    #[test]
+    fn test_activity_url() -> Result<(), Box<dyn Error>> {
+        let activity = ActivityBuilder::streaming("a")?
+            .url("https://www.twitch.tv/twilight")?
+            .build();
+        assert_eq!(
+            Some("https://www.twitch.tv/twilight"),
+            activity.url.as_deref()
+        );
+        assert!(ActivityBuilder::streaming("a")?
+            .url("https://youtube.com/watch?v=abc")
+            .is_ok());
+
+        assert!(matches!(
+            ActivityBuilder::playing("a")?
+                .url("https://twitch.tv/twilight")
+                .unwrap_err(),
+            ActivityUrlError::NotStreaming {
+                kind: ActivityType::Playing,
+                ..
+            }
+        ));
+        assert!(matches!(
+            ActivityBuilder::streaming("a")?
+                .url("https://example.com/twitch.tv")
+                .unwrap_err(),
+            ActivityUrlError::Unsupported { .. }
+        ));
+        assert!(matches!(
+            ActivityBuilder::streaming("a")?
+                .url("twitch.tv/twilight")
+                .unwrap_err(),
+            ActivityUrlError::Unsupported { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_presence() -> Result<(), Box<dyn Error>> {
+        let default = PresenceBuilder::new().build();
+        assert_eq!(Some(Vec::new()), default.activities);
+        assert!(!default.afk);
+        assert!(default.since.is_none());
+        assert_eq!(Status::Online, default.status);
+
+        let activity = ActivityBuilder::playing("a game")?.build();
+        let presence = PresenceBuilder::new()
+            .activity(activity.clone())
+            .afk(true)
+            .since(1_600_000_000_000)
+            .status(Status::DoNotDisturb)
+            .build();
+        assert_eq!(Some(vec![activity]), presence.activities);
+        assert!(presence.afk);
+        assert_eq!(Some(1_600_000_000_000), presence.since);
+        assert_eq!(Status::DoNotDisturb, presence.status);
+
+        Ok(())
+    }
+}
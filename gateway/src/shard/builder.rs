@@ -1,4 +1,8 @@
-use super::{config::Config, Shard};
+use super::{
+    config::Config,
+    reconnect::{ExponentialBackoff, ReconnectStrategy},
+    Shard,
+};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -112,6 +116,7 @@ impl ShardBuilder {
             large_threshold: 250,
             presence: None,
             queue: Arc::new(Box::new(LocalQueue::new())),
+            reconnect_strategy: Arc::new(Box::new(ExponentialBackoff::new())),
             shard: [0, 1],
             token: token.into_boxed_str(),
             session_id: None,
@@ -206,6 +211,23 @@ impl ShardBuilder {
         self
     }
 
+    /// Set the strategy used to reconnect to the gateway.
+    ///
+    /// The strategy decides the delay between reconnect attempts, how many
+    /// attempts are made, and which close codes stop the shard. Refer to
+    /// [`ReconnectStrategy`] for more information.
+    ///
+    /// The default value is an [`ExponentialBackoff`] with its default
+    /// configuration.
+    pub fn reconnect_strategy(
+        mut self,
+        reconnect_strategy: Arc<Box<dyn ReconnectStrategy>>,
+    ) -> Self {
+        self.0.reconnect_strategy = reconnect_strategy;
+
+        self
+    }
+
     /// Set the shard ID to connect as, and the total number of shards used by
     /// the bot.
     ///
@@ -1,3 +1,4 @@
+use super::reconnect::ReconnectStrategy;
 use std::sync::Arc;
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
@@ -17,6 +18,7 @@ pub struct Config {
     pub(super) large_threshold: u64,
     pub(super) presence: Option<UpdateStatusInfo>,
     pub(super) queue: Arc<Box<dyn Queue>>,
+    pub(super) reconnect_strategy: Arc<Box<dyn ReconnectStrategy>>,
     pub(crate) shard: [u64; 2],
     pub(super) token: Box<str>,
     pub(crate) session_id: Option<Box<str>>,
@@ -55,6 +57,12 @@ impl Config {
         self.presence.as_ref()
     }
 
+    /// Return an immutable reference to the strategy used to reconnect to the
+    /// gateway.
+    pub fn reconnect_strategy(&self) -> &dyn ReconnectStrategy {
+        self.reconnect_strategy.as_ref().as_ref()
+    }
+
     /// The shard's ID and the total number of shards used by the bot.
     pub fn shard(&self) -> [u64; 2] {
         self.shard
@@ -397,6 +397,7 @@ impl Shard {
                 .gateway()
                 .authed()
                 .await
+                .and_then(|response| response.model())
                 .map_err(|source| ShardStartError::RetrievingGatewayUrl { source })?
                 .url
         };
@@ -28,6 +28,7 @@ mod event;
 mod r#impl;
 mod json;
 mod processor;
+mod reconnect;
 mod sink;
 
 pub use self::{
@@ -39,6 +40,7 @@ pub use self::{
         CommandError, Information, ResumeSession, SendError, SessionInactiveError, Shard,
         ShardStartError,
     },
+    reconnect::{ExponentialBackoff, ReconnectStrategy},
     sink::ShardSink,
     stage::Stage,
 };
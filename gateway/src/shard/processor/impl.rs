@@ -21,19 +21,22 @@ use futures_util::stream::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
+    convert::TryFrom,
     env::consts::OS,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     str::{self, Utf8Error},
     sync::{atomic::Ordering, Arc},
-    time::Duration,
 };
 use tokio::sync::watch::{
     channel as watch_channel, Receiver as WatchReceiver, Sender as WatchSender,
 };
 use twilight_model::gateway::{
     event::{
-        shard::{Connected, Connecting, Disconnected, Identifying, Reconnecting, Resuming},
+        shard::{
+            Connected, Connecting, Disconnected, Identifying, Reconnecting, Resuming, StopReason,
+            Stopped,
+        },
         DispatchEvent, Event, GatewayEvent, GatewayEventDeserializer,
     },
     payload::{
@@ -41,7 +44,7 @@ use twilight_model::gateway::{
         resume::Resume,
         Ready,
     },
-    Intents, OpCode,
+    CloseCode as GatewayCloseCode, Intents, OpCode,
 };
 use url::{ParseError as UrlParseError, Url};
 
@@ -151,6 +154,14 @@ impl Error for ProcessError {
 enum ReceivingEventError {
     /// Provided authorization token is invalid.
     AuthorizationInvalid { shard_id: u64, token: String },
+    /// Gateway closed the connection with a close code the reconnect strategy
+    /// considers fatal.
+    CloseCodeFatal {
+        /// Close code sent by the gateway.
+        code: GatewayCloseCode,
+        /// ID of the shard.
+        shard_id: u64,
+    },
     /// Decompressing a frame from Discord failed.
     Decompressing {
         /// Reason for the error.
@@ -184,6 +195,7 @@ impl ReceivingEventError {
         matches!(
             self,
             ReceivingEventError::AuthorizationInvalid { .. }
+                | ReceivingEventError::CloseCodeFatal { .. }
                 | ReceivingEventError::IntentsDisallowed { .. }
                 | ReceivingEventError::IntentsInvalid { .. }
         )
@@ -205,6 +217,10 @@ impl Display for ReceivingEventError {
                 "the authorization token for shard {} is invalid",
                 shard_id
             )),
+            Self::CloseCodeFatal { code, shard_id } => f.write_fmt(format_args!(
+                "shard {} was closed with the fatal close code {:?}",
+                shard_id, code
+            )),
             Self::Decompressing { .. } => f.write_str("a frame could not be decompressed"),
             Self::IntentsDisallowed { intents, shard_id } => f.write_fmt(format_args!(
                 "at least one of the intents ({:?}) for shard {} are disallowed",
@@ -238,6 +254,8 @@ pub struct ShardProcessor {
     inflater: Inflater,
     url: Box<str>,
     resume: Option<(u64, Box<str>)>,
+    /// Reason the shard is stopping, if it is.
+    stop: Option<StopReason>,
     wtx: WatchSender<Arc<Session>>,
 }
 
@@ -294,6 +312,7 @@ impl ShardProcessor {
             inflater: Inflater::new(shard_id),
             url: url.into_boxed_str(),
             resume: None,
+            stop: None,
             wtx,
         };
 
@@ -307,6 +326,10 @@ impl ShardProcessor {
 
     pub async fn run(mut self) {
         loop {
+            if self.stop.is_some() {
+                break;
+            }
+
             match self.next_payload().await {
                 Ok(v) => v,
                 Err(source) => {
@@ -344,6 +367,20 @@ impl ShardProcessor {
             }
         }
 
+        if let Some(reason) = self.stop.take() {
+            tracing::warn!(
+                shard_id = self.config.shard()[0],
+                shard_total = self.config.shard()[1],
+                "shard stopped: {:?}",
+                reason,
+            );
+
+            self.emitter.event(Event::ShardStopped(Stopped {
+                reason,
+                shard_id: self.config.shard()[0],
+            }));
+        }
+
         self.emitter.into_listeners().remove_all();
     }
 
@@ -691,28 +728,36 @@ impl ShardProcessor {
             shard_id: self.config.shard()[0],
         }));
 
-        if let Some(close_frame) = close_frame {
-            match close_frame.code {
-                CloseCode::Library(4004) => {
-                    return Err(ReceivingEventError::AuthorizationInvalid {
+        let fatal_code = close_frame
+            .and_then(|frame| match frame.code {
+                CloseCode::Library(code) => GatewayCloseCode::try_from(code).ok(),
+                _ => None,
+            })
+            .filter(|code| self.config.reconnect_strategy().fatal(*code));
+
+        if let Some(code) = fatal_code {
+            self.stop.replace(StopReason::FatalCloseCode { code });
+
+            return Err(match code {
+                GatewayCloseCode::AuthenticationFailed => {
+                    ReceivingEventError::AuthorizationInvalid {
                         shard_id: self.config.shard()[0],
                         token: self.config.token().to_owned(),
-                    });
-                }
-                CloseCode::Library(4013) => {
-                    return Err(ReceivingEventError::IntentsInvalid {
-                        intents: self.config.intents(),
-                        shard_id: self.config.shard()[0],
-                    });
-                }
-                CloseCode::Library(4014) => {
-                    return Err(ReceivingEventError::IntentsDisallowed {
-                        intents: self.config.intents(),
-                        shard_id: self.config.shard()[0],
-                    });
+                    }
                 }
-                _ => {}
-            }
+                GatewayCloseCode::InvalidIntents => ReceivingEventError::IntentsInvalid {
+                    intents: self.config.intents(),
+                    shard_id: self.config.shard()[0],
+                },
+                GatewayCloseCode::DisallowedIntents => ReceivingEventError::IntentsDisallowed {
+                    intents: self.config.intents(),
+                    shard_id: self.config.shard()[0],
+                },
+                code => ReceivingEventError::CloseCodeFatal {
+                    code,
+                    shard_id: self.config.shard()[0],
+                },
+            });
         }
 
         self.resume().await;
@@ -758,15 +803,32 @@ impl ShardProcessor {
     }
 
     /// Perform a full reconnect to the gateway, instantiating a new session.
+    ///
+    /// If the reconnect strategy gives up then the shard is marked as
+    /// stopping.
     async fn reconnect(&mut self) {
+        if self.stop.is_some() {
+            return;
+        }
+
         tracing::info!("reconnection started");
 
-        let mut wait = Duration::from_secs(1);
+        let mut attempt = 0;
 
         loop {
+            let wait = if let Some(wait) = self.config.reconnect_strategy().delay(attempt) {
+                wait
+            } else {
+                self.stop
+                    .replace(StopReason::ReconnectAttemptsExhausted { attempts: attempt });
+
+                return;
+            };
+
             tracing::debug!(
                 shard_id = self.config.shard()[0],
                 shard_total = self.config.shard()[1],
+                attempt,
                 wait_in_seconds = wait.as_secs(),
                 "waiting before attempting a reconnect",
             );
@@ -784,9 +846,7 @@ impl ShardProcessor {
                 Err(why) => {
                     tracing::warn!("reconnecting failed: {:?}", why);
 
-                    if wait < Duration::from_secs(128) {
-                        wait *= 2;
-                    }
+                    attempt = attempt.saturating_add(1);
 
                     continue;
                 }
@@ -806,6 +866,10 @@ impl ShardProcessor {
     /// Resume a session if possible, defaulting to instantiating a new
     /// connection.
     async fn resume(&mut self) {
+        if self.stop.is_some() {
+            return;
+        }
+
         tracing::info!("resuming shard {:?}", self.config.shard());
         self.session.set_stage(Stage::Resuming);
         self.session.stop_heartbeater();
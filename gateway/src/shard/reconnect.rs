@@ -0,0 +1,243 @@
+//! Strategies for how a shard reconnects to the gateway.
+
+use std::{
+    collections::hash_map::RandomState,
+    fmt::Debug,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+use twilight_model::gateway::CloseCode;
+
+/// Strategy deciding when and whether a shard reconnects to the gateway.
+///
+/// A strategy is consulted whenever a shard has to create a new connection,
+/// such as after the connection was closed and the session couldn't be
+/// resumed. It decides how long to wait before each attempt, how many
+/// attempts to make, and which close codes are fatal.
+///
+/// When a shard stops because of a strategy's decision, it emits a
+/// [`Event::ShardStopped`] event with the reason as its last event.
+///
+/// The default strategy is an [`ExponentialBackoff`] with its default
+/// configuration.
+///
+/// # Examples
+///
+/// Reconnect every 5 seconds, giving up after 10 failed attempts:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use twilight_gateway::shard::ReconnectStrategy;
+///
+/// #[derive(Debug)]
+/// struct FixedDelay;
+///
+/// impl ReconnectStrategy for FixedDelay {
+///     fn delay(&self, attempt: u32) -> Option<Duration> {
+///         if attempt < 10 {
+///             Some(Duration::from_secs(5))
+///         } else {
+///             None
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`Event::ShardStopped`]: twilight_model::gateway::event::Event::ShardStopped
+pub trait ReconnectStrategy: Debug + Send + Sync {
+    /// Return how long to wait before a reconnect attempt.
+    ///
+    /// The attempt is 0-indexed and resets once a connection is successfully
+    /// established. Return `None` to stop reconnecting, which stops the shard.
+    fn delay(&self, attempt: u32) -> Option<Duration>;
+
+    /// Return whether a close code sent by the gateway is fatal.
+    ///
+    /// Shards don't reconnect after being closed with a fatal close code.
+    ///
+    /// Defaults to [`CloseCode::AuthenticationFailed`],
+    /// [`CloseCode::InvalidIntents`], and [`CloseCode::DisallowedIntents`],
+    /// which can't be recovered from without changing the configuration of
+    /// the shard.
+    fn fatal(&self, code: CloseCode) -> bool {
+        matches!(
+            code,
+            CloseCode::AuthenticationFailed
+                | CloseCode::InvalidIntents
+                | CloseCode::DisallowedIntents
+        )
+    }
+}
+
+/// Reconnect strategy doubling the delay between each attempt.
+///
+/// By default the first attempt is made after 1 second, the delay is capped at
+/// 128 seconds, there's no jitter, and attempts are made indefinitely.
+///
+/// # Examples
+///
+/// Start at 500 milliseconds, add jitter, and give up after 8 attempts:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use twilight_gateway::shard::ExponentialBackoff;
+///
+/// let strategy = ExponentialBackoff::new()
+///     .base(Duration::from_millis(500))
+///     .jitter(true)
+///     .max_attempts(Some(8));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExponentialBackoff {
+    base: Duration,
+    jitter: bool,
+    max_attempts: Option<u32>,
+    max_delay: Duration,
+}
+
+impl ExponentialBackoff {
+    /// Create a new exponential backoff strategy with the default
+    /// configuration.
+    pub fn new() -> Self {
+        Self {
+            base: Duration::from_secs(1),
+            jitter: false,
+            max_attempts: None,
+            max_delay: Duration::from_secs(128),
+        }
+    }
+
+    /// Set the delay before the first attempt.
+    ///
+    /// Defaults to 1 second.
+    pub fn base(mut self, base: Duration) -> Self {
+        self.base = base;
+
+        self
+    }
+
+    /// Set whether to randomize delays.
+    ///
+    /// When enabled each delay is randomly chosen between half of the delay
+    /// and the full delay, which prevents many shards that disconnected at the
+    /// same time from reconnecting in lockstep.
+    ///
+    /// Defaults to `false`.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Set the maximum number of attempts to make before stopping the shard.
+    ///
+    /// Defaults to `None`, making attempts indefinitely.
+    pub fn max_attempts(mut self, max_attempts: Option<u32>) -> Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Set the maximum delay between attempts.
+    ///
+    /// Defaults to 128 seconds.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+
+        self
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReconnectStrategy for ExponentialBackoff {
+    fn delay(&self, attempt: u32) -> Option<Duration> {
+        if self.max_attempts.map_or(false, |max| attempt >= max) {
+            return None;
+        }
+
+        let factor = 2_u32.checked_pow(attempt).unwrap_or(u32::MAX);
+        let delay = self
+            .base
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        if !self.jitter {
+            return Some(delay);
+        }
+
+        let half = delay / 2;
+        #[allow(clippy::cast_possible_truncation)]
+        let spread = half.as_millis().min(u128::from(u64::MAX)) as u64;
+        let jitter = random() % spread.saturating_add(1);
+
+        Some(half + Duration::from_millis(jitter))
+    }
+}
+
+/// Return a random number without depending on a random number generator.
+///
+/// The standard library seeds each [`RandomState`] with unique random keys,
+/// which is enough for spreading out reconnects.
+fn random() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExponentialBackoff, ReconnectStrategy};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::gateway::CloseCode;
+
+    assert_impl_all!(ExponentialBackoff: Clone, Debug, Default, ReconnectStrategy, Send, Sync);
+
+    #[test]
+    fn test_exponential_backoff_defaults() {
+        let strategy = ExponentialBackoff::new();
+
+        assert_eq!(Some(Duration::from_secs(1)), strategy.delay(0));
+        assert_eq!(Some(Duration::from_secs(2)), strategy.delay(1));
+        assert_eq!(Some(Duration::from_secs(64)), strategy.delay(6));
+        assert_eq!(Some(Duration::from_secs(128)), strategy.delay(7));
+        assert_eq!(Some(Duration::from_secs(128)), strategy.delay(8));
+        assert_eq!(Some(Duration::from_secs(128)), strategy.delay(u32::MAX));
+    }
+
+    #[test]
+    fn test_exponential_backoff_max_attempts() {
+        let strategy = ExponentialBackoff::new().max_attempts(Some(2));
+
+        assert!(strategy.delay(0).is_some());
+        assert!(strategy.delay(1).is_some());
+        assert!(strategy.delay(2).is_none());
+    }
+
+    #[test]
+    fn test_exponential_backoff_jitter() {
+        let strategy = ExponentialBackoff::new()
+            .base(Duration::from_secs(10))
+            .jitter(true);
+
+        for _ in 0..100 {
+            let delay = strategy.delay(0).unwrap();
+            assert!(delay >= Duration::from_secs(5));
+            assert!(delay <= Duration::from_secs(10));
+        }
+    }
+
+    #[test]
+    fn test_fatal_close_codes() {
+        let strategy = ExponentialBackoff::new();
+
+        assert!(strategy.fatal(CloseCode::AuthenticationFailed));
+        assert!(strategy.fatal(CloseCode::InvalidIntents));
+        assert!(strategy.fatal(CloseCode::DisallowedIntents));
+        assert!(!strategy.fatal(CloseCode::SessionTimedOut));
+        assert!(!strategy.fatal(CloseCode::UnknownError));
+    }
+}
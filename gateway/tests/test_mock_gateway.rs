@@ -0,0 +1,417 @@
+use futures_util::stream::{Stream, StreamExt};
+use serde_json::json;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::time::timeout;
+use twilight_gateway::{
+    cluster::{Cluster, ShardScheme},
+    presence::{ActivityBuilder, PresenceBuilder},
+    shard::{ExponentialBackoff, ReconnectStrategy, Stage},
+    Event, EventTypeFlags, Intents, Shard,
+};
+use twilight_gateway_mock::{MockGateway, MockQueue, Script};
+use twilight_model::gateway::{event::shard::StopReason, presence::Status, CloseCode, OpCode};
+
+const WAIT: Duration = Duration::from_secs(10);
+
+fn shard(gateway: &MockGateway) -> Shard {
+    Shard::builder("token", Intents::empty())
+        .gateway_url(Some(gateway.url()))
+        .queue(Arc::new(Box::new(MockQueue)))
+        .build()
+}
+
+fn typing_start() -> serde_json::Value {
+    json!({
+        "channel_id": "2",
+        "timestamp": 1_600_000_000,
+        "user_id": "3",
+    })
+}
+
+/// Wait for the next event matching a predicate, skipping all others.
+async fn next_matching<T>(
+    events: &mut (impl Stream<Item = T> + Unpin),
+    mut predicate: impl FnMut(&T) -> bool,
+) -> T {
+    timeout(WAIT, async {
+        loop {
+            let event = events.next().await.expect("event stream ended");
+
+            if predicate(&event) {
+                return event;
+            }
+        }
+    })
+    .await
+    .expect("timed out waiting for event")
+}
+
+#[tokio::test]
+async fn test_shard_identifies() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+    gateway.push_script(Script::new().dispatch("TYPING_START", typing_start()));
+
+    let mut shard = shard(&gateway);
+    let mut events = shard.events();
+    shard.start().await?;
+
+    next_matching(&mut events, |event| matches!(event, Event::GatewayHello(_))).await;
+    let ready = next_matching(&mut events, |event| matches!(event, Event::Ready(_))).await;
+    assert!(matches!(ready, Event::Ready(ready) if ready.session_id == "mock-session-0"));
+    next_matching(&mut events, |event| matches!(event, Event::TypingStart(_))).await;
+
+    let info = shard.info()?;
+    assert_eq!(Some("mock-session-0"), info.session_id());
+    assert_eq!(Stage::Connected, info.stage());
+
+    let identify = gateway
+        .commands()
+        .into_iter()
+        .find(|command| command.op == OpCode::Identify)
+        .expect("shard didn't identify");
+    assert_eq!(json!([0, 1]), identify.data["shard"]);
+    assert_eq!("Bot token", identify.data["token"]);
+
+    shard.shutdown();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shard_resumes_after_close() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+    gateway.push_script(
+        Script::new()
+            .dispatch("TYPING_START", typing_start())
+            .close(CloseCode::UnknownError),
+    );
+
+    let mut shard = shard(&gateway);
+    let mut events = shard.events();
+    shard.start().await?;
+
+    next_matching(&mut events, |event| matches!(event, Event::TypingStart(_))).await;
+    next_matching(&mut events, |event| {
+        matches!(event, Event::ShardDisconnected(disconnected) if disconnected.code == Some(4000))
+    })
+    .await;
+    next_matching(&mut events, |event| {
+        matches!(event, Event::ShardResuming(_))
+    })
+    .await;
+    next_matching(&mut events, |event| matches!(event, Event::Resumed)).await;
+
+    let resume = gateway
+        .commands()
+        .into_iter()
+        .find(|command| command.op == OpCode::Resume)
+        .expect("shard didn't resume");
+    assert_eq!(1, resume.connection);
+    assert_eq!("mock-session-0", resume.data["session_id"]);
+    assert_eq!(2, resume.data["seq"]);
+    assert_eq!(vec!["mock-session-0".to_owned()], gateway.sessions());
+
+    shard.shutdown();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shard_reidentifies_after_invalid_session() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+    gateway.push_script(Script::new().invalid_session(false));
+
+    let mut shard = shard(&gateway);
+    let mut events = shard.events();
+    shard.start().await?;
+
+    // The shard reconnects while processing the invalid session, so the
+    // reconnecting event is emitted first.
+    next_matching(&mut events, |event| {
+        matches!(event, Event::ShardReconnecting(_))
+    })
+    .await;
+    next_matching(&mut events, |event| {
+        matches!(event, Event::GatewayInvalidateSession(false))
+    })
+    .await;
+    let ready = next_matching(&mut events, |event| matches!(event, Event::Ready(_))).await;
+    assert!(matches!(ready, Event::Ready(ready) if ready.session_id == "mock-session-1"));
+
+    let identifies = gateway
+        .commands()
+        .into_iter()
+        .filter(|command| command.op == OpCode::Identify)
+        .count();
+    assert_eq!(2, identifies);
+
+    shard.shutdown();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shard_stops_on_fatal_close_code() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+    gateway.push_script(Script::new().close(CloseCode::AuthenticationFailed));
+
+    let mut shard = shard(&gateway);
+    let mut events = shard.events();
+    shard.start().await?;
+
+    next_matching(&mut events, |event| {
+        matches!(event, Event::ShardDisconnected(disconnected) if disconnected.code == Some(4004))
+    })
+    .await;
+
+    // The shard stops processing, removing all listeners.
+    let remaining = timeout(WAIT, events.collect::<Vec<_>>()).await?;
+    assert!(!remaining
+        .iter()
+        .any(|event| matches!(event, Event::ShardResuming(_) | Event::ShardReconnecting(_))));
+    assert!(matches!(
+        remaining.last(),
+        Some(Event::ShardStopped(stopped)) if stopped.reason == StopReason::FatalCloseCode {
+            code: CloseCode::AuthenticationFailed,
+        }
+    ));
+    assert_eq!(1, gateway.connections());
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct InvalidShardFatal;
+
+impl ReconnectStrategy for InvalidShardFatal {
+    fn delay(&self, _: u32) -> Option<Duration> {
+        Some(Duration::from_millis(10))
+    }
+
+    fn fatal(&self, code: CloseCode) -> bool {
+        code == CloseCode::InvalidShard
+    }
+}
+
+#[tokio::test]
+async fn test_shard_stops_on_strategy_fatal_close_code() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+    gateway.push_script(Script::new().close(CloseCode::InvalidShard));
+
+    let mut shard = Shard::builder("token", Intents::empty())
+        .gateway_url(Some(gateway.url()))
+        .queue(Arc::new(Box::new(MockQueue)))
+        .reconnect_strategy(Arc::new(Box::new(InvalidShardFatal)))
+        .build();
+    let mut events = shard.some_events(EventTypeFlags::SHARD_STOPPED);
+    shard.start().await?;
+
+    let stopped = next_matching(&mut events, |_| true).await;
+    assert!(matches!(
+        stopped,
+        Event::ShardStopped(stopped) if stopped.reason == StopReason::FatalCloseCode {
+            code: CloseCode::InvalidShard,
+        }
+    ));
+    assert_eq!(1, gateway.connections());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shard_stops_after_reconnect_attempts() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+    gateway.push_script(
+        Script::new()
+            .sleep(Duration::from_millis(100))
+            .invalid_session(false),
+    );
+
+    let strategy = ExponentialBackoff::new()
+        .base(Duration::from_millis(10))
+        .max_attempts(Some(2));
+    let mut shard = Shard::builder("token", Intents::empty())
+        .gateway_url(Some(gateway.url()))
+        .queue(Arc::new(Box::new(MockQueue)))
+        .reconnect_strategy(Arc::new(Box::new(strategy)))
+        .build();
+    let mut events = shard.events();
+    shard.start().await?;
+
+    next_matching(&mut events, |event| matches!(event, Event::Ready(_))).await;
+
+    // Further connections are refused, so reconnecting fails.
+    gateway.shutdown();
+
+    let remaining = timeout(WAIT, events.collect::<Vec<_>>()).await?;
+    let reconnects = remaining
+        .iter()
+        .filter(|event| matches!(event, Event::ShardReconnecting(_)))
+        .count();
+    assert_eq!(2, reconnects);
+    assert!(matches!(
+        remaining.last(),
+        Some(Event::ShardStopped(stopped))
+            if stopped.reason == StopReason::ReconnectAttemptsExhausted { attempts: 2 }
+    ));
+    assert_eq!(1, gateway.connections());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shard_heartbeats() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::builder()
+        .heartbeat_interval(100)
+        .bind()
+        .await?;
+
+    let mut shard = shard(&gateway);
+    let mut events = shard.some_events(EventTypeFlags::GATEWAY_HEARTBEAT_ACK);
+    shard.start().await?;
+
+    next_matching(&mut events, |event| {
+        matches!(event, Event::GatewayHeartbeatAck)
+    })
+    .await;
+    next_matching(&mut events, |event| {
+        matches!(event, Event::GatewayHeartbeatAck)
+    })
+    .await;
+
+    let heartbeats = gateway
+        .commands()
+        .into_iter()
+        .filter(|command| command.op == OpCode::Heartbeat)
+        .count();
+    assert!(heartbeats >= 2);
+
+    shard.shutdown();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cluster_starts_and_resumes() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+
+    let cluster = Cluster::builder("token", Intents::empty())
+        .gateway_url(Some(gateway.url()))
+        .queue(Arc::new(Box::new(MockQueue)))
+        .shard_scheme(ShardScheme::Range {
+            from: 0,
+            to: 1,
+            total: 2,
+        })
+        .build()
+        .await?;
+    let mut events = cluster.some_events(EventTypeFlags::READY);
+    cluster.up().await;
+
+    let mut shards = Vec::new();
+
+    for _ in 0..2 {
+        let (shard_id, _) =
+            next_matching(&mut events, |(_, event)| matches!(event, Event::Ready(_))).await;
+        shards.push(shard_id);
+    }
+
+    shards.sort_unstable();
+    assert_eq!(vec![0, 1], shards);
+
+    let sessions = cluster.down_resumable();
+    assert_eq!(2, sessions.len());
+
+    let cluster = Cluster::builder("token", Intents::empty())
+        .gateway_url(Some(gateway.url()))
+        .queue(Arc::new(Box::new(MockQueue)))
+        .shard_scheme(ShardScheme::Range {
+            from: 0,
+            to: 1,
+            total: 2,
+        })
+        .resume_sessions(sessions)
+        .build()
+        .await?;
+    let mut events = cluster.some_events(EventTypeFlags::RESUMED);
+    cluster.up().await;
+
+    for _ in 0..2 {
+        next_matching(&mut events, |(_, event)| matches!(event, Event::Resumed)).await;
+    }
+
+    let resumes = gateway
+        .commands()
+        .into_iter()
+        .filter(|command| command.op == OpCode::Resume)
+        .count();
+    assert_eq!(2, resumes);
+    assert_eq!(2, gateway.sessions().len());
+
+    cluster.down();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cluster_set_presence() -> Result<(), Box<dyn Error>> {
+    let gateway = MockGateway::bind().await?;
+
+    let cluster = Cluster::builder("token", Intents::empty())
+        .gateway_url(Some(gateway.url()))
+        .queue(Arc::new(Box::new(MockQueue)))
+        .shard_scheme(ShardScheme::Range {
+            from: 0,
+            to: 1,
+            total: 2,
+        })
+        .build()
+        .await?;
+    let mut events = cluster.some_events(EventTypeFlags::READY);
+    cluster.up().await;
+
+    for _ in 0..2 {
+        next_matching(&mut events, |(_, event)| matches!(event, Event::Ready(_))).await;
+    }
+
+    let activity = ActivityBuilder::watching("the mock")?.build();
+    let presence = PresenceBuilder::new()
+        .activity(activity)
+        .status(Status::Idle)
+        .build();
+    cluster.set_presence(presence).await?;
+
+    let updates = timeout(WAIT, async {
+        loop {
+            let updates = gateway
+                .commands()
+                .into_iter()
+                .filter(|command| command.op == OpCode::StatusUpdate)
+                .collect::<Vec<_>>();
+
+            if updates.len() == 2 {
+                return updates;
+            }
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+
+    let mut connections = updates
+        .iter()
+        .map(|update| update.connection)
+        .collect::<Vec<_>>();
+    connections.sort_unstable();
+    assert_eq!(vec![0, 1], connections);
+
+    for update in updates {
+        assert_eq!("idle", update.data["status"]);
+        assert_eq!("the mock", update.data["activities"][0]["name"]);
+        assert_eq!(3, update.data["activities"][0]["type"]);
+    }
+
+    cluster.down();
+
+    Ok(())
+}
@@ -8,6 +8,8 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     // Initialize the tracing subscriber.
     tracing_subscriber::fmt::init();
 
+    // Make requests through a `twilight-http-proxy` listening on port 3000,
+    // which ratelimits them instead of the client.
     let client = Client::builder()
         .proxy("localhost:3000", true)
         .ratelimiter(None)
@@ -22,7 +24,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     }))
     .await;
 
-    let me = client.current_user().await?;
+    let me = client.current_user().await?.model()?;
     println!("Current user: {}#{}", me.name, me.discriminator);
 
     Ok(())
@@ -0,0 +1,171 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use twilight_http::routing::Path;
+
+/// Ratelimit bucket configuration of the mock API.
+///
+/// Paths configured with buckets that have the same hash share their
+/// ratelimit, like Discord does for some routes.
+///
+/// # Examples
+///
+/// Create a bucket allowing 5 requests every 2 seconds:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use twilight_http_mock::MockBucket;
+///
+/// let bucket = MockBucket::new(5, Duration::from_secs(2)).hash("abcd1234");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MockBucket {
+    pub(crate) hash: Option<String>,
+    pub(crate) limit: u64,
+    pub(crate) reset_after: Duration,
+}
+
+impl MockBucket {
+    /// Create a new bucket allowing a number of requests per period.
+    pub fn new(limit: u64, reset_after: Duration) -> Self {
+        Self {
+            hash: None,
+            limit,
+            reset_after,
+        }
+    }
+
+    /// Set the hash of the bucket sent in the `X-RateLimit-Bucket` header.
+    ///
+    /// Defaults to a hash unique to each path.
+    pub fn hash(mut self, hash: impl Into<String>) -> Self {
+        self.hash.replace(hash.into());
+
+        self
+    }
+
+    /// Return the hash of the bucket for a path.
+    pub(crate) fn hash_for(&self, path: &Path) -> String {
+        if let Some(hash) = self.hash.as_ref() {
+            return hash.clone();
+        }
+
+        let mut hasher = DefaultHasher::new();
+        path.hash(&mut hasher);
+
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+/// Headers describing the state of a bucket.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct BucketHeaders {
+    pub hash: String,
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset: f64,
+    pub reset_after: f64,
+}
+
+/// Outcome of taking a ticket from a bucket.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Ticket {
+    /// The request is allowed.
+    Allowed(BucketHeaders),
+    /// The bucket is exhausted, and the request must be retried later.
+    Exhausted(BucketHeaders),
+}
+
+/// Current state of a bucket.
+#[derive(Debug)]
+pub(crate) struct BucketState {
+    config: MockBucket,
+    hash: String,
+    remaining: u64,
+    reset_at: Option<Instant>,
+}
+
+impl BucketState {
+    pub fn new(config: MockBucket, hash: String) -> Self {
+        Self {
+            remaining: config.limit,
+            config,
+            hash,
+            reset_at: None,
+        }
+    }
+
+    /// Take a ticket for a request from the bucket.
+    pub fn take(&mut self, now: Instant) -> Ticket {
+        if self.reset_at.map_or(false, |reset_at| reset_at <= now) {
+            self.remaining = self.config.limit;
+            self.reset_at = None;
+        }
+
+        let reset_after = self.config.reset_after;
+        let reset_at = *self.reset_at.get_or_insert_with(|| now + reset_after);
+
+        if self.remaining == 0 {
+            return Ticket::Exhausted(self.headers(now, reset_at));
+        }
+
+        self.remaining -= 1;
+
+        Ticket::Allowed(self.headers(now, reset_at))
+    }
+
+    fn headers(&self, now: Instant, reset_at: Instant) -> BucketHeaders {
+        let reset_after = reset_at.saturating_duration_since(now);
+        let reset = (SystemTime::now() + reset_after)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        BucketHeaders {
+            hash: self.hash.clone(),
+            limit: self.config.limit,
+            remaining: self.remaining,
+            reset: reset.as_secs_f64(),
+            reset_after: reset_after.as_secs_f64(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketState, MockBucket, Ticket};
+    use std::time::{Duration, Instant};
+    use twilight_http::routing::Path;
+
+    #[test]
+    fn test_hash_for() {
+        let bucket = MockBucket::new(1, Duration::from_secs(1));
+        assert_ne!(
+            bucket.hash_for(&Path::ChannelsId(1)),
+            bucket.hash_for(&Path::ChannelsId(2))
+        );
+        assert_eq!("abc", bucket.hash("abc").hash_for(&Path::ChannelsId(1)));
+    }
+
+    #[test]
+    fn test_take_exhausts_and_resets() {
+        let now = Instant::now();
+        let mut state =
+            BucketState::new(MockBucket::new(2, Duration::from_secs(5)), "abc".to_owned());
+
+        assert!(matches!(state.take(now), Ticket::Allowed(headers) if headers.remaining == 1));
+        assert!(matches!(
+            state.take(now + Duration::from_secs(1)),
+            Ticket::Allowed(headers) if headers.remaining == 0 && (headers.reset_after - 4.0).abs() < f64::EPSILON
+        ));
+        assert!(matches!(
+            state.take(now + Duration::from_secs(2)),
+            Ticket::Exhausted(headers) if (headers.reset_after - 3.0).abs() < f64::EPSILON
+        ));
+        assert!(matches!(
+            state.take(now + Duration::from_secs(5)),
+            Ticket::Allowed(headers) if headers.remaining == 1
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+//! # twilight-http-mock
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-http-mock` is an in-process mock of Discord's REST API for
+//! testing code using `twilight-http` without making requests to Discord.
+//!
+//! The [`MockHttp`] server listens on a local port and is used by pointing
+//! the client's proxy at it via `ClientBuilder::proxy`. Requests are validated
+//! against `twilight_http::routing::Path`; requests to unknown paths receive a
+//! `404 Not Found`, just like responses to routes without a canned
+//! [`MockResponse`].
+//!
+//! Paths can be given ratelimit buckets via [`MockBucket`]s. Responses of
+//! ratelimited paths include the same `X-RateLimit-*` headers Discord sends,
+//! and requests exceeding a bucket receive a `429 Too Many Requests` with a
+//! `Retry-After` header. Global ratelimits can be triggered via
+//! [`MockHttp::global_ratelimit`]. This allows testing the client's
+//! `Ratelimiter` deterministically.
+//!
+//! All received requests are recorded and can be inspected via
+//! [`MockHttp::requests`].
+//!
+//! ## Examples
+//!
+//! Respond to requests for the current user and make a request:
+//!
+//! ```rust,no_run
+//! use hyper::Method;
+//! use std::time::Duration;
+//! use twilight_http::{routing::Path, Client};
+//! use twilight_http_mock::{MockBucket, MockHttp, MockResponse};
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mock = MockHttp::builder()
+//!     .bucket(Path::UsersId, MockBucket::new(5, Duration::from_secs(5)))
+//!     .bind()?;
+//! mock.respond(
+//!     Method::GET,
+//!     "users/@me",
+//!     MockResponse::json(&serde_json::json!({
+//!         "avatar": null,
+//!         "bot": true,
+//!         "discriminator": "0001",
+//!         "id": "1",
+//!         "mfa_enabled": false,
+//!         "username": "twilight",
+//!     })),
+//! );
+//!
+//! let client = Client::builder()
+//!     .proxy(mock.proxy_url(), true)
+//!     .token("token")
+//!     .build();
+//! let user = client.current_user().await?.model()?;
+//! assert_eq!("twilight", user.name);
+//! # Ok(()) }
+//! ```
+//!
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+mod bucket;
+mod response;
+mod server;
+
+pub use self::{
+    bucket::MockBucket,
+    response::MockResponse,
+    server::{MockHttp, MockHttpBuilder, MockRequest},
+};
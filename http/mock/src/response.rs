@@ -0,0 +1,95 @@
+use hyper::{
+    header::{HeaderName, HeaderValue},
+    HeaderMap, StatusCode,
+};
+use serde::Serialize;
+
+/// Canned response returned by the mock API.
+///
+/// # Examples
+///
+/// Respond with a JSON body and a custom header:
+///
+/// ```rust
+/// use twilight_http_mock::MockResponse;
+///
+/// let response = MockResponse::json(&serde_json::json!({ "url": "wss://gateway.discord.gg" }))
+///     .header("x-custom", "value");
+/// ```
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub(crate) body: Vec<u8>,
+    pub(crate) headers: HeaderMap,
+    pub(crate) status: StatusCode,
+}
+
+impl MockResponse {
+    /// Create a response with a status code and no body.
+    pub fn status(status: StatusCode) -> Self {
+        Self {
+            body: Vec::new(),
+            headers: HeaderMap::new(),
+            status,
+        }
+    }
+
+    /// Create a `204 No Content` response.
+    pub fn empty() -> Self {
+        Self::status(StatusCode::NO_CONTENT)
+    }
+
+    /// Create a `200 OK` response with a value serialized as the JSON body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can't be serialized.
+    pub fn json<T: Serialize + ?Sized>(value: &T) -> Self {
+        let body = serde_json::to_vec(value).expect("value must serialize to json");
+
+        Self::status(StatusCode::OK).body(body)
+    }
+
+    /// Create an error response in the format of the API.
+    pub fn error(status: StatusCode, code: u64, message: impl Into<String>) -> Self {
+        let body = serde_json::json!({
+            "code": code,
+            "message": message.into(),
+        });
+
+        Self::json(&body).with_status(status)
+    }
+
+    /// Set the raw body of the response.
+    ///
+    /// A `Content-Type` of `application/json` is set if no content type has
+    /// been set.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+
+        self.headers
+            .entry(hyper::header::CONTENT_TYPE)
+            .or_insert_with(|| HeaderValue::from_static("application/json"));
+
+        self
+    }
+
+    /// Add a header to the response.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or value aren't valid header components.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        let name = HeaderName::from_bytes(name.as_bytes()).expect("header name must be valid");
+        let value = HeaderValue::from_str(value).expect("header value must be valid");
+        self.headers.insert(name, value);
+
+        self
+    }
+
+    /// Set the status code of the response.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+
+        self
+    }
+}
@@ -0,0 +1,398 @@
+use super::{
+    bucket::{BucketHeaders, BucketState, MockBucket, Ticket},
+    response::MockResponse,
+};
+use hyper::{
+    header::{HeaderValue, CONTENT_TYPE, RETRY_AFTER},
+    server::Server,
+    service::{make_service_fn, service_fn},
+    Body, Error as HyperError, HeaderMap, Method, Request, Response, StatusCode,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::{Infallible, TryFrom},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::task::JoinHandle;
+use twilight_http::{routing::Path, API_VERSION};
+
+/// Request received by the mock API.
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    /// Body of the request.
+    pub body: Vec<u8>,
+    /// Headers of the request.
+    pub headers: HeaderMap,
+    /// Method of the request.
+    pub method: Method,
+    /// Ratelimiting path of the request.
+    pub path: Path,
+    /// Route of the request after the API prefix, including the query.
+    pub route: String,
+    /// Status code that was responded with.
+    pub status: StatusCode,
+}
+
+impl MockRequest {
+    /// Deserialize the body of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns a JSON error if the body couldn't be deserialized.
+    pub fn json<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    buckets: HashMap<Path, MockBucket>,
+    default_bucket: Option<MockBucket>,
+    /// Time until which requests are globally ratelimited.
+    global_until: Option<Instant>,
+    requests: Vec<MockRequest>,
+    responses: HashMap<(Method, String), VecDeque<MockResponse>>,
+    /// States of buckets by their hash.
+    states: HashMap<String, BucketState>,
+}
+
+/// Builder for a [`MockHttp`].
+#[derive(Debug, Default)]
+pub struct MockHttpBuilder {
+    buckets: HashMap<Path, MockBucket>,
+    default_bucket: Option<MockBucket>,
+}
+
+impl MockHttpBuilder {
+    /// Create a new builder for a mock API.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configure the ratelimit bucket of a path.
+    pub fn bucket(mut self, path: Path, bucket: MockBucket) -> Self {
+        self.buckets.insert(path, bucket);
+
+        self
+    }
+
+    /// Configure the ratelimit bucket of paths without a configured bucket.
+    ///
+    /// Each path gets its own bucket with this configuration. By default
+    /// these paths aren't ratelimited and responses have no ratelimit
+    /// headers.
+    pub fn default_bucket(mut self, bucket: MockBucket) -> Self {
+        self.default_bucket.replace(bucket);
+
+        self
+    }
+
+    /// Bind the mock API to a random local port and start serving requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns a hyper error if binding the server failed.
+    pub fn bind(self) -> Result<MockHttp, HyperError> {
+        let state = Arc::new(Mutex::new(State {
+            buckets: self.buckets,
+            default_bucket: self.default_bucket,
+            global_until: None,
+            requests: Vec::new(),
+            responses: HashMap::new(),
+            states: HashMap::new(),
+        }));
+
+        let service_state = Arc::clone(&state);
+        let make_service = make_service_fn(move |_| {
+            let state = Arc::clone(&service_state);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle(Arc::clone(&state), request)
+                }))
+            }
+        });
+
+        let server = Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0)))?.serve(make_service);
+        let addr = server.local_addr();
+
+        let handle = tokio::spawn(async move {
+            if let Err(source) = server.await {
+                tracing::warn!("mock http server failed: {}", source);
+            }
+        });
+
+        Ok(MockHttp {
+            addr,
+            handle,
+            state,
+        })
+    }
+}
+
+/// In-process mock of Discord's REST API.
+///
+/// Refer to the [crate-level] documentation for more information.
+///
+/// The mock stops serving requests when dropped.
+///
+/// [crate-level]: crate
+#[derive(Debug)]
+pub struct MockHttp {
+    addr: SocketAddr,
+    handle: JoinHandle<()>,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockHttp {
+    /// Bind a mock API with the default configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a hyper error if binding the server failed.
+    pub fn bind() -> Result<Self, HyperError> {
+        MockHttpBuilder::new().bind()
+    }
+
+    /// Create a builder to configure a mock API.
+    pub fn builder() -> MockHttpBuilder {
+        MockHttpBuilder::new()
+    }
+
+    /// Return the local address the mock API is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Return the value to pass to `ClientBuilder::proxy`.
+    ///
+    /// The mock only speaks HTTP, so `use_http` must be `true`.
+    pub fn proxy_url(&self) -> String {
+        self.addr.to_string()
+    }
+
+    /// Queue a response for requests with a method and route.
+    ///
+    /// The route is relative to the API prefix, such as `channels/1/messages`.
+    /// The query of requests isn't considered when matching routes.
+    ///
+    /// Queued responses are returned in order, and the last response of a
+    /// route is returned for all further requests. Requests to routes without
+    /// a response receive a `404 Not Found`.
+    pub fn respond(&self, method: Method, route: impl Into<String>, response: MockResponse) {
+        let route = route.into().trim_start_matches('/').to_owned();
+
+        self.lock()
+            .responses
+            .entry((method, route))
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Ratelimit all requests globally for a duration.
+    pub fn global_ratelimit(&self, duration: Duration) {
+        self.lock().global_until.replace(Instant::now() + duration);
+    }
+
+    /// Return a copy of all of the requests that have been received.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Stop serving requests.
+    pub fn shutdown(&self) {
+        self.handle.abort();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().expect("state poisoned")
+    }
+}
+
+impl Drop for MockHttp {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+async fn handle(
+    state: Arc<Mutex<State>>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = hyper::body::to_bytes(body)
+        .await
+        .map(|bytes| bytes.to_vec())
+        .unwrap_or_default();
+
+    let prefix = format!("/api/v{}/", API_VERSION);
+    let route = match parts
+        .uri
+        .path_and_query()
+        .map(|path_and_query| path_and_query.as_str())
+        .and_then(|path_and_query| path_and_query.strip_prefix(&prefix))
+    {
+        Some(route) => route.to_owned(),
+        None => return Ok(not_found()),
+    };
+
+    let without_query = route.split('?').next().unwrap_or_default().to_owned();
+
+    let path = if let Ok(path) = Path::try_from((parts.method.clone(), without_query.as_str())) {
+        path
+    } else {
+        tracing::warn!(method = %parts.method, %route, "request to unknown path");
+
+        return Ok(not_found());
+    };
+
+    let mut state = state.lock().expect("state poisoned");
+    let now = Instant::now();
+
+    let response = if let Some(retry_after) = state
+        .global_until
+        .and_then(|until| until.checked_duration_since(now))
+    {
+        global_ratelimited(retry_after)
+    } else {
+        let ticket = bucket_ticket(&mut state, &path, now);
+
+        match ticket {
+            Some(Ticket::Exhausted(headers)) => {
+                let mut response = ratelimited(headers.reset_after, false);
+                insert_bucket_headers(response.headers_mut(), &headers);
+
+                response
+            }
+            Some(Ticket::Allowed(headers)) => {
+                let mut response = canned(&mut state, &parts.method, without_query);
+                insert_bucket_headers(response.headers_mut(), &headers);
+
+                response
+            }
+            None => canned(&mut state, &parts.method, without_query),
+        }
+    };
+
+    tracing::debug!(method = %parts.method, %route, status = %response.status(), "handled request");
+
+    state.requests.push(MockRequest {
+        body,
+        headers: parts.headers,
+        method: parts.method,
+        path,
+        route,
+        status: response.status(),
+    });
+
+    Ok(response)
+}
+
+fn bucket_ticket(state: &mut State, path: &Path, now: Instant) -> Option<Ticket> {
+    let config = state
+        .buckets
+        .get(path)
+        .or_else(|| state.default_bucket.as_ref())?
+        .clone();
+    let hash = config.hash_for(path);
+
+    let bucket = state
+        .states
+        .entry(hash.clone())
+        .or_insert_with(|| BucketState::new(config, hash));
+
+    Some(bucket.take(now))
+}
+
+fn canned(state: &mut State, method: &Method, route: String) -> Response<Body> {
+    let queue = match state.responses.get_mut(&(method.clone(), route)) {
+        Some(queue) => queue,
+        None => return not_found(),
+    };
+
+    let response = if queue.len() > 1 {
+        queue.pop_front()
+    } else {
+        queue.front().cloned()
+    };
+
+    let response = match response {
+        Some(response) => response,
+        None => return not_found(),
+    };
+
+    let mut built = Response::new(Body::from(response.body));
+    *built.status_mut() = response.status;
+    *built.headers_mut() = response.headers;
+
+    built
+}
+
+fn insert_bucket_headers(map: &mut HeaderMap, headers: &BucketHeaders) {
+    let values = [
+        ("x-ratelimit-bucket", headers.hash.clone()),
+        ("x-ratelimit-limit", headers.limit.to_string()),
+        ("x-ratelimit-remaining", headers.remaining.to_string()),
+        ("x-ratelimit-reset", format!("{:.3}", headers.reset)),
+        (
+            "x-ratelimit-reset-after",
+            format!("{:.3}", headers.reset_after),
+        ),
+    ];
+
+    for (name, value) in &values {
+        if let Ok(value) = HeaderValue::from_str(value) {
+            map.insert(*name, value);
+        }
+    }
+}
+
+fn global_ratelimited(retry_after: Duration) -> Response<Body> {
+    let mut response = ratelimited(retry_after.as_secs_f64(), true);
+    let headers = response.headers_mut();
+    headers.insert("x-ratelimit-global", HeaderValue::from_static("true"));
+
+    if let Ok(value) = HeaderValue::from_str(&format!("{:.3}", retry_after.as_secs_f64())) {
+        headers.insert("x-ratelimit-reset-after", value);
+    }
+
+    response
+}
+
+fn ratelimited(retry_after: f64, global: bool) -> Response<Body> {
+    let body = serde_json::json!({
+        "global": global,
+        "message": "You are being rate limited.",
+        "retry_after": retry_after,
+    });
+
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let retry_after_secs = retry_after.ceil() as u64;
+    headers.insert(RETRY_AFTER, HeaderValue::from(retry_after_secs));
+
+    response
+}
+
+fn not_found() -> Response<Body> {
+    let body = serde_json::json!({
+        "code": 0,
+        "message": "404: Not Found",
+    });
+
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = StatusCode::NOT_FOUND;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response
+}
@@ -0,0 +1,176 @@
+use super::{
+    clients::Clients,
+    proxy::{self, Proxy, ProxyRef, RatelimiterFactory},
+};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use twilight_http::ratelimiting::{InMemoryRatelimiter, Ratelimiter};
+
+/// Builder for a [`Proxy`].
+pub struct ProxyBuilder {
+    client_idle_timeout: Duration,
+    default_token: Option<String>,
+    invalid_request_threshold: Option<usize>,
+    max_clients: usize,
+    ratelimiter: Box<RatelimiterFactory>,
+    timeout: Duration,
+    upstream: Option<(String, bool)>,
+}
+
+impl ProxyBuilder {
+    /// Create a new builder to configure a proxy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the proxy.
+    pub fn build(self) -> Proxy {
+        let unauthenticated = proxy::client_builder(
+            self.ratelimiter.as_ref(),
+            self.timeout,
+            self.upstream.as_ref(),
+            None,
+        )
+        .invalid_request_threshold(self.invalid_request_threshold)
+        .build();
+
+        Proxy(Arc::new(ProxyRef {
+            clients: Mutex::new(Clients::new(self.max_clients, self.client_idle_timeout)),
+            default_token: self.default_token,
+            ratelimiter: self.ratelimiter,
+            timeout: self.timeout,
+            unauthenticated,
+            upstream: self.upstream,
+        }))
+    }
+
+    /// Set how long the client of a token is kept without requests being made
+    /// with it.
+    ///
+    /// Dropping a client drops the state of its ratelimiter, so the timeout
+    /// should be longer than the time it takes ratelimits to reset. Clients
+    /// with requests in flight aren't dropped.
+    ///
+    /// The default is 10 minutes.
+    pub fn client_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.client_idle_timeout = timeout;
+
+        self
+    }
+
+    /// Set the token to use for requests without an `Authorization` header.
+    ///
+    /// Requests without a token are made without authentication by default.
+    pub fn default_token(mut self, token: impl Into<String>) -> Self {
+        self.default_token.replace(token.into());
+
+        self
+    }
+
+    /// Set the number of `401 Unauthorized`, `403 Forbidden`, and `429 Too
+    /// Many Requests` responses within 10 minutes after which the proxy
+    /// stops forwarding requests.
+    ///
+    /// The responses to requests of all tokens are counted together, as
+    /// Discord bans the IP address of the proxy once it receives 10,000 of
+    /// them within 10 minutes. If the argument is `None` then the responses
+    /// aren't counted.
+    ///
+    /// The default is 9,000.
+    pub fn invalid_request_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.invalid_request_threshold = threshold;
+
+        self
+    }
+
+    /// Set the maximum number of tokens to keep clients for.
+    ///
+    /// Once the maximum is reached, the client of the least recently used
+    /// token without requests in flight is dropped when a request with a new
+    /// token is made. If all clients have requests in flight, the maximum is
+    /// exceeded until they finish.
+    ///
+    /// The default is 1,000.
+    pub fn max_clients(mut self, max: usize) -> Self {
+        self.max_clients = max;
+
+        self
+    }
+
+    /// Set the function creating the ratelimiter of each token.
+    ///
+    /// The function is called with the token the first time a request with
+    /// it is made, or with `None` for requests without a token. Ratelimiters
+    /// shared by multiple proxies, such as the `RedisRatelimiter`, must use a
+    /// different key prefix for each token.
+    ///
+    /// Defaults to creating an [`InMemoryRatelimiter`].
+    pub fn ratelimiter(
+        mut self,
+        ratelimiter: impl Fn(Option<&str>) -> Box<dyn Ratelimiter> + Send + Sync + 'static,
+    ) -> Self {
+        self.ratelimiter = Box::new(ratelimiter);
+
+        self
+    }
+
+    /// Set the timeout of requests forwarded to the API.
+    ///
+    /// The default is 10 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Set the host to forward requests to instead of Discord.
+    ///
+    /// This is useful to chain proxies or test the proxy against a mock of
+    /// the API. If `use_http` is true then requests are forwarded via HTTP
+    /// instead of HTTPS.
+    pub fn upstream(mut self, host: impl Into<String>, use_http: bool) -> Self {
+        self.upstream.replace((host.into(), use_http));
+
+        self
+    }
+}
+
+impl Debug for ProxyBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ProxyBuilder")
+            .field("client_idle_timeout", &self.client_idle_timeout)
+            .field("default_token", &self.default_token)
+            .field("invalid_request_threshold", &self.invalid_request_threshold)
+            .field("max_clients", &self.max_clients)
+            .field("ratelimiter", &"<fn>")
+            .field("timeout", &self.timeout)
+            .field("upstream", &self.upstream)
+            .finish()
+    }
+}
+
+impl Default for ProxyBuilder {
+    fn default() -> Self {
+        Self {
+            client_idle_timeout: Duration::from_secs(10 * 60),
+            default_token: None,
+            invalid_request_threshold: Some(9_000),
+            max_clients: 1_000,
+            ratelimiter: Box::new(|_| Box::new(InMemoryRatelimiter::new())),
+            timeout: Duration::from_secs(10),
+            upstream: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProxyBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(ProxyBuilder: Debug, Default, Send, Sync);
+}
@@ -0,0 +1,176 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use twilight_http::Client;
+
+/// Handle held while a request of a client is in flight, which keeps the
+/// client from being dropped.
+pub(crate) type InFlight = Arc<()>;
+
+/// Client of a token and when it was last used.
+#[derive(Debug)]
+struct Entry {
+    client: Client,
+    in_flight: InFlight,
+    used_at: Instant,
+}
+
+impl Entry {
+    /// Whether the client has requests in flight, including requests waiting
+    /// for their ratelimit.
+    fn is_in_flight(&self) -> bool {
+        Arc::strong_count(&self.in_flight) > 1
+    }
+
+    fn is_idle(&self, now: Instant, idle_timeout: Duration) -> bool {
+        !self.is_in_flight() && now.saturating_duration_since(self.used_at) >= idle_timeout
+    }
+}
+
+/// Clients making the requests of each token.
+///
+/// Clients that haven't been used for longer than the idle timeout are
+/// dropped, and once the maximum number of clients is reached the least
+/// recently used client is dropped to make room for a new one. Dropping a
+/// client drops the state of its ratelimiter, so clients with requests in
+/// flight are kept even if there are more than the maximum.
+#[derive(Debug)]
+pub(crate) struct Clients {
+    entries: HashMap<String, Entry>,
+    idle_timeout: Duration,
+    max: usize,
+}
+
+impl Clients {
+    pub fn new(max: usize, idle_timeout: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            idle_timeout,
+            max: max.max(1),
+        }
+    }
+
+    /// Return the client of a token, marking it as used.
+    ///
+    /// The returned handle must be held until the request is finished.
+    pub fn get(&mut self, token: &str) -> Option<(Client, InFlight)> {
+        let now = Instant::now();
+        let idle_timeout = self.idle_timeout;
+        let entry = self.entries.get_mut(token)?;
+
+        if entry.is_idle(now, idle_timeout) {
+            self.entries.remove(token);
+
+            return None;
+        }
+
+        entry.used_at = now;
+
+        Some((entry.client.clone(), Arc::clone(&entry.in_flight)))
+    }
+
+    /// Insert the client of a token, dropping idle clients and the least
+    /// recently used client without requests in flight if there are too
+    /// many.
+    ///
+    /// Returns the handle of the request the client was created for.
+    pub fn insert(&mut self, token: String, client: Client) -> InFlight {
+        let now = Instant::now();
+        let idle_timeout = self.idle_timeout;
+
+        self.entries
+            .retain(|_, entry| !entry.is_idle(now, idle_timeout));
+
+        if !self.entries.contains_key(&token) && self.entries.len() >= self.max {
+            let least_recent = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| !entry.is_in_flight())
+                .min_by_key(|(_, entry)| entry.used_at)
+                .map(|(token, _)| token.clone());
+
+            if let Some(least_recent) = least_recent {
+                tracing::debug!("dropping the least recently used client");
+                self.entries.remove(&least_recent);
+            }
+        }
+
+        let in_flight = InFlight::default();
+
+        self.entries.insert(
+            token,
+            Entry {
+                client,
+                in_flight: Arc::clone(&in_flight),
+                used_at: now,
+            },
+        );
+
+        in_flight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clients;
+    use std::time::Duration;
+    use twilight_http::Client;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut clients = Clients::new(2, Duration::from_secs(60));
+        clients.insert("a".to_owned(), Client::builder().build());
+        clients.insert("b".to_owned(), Client::builder().build());
+        assert!(clients.get("a").is_some());
+
+        clients.insert("c".to_owned(), Client::builder().build());
+        assert_eq!(2, clients.entries.len());
+        assert!(clients.get("a").is_some());
+        assert!(clients.get("b").is_none());
+        assert!(clients.get("c").is_some());
+    }
+
+    #[test]
+    fn test_keeps_clients_in_flight() {
+        let mut clients = Clients::new(2, Duration::from_millis(20));
+        let in_flight = clients.insert("a".to_owned(), Client::builder().build());
+        clients.insert("b".to_owned(), Client::builder().build());
+
+        // The least recently used client without requests in flight is
+        // dropped instead.
+        clients.insert("c".to_owned(), Client::builder().build());
+        assert!(clients.entries.contains_key("a"));
+        assert!(!clients.entries.contains_key("b"));
+
+        // Clients with requests in flight aren't idle.
+        let (_, c_in_flight) = clients.get("c").unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(clients.get("a").is_some());
+
+        // Once all clients are in flight the maximum is exceeded.
+        clients.insert("d".to_owned(), Client::builder().build());
+        assert_eq!(3, clients.entries.len());
+
+        drop((in_flight, c_in_flight));
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(clients.get("a").is_none());
+    }
+
+    #[test]
+    fn test_evicts_idle() {
+        let mut clients = Clients::new(10, Duration::from_millis(20));
+        clients.insert("a".to_owned(), Client::builder().build());
+        assert!(clients.get("a").is_some());
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(clients.get("a").is_none());
+
+        clients.insert("b".to_owned(), Client::builder().build());
+        clients.insert("c".to_owned(), Client::builder().build());
+        std::thread::sleep(Duration::from_millis(30));
+        clients.insert("d".to_owned(), Client::builder().build());
+        assert_eq!(1, clients.entries.len());
+    }
+}
@@ -0,0 +1,103 @@
+//! # twilight-http-proxy
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-http-proxy` is a reverse proxy for Discord's REST API that
+//! ratelimits requests using `twilight-http`'s ratelimiters before forwarding
+//! them to Discord. Services making requests through the same proxy, whether
+//! they're written in Rust or not, share one view of the ratelimits and don't
+//! need to ratelimit requests themselves.
+//!
+//! Requests are made to the proxy just like they would be made to Discord,
+//! such as `POST /api/v8/channels/123/messages`. The paths of requests are
+//! parsed into `twilight_http::routing::Path`s to determine their ratelimit
+//! buckets, and requests to unknown paths receive a `404 Not Found`.
+//!
+//! Each token in the `Authorization` header of requests gets its own
+//! ratelimiter, since ratelimits apply per token. Tokens without a `Bot ` or
+//! `Bearer ` prefix are bot tokens, like in `twilight-http`. Requests without a
+//! token use the default token of the proxy, if one is configured. Responses
+//! are passed through as they are, including the `X-RateLimit-*` headers.
+//!
+//! The ratelimiters of tokens that haven't been used for a while are dropped,
+//! once their requests are finished.
+//! The `401`, `403`, and `429` responses to requests of all tokens are counted
+//! together, and requests are blocked with a `503 Service Unavailable` before
+//! enough of them are received to get the proxy's IP address banned.
+//!
+//! Twilight's HTTP client can make requests through the proxy via
+//! `ClientBuilder::proxy`, with its own ratelimiter disabled.
+//!
+//! ## Binary
+//!
+//! The `twilight-http-proxy` binary runs the proxy and requires the `bin`
+//! feature, such as via `cargo run --features bin`. It's configured via the
+//! following environment variables:
+//!
+//! - `DISCORD_TOKEN`: default token for requests without one, optional;
+//! - `HOST`: host to listen on, defaults to `0.0.0.0`;
+//! - `PORT`: port to listen on, defaults to `3000`;
+//! - `REDIS_URL`: URL of a Redis server to keep the ratelimits in, optional,
+//!   requires the `redis-ratelimiter` feature.
+//!
+//! Logging is configured via the `RUST_LOG` environment variable.
+//!
+//! ## Examples
+//!
+//! Run a proxy with a default token, keeping ratelimits in memory:
+//!
+//! ```rust,no_run
+//! use std::{env, net::SocketAddr};
+//! use twilight_http_proxy::Proxy;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let proxy = Proxy::builder()
+//!     .default_token(env::var("DISCORD_TOKEN")?)
+//!     .build();
+//!
+//! proxy.serve(SocketAddr::from(([0, 0, 0, 0], 3000))).await?;
+//! # Ok(()) }
+//! ```
+//!
+//! Make requests through the proxy with the HTTP client:
+//!
+//! ```rust,no_run
+//! use twilight_http::Client;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::builder()
+//!     .proxy("localhost:3000", true)
+//!     .ratelimiter(None)
+//!     .token("my token")
+//!     .build();
+//!
+//! let user = client.current_user().await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+mod builder;
+mod clients;
+mod proxy;
+
+pub use self::{builder::ProxyBuilder, proxy::Proxy};
@@ -0,0 +1,58 @@
+use std::{env, error::Error, net::SocketAddr};
+use twilight_http_proxy::Proxy;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_owned());
+    let port = env::var("PORT").unwrap_or_else(|_| "3000".to_owned());
+    let addr = format!("{}:{}", host, port).parse::<SocketAddr>()?;
+
+    let mut builder = Proxy::builder();
+
+    if let Ok(token) = env::var("DISCORD_TOKEN") {
+        builder = builder.default_token(token);
+    }
+
+    #[cfg(feature = "redis-ratelimiter")]
+    if let Ok(url) = env::var("REDIS_URL") {
+        use twilight_http::ratelimiting::RedisRatelimiter;
+
+        let ratelimiter = RedisRatelimiter::new(&url)?;
+
+        builder = builder.ratelimiter(move |token| {
+            // Keep the ratelimits of each token under their own keys.
+            let prefix = match token {
+                Some(token) => format!(
+                    "{}{}:",
+                    RedisRatelimiter::DEFAULT_KEY_PREFIX,
+                    token_id(token)
+                ),
+                None => RedisRatelimiter::DEFAULT_KEY_PREFIX.to_owned(),
+            };
+
+            Box::new(ratelimiter.clone().key_prefix(prefix))
+        });
+    }
+
+    builder.build().serve(addr).await?;
+
+    Ok(())
+}
+
+/// Return the ID of the bot of a token, falling back to the token itself.
+///
+/// The first segment of bot tokens is the base64 encoded ID of the bot. Using
+/// it in keys keeps the secret part of tokens out of the Redis server.
+#[cfg(feature = "redis-ratelimiter")]
+fn token_id(token: &str) -> &str {
+    let token = token
+        .strip_prefix("Bot ")
+        .or_else(|| token.strip_prefix("Bearer "))
+        .unwrap_or(token);
+
+    token.split('.').next().unwrap_or(token)
+}
@@ -0,0 +1,352 @@
+use super::clients::{Clients, InFlight};
+use hyper::{
+    header::{
+        HeaderName, HeaderValue, AUTHORIZATION, CONNECTION, CONTENT_TYPE, RETRY_AFTER,
+        TRANSFER_ENCODING,
+    },
+    server::Server,
+    service::{make_service_fn, service_fn},
+    Body, Error as HyperError, HeaderMap, Request, Response, StatusCode,
+};
+use std::{
+    borrow::Cow,
+    convert::{Infallible, TryFrom},
+    fmt::{Debug, Formatter, Result as FmtResult},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use twilight_http::{
+    client::ClientBuilder, ratelimiting::Ratelimiter, request::Request as ApiRequest,
+    routing::Path, Client, Error as ApiError, API_VERSION,
+};
+
+/// Function creating the ratelimiter of a token.
+pub(crate) type RatelimiterFactory = dyn Fn(Option<&str>) -> Box<dyn Ratelimiter> + Send + Sync;
+
+/// Headers of requests that are forwarded to the API.
+const FORWARDED_HEADERS: &[&str] = &["content-type", "x-audit-log-reason"];
+
+pub(crate) struct ProxyRef {
+    /// Clients making the requests of each token.
+    pub clients: Mutex<Clients>,
+    pub default_token: Option<String>,
+    pub ratelimiter: Box<RatelimiterFactory>,
+    pub timeout: Duration,
+    /// Client making requests without a token.
+    ///
+    /// The clients of all tokens share its count of invalid requests, as
+    /// they make requests from the same IP address.
+    pub unauthenticated: Client,
+    pub upstream: Option<(String, bool)>,
+}
+
+impl Debug for ProxyRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ProxyRef")
+            .field("clients", &self.clients)
+            .field("default_token", &self.default_token)
+            .field("ratelimiter", &"<fn>")
+            .field("timeout", &self.timeout)
+            .field("unauthenticated", &self.unauthenticated)
+            .field("upstream", &self.upstream)
+            .finish()
+    }
+}
+
+/// Create a builder for a client forwarding requests with a token.
+pub(crate) fn client_builder(
+    ratelimiter: &RatelimiterFactory,
+    timeout: Duration,
+    upstream: Option<&(String, bool)>,
+    token: Option<&str>,
+) -> ClientBuilder {
+    let mut builder = Client::builder()
+        .ratelimiter(Some(ratelimiter(token)))
+        .timeout(timeout);
+
+    if let Some(token) = token {
+        builder = builder.token(token);
+    }
+
+    if let Some((host, use_http)) = upstream {
+        builder = builder.proxy(host.clone(), *use_http);
+    }
+
+    builder
+}
+
+/// Ratelimiting proxy for Discord's REST API.
+///
+/// Refer to the [crate-level] documentation for more information.
+///
+/// The proxy can be cloned cheaply, with clones sharing the ratelimiters.
+///
+/// [crate-level]: crate
+#[derive(Clone, Debug)]
+pub struct Proxy(pub(crate) Arc<ProxyRef>);
+
+impl Proxy {
+    /// Create a proxy with the default configuration.
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Create a builder to configure a proxy.
+    pub fn builder() -> super::ProxyBuilder {
+        super::ProxyBuilder::new()
+    }
+
+    /// Serve requests on an address until an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// Returns a hyper error if binding to the address or serving requests
+    /// failed.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), HyperError> {
+        let make_service = make_service_fn(move |_| {
+            let proxy = self.clone();
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let proxy = proxy.clone();
+
+                    async move { Ok::<_, Infallible>(proxy.handle(request).await) }
+                }))
+            }
+        });
+
+        let server = Server::try_bind(&addr)?.serve(make_service);
+        tracing::info!("listening on {}", server.local_addr());
+
+        server.await
+    }
+
+    /// Ratelimit a request and forward it to the API, returning its response.
+    ///
+    /// This can be used to serve requests from an existing hyper server.
+    pub async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        let (parts, body) = request.into_parts();
+
+        let route = match parts
+            .uri
+            .path_and_query()
+            .and_then(|path_and_query| strip_api_prefix(path_and_query.as_str()))
+        {
+            Some(route) => route.to_owned(),
+            None => return error(StatusCode::NOT_FOUND, "404: Not Found"),
+        };
+
+        let without_query = route.split('?').next().unwrap_or_default();
+
+        let path = if let Ok(path) = Path::try_from((parts.method.clone(), without_query)) {
+            path
+        } else {
+            tracing::debug!(method = %parts.method, %route, "request to unknown path");
+
+            return error(StatusCode::NOT_FOUND, "404: Not Found");
+        };
+
+        let body = match hyper::body::to_bytes(body).await {
+            Ok(body) => body,
+            Err(source) => {
+                tracing::debug!("reading the request body failed: {}", source);
+
+                return error(StatusCode::BAD_REQUEST, "reading the body failed");
+            }
+        };
+
+        let mut headers = HeaderMap::new();
+
+        for name in FORWARDED_HEADERS {
+            if let Some(value) = parts.headers.get(*name) {
+                headers.insert(HeaderName::from_static(name), value.clone());
+            }
+        }
+
+        let token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .or_else(|| self.0.default_token.as_deref())
+            .map(normalize_token);
+        let (client, _in_flight) = self.client(token.as_deref());
+
+        tracing::debug!(method = %parts.method, %route, "forwarding request");
+
+        let request = ApiRequest {
+            body: if body.is_empty() {
+                None
+            } else {
+                Some(body.to_vec())
+            },
+            form: None,
+            headers: Some(headers),
+            method: parts.method,
+            path,
+            path_str: Cow::Owned(route),
+        };
+
+        match client.raw(request).await {
+            Ok(mut response) => {
+                let headers = response.headers_mut();
+                headers.remove(CONNECTION);
+                headers.remove(TRANSFER_ENCODING);
+
+                response
+            }
+            Err(source) => error_response(&source),
+        }
+    }
+
+    /// Return the client making requests with a token, creating it if
+    /// needed.
+    ///
+    /// The client of a token is kept while the returned handle is held.
+    fn client(&self, token: Option<&str>) -> (Client, Option<InFlight>) {
+        let token = match token {
+            Some(token) => token,
+            None => return (self.0.unauthenticated.clone(), None),
+        };
+
+        let mut clients = self.0.clients.lock().expect("clients poisoned");
+
+        if let Some((client, in_flight)) = clients.get(token) {
+            return (client, Some(in_flight));
+        }
+
+        let client = client_builder(
+            self.0.ratelimiter.as_ref(),
+            self.0.timeout,
+            self.0.upstream.as_ref(),
+            Some(token),
+        )
+        .share_invalid_requests(&self.0.unauthenticated)
+        .build();
+        let in_flight = clients.insert(token.to_owned(), client.clone());
+
+        (client, Some(in_flight))
+    }
+}
+
+impl Default for Proxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Prefix a token with `Bot ` if it has neither a `Bot ` nor a `Bearer `
+/// prefix, like the client does.
+///
+/// This way requests with and without the prefix share a client and
+/// ratelimiter.
+fn normalize_token(token: &str) -> Cow<'_, str> {
+    if token.starts_with("Bot ") || token.starts_with("Bearer ") {
+        Cow::Borrowed(token)
+    } else {
+        Cow::Owned(format!("Bot {}", token))
+    }
+}
+
+/// Strip the `/api/v{version}/` prefix from the path of a request, returning
+/// the route after it.
+///
+/// Paths without a version are accepted, but paths for other versions of the
+/// API than the one supported by `twilight-http` are not.
+fn strip_api_prefix(path: &str) -> Option<&str> {
+    let route = path.strip_prefix("/api/")?;
+    let version = format!("v{}/", API_VERSION);
+
+    if let Some(route) = route.strip_prefix(&version) {
+        return Some(route);
+    }
+
+    let is_versioned = route
+        .strip_prefix('v')
+        .and_then(|rest| rest.split('/').next())
+        .map_or(false, |version| {
+            !version.is_empty() && version.chars().all(|c| c.is_ascii_digit())
+        });
+
+    if is_versioned {
+        None
+    } else {
+        Some(route)
+    }
+}
+
+/// Create a response for an error of the client.
+fn error_response(source: &ApiError) -> Response<Body> {
+    match source {
+        ApiError::InvalidRequestLimit { retry_after } => {
+            tracing::warn!("blocking requests to prevent an IP ban: {}", source);
+
+            let mut response = error(StatusCode::SERVICE_UNAVAILABLE, &source.to_string());
+
+            if let Ok(value) = HeaderValue::try_from(retry_after.as_secs().to_string()) {
+                response.headers_mut().insert(RETRY_AFTER, value);
+            }
+
+            response
+        }
+        ApiError::RequestTimedOut { .. } => {
+            error(StatusCode::GATEWAY_TIMEOUT, "request to the api timed out")
+        }
+        ApiError::Unauthorized => error(StatusCode::UNAUTHORIZED, "401: Unauthorized"),
+        other => {
+            tracing::warn!("forwarding request failed: {}", other);
+
+            error(StatusCode::BAD_GATEWAY, "forwarding the request failed")
+        }
+    }
+}
+
+/// Create an error response in the format of the API.
+fn error(status: StatusCode, message: &str) -> Response<Body> {
+    let body = serde_json::json!({
+        "code": 0,
+        "message": message,
+    });
+
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_token, strip_api_prefix, Proxy};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Proxy: Clone, Debug, Default, Send, Sync);
+
+    #[test]
+    fn test_normalize_token() {
+        assert_eq!("Bot abc", normalize_token("abc"));
+        assert_eq!("Bot abc", normalize_token("Bot abc"));
+        assert_eq!("Bearer abc", normalize_token("Bearer abc"));
+    }
+
+    #[test]
+    fn test_strip_api_prefix() {
+        assert_eq!(
+            Some("channels/1/messages"),
+            strip_api_prefix("/api/v8/channels/1/messages")
+        );
+        assert_eq!(
+            Some("channels/1/messages?limit=5"),
+            strip_api_prefix("/api/channels/1/messages?limit=5")
+        );
+        assert_eq!(
+            Some("voice/regions"),
+            strip_api_prefix("/api/voice/regions")
+        );
+        assert!(strip_api_prefix("/api/v6/channels/1").is_none());
+        assert!(strip_api_prefix("/channels/1").is_none());
+    }
+}
@@ -0,0 +1,183 @@
+use hyper::{Method, StatusCode};
+use serde_json::json;
+use std::{
+    error::Error,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+use twilight_http::{routing::Path, Client, Error as HttpError};
+use twilight_http_mock::{MockBucket, MockHttp, MockResponse};
+use twilight_http_proxy::Proxy;
+use twilight_model::id::ChannelId;
+
+/// Start a proxy forwarding requests to a mock, returning its address.
+async fn proxy(mock: &MockHttp) -> Result<SocketAddr, Box<dyn Error>> {
+    serve(
+        Proxy::builder()
+            .default_token("default")
+            .upstream(mock.proxy_url(), true)
+            .build(),
+    )
+    .await
+}
+
+/// Start a proxy, returning its address.
+async fn serve(proxy: Proxy) -> Result<SocketAddr, Box<dyn Error>> {
+    // Find a free port for the proxy.
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    tokio::spawn(proxy.serve(addr));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    Ok(addr)
+}
+
+fn client(addr: SocketAddr, token: Option<&str>) -> Client {
+    let builder = Client::builder()
+        .proxy(addr.to_string(), true)
+        .ratelimiter(None);
+
+    match token {
+        Some(token) => builder.token(token),
+        None => builder,
+    }
+    .build()
+}
+
+#[tokio::test]
+async fn test_forwards_requests() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::builder()
+        .bucket(
+            Path::UsersId,
+            MockBucket::new(5, Duration::from_secs(5)).hash("abc"),
+        )
+        .bind()?;
+    mock.respond(
+        Method::GET,
+        "users/@me",
+        MockResponse::json(&json!({
+            "avatar": null,
+            "bot": true,
+            "discriminator": "0001",
+            "id": "1",
+            "mfa_enabled": false,
+            "username": "twilight",
+        })),
+    );
+    let addr = proxy(&mock).await?;
+
+    let user = client(addr, Some("first")).current_user().await?.model()?;
+    assert_eq!("twilight", user.name);
+
+    // Ratelimit headers are passed through.
+    let response = client(addr, Some("second")).current_user().await?;
+    assert_eq!(StatusCode::OK, response.status());
+    assert_eq!("abc", response.headers()["x-ratelimit-bucket"]);
+    assert_eq!("3", response.headers()["x-ratelimit-remaining"]);
+
+    client(addr, None).current_user().await?;
+
+    let tokens = mock
+        .requests()
+        .iter()
+        .map(|request| {
+            request.headers["authorization"]
+                .to_str()
+                .unwrap()
+                .to_owned()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["Bot first", "Bot second", "Bot default"], tokens);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ratelimits_requests() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::builder()
+        .bucket(
+            Path::ChannelsIdTyping(1),
+            MockBucket::new(2, Duration::from_millis(500)),
+        )
+        .bind()?;
+    mock.respond(Method::POST, "channels/1/typing", MockResponse::empty());
+    let addr = proxy(&mock).await?;
+
+    // The client doesn't ratelimit requests itself, so the proxy must.
+    let client = client(addr, Some("token"));
+    let start = Instant::now();
+
+    for _ in 0..3 {
+        client.create_typing_trigger(ChannelId(1)).await?;
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(400));
+    assert!(mock
+        .requests()
+        .iter()
+        .all(|request| request.status == StatusCode::NO_CONTENT));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shares_invalid_requests() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "channels/1/typing",
+        MockResponse::error(StatusCode::FORBIDDEN, 50001, "Missing Access"),
+    );
+    let addr = serve(
+        Proxy::builder()
+            .invalid_request_threshold(Some(2))
+            .upstream(mock.proxy_url(), true)
+            .build(),
+    )
+    .await?;
+
+    for token in &["first", "second"] {
+        let error = client(addr, Some(token))
+            .create_typing_trigger(ChannelId(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            HttpError::Response { status, .. } if status == StatusCode::FORBIDDEN
+        ));
+    }
+
+    // The responses to requests of both tokens count towards the threshold.
+    let error = client(addr, Some("third"))
+        .create_typing_trigger(ChannelId(1))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, HttpError::ServiceUnavailable { .. }));
+    assert_eq!(2, mock.requests().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_path() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    let addr = proxy(&mock).await?;
+
+    let response = Proxy::new()
+        .handle(
+            hyper::Request::get(format!("http://{}/api/v8/unknown", addr))
+                .body(hyper::Body::empty())?,
+        )
+        .await;
+    assert_eq!(StatusCode::NOT_FOUND, response.status());
+
+    let error = client(addr, None)
+        .create_typing_trigger(ChannelId(1))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        HttpError::Response { status, .. } if status == StatusCode::NOT_FOUND
+    ));
+
+    Ok(())
+}
@@ -435,9 +435,11 @@ impl Serialize for ErrorCode {
 #[serde(untagged)]
 pub enum ApiError {
     General(GeneralApiError),
+    // Ratelimited errors must be tried before message errors, since every
+    // field of a message error is optional and it would match any object.
+    Ratelimited(RatelimitedApiError),
     /// Something was wrong with the input when sending a message.
     Message(MessageApiError),
-    Ratelimited(RatelimitedApiError),
 }
 
 impl Display for ApiError {
@@ -645,4 +647,15 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_api_error_ratelimited_untagged() {
+        let input = r#"{"global":false,"message":"You are being rate limited.","retry_after":1.5}"#;
+        let error = serde_json::from_str::<ApiError>(input).unwrap();
+
+        assert!(matches!(
+            error,
+            ApiError::Ratelimited(RatelimitedApiError { global: false, .. })
+        ));
+    }
 }
@@ -1,5 +1,8 @@
-use super::{Client, HttpsConnector, State};
-use crate::{ratelimiting::Ratelimiter, request::channel::allowed_mentions::AllowedMentions};
+use super::{Client, HttpsConnector, InvalidRequests, RetryPolicy, State};
+use crate::{
+    ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    request::channel::allowed_mentions::AllowedMentions,
+};
 use hyper::client::{Client as HyperClient, HttpConnector};
 use std::{
     sync::{atomic::AtomicBool, Arc},
@@ -11,8 +14,11 @@ use std::{
 pub struct ClientBuilder {
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     pub(crate) proxy: Option<Box<str>>,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
+    pub(crate) retry_policy: RetryPolicy,
     pub(crate) hyper_client: Option<HyperClient<HttpsConnector<HttpConnector>>>,
+    pub(crate) invalid_request_threshold: Option<usize>,
+    pub(crate) shared_invalid_requests: Option<Arc<InvalidRequests>>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
     pub(crate) use_http: bool,
@@ -44,11 +50,19 @@ impl ClientBuilder {
             hyper::client::Builder::default().build(connector)
         });
 
+        let threshold = self.invalid_request_threshold;
+        let invalid_requests = self.shared_invalid_requests.or_else(|| {
+            threshold
+                .map(|threshold| Arc::new(InvalidRequests::new(threshold, InvalidRequests::WINDOW)))
+        });
+
         Client {
             state: Arc::new(State {
                 http,
+                invalid_requests,
                 proxy: self.proxy,
                 ratelimiter: self.ratelimiter,
+                retry_policy: self.retry_policy,
                 timeout: self.timeout,
                 token_invalid: AtomicBool::new(false),
                 token: self.token,
@@ -78,6 +92,22 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the number of `401 Unauthorized`, `403 Forbidden`, and `429 Too
+    /// Many Requests` responses within 10 minutes after which the client
+    /// stops executing requests.
+    ///
+    /// Discord bans IP addresses receiving 10,000 of these responses within
+    /// 10 minutes. If the argument is `None` then the client doesn't count
+    /// them.
+    ///
+    /// The default is 9,000.
+    pub fn invalid_request_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.invalid_request_threshold = threshold;
+        self.shared_invalid_requests = None;
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -105,15 +135,73 @@ impl ClientBuilder {
         self
     }
 
-    /// Set a ratelimiter to use.
+    /// Set a ratelimiter to use, such as one sharing its state across
+    /// processes.
     ///
     /// If the argument is `None` then the client's ratelimiter will be skipped
     /// before making a request.
     ///
-    /// If this method is not called at all then a default ratelimiter will be
-    /// created by [`ClientBuilder::build`].
-    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<Ratelimiter>>) -> Self {
-        self.ratelimiter = ratelimiter.into();
+    /// If this method is not called at all then an [`InMemoryRatelimiter`]
+    /// is used.
+    ///
+    /// # Examples
+    ///
+    /// Share an [`InMemoryRatelimiter`] with another client:
+    ///
+    /// ```rust
+    /// use twilight_http::{ratelimiting::InMemoryRatelimiter, Client};
+    ///
+    /// let ratelimiter = InMemoryRatelimiter::new();
+    /// let client = Client::builder()
+    ///     .ratelimiter(Some(Box::new(ratelimiter.clone())))
+    ///     .token("my token")
+    ///     .build();
+    /// ```
+    pub fn ratelimiter(mut self, ratelimiter: Option<Box<dyn Ratelimiter>>) -> Self {
+        self.ratelimiter = ratelimiter;
+
+        self
+    }
+
+    /// Set the policy for retrying requests that failed due to a temporary
+    /// error.
+    ///
+    /// By default requests aren't retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+
+        self
+    }
+
+    /// Share the count of `401 Unauthorized`, `403 Forbidden`, and `429 Too
+    /// Many Requests` responses with another client.
+    ///
+    /// Clients making requests with different tokens from the same IP address
+    /// should share the count, as Discord bans the IP address once their
+    /// combined count reaches the limit. The threshold of the other client is
+    /// used, and if it doesn't count these responses then neither does the
+    /// built client.
+    ///
+    /// Calling [`invalid_request_threshold`] afterwards creates a separate
+    /// count again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::Client;
+    ///
+    /// let bot = Client::new("bot token");
+    /// let user = Client::builder()
+    ///     .token("Bearer user token")
+    ///     .share_invalid_requests(&bot)
+    ///     .build();
+    /// ```
+    ///
+    /// [`invalid_request_threshold`]: Self::invalid_request_threshold
+    pub fn share_invalid_requests(mut self, client: &Client) -> Self {
+        self.shared_invalid_requests
+            .clone_from(&client.state.invalid_requests);
+        self.invalid_request_threshold = None;
 
         self
     }
@@ -151,8 +239,11 @@ impl Default for ClientBuilder {
         Self {
             default_allowed_mentions: None,
             hyper_client: None,
+            invalid_request_threshold: Some(InvalidRequests::DEFAULT_THRESHOLD),
+            shared_invalid_requests: None,
             proxy: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
+            retry_policy: RetryPolicy::default(),
             timeout: Duration::from_secs(10),
             token: None,
             use_http: false,
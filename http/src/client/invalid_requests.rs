@@ -0,0 +1,158 @@
+use hyper::StatusCode;
+use std::{
+    collections::VecDeque,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Sliding window counter of invalid responses received by a client.
+///
+/// Discord bans the IP addresses of clients receiving too many `401
+/// Unauthorized`, `403 Forbidden`, and `429 Too Many Requests` responses in a
+/// short period of time. The counter is used to stop making requests before
+/// the limit is reached.
+#[derive(Debug)]
+pub(crate) struct InvalidRequests {
+    responses: Mutex<VecDeque<Instant>>,
+    threshold: usize,
+    window: Duration,
+}
+
+impl InvalidRequests {
+    /// Default threshold, leaving a margin below Discord's limit of 10,000
+    /// invalid responses per 10 minutes.
+    pub const DEFAULT_THRESHOLD: usize = 9_000;
+
+    /// Length of the window during which invalid responses are counted.
+    pub const WINDOW: Duration = Duration::from_secs(10 * 60);
+
+    pub fn new(threshold: usize, window: Duration) -> Self {
+        Self {
+            responses: Mutex::new(VecDeque::new()),
+            threshold,
+            window,
+        }
+    }
+
+    /// Return whether a response with the status counts as invalid.
+    pub fn is_invalid(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+        )
+    }
+
+    /// Return the number of invalid responses received in the current window.
+    pub fn count(&self) -> usize {
+        let mut responses = self.responses.lock().expect("invalid requests poisoned");
+        self.prune(&mut responses, Instant::now());
+
+        responses.len()
+    }
+
+    /// Check whether a request may be made.
+    ///
+    /// Returns the time until a request may be made if the threshold has
+    /// been reached.
+    pub fn check(&self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut responses = self.responses.lock().expect("invalid requests poisoned");
+        self.prune(&mut responses, now);
+
+        if responses.len() < self.threshold {
+            return Ok(());
+        }
+
+        // The threshold is no longer reached once enough of the oldest
+        // responses leave the window.
+        let index = responses.len() - self.threshold;
+        let expires_at = responses
+            .get(index)
+            .map_or(now, |received_at| *received_at + self.window);
+
+        Err(expires_at.saturating_duration_since(now))
+    }
+
+    /// Record the status of a response, counting it if it's invalid.
+    pub fn record(&self, status: StatusCode) {
+        if !Self::is_invalid(status) {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut responses = self.responses.lock().expect("invalid requests poisoned");
+        self.prune(&mut responses, now);
+        responses.push_back(now);
+
+        let count = responses.len();
+
+        // Warn at every tenth of the threshold once half of it is reached.
+        let step = (self.threshold / 10).max(1);
+
+        if count * 2 >= self.threshold && count % step == 0 {
+            tracing::warn!(
+                "received {} of {} allowed invalid (401, 403, 429) responses in {:?}, requests \
+                 will be blocked once the threshold is reached to prevent a ban",
+                count,
+                self.threshold,
+                self.window,
+            );
+        }
+    }
+
+    fn prune(&self, responses: &mut VecDeque<Instant>, now: Instant) {
+        while let Some(received_at) = responses.front() {
+            if now.saturating_duration_since(*received_at) < self.window {
+                break;
+            }
+
+            responses.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InvalidRequests;
+    use hyper::StatusCode;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, thread, time::Duration};
+
+    assert_impl_all!(InvalidRequests: Debug, Send, Sync);
+
+    #[test]
+    fn test_is_invalid() {
+        assert!(InvalidRequests::is_invalid(StatusCode::UNAUTHORIZED));
+        assert!(InvalidRequests::is_invalid(StatusCode::FORBIDDEN));
+        assert!(InvalidRequests::is_invalid(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!InvalidRequests::is_invalid(StatusCode::NOT_FOUND));
+        assert!(!InvalidRequests::is_invalid(StatusCode::OK));
+    }
+
+    #[test]
+    fn test_threshold() {
+        let invalid_requests = InvalidRequests::new(2, Duration::from_secs(60));
+
+        invalid_requests.record(StatusCode::FORBIDDEN);
+        invalid_requests.record(StatusCode::NOT_FOUND);
+        assert!(invalid_requests.check().is_ok());
+
+        invalid_requests.record(StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(2, invalid_requests.count());
+
+        let retry_after = invalid_requests.check().unwrap_err();
+        assert!(retry_after > Duration::from_secs(59) && retry_after <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_window() {
+        let invalid_requests = InvalidRequests::new(1, Duration::from_millis(50));
+
+        invalid_requests.record(StatusCode::UNAUTHORIZED);
+        assert!(invalid_requests.check().is_err());
+
+        thread::sleep(Duration::from_millis(60));
+        assert!(invalid_requests.check().is_ok());
+        assert_eq!(0, invalid_requests.count());
+    }
+}
@@ -1,27 +1,39 @@
 mod builder;
+mod invalid_requests;
+mod retry;
+mod webhook;
+
+pub use self::{
+    builder::ClientBuilder,
+    retry::RetryPolicy,
+    webhook::{WebhookClient, WebhookUrlError},
+};
 
-pub use self::builder::ClientBuilder;
+use self::invalid_requests::InvalidRequests;
 
 use crate::{
     api_error::{ApiError, ErrorCode},
     error::{Error, Result},
-    ratelimiting::{RatelimitHeaders, Ratelimiter},
+    ratelimiting::{InMemoryRatelimiter, RatelimitHeaders, Ratelimiter},
     request::{
         channel::allowed_mentions::AllowedMentions,
         guild::{create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError},
         prelude::*,
-        GetUserApplicationInfo, Request,
+        scheduled_event::CreateGuildScheduledEventError,
+        sticker::CreateGuildStickerError,
+        template::{CreateGuildFromTemplateError, CreateTemplateError},
+        Attachment, GetUserApplicationInfo, Request,
     },
+    response::{EmptyBody, Response},
     API_VERSION,
 };
 use bytes::Bytes;
 use hyper::{
-    body::{self, Buf},
+    body::Buf,
     client::{Client as HyperClient, HttpConnector},
     header::{HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
-    Body, Method, Response, StatusCode,
+    Body, Method, Response as HyperResponse, StatusCode,
 };
-use serde::de::DeserializeOwned;
 use std::{
     convert::TryFrom,
     fmt::{Debug, Formatter, Result as FmtResult},
@@ -34,8 +46,17 @@ use std::{
 };
 use tokio::time;
 use twilight_model::{
-    guild::Permissions,
-    id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, UserId, WebhookId},
+    channel::message::sticker::StickerId,
+    guild::{
+        auto_moderation::{AutoModerationEventType, AutoModerationTriggerType},
+        Permissions,
+    },
+    id::{
+        ApplicationId, AutoModerationRuleId, ChannelId, EmojiId, GuildId, IntegrationId, MessageId,
+        RoleId, ScheduledEventId, UserId, WebhookId,
+    },
+    oauth::Scope,
+    scheduled_event::EntityType,
 };
 
 #[cfg(feature = "hyper-rustls")]
@@ -45,8 +66,10 @@ type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
 
 struct State {
     http: HyperClient<HttpsConnector<HttpConnector>, Body>,
+    invalid_requests: Option<Arc<InvalidRequests>>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Ratelimiter>,
+    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    retry_policy: RetryPolicy,
     timeout: Duration,
     token_invalid: AtomicBool,
     token: Option<Box<str>>,
@@ -58,8 +81,10 @@ impl Debug for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("State")
             .field("http", &self.http)
+            .field("invalid_requests", &self.invalid_requests)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
+            .field("retry_policy", &self.retry_policy)
             .field("token", &self.token)
             .field("use_http", &self.use_http)
             .finish()
@@ -83,6 +108,23 @@ impl Debug for State {
 /// revoked or expired. When this happens, you must create a new client with the
 /// new token. The client will no longer execute requests in order to
 /// prevent API bans and will always return [`Error::Unauthorized`].
+/// Unauthorized responses to requests authenticated by other credentials,
+/// such as [`OAuth2` token requests], don't invalidate the client's token.
+///
+/// # Invalid request protection
+///
+/// Discord bans the IP addresses of clients that receive 10,000 `401
+/// Unauthorized`, `403 Forbidden`, and `429 Too Many Requests` responses
+/// within 10 minutes. The client counts these responses, and once 9,000 of
+/// them have been received within 10 minutes it stops executing requests and
+/// returns [`Error::InvalidRequestLimit`] until enough of them are older than
+/// 10 minutes. Warnings are logged as the threshold is approached.
+///
+/// The threshold can be configured via
+/// [`ClientBuilder::invalid_request_threshold`]. Note that the count is only
+/// shared by clones of a client and clients built with
+/// [`ClientBuilder::share_invalid_requests`], so the threshold should be
+/// lowered when other clients make requests from the same IP address.
 ///
 /// # Examples
 ///
@@ -113,6 +155,7 @@ impl Debug for State {
 /// All the examples on this page assume you have already created a client, and have named it
 /// `client`.
 ///
+/// [`OAuth2` token requests]: crate::request::oauth
 /// [here]: https://discord.com/developers/applications
 #[derive(Clone, Debug)]
 pub struct Client {
@@ -142,8 +185,13 @@ impl Client {
         Self {
             state: Arc::new(State {
                 http: HyperClient::builder().build(connector),
+                invalid_requests: Some(Arc::new(InvalidRequests::new(
+                    InvalidRequests::DEFAULT_THRESHOLD,
+                    InvalidRequests::WINDOW,
+                ))),
                 proxy: None,
-                ratelimiter: Some(Ratelimiter::new()),
+                ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
+                retry_policy: RetryPolicy::default(),
                 timeout: Duration::from_secs(10),
                 token_invalid: AtomicBool::new(false),
                 token: Some(token.into_boxed_str()),
@@ -177,12 +225,32 @@ impl Client {
         self.state.default_allowed_mentions.clone()
     }
 
-    /// Get the Ratelimiter used by the client internally.
+    /// Get the ratelimiter used by the client internally.
     ///
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
-        self.state.ratelimiter.clone()
+    pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
+        self.state.ratelimiter.as_deref()
+    }
+
+    /// Get the number of `401 Unauthorized`, `403 Forbidden`, and `429 Too
+    /// Many Requests` responses received in the last 10 minutes.
+    ///
+    /// Returns `None` if invalid request protection is disabled. Refer to the
+    /// [client's documentation] for more information.
+    ///
+    /// [client's documentation]: Self#invalid-request-protection
+    pub fn invalid_request_count(&self) -> Option<usize> {
+        self.state
+            .invalid_requests
+            .as_ref()
+            .map(|invalid_requests| invalid_requests.count())
+    }
+
+    /// Get the policy for retrying requests that failed due to a temporary
+    /// error.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.state.retry_policy
     }
 
     /// Get the audit log for a guild.
@@ -473,6 +541,8 @@ impl Client {
 
     /// Returns a list of guilds for the current user.
     ///
+    /// Requires the `guilds` `OAuth2` scope when using a `Bearer` token.
+    ///
     /// # Examples
     ///
     /// Get the first 25 guilds with an ID after `300` and before
@@ -520,6 +590,53 @@ impl Client {
         GetCurrentUserPrivateChannels::new(self)
     }
 
+    /// Exchange an `OAuth2` authorization code for an access token.
+    ///
+    /// The redirect URI must match the one the user was redirected to. Refer
+    /// to [`ExchangeCode`] for an example of the authorization code flow.
+    ///
+    /// [`ExchangeCode`]: crate::request::oauth::ExchangeCode
+    pub fn exchange_code(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        code: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> ExchangeCode<'_> {
+        ExchangeCode::new(self, client_id, client_secret, code, redirect_uri)
+    }
+
+    /// Refresh an `OAuth2` access token using its refresh token.
+    pub fn refresh_access_token(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> RefreshAccessToken<'_> {
+        RefreshAccessToken::new(self, client_id, client_secret, refresh_token)
+    }
+
+    /// Get an `OAuth2` access token for the owner of the application via the
+    /// client credentials grant.
+    pub fn client_credentials_grant(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = Scope>,
+    ) -> ClientCredentialsGrant<'_> {
+        ClientCredentialsGrant::new(self, client_id, client_secret, scopes)
+    }
+
+    /// Revoke an `OAuth2` access token or refresh token.
+    pub fn revoke_token(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        token: impl Into<String>,
+    ) -> RevokeToken<'_> {
+        RevokeToken::new(self, client_id, client_secret, token)
+    }
+
     /// Get the emojis for a guild, by the guild's id.
     ///
     /// # Examples
@@ -593,6 +710,227 @@ impl Client {
         UpdateEmoji::new(self, guild_id, emoji_id)
     }
 
+    /// Get a sticker by its ID.
+    ///
+    /// The sticker may be a standard sticker or a guild sticker.
+    pub fn sticker(&self, sticker_id: StickerId) -> GetSticker<'_> {
+        GetSticker::new(self, sticker_id)
+    }
+
+    /// Get the sticker packs available to Nitro subscribers.
+    pub fn nitro_sticker_packs(&self) -> GetNitroStickerPacks<'_> {
+        GetNitroStickerPacks::new(self)
+    }
+
+    /// Get the stickers of a guild.
+    pub fn guild_stickers(&self, guild_id: GuildId) -> GetGuildStickers<'_> {
+        GetGuildStickers::new(self, guild_id)
+    }
+
+    /// Get a sticker of a guild.
+    pub fn guild_sticker(&self, guild_id: GuildId, sticker_id: StickerId) -> GetGuildSticker<'_> {
+        GetGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Upload a sticker to a guild.
+    ///
+    /// The file must be a PNG, APNG, or Lottie JSON file of at most 500 KiB.
+    /// The tags are the name of a unicode emoji used for autocompletion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use twilight_http::{request::Attachment, Client};
+    /// use twilight_model::id::GuildId;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let file = Attachment::from_bytes("cat.png", vec![0x89, 0x50, 0x4E, 0x47]);
+    /// let sticker = client
+    ///     .create_guild_sticker(GuildId(1), "cat", "cat", file)?
+    ///     .description("a cat")?
+    ///     .await?
+    ///     .model()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildStickerError::NameInvalid`] if the name is fewer
+    /// than 2 or more than 30 characters.
+    ///
+    /// Returns [`CreateGuildStickerError::TagsInvalid`] if the tags are fewer
+    /// than 2 or more than 200 characters.
+    ///
+    /// [`CreateGuildStickerError::NameInvalid`]: crate::request::sticker::CreateGuildStickerError::NameInvalid
+    /// [`CreateGuildStickerError::TagsInvalid`]: crate::request::sticker::CreateGuildStickerError::TagsInvalid
+    pub fn create_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        tags: impl Into<String>,
+        file: Attachment,
+    ) -> StdResult<CreateGuildSticker<'_>, CreateGuildStickerError> {
+        CreateGuildSticker::new(self, guild_id, name, tags, file)
+    }
+
+    /// Update a sticker of a guild.
+    pub fn update_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        sticker_id: StickerId,
+    ) -> UpdateGuildSticker<'_> {
+        UpdateGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Delete a sticker of a guild.
+    pub fn delete_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        sticker_id: StickerId,
+    ) -> DeleteGuildSticker<'_> {
+        DeleteGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Get the auto moderation rules of a guild.
+    pub fn guild_auto_moderation_rules(
+        &self,
+        guild_id: GuildId,
+    ) -> GetGuildAutoModerationRules<'_> {
+        GetGuildAutoModerationRules::new(self, guild_id)
+    }
+
+    /// Get an auto moderation rule of a guild.
+    pub fn auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> GetAutoModerationRule<'_> {
+        GetAutoModerationRule::new(self, guild_id, auto_moderation_rule_id)
+    }
+
+    /// Create an auto moderation rule in a guild.
+    ///
+    /// Refer to [`CreateAutoModerationRule`] for an example.
+    pub fn create_auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        event_type: AutoModerationEventType,
+        trigger_type: AutoModerationTriggerType,
+    ) -> CreateAutoModerationRule<'_> {
+        CreateAutoModerationRule::new(self, guild_id, name, event_type, trigger_type)
+    }
+
+    /// Update an auto moderation rule of a guild.
+    pub fn update_auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> UpdateAutoModerationRule<'_> {
+        UpdateAutoModerationRule::new(self, guild_id, auto_moderation_rule_id)
+    }
+
+    /// Delete an auto moderation rule of a guild.
+    pub fn delete_auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> DeleteAutoModerationRule<'_> {
+        DeleteAutoModerationRule::new(self, guild_id, auto_moderation_rule_id)
+    }
+
+    /// Get the scheduled events of a guild.
+    pub fn guild_scheduled_events(&self, guild_id: GuildId) -> GetGuildScheduledEvents<'_> {
+        GetGuildScheduledEvents::new(self, guild_id)
+    }
+
+    /// Get a scheduled event of a guild.
+    pub fn guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEvent<'_> {
+        GetGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Get the users subscribed to a scheduled event of a guild.
+    ///
+    /// The upper limit to this request is 100. Use [`paginate`] to retrieve
+    /// all subscribed users.
+    ///
+    /// # Examples
+    ///
+    /// Get all users subscribed to event `2` of guild `1`, including their
+    /// member data:
+    ///
+    /// ```rust,no_run
+    /// use futures_util::stream::TryStreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::{GuildId, ScheduledEventId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let users = client
+    ///     .guild_scheduled_event_users(GuildId(1), ScheduledEventId(2))
+    ///     .with_member(true)
+    ///     .paginate()
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`paginate`]: crate::request::scheduled_event::GetGuildScheduledEventUsers::paginate
+    pub fn guild_scheduled_event_users(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEventUsers<'_> {
+        GetGuildScheduledEventUsers::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Create a scheduled event in a guild.
+    ///
+    /// The start time is an ISO 8601 timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildScheduledEventError::NameInvalid`] if the name is
+    /// empty or more than 100 characters.
+    ///
+    /// [`CreateGuildScheduledEventError::NameInvalid`]: crate::request::scheduled_event::CreateGuildScheduledEventError::NameInvalid
+    pub fn create_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        entity_type: EntityType,
+        scheduled_start_time: impl Into<String>,
+    ) -> StdResult<CreateGuildScheduledEvent<'_>, CreateGuildScheduledEventError> {
+        CreateGuildScheduledEvent::new(self, guild_id, name, entity_type, scheduled_start_time)
+    }
+
+    /// Update a scheduled event of a guild.
+    pub fn update_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> UpdateGuildScheduledEvent<'_> {
+        UpdateGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Delete a scheduled event of a guild.
+    pub fn delete_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> DeleteGuildScheduledEvent<'_> {
+        DeleteGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
     /// Get information about the gateway, optionally with additional information detailing the
     /// number of shards to use and sessions remaining.
     ///
@@ -621,7 +959,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let info = client.gateway().authed().await?;
+    /// let info = client.gateway().authed().await?.model()?;
     ///
     /// println!("URL: {}", info.url);
     /// println!("Recommended shards to use: {}", info.shards);
@@ -932,6 +1270,40 @@ impl Client {
         GetGuildVanityUrl::new(self, guild_id)
     }
 
+    /// Get a guild's welcome screen.
+    pub fn guild_welcome_screen(&self, guild_id: GuildId) -> GetGuildWelcomeScreen<'_> {
+        GetGuildWelcomeScreen::new(self, guild_id)
+    }
+
+    /// Update a guild's welcome screen.
+    ///
+    /// Requires the guild to have community features enabled.
+    pub fn update_guild_welcome_screen(&self, guild_id: GuildId) -> UpdateGuildWelcomeScreen<'_> {
+        UpdateGuildWelcomeScreen::new(self, guild_id)
+    }
+
+    /// Get a guild's membership screening form.
+    ///
+    /// Refer to [the discord docs] for more information.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/resources/guild#get-guild-membership-screening-form
+    pub fn guild_membership_screening(&self, guild_id: GuildId) -> GetGuildMembershipScreening<'_> {
+        GetGuildMembershipScreening::new(self, guild_id)
+    }
+
+    /// Update a guild's membership screening form.
+    ///
+    /// Members who haven't passed the screening yet are marked as
+    /// [`pending`].
+    ///
+    /// [`pending`]: twilight_model::guild::Member::pending
+    pub fn update_guild_membership_screening(
+        &self,
+        guild_id: GuildId,
+    ) -> UpdateGuildMembershipScreening<'_> {
+        UpdateGuildMembershipScreening::new(self, guild_id)
+    }
+
     /// Get voice region data for the guild.
     ///
     /// Can return VIP servers if the guild is VIP-enabled.
@@ -939,6 +1311,80 @@ impl Client {
         GetGuildVoiceRegions::new(self, guild_id)
     }
 
+    /// Get a template by its code.
+    pub fn get_template(&self, template_code: impl Into<String>) -> GetTemplate<'_> {
+        GetTemplate::new(self, template_code)
+    }
+
+    /// Create a new guild based on a template.
+    ///
+    /// The minimum length of the name is 2 UTF-16 characters and the maximum is 100 UTF-16
+    /// characters. This endpoint can only be used by bots in less than 10 guilds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildFromTemplateError::NameInvalid`] if the name length is too short or
+    /// too long.
+    ///
+    /// [`CreateGuildFromTemplateError::NameInvalid`]: crate::request::template::CreateGuildFromTemplateError::NameInvalid
+    pub fn create_guild_from_template(
+        &self,
+        template_code: impl Into<String>,
+        name: impl Into<String>,
+    ) -> StdResult<CreateGuildFromTemplate<'_>, CreateGuildFromTemplateError> {
+        CreateGuildFromTemplate::new(self, template_code, name)
+    }
+
+    /// Get the templates of a guild.
+    pub fn get_templates(&self, guild_id: GuildId) -> GetTemplates<'_> {
+        GetTemplates::new(self, guild_id)
+    }
+
+    /// Create a template from the current state of a guild.
+    ///
+    /// The minimum length of the name is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateTemplateError::NameInvalid`] if the name length is too short or too long.
+    ///
+    /// [`CreateTemplateError::NameInvalid`]: crate::request::template::CreateTemplateError::NameInvalid
+    pub fn create_template(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+    ) -> StdResult<CreateTemplate<'_>, CreateTemplateError> {
+        CreateTemplate::new(self, guild_id, name)
+    }
+
+    /// Sync a template to the current state of its guild.
+    pub fn sync_template(
+        &self,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> SyncTemplate<'_> {
+        SyncTemplate::new(self, guild_id, template_code)
+    }
+
+    /// Update the name and description of a template.
+    pub fn update_template(
+        &self,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> UpdateTemplate<'_> {
+        UpdateTemplate::new(self, guild_id, template_code)
+    }
+
+    /// Delete a template of a guild.
+    pub fn delete_template(
+        &self,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> DeleteTemplate<'_> {
+        DeleteTemplate::new(self, guild_id, template_code)
+    }
+
     /// Get the webhooks of a guild.
     pub fn guild_webhooks(&self, guild_id: GuildId) -> GetGuildWebhooks<'_> {
         GetGuildWebhooks::new(self, guild_id)
@@ -1031,12 +1477,18 @@ impl Client {
     /// The method [`embed`] returns [`CreateMessageError::EmbedTooLarge`] if the length of the
     /// embed is over 6000 characters.
     ///
+    /// The method [`sticker_ids`] returns [`CreateMessageError::StickersInvalid`] if more than 3
+    /// stickers are provided.
+    ///
     /// [`content`]: crate::request::channel::message::create_message::CreateMessage::content
     /// [`embed`]: crate::request::channel::message::create_message::CreateMessage::embed
+    /// [`sticker_ids`]: crate::request::channel::message::create_message::CreateMessage::sticker_ids
     /// [`CreateMessageError::ContentInvalid`]:
     /// crate::request::channel::message::create_message::CreateMessageError::ContentInvalid
     /// [`CreateMessageError::EmbedTooLarge`]:
     /// crate::request::channel::message::create_message::CreateMessageError::EmbedTooLarge
+    /// [`CreateMessageError::StickersInvalid`]:
+    /// crate::request::channel::message::create_message::CreateMessageError::StickersInvalid
     pub fn create_message(&self, channel_id: ChannelId) -> CreateMessage<'_> {
         CreateMessage::new(self, channel_id)
     }
@@ -1345,7 +1797,7 @@ impl Client {
 
     /// Executes a webhook, sending a message to its channel.
     ///
-    /// You can only specify one of [`content`], [`embeds`], or [`file`].
+    /// You can only specify one of [`content`], [`embeds`], or [`attachment`].
     ///
     /// # Examples
     ///
@@ -1365,9 +1817,9 @@ impl Client {
     /// # Ok(()) }
     /// ```
     ///
+    /// [`attachment`]: crate::request::channel::webhook::ExecuteWebhook::attachment
     /// [`content`]: crate::request::channel::webhook::ExecuteWebhook::content
     /// [`embeds`]: crate::request::channel::webhook::ExecuteWebhook::embeds
-    /// [`file`]: crate::request::channel::webhook::ExecuteWebhook::file
     pub fn execute_webhook(
         &self,
         webhook_id: WebhookId,
@@ -1376,6 +1828,18 @@ impl Client {
         ExecuteWebhook::new(self, webhook_id, token)
     }
 
+    /// Get a message created by a webhook.
+    ///
+    /// Refer to [`GetWebhookMessage`] for an example.
+    pub fn webhook_message(
+        &self,
+        webhook_id: WebhookId,
+        token: impl Into<String>,
+        message_id: MessageId,
+    ) -> GetWebhookMessage<'_> {
+        GetWebhookMessage::new(self, webhook_id, token, message_id)
+    }
+
     /// Update a message executed by a webhook.
     ///
     /// # Examples
@@ -1432,12 +1896,33 @@ impl Client {
     ///
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
+    ///
+    /// Returns [`Error::InvalidRequestLimit`] if too many invalid requests
+    /// have been made recently.
     #[allow(clippy::too_many_lines)]
-    pub async fn raw(&self, request: Request) -> Result<Response<Body>> {
+    pub async fn raw(&self, request: Request) -> Result<HyperResponse<Body>> {
         if self.state.token_invalid.load(Ordering::Relaxed) {
             return Err(Error::Unauthorized);
         }
 
+        if let Some(invalid_requests) = self.state.invalid_requests.as_ref() {
+            if let Err(retry_after) = invalid_requests.check() {
+                tracing::warn!("not making request, too many invalid requests were made recently");
+
+                return Err(Error::InvalidRequestLimit { retry_after });
+            }
+        }
+
+        // Requests authenticated by their own authorization header, such as
+        // `OAuth2` token requests, say nothing about the client's token. The
+        // same goes for all requests of clients without a token, such as
+        // webhook clients, which are authenticated by the webhook's token.
+        let uses_token = self.state.token.is_some()
+            && request
+                .headers
+                .as_ref()
+                .map_or(true, |headers| !headers.contains_key(AUTHORIZATION));
+
         let Request {
             body,
             form,
@@ -1490,23 +1975,31 @@ impl Client {
 
         let req = if let Some(form) = form {
             let content_type = HeaderValue::try_from(form.content_type());
-            let form_bytes = form.build();
             if let Some(headers) = builder.headers_mut() {
                 if let Ok(content_type) = content_type {
                     headers.insert(CONTENT_TYPE, content_type);
                 }
-                headers.insert(CONTENT_LENGTH, form_bytes.len().into());
+
+                // Forms with files read from a reader have no known length
+                // and are sent with chunked encoding.
+                if let Some(length) = form.content_length() {
+                    headers.insert(CONTENT_LENGTH, length.into());
+                }
             };
             builder
-                .body(Body::from(form_bytes))
+                .body(Body::wrap_stream(form.stream()))
                 .map_err(|source| Error::BuildingRequest { source })?
         } else if let Some(bytes) = body {
             let len = bytes.len();
 
             if let Some(headers) = builder.headers_mut() {
                 headers.insert(CONTENT_LENGTH, len.into());
-                let content_type = HeaderValue::from_static("application/json");
-                headers.insert(CONTENT_TYPE, content_type);
+
+                // Requests forwarded by a proxy may already have a content
+                // type, such as a multipart form.
+                headers
+                    .entry(CONTENT_TYPE)
+                    .or_insert_with(|| HeaderValue::from_static("application/json"));
             }
 
             builder
@@ -1536,13 +2029,24 @@ impl Client {
                     .await
                     .map_err(|source| Error::RequestTimedOut { source })?
                     .map_err(|source| Error::RequestError { source })
+                    .map(|resp| {
+                        self.record_status(resp.status());
+
+                        resp
+                    })
             }
         };
 
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx
+        #[cfg(feature = "metrics")]
+        let waiting_since = std::time::Instant::now();
+
+        let tx = ratelimiter
+            .ticket(method, bucket)
             .await
-            .map_err(|source| Error::RequestCanceled { source })?;
+            .map_err(|source| Error::RatelimiterTicket { source })?;
+
+        #[cfg(feature = "metrics")]
+        metrics::timing!("Ratelimiter-Wait", waiting_since.elapsed());
 
         let resp = fut
             .await
@@ -1552,77 +2056,122 @@ impl Client {
         // If the API sent back an Unauthorized response, then the client's
         // configured token is permanently invalid and future requests must be
         // ignored to avoid API bans.
-        if resp.status() == StatusCode::UNAUTHORIZED {
+        if uses_token && resp.status() == StatusCode::UNAUTHORIZED {
             self.state.token_invalid.store(true, Ordering::Relaxed);
         }
 
+        self.record_status(resp.status());
+
         match RatelimitHeaders::try_from(resp.headers()) {
             Ok(v) => {
-                let _ = tx.send(Some(v));
+                #[cfg(feature = "metrics")]
+                if v.global() {
+                    metrics::counter!("Ratelimiter-GlobalLimited", 1);
+                }
+
+                let _ = tx.headers(Some(v));
             }
             Err(why) => {
                 tracing::warn!("header parsing failed: {:?}; {:?}", why, resp);
 
-                let _ = tx.send(None);
+                let _ = tx.headers(None);
             }
         }
 
         Ok(resp)
     }
 
-    /// Execute a request, chunking and deserializing the response.
+    /// Count the response towards the invalid request threshold if it's
+    /// invalid.
+    fn record_status(&self, status: StatusCode) {
+        if let Some(invalid_requests) = self.state.invalid_requests.as_ref() {
+            invalid_requests.record(status);
+        }
+    }
+
+    /// Execute a request, chunking the body of the response.
+    ///
+    /// The body is deserialized into `T` on demand via [`Response::model`].
     ///
     /// # Errors
     ///
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
-    pub async fn request<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
+    pub async fn request<T>(&self, request: Request) -> Result<Response<T>> {
         let resp = self.make_request(request).await?;
+        let (parts, body) = resp.into_parts();
 
-        let mut buf = body::aggregate(resp.into_body())
+        let bytes = hyper::body::to_bytes(body)
             .await
             .map_err(|source| Error::ChunkingResponse { source })?;
 
-        let mut bytes = vec![0; buf.remaining()];
-        buf.copy_to_slice(&mut bytes);
-
-        let result = crate::json_from_slice(&mut bytes);
-
-        result.map_err(|source| Error::Parsing {
-            body: bytes.to_vec(),
-            source,
-        })
-    }
-
-    pub(crate) async fn request_bytes(&self, request: Request) -> Result<Bytes> {
-        let resp = self.make_request(request).await?;
-
-        hyper::body::to_bytes(resp.into_body())
-            .await
-            .map_err(|source| Error::ChunkingResponse { source })
+        Ok(Response::new(parts.status, parts.headers, bytes))
     }
 
     /// Execute a request, checking only that the response was a success.
     ///
-    /// This will not chunk and deserialize the body of the response.
+    /// This will not chunk the body of the response, so the returned response
+    /// has an empty body.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
-    pub async fn verify(&self, request: Request) -> Result<()> {
-        self.make_request(request).await?;
+    pub async fn verify(&self, request: Request) -> Result<Response<EmptyBody>> {
+        let resp = self.make_request(request).await?;
+        let (parts, _) = resp.into_parts();
 
-        Ok(())
+        Ok(Response::new(parts.status, parts.headers, Bytes::new()))
     }
 
-    async fn make_request(&self, request: Request) -> Result<Response<Body>> {
-        let resp = self.raw(request).await?;
-        let status = resp.status();
+    async fn make_request(&self, mut request: Request) -> Result<HyperResponse<Body>> {
+        let mut attempt = 1;
+
+        loop {
+            // Keep a copy of the request in case it needs to be retried.
+            let retry = if request.is_replayable() && attempt < self.state.retry_policy.attempts() {
+                Some(request.clone())
+            } else {
+                None
+            };
+
+            let resp = self.raw(request).await?;
+            let status = resp.status();
+
+            if status.is_success() {
+                return Ok(resp);
+            }
+
+            let error = Self::response_error(resp).await;
+
+            let (next, delay) = match retry.and_then(|next| {
+                self.state
+                    .retry_policy
+                    .retry_delay(attempt, &next.method, status, &error)
+                    .map(|delay| (next, delay))
+            }) {
+                Some(retry) => retry,
+                None => return Err(error),
+            };
+
+            tracing::debug!(
+                "retrying request to {:?} in {:?} after attempt {}: {}",
+                next.path,
+                delay,
+                attempt,
+                error,
+            );
 
-        if status.is_success() {
-            return Ok(resp);
+            time::sleep(delay).await;
+
+            attempt += 1;
+            request = next;
         }
+    }
+
+    /// Convert an unsuccessful response into an error.
+    async fn response_error(resp: HyperResponse<Body>) -> Error {
+        let status = resp.status();
 
         match status {
             StatusCode::IM_A_TEAPOT => tracing::warn!(
@@ -1630,24 +2179,27 @@ impl Client {
                 resp,
             ),
             StatusCode::TOO_MANY_REQUESTS => tracing::warn!("429 response: {:?}", resp),
-            StatusCode::SERVICE_UNAVAILABLE => {
-                return Err(Error::ServiceUnavailable { response: resp })
-            }
+            StatusCode::SERVICE_UNAVAILABLE => return Error::ServiceUnavailable { response: resp },
             _ => {}
         }
 
-        let mut buf = hyper::body::aggregate(resp.into_body())
-            .await
-            .map_err(|source| Error::ChunkingResponse { source })?;
+        let mut buf = match hyper::body::aggregate(resp.into_body()).await {
+            Ok(buf) => buf,
+            Err(source) => return Error::ChunkingResponse { source },
+        };
 
         let mut bytes = vec![0; buf.remaining()];
         buf.copy_to_slice(&mut bytes);
 
-        let error =
-            crate::json_from_slice::<ApiError>(&mut bytes).map_err(|source| Error::Parsing {
-                body: bytes.clone(),
-                source,
-            })?;
+        let error = match crate::json_from_slice::<ApiError>(&mut bytes) {
+            Ok(error) => error,
+            Err(source) => {
+                return Error::Parsing {
+                    body: bytes.clone(),
+                    source,
+                }
+            }
+        };
 
         if let ApiError::General(ref general) = error {
             if let ErrorCode::Other(num) = general.code {
@@ -1655,11 +2207,11 @@ impl Client {
             }
         }
 
-        Err(Error::Response {
+        Error::Response {
             body: bytes,
             error,
             status,
-        })
+        }
     }
 }
 
@@ -1668,8 +2220,13 @@ impl From<HyperClient<HttpsConnector<HttpConnector>>> for Client {
         Self {
             state: Arc::new(State {
                 http: hyper_client,
+                invalid_requests: Some(Arc::new(InvalidRequests::new(
+                    InvalidRequests::DEFAULT_THRESHOLD,
+                    InvalidRequests::WINDOW,
+                ))),
                 proxy: None,
-                ratelimiter: Some(Ratelimiter::new()),
+                ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
+                retry_policy: RetryPolicy::default(),
                 timeout: Duration::from_secs(10),
                 token_invalid: AtomicBool::new(false),
                 token: None,
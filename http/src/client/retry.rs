@@ -0,0 +1,315 @@
+use crate::{api_error::ApiError, error::Error};
+use hyper::{Method, StatusCode};
+use rand::Rng;
+use std::time::Duration;
+
+/// Policy for retrying requests that failed due to a temporary error.
+///
+/// Requests are retried when the API responds with one of the following:
+///
+/// - `502 Bad Gateway`, `503 Service Unavailable`, or `504 Gateway Timeout`,
+///   which may occur during Discord API stability incidents;
+/// - `429 Too Many Requests` that the ratelimiter didn't prevent, such as
+///   when another process is using the same token.
+///
+/// Server errors are retried after an exponential backoff with jitter. The
+/// API may have processed a request despite responding with a server error,
+/// so requests that aren't idempotent, such as `POST` and `PATCH` requests,
+/// are only retried on server errors if [`retry_non_idempotent`] is enabled.
+///
+/// Ratelimited requests weren't processed, so they're retried regardless of
+/// their method after the `retry_after` duration of the
+/// [`RatelimitedApiError`].
+///
+/// The default policy doesn't retry requests.
+///
+/// # Examples
+///
+/// Retry requests up to 3 times in total, waiting at least half a second
+/// before each retry of a server error:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use twilight_http::{client::RetryPolicy, Client};
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(3)
+///     .backoff(Duration::from_millis(500), Duration::from_secs(5));
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .retry_policy(policy)
+///     .build();
+/// ```
+///
+/// [`RatelimitedApiError`]: crate::api_error::RatelimitedApiError
+/// [`retry_non_idempotent`]: Self::retry_non_idempotent
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    base_delay: Duration,
+    max_attempts: u32,
+    max_delay: Duration,
+    retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy.
+    ///
+    /// The policy makes a single attempt, and so doesn't retry requests until
+    /// the number of attempts is raised via [`max_attempts`].
+    ///
+    /// [`max_attempts`]: Self::max_attempts
+    pub fn new() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            max_attempts: 1,
+            max_delay: Duration::from_secs(10),
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// Set the delays of the exponential backoff between retries of server
+    /// errors.
+    ///
+    /// The delay before the first retry is around `base`, and doubles with
+    /// each retry up to `max`. Each delay is randomized to between half and
+    /// all of its value, so that clients don't retry in lockstep.
+    ///
+    /// Defaults to a base of 500 milliseconds and a maximum of 10 seconds.
+    pub fn backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.base_delay = base;
+        self.max_delay = max;
+
+        self
+    }
+
+    /// Set the maximum number of attempts to make for a request, including
+    /// the first.
+    ///
+    /// A value of 0 is treated as 1. Defaults to 1, which disables retries.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+
+        self
+    }
+
+    /// Set whether to retry requests that aren't idempotent on server errors.
+    ///
+    /// Enabling this may cause actions, such as creating a message, to be
+    /// done twice.
+    ///
+    /// Defaults to false.
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+
+        self
+    }
+
+    /// Return the delays of the exponential backoff.
+    pub fn backoff_delays(&self) -> (Duration, Duration) {
+        (self.base_delay, self.max_delay)
+    }
+
+    /// Return the maximum number of attempts made for a request.
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Return whether requests that aren't idempotent are retried on server
+    /// errors.
+    pub fn retries_non_idempotent(&self) -> bool {
+        self.retry_non_idempotent
+    }
+
+    /// Return the delay before retrying a failed request, or `None` if it
+    /// shouldn't be retried.
+    ///
+    /// `attempt` is the number of attempts made so far, starting at 1.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        method: &Method,
+        status: StatusCode,
+        error: &Error,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        match status {
+            StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+                if self.retry_non_idempotent || is_idempotent(method) =>
+            {
+                Some(self.jittered_backoff(attempt))
+            }
+            StatusCode::TOO_MANY_REQUESTS => match error {
+                Error::Response {
+                    error: ApiError::Ratelimited(ratelimited),
+                    ..
+                } => Some(Duration::from_secs_f64(ratelimited.retry_after.max(0.0))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn jittered_backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .checked_mul(2_u32.saturating_pow(attempt - 1))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        let half = delay / 2;
+
+        half + rand::thread_rng().gen_range(Duration::default()..=half)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a request with the method can be made multiple times with the same
+/// effect as making it once.
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::DELETE | Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::{
+        api_error::{ApiError, ErrorCode, GeneralApiError, RatelimitedApiError},
+        error::Error,
+    };
+    use hyper::{Method, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(RetryPolicy: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    fn server_error(status: StatusCode) -> Error {
+        Error::Response {
+            body: Vec::new(),
+            error: ApiError::General(GeneralApiError {
+                code: ErrorCode::GeneralError,
+                message: String::new(),
+            }),
+            status,
+        }
+    }
+
+    fn ratelimited(retry_after: f64) -> Error {
+        Error::Response {
+            body: Vec::new(),
+            error: ApiError::Ratelimited(RatelimitedApiError {
+                global: false,
+                message: "You are being rate limited.".to_owned(),
+                retry_after,
+            }),
+            status: StatusCode::TOO_MANY_REQUESTS,
+        }
+    }
+
+    #[test]
+    fn test_default_doesnt_retry() {
+        let policy = RetryPolicy::default();
+        let error = server_error(StatusCode::BAD_GATEWAY);
+
+        assert_eq!(1, policy.attempts());
+        assert!(policy
+            .retry_delay(1, &Method::GET, StatusCode::BAD_GATEWAY, &error)
+            .is_none());
+    }
+
+    #[test]
+    fn test_max_attempts() {
+        let policy = RetryPolicy::new().max_attempts(3);
+        let error = server_error(StatusCode::GATEWAY_TIMEOUT);
+
+        assert!(policy
+            .retry_delay(2, &Method::GET, StatusCode::GATEWAY_TIMEOUT, &error)
+            .is_some());
+        assert!(policy
+            .retry_delay(3, &Method::GET, StatusCode::GATEWAY_TIMEOUT, &error)
+            .is_none());
+        assert_eq!(1, RetryPolicy::new().max_attempts(0).attempts());
+    }
+
+    #[test]
+    fn test_idempotency() {
+        let policy = RetryPolicy::new().max_attempts(2);
+        let error = server_error(StatusCode::SERVICE_UNAVAILABLE);
+
+        for method in &[Method::DELETE, Method::GET, Method::PUT] {
+            assert!(policy
+                .retry_delay(1, method, StatusCode::SERVICE_UNAVAILABLE, &error)
+                .is_some());
+        }
+
+        for method in &[Method::PATCH, Method::POST] {
+            assert!(policy
+                .retry_delay(1, method, StatusCode::SERVICE_UNAVAILABLE, &error)
+                .is_none());
+            assert!(policy
+                .clone()
+                .retry_non_idempotent(true)
+                .retry_delay(1, method, StatusCode::SERVICE_UNAVAILABLE, &error)
+                .is_some());
+        }
+
+        // Ratelimited requests weren't processed.
+        assert_eq!(
+            Some(Duration::from_millis(1500)),
+            policy.retry_delay(
+                1,
+                &Method::POST,
+                StatusCode::TOO_MANY_REQUESTS,
+                &ratelimited(1.5)
+            )
+        );
+    }
+
+    #[test]
+    fn test_other_errors_arent_retried() {
+        let policy = RetryPolicy::new().max_attempts(2);
+
+        for status in &[
+            StatusCode::BAD_REQUEST,
+            StatusCode::FORBIDDEN,
+            StatusCode::NOT_FOUND,
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ] {
+            assert!(policy
+                .retry_delay(1, &Method::GET, *status, &server_error(*status))
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_jittered_backoff() {
+        let policy = RetryPolicy::new()
+            .max_attempts(10)
+            .backoff(Duration::from_millis(100), Duration::from_millis(300));
+
+        for _ in 0..20 {
+            let first = policy.jittered_backoff(1);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+
+            let second = policy.jittered_backoff(2);
+            assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+
+            let capped = policy.jittered_backoff(9);
+            assert!(capped >= Duration::from_millis(150) && capped <= Duration::from_millis(300));
+        }
+
+        assert!(policy.jittered_backoff(u32::max_value()) <= Duration::from_millis(300));
+    }
+}
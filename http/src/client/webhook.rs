@@ -0,0 +1,192 @@
+use super::{Client, ClientBuilder};
+use crate::request::channel::webhook::{
+    DeleteWebhookMessage, ExecuteWebhook, GetWebhookMessage, UpdateWebhookMessage,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::id::{MessageId, WebhookId};
+use twilight_util::link::webhook::{self, WebhookParseError};
+
+/// A [`WebhookClient`] can not be created from a webhook URL.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum WebhookUrlError {
+    /// URL isn't a valid webhook URL.
+    Parse {
+        /// Reason for the error.
+        source: WebhookParseError,
+    },
+    /// URL doesn't contain the token of the webhook.
+    TokenMissing,
+}
+
+impl Display for WebhookUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Parse { .. } => f.write_str("the webhook url is invalid"),
+            Self::TokenMissing => f.write_str("the webhook url doesn't contain a token"),
+        }
+    }
+}
+
+impl Error for WebhookUrlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse { source } => Some(source),
+            Self::TokenMissing => None,
+        }
+    }
+}
+
+/// Client for executing a single webhook and managing its messages.
+///
+/// Webhooks are authenticated by their token, so no bot token is required.
+/// Requests are sent through an underlying [`Client`] without a token, which
+/// handles the ratelimits of the webhook like any other route.
+///
+/// # Cloning
+///
+/// Like [`Client`], the webhook client can be cloned cheaply, and clones share
+/// the same ratelimiter.
+///
+/// # Examples
+///
+/// Create a client from a webhook URL and send a message as a different user:
+///
+/// ```rust,no_run
+/// use twilight_http::client::WebhookClient;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let url = "https://discord.com/api/webhooks/794590023369752587/tjxHaPHLKp9aEdSwJuLeHhHHGEqIxt1aay4I67FOP9uzsYEWmj0eJmDn-2ZvCYLyOb_K";
+/// let webhook = WebhookClient::from_url(url)?;
+///
+/// let message = webhook
+///     .execute()
+///     .content("disk usage is at 90%")
+///     .username("alerts")
+///     .wait()
+///     .await?
+///     .model()?;
+///
+/// webhook
+///     .update_message(message.id)
+///     .content(Some("disk usage is back to normal".to_owned()))?
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebhookClient {
+    client: Client,
+    id: WebhookId,
+    token: String,
+}
+
+impl WebhookClient {
+    /// Create a new client for a webhook by its ID and token.
+    pub fn new(id: WebhookId, token: impl Into<String>) -> Self {
+        Self::with_client(ClientBuilder::new().build(), id, token)
+    }
+
+    /// Create a new client for a webhook from its URL.
+    ///
+    /// Refer to [`twilight_util::link::webhook::parse`] for the accepted
+    /// formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WebhookUrlError::Parse`] if the URL isn't a valid webhook
+    /// URL.
+    ///
+    /// Returns [`WebhookUrlError::TokenMissing`] if the URL doesn't contain
+    /// the token of the webhook.
+    pub fn from_url(url: &str) -> Result<Self, WebhookUrlError> {
+        let (id, token) =
+            webhook::parse(url).map_err(|source| WebhookUrlError::Parse { source })?;
+        let token = token.ok_or(WebhookUrlError::TokenMissing)?;
+
+        Ok(Self::new(id, token))
+    }
+
+    /// Create a new client for a webhook, sending requests through a
+    /// configured [`Client`].
+    ///
+    /// This can be used to configure a proxy, timeout, or a ratelimiter
+    /// shared with other clients. The client doesn't need a token.
+    pub fn with_client(client: Client, id: WebhookId, token: impl Into<String>) -> Self {
+        Self {
+            client,
+            id,
+            token: token.into(),
+        }
+    }
+
+    /// Underlying client that requests are sent through.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// ID of the webhook.
+    pub fn id(&self) -> WebhookId {
+        self.id
+    }
+
+    /// Token of the webhook.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Execute the webhook, sending a message to its channel.
+    ///
+    /// Use [`ExecuteWebhook::wait`] to receive the created message.
+    pub fn execute(&self) -> ExecuteWebhook<'_> {
+        self.client.execute_webhook(self.id, self.token.as_str())
+    }
+
+    /// Get a message created by the webhook.
+    pub fn message(&self, message_id: MessageId) -> GetWebhookMessage<'_> {
+        self.client
+            .webhook_message(self.id, self.token.as_str(), message_id)
+    }
+
+    /// Update a message created by the webhook.
+    pub fn update_message(&self, message_id: MessageId) -> UpdateWebhookMessage<'_> {
+        self.client
+            .update_webhook_message(self.id, self.token.as_str(), message_id)
+    }
+
+    /// Delete a message created by the webhook.
+    pub fn delete_message(&self, message_id: MessageId) -> DeleteWebhookMessage<'_> {
+        self.client
+            .delete_webhook_message(self.id, self.token.as_str(), message_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebhookClient, WebhookUrlError};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::WebhookId;
+
+    assert_impl_all!(WebhookClient: Clone, Debug, Send, Sync);
+    assert_impl_all!(WebhookUrlError: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_from_url() {
+        let client = WebhookClient::from_url("https://discord.com/api/webhooks/1/token").unwrap();
+        assert_eq!(WebhookId(1), client.id());
+        assert_eq!("token", client.token());
+
+        assert!(matches!(
+            WebhookClient::from_url("https://discord.com/api/webhooks/1"),
+            Err(WebhookUrlError::TokenMissing)
+        ));
+        assert!(matches!(
+            WebhookClient::from_url("https://discord.com/api/webhooks/a/token"),
+            Err(WebhookUrlError::Parse { .. })
+        ));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{api_error::ApiError, ratelimiting::RatelimitError};
+use crate::{
+    api_error::ApiError,
+    ratelimiting::{GenericError, RatelimitError},
+};
 use futures_channel::oneshot::Canceled;
 use hyper::{
     header::InvalidHeaderValue, http::Error as HttpError, Body, Error as HyperError, Response,
@@ -8,6 +11,7 @@ use std::{
     error::Error as StdError,
     fmt::{Display, Error as FmtError, Formatter, Result as FmtResult},
     result::Result as StdResult,
+    time::Duration,
 };
 use tokio::time::error::Elapsed;
 
@@ -34,9 +38,25 @@ pub enum Error {
     Formatting {
         source: FmtError,
     },
+    /// Too many invalid requests were made recently, so the request wasn't
+    /// made to prevent Discord from banning the IP address of the client.
+    ///
+    /// Refer to the [client's documentation] for more information.
+    ///
+    /// [client's documentation]: crate::Client#invalid-request-protection
+    InvalidRequestLimit {
+        /// Time until requests may be made again.
+        retry_after: Duration,
+    },
     Json {
         source: JsonError,
     },
+    /// Members of a response can't be deserialized because the ID of the
+    /// guild they belong to isn't known.
+    ///
+    /// This only occurs for responses that weren't returned by a request for
+    /// members.
+    MemberGuildIdMissing,
     Parsing {
         body: Vec<u8>,
         source: JsonError,
@@ -44,6 +64,10 @@ pub enum Error {
     Ratelimiting {
         source: RatelimitError,
     },
+    /// Getting a ticket from the ratelimiter failed.
+    RatelimiterTicket {
+        source: GenericError,
+    },
     RequestCanceled {
         source: Canceled,
     },
@@ -94,11 +118,20 @@ impl Display for Error {
                 write!(f, "Parsing the value for header {} failed", name)
             }
             Self::Formatting { .. } => f.write_str("Formatting a string failed"),
+            Self::InvalidRequestLimit { retry_after } => write!(
+                f,
+                "too many invalid requests were made recently, requests are blocked for {:?}",
+                retry_after
+            ),
             Self::Json { .. } => f.write_str("Given value couldn't be serialized"),
+            Self::MemberGuildIdMissing => {
+                f.write_str("guild id of the members of the response is unknown")
+            }
             Self::Parsing { body, .. } => {
                 write!(f, "Response body couldn't be deserialized: {:?}", body)
             }
             Self::Ratelimiting { .. } => f.write_str("Ratelimiting failure"),
+            Self::RatelimiterTicket { .. } => f.write_str("Getting a ratelimiter ticket failed"),
             Self::RequestCanceled { .. } => {
                 f.write_str("Request was canceled either before or while being sent")
             }
@@ -125,10 +158,15 @@ impl StdError for Error {
             Self::Formatting { source } => Some(source),
             Self::Json { source } | Self::Parsing { source, .. } => Some(source),
             Self::Ratelimiting { source } => Some(source),
+            Self::RatelimiterTicket { source } => Some(&**source),
             Self::RequestCanceled { source } => Some(source),
             Self::ChunkingResponse { source } | Self::RequestError { source } => Some(source),
             Self::RequestTimedOut { source } => Some(source),
-            Self::Response { .. } | Self::ServiceUnavailable { .. } | Self::Unauthorized => None,
+            Self::InvalidRequestLimit { .. }
+            | Self::MemberGuildIdMissing
+            | Self::Response { .. }
+            | Self::ServiceUnavailable { .. }
+            | Self::Unauthorized => None,
         }
     }
 }
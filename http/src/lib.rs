@@ -36,6 +36,23 @@
 //! twilight-http = { default-features = false, features = ["rustls", "simd-json"], version = "0.2" }
 //! ```
 //!
+//! ### Metrics
+//!
+//! The `metrics` feature provides metrics information via the `metrics` crate.
+//! The metrics logged are a timing of how long requests wait for the
+//! ratelimiter, a counter of responses reporting a global ratelimit, and a
+//! gauge of the number of buckets of the `InMemoryRatelimiter`.
+//!
+//! This is disabled by default.
+//!
+//! ### Ratelimiting
+//!
+//! #### `redis-ratelimiter`
+//!
+//! The `redis-ratelimiter` feature enables the `RedisRatelimiter`, which
+//! keeps ratelimits in a server speaking the Redis protocol so that multiple
+//! processes can share them. It is not enabled by default.
+//!
 //! ### TLS
 //!
 //! `twilight-http` has features to enable [`hyper`]'s TLS features. These
@@ -96,6 +113,7 @@ pub mod client;
 pub mod error;
 pub mod ratelimiting;
 pub mod request;
+pub mod response;
 pub mod routing;
 
 /// Discord API version used by this crate.
@@ -104,6 +122,7 @@ pub const API_VERSION: u8 = 8;
 pub use crate::{
     client::Client,
     error::{Error, Result},
+    response::Response,
 };
 
 #[cfg(not(feature = "simd-json"))]
@@ -123,5 +142,10 @@ pub(crate) use serde_json::to_vec as json_to_vec;
 #[cfg(feature = "simd-json")]
 pub(crate) use simd_json::to_vec as json_to_vec;
 
+#[cfg(not(feature = "simd-json"))]
+pub(crate) use serde_json::to_string as json_to_string;
+#[cfg(feature = "simd-json")]
+pub(crate) use simd_json::to_string as json_to_string;
+
 #[cfg(not(any(feature = "native", feature = "rustls")))]
 compile_error!("Either the `native` or `rustls` feature must be enabled.");
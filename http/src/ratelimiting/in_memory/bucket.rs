@@ -0,0 +1,443 @@
+use super::{BucketKey, Buckets, GlobalLockPair};
+use crate::{
+    ratelimiting::{BucketInfo, RatelimitHeaders},
+    routing::Path,
+};
+use futures_channel::{
+    mpsc::{self, UnboundedReceiver, UnboundedSender},
+    oneshot::{self, Sender},
+};
+use futures_util::{future::FutureExt, lock::Mutex, stream::StreamExt};
+use hyper::Method;
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::time::{sleep, timeout};
+
+#[derive(Clone, Debug)]
+pub enum TimeRemaining {
+    Finished,
+    NotStarted,
+    Some(Duration),
+}
+
+#[derive(Debug)]
+pub struct Bucket {
+    pub limit: AtomicU64,
+    pub method: Method,
+    pub path: Path,
+    pub queue: BucketQueue,
+    pub remaining: AtomicU64,
+    pub reset_after: AtomicU64,
+    pub started_at: Mutex<Option<Instant>>,
+}
+
+impl Bucket {
+    pub fn new(method: Method, path: Path) -> Self {
+        Self {
+            limit: AtomicU64::new(u64::max_value()),
+            method,
+            path,
+            queue: BucketQueue::default(),
+            remaining: AtomicU64::new(u64::max_value()),
+            reset_after: AtomicU64::new(u64::max_value()),
+            started_at: Mutex::new(None),
+        }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining.load(Ordering::Relaxed)
+    }
+
+    pub fn reset_after(&self) -> u64 {
+        self.reset_after.load(Ordering::Relaxed)
+    }
+
+    /// Return information about the bucket.
+    pub async fn info(&self, hash: Option<String>) -> BucketInfo {
+        let known = |value: u64| Some(value).filter(|value| *value != u64::max_value());
+
+        let period = known(self.reset_after()).map(Duration::from_millis);
+        let started_at = *self.started_at.lock().await;
+
+        BucketInfo {
+            hash,
+            limit: known(self.limit()),
+            method: self.method.clone(),
+            path: self.path.clone(),
+            period,
+            queued: self.queue.len(),
+            remaining: known(self.remaining()),
+            reset_at: started_at
+                .zip(period)
+                .map(|(started_at, period)| started_at + period),
+            time_remaining: match self.time_remaining().await {
+                TimeRemaining::Some(duration) => Some(duration),
+                TimeRemaining::Finished | TimeRemaining::NotStarted => None,
+            },
+        }
+    }
+
+    pub async fn time_remaining(&self) -> TimeRemaining {
+        let reset_after = self.reset_after();
+        let started_at = match *self.started_at.lock().await {
+            Some(v) => v,
+            None => return TimeRemaining::NotStarted,
+        };
+        let elapsed = started_at.elapsed();
+
+        if elapsed > Duration::from_millis(reset_after) {
+            return TimeRemaining::Finished;
+        }
+
+        TimeRemaining::Some(Duration::from_millis(reset_after) - elapsed)
+    }
+
+    pub async fn try_reset(&self) -> bool {
+        if self.started_at.lock().await.is_none() {
+            return false;
+        }
+
+        if let TimeRemaining::Finished = self.time_remaining().await {
+            self.remaining.store(self.limit(), Ordering::Relaxed);
+            *self.started_at.lock().await = None;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    pub async fn update(&self, ratelimits: Option<(u64, u64, u64)>) {
+        let bucket_limit = self.limit();
+
+        {
+            let mut started_at = self.started_at.lock().await;
+
+            if started_at.is_none() {
+                started_at.replace(Instant::now());
+            }
+        }
+
+        if let Some((limit, remaining, reset_after)) = ratelimits {
+            if bucket_limit != limit && bucket_limit == u64::max_value() {
+                self.reset_after.store(reset_after, Ordering::SeqCst);
+                self.limit.store(limit, Ordering::SeqCst);
+            }
+
+            self.remaining.store(remaining, Ordering::Relaxed);
+        } else {
+            self.remaining.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BucketQueue {
+    len: AtomicUsize,
+    rx: Mutex<UnboundedReceiver<Sender<Sender<Option<RatelimitHeaders>>>>>,
+    tx: UnboundedSender<Sender<Sender<Option<RatelimitHeaders>>>>,
+}
+
+impl BucketQueue {
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    pub fn push(&self, tx: Sender<Sender<Option<RatelimitHeaders>>>) {
+        if self.tx.unbounded_send(tx).is_ok() {
+            self.len.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Move the queued requests to the end of another queue.
+    ///
+    /// Must only be called by the task processing the queue while it isn't
+    /// waiting for the next request.
+    pub fn move_to(&self, other: &Self) {
+        let mut rx = match self.rx.try_lock() {
+            Some(rx) => rx,
+            None => return,
+        };
+
+        while let Some(Some(tx)) = rx.next().now_or_never() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+            other.push(tx);
+        }
+    }
+
+    pub async fn pop(
+        &self,
+        timeout_duration: Duration,
+    ) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+        let mut rx = self.rx.lock().await;
+
+        let tx = match timeout(timeout_duration, StreamExt::next(&mut *rx))
+            .await
+            .ok()
+        {
+            Some(x) => x,
+            None => None,
+        };
+
+        if tx.is_some() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+        }
+
+        tx
+    }
+}
+
+impl Default for BucketQueue {
+    fn default() -> Self {
+        let (tx, rx) = mpsc::unbounded();
+
+        Self {
+            len: AtomicUsize::new(0),
+            rx: Mutex::new(rx),
+            tx,
+        }
+    }
+}
+
+pub(super) struct BucketQueueTask {
+    bucket: Arc<Bucket>,
+    buckets: Arc<Mutex<Buckets>>,
+    global: Arc<GlobalLockPair>,
+    method: Method,
+    path: Path,
+}
+
+impl BucketQueueTask {
+    const WAIT: Duration = Duration::from_secs(10);
+
+    pub fn new(
+        bucket: Arc<Bucket>,
+        buckets: Arc<Mutex<Buckets>>,
+        global: Arc<GlobalLockPair>,
+        method: Method,
+        path: Path,
+    ) -> Self {
+        Self {
+            bucket,
+            buckets,
+            global,
+            method,
+            path,
+        }
+    }
+
+    pub async fn run(self) {
+        let span = tracing::debug_span!("background queue task", path=?self.path);
+
+        loop {
+            while let Some(queue_tx) = self.next().await {
+                let (tx, rx) = oneshot::channel();
+
+                if self.global.is_locked() {
+                    self.global.0.lock().await;
+                }
+
+                let _ = queue_tx.send(tx);
+
+                tracing::debug!(parent: &span, "starting to wait for response headers",);
+
+                // TODO: Find a better way of handling nested types.
+                match timeout(Self::WAIT, rx).await {
+                    Ok(Ok(Some(headers))) => {
+                        if self.handle_headers(&headers).await {
+                            tracing::debug!(
+                                parent: &span,
+                                "bucket merged into another bucket, stopping",
+                            );
+
+                            return;
+                        }
+                    }
+                    // - None was sent through the channel (request aborted)
+                    // - channel was closed
+                    // - timeout reached
+                    Ok(Err(_)) | Err(_) | Ok(Ok(None)) => {
+                        tracing::debug!(parent: &span, "receiver timed out");
+                    }
+                }
+            }
+
+            let mut buckets = self.buckets.lock().await;
+
+            // Requests of a merged bucket may have been moved into this one
+            // after the queue was found empty.
+            if self.bucket.queue.len() > 0 {
+                continue;
+            }
+
+            tracing::debug!(parent: &span, "bucket appears finished, removing");
+
+            buckets
+                .buckets
+                .retain(|_, bucket| !Arc::ptr_eq(bucket, &self.bucket));
+
+            #[cfg(feature = "metrics")]
+            buckets.record_metrics();
+
+            return;
+        }
+    }
+
+    /// Update the bucket with the headers of a response.
+    ///
+    /// Returns whether the bucket was merged into the bucket of another path,
+    /// in which case its queued requests were moved there and the task must
+    /// stop.
+    async fn handle_headers(&self, headers: &RatelimitHeaders) -> bool {
+        let ratelimits = match headers {
+            RatelimitHeaders::GlobalLimited { reset_after } => {
+                self.lock_global(*reset_after).await;
+
+                None
+            }
+            RatelimitHeaders::None => return false,
+            RatelimitHeaders::Present {
+                global,
+                limit,
+                remaining,
+                reset_after,
+                ..
+            } => {
+                if *global {
+                    self.lock_global(*reset_after).await;
+                }
+
+                Some((*limit, *remaining, *reset_after))
+            }
+        };
+
+        let hash = match headers {
+            RatelimitHeaders::Present {
+                bucket: Some(hash), ..
+            } => hash,
+            _ => {
+                tracing::debug!(path=?self.path, "updating bucket");
+                self.bucket.update(ratelimits).await;
+
+                return false;
+            }
+        };
+
+        let mut buckets = self.buckets.lock().await;
+        let merged = self.merge(&mut buckets, hash);
+
+        tracing::debug!(path=?self.path, "updating bucket");
+        merged.update(ratelimits).await;
+
+        if Arc::ptr_eq(&merged, &self.bucket) {
+            return false;
+        }
+
+        // The merged bucket's task sends the requests queued in this bucket,
+        // so that requests to the shared bucket are sent one at a time and
+        // wait for it to refresh. No more requests are queued in this bucket,
+        // as it was removed while the buckets are still locked.
+        tracing::debug!(path=?self.path, hash, "moving queued requests into hash bucket");
+        self.bucket.queue.move_to(&merged.queue);
+
+        true
+    }
+
+    /// Record the hash of the path's bucket, merging the bucket with the
+    /// buckets of other paths sharing the hash and major parameter.
+    ///
+    /// Returns the merged bucket, which new requests of the path are queued
+    /// in.
+    fn merge(&self, buckets: &mut Buckets, hash: &str) -> Arc<Bucket> {
+        buckets.learn_hash(&self.method, &self.path, hash);
+
+        let key = BucketKey::Hash {
+            hash: hash.to_owned(),
+            major_parameter: self.path.major_parameter(),
+        };
+        let merged = Arc::clone(
+            buckets
+                .buckets
+                .entry(key)
+                .or_insert_with(|| Arc::clone(&self.bucket)),
+        );
+
+        let path_key = BucketKey::Path(self.method.clone(), self.path.clone());
+
+        if buckets
+            .buckets
+            .get(&path_key)
+            .map_or(false, |bucket| Arc::ptr_eq(bucket, &self.bucket))
+        {
+            tracing::debug!(path=?self.path, hash, "merging bucket of path into hash bucket");
+            buckets.buckets.remove(&path_key);
+        }
+
+        merged
+    }
+
+    async fn lock_global(&self, wait: u64) {
+        tracing::debug!(path=?self.path, "request got global ratelimited");
+        self.global.lock();
+        let lock = self.global.0.lock().await;
+        sleep(Duration::from_millis(wait)).await;
+        self.global.unlock();
+
+        drop(lock);
+    }
+
+    async fn next(&self) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+        tracing::debug!(path=?self.path, "starting to get next in queue");
+
+        let tx = self.bucket.queue.pop(Self::WAIT).await?;
+
+        // Wait after a request is queued, since requests to other paths
+        // sharing the bucket may have used it up while the queue was empty.
+        self.wait_if_needed().await;
+
+        Some(tx)
+    }
+
+    async fn wait_if_needed(&self) {
+        let span = tracing::debug_span!("waiting for bucket to refresh", path=?self.path);
+
+        let wait = {
+            if self.bucket.remaining() > 0 {
+                return;
+            }
+
+            tracing::debug!(parent: &span, "0 tickets remaining, may have to wait");
+
+            match self.bucket.time_remaining().await {
+                TimeRemaining::Finished => {
+                    self.bucket.try_reset().await;
+
+                    return;
+                }
+                TimeRemaining::NotStarted => return,
+                TimeRemaining::Some(dur) => dur,
+            }
+        };
+
+        tracing::debug!(
+            parent: &span,
+            milliseconds=%wait.as_millis(),
+            "waiting for ratelimit to pass",
+        );
+
+        sleep(wait).await;
+
+        tracing::debug!(parent: &span, "done waiting for ratelimit to pass");
+
+        self.bucket.try_reset().await;
+    }
+}
@@ -0,0 +1,538 @@
+mod bucket;
+
+use self::bucket::{Bucket, BucketQueueTask, TimeRemaining};
+use super::{
+    BucketInfo, GetTicketFuture, RatelimitHeaders, Ratelimiter, RatelimiterSnapshot,
+    SnapshotFuture, TicketSender, TimeUntilAvailableFuture,
+};
+use crate::routing::Path;
+use futures_channel::oneshot::{self, Receiver, Sender};
+use futures_util::{future::FutureExt, lock::Mutex};
+use hyper::Method;
+use std::{
+    collections::{
+        hash_map::{Entry, HashMap},
+        HashSet,
+    },
+    mem::{self, Discriminant},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Global lock. We use a pair to avoid actually locking the mutex every check.
+/// This allows futures to only wait on the global lock when a global ratelimit
+/// is in place by, in turn, waiting for a guard, and then each immediately
+/// dropping it.
+#[derive(Debug, Default)]
+struct GlobalLockPair(Mutex<()>, AtomicBool);
+
+impl GlobalLockPair {
+    pub fn lock(&self) {
+        self.1.store(true, Ordering::Release);
+    }
+
+    pub fn unlock(&self) {
+        self.1.store(false, Ordering::Release);
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.1.load(Ordering::Relaxed)
+    }
+}
+
+/// Key of a bucket.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum BucketKey {
+    /// Bucket shared by the paths with a bucket hash and major parameter.
+    Hash {
+        hash: String,
+        major_parameter: Option<u64>,
+    },
+    /// Bucket of a method and path whose bucket hash isn't known yet.
+    Path(Method, Path),
+}
+
+/// Route of a request, which is its method and path without the path's major
+/// parameter.
+///
+/// Discord sends the same bucket hash for all requests of a route.
+type Route = (Method, Discriminant<Path>);
+
+/// Buckets and the bucket hashes of routes learned from responses.
+#[derive(Debug, Default)]
+struct Buckets {
+    buckets: HashMap<BucketKey, Arc<Bucket>>,
+    hashes: HashMap<Route, String>,
+}
+
+impl Buckets {
+    fn route(method: &Method, path: &Path) -> Route {
+        (method.clone(), mem::discriminant(path))
+    }
+
+    fn hash(&self, method: &Method, path: &Path) -> Option<&String> {
+        self.hashes.get(&Self::route(method, path))
+    }
+
+    /// Return the key of the bucket a method and path's requests are queued
+    /// in.
+    fn key(&self, method: &Method, path: &Path) -> BucketKey {
+        match self.hash(method, path) {
+            Some(hash) => BucketKey::Hash {
+                hash: hash.clone(),
+                major_parameter: path.major_parameter(),
+            },
+            None => BucketKey::Path(method.clone(), path.clone()),
+        }
+    }
+
+    /// Record the number of buckets.
+    #[cfg(feature = "metrics")]
+    fn record_metrics(&self) {
+        use std::convert::TryInto;
+
+        metrics::gauge!(
+            "Ratelimiter-Buckets",
+            self.buckets.len().try_into().unwrap_or(-1)
+        );
+    }
+
+    fn learn_hash(&mut self, method: &Method, path: &Path, hash: &str) {
+        if self.hash(method, path).map(String::as_str) != Some(hash) {
+            tracing::debug!(%method, path=?path, hash, "learned bucket hash of route");
+            self.hashes
+                .insert(Self::route(method, path), hash.to_owned());
+        }
+    }
+}
+
+/// Ratelimiter keeping the state of buckets in the memory of the process.
+///
+/// This is the default ratelimiter of the client. It's only aware of the
+/// requests made by clients sharing it, so separate processes making
+/// requests with the same token should use a shared ratelimiter such as the
+/// `RedisRatelimiter` instead.
+///
+/// Requests are queued in a bucket per method and path until the bucket hash
+/// of the route is known from the `X-RateLimit-Bucket` header of a response.
+/// From then on, requests to routes sharing a bucket hash and major parameter
+/// are queued in the same bucket, like Discord ratelimits them.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRatelimiter {
+    buckets: Arc<Mutex<Buckets>>,
+    global: Arc<GlobalLockPair>,
+}
+
+impl InMemoryRatelimiter {
+    /// Create a new ratelimiter.
+    ///
+    /// Most users won't need to use this directly. If you're creating your own
+    /// HTTP proxy then this is good to use for your own ratelimiting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn get(
+        &self,
+        method: Method,
+        path: Path,
+    ) -> Receiver<Sender<Option<RatelimitHeaders>>> {
+        tracing::debug!("getting bucket for path: {} {:?}", method, path);
+
+        let (tx, rx) = oneshot::channel();
+        let (bucket, fresh) = self.entry(method.clone(), path.clone(), tx).await;
+
+        if fresh {
+            tokio::spawn(
+                BucketQueueTask::new(
+                    bucket,
+                    Arc::clone(&self.buckets),
+                    Arc::clone(&self.global),
+                    method,
+                    path,
+                )
+                .run(),
+            );
+        }
+
+        rx
+    }
+
+    /// Return information about the bucket requests with a method to a path
+    /// are queued in.
+    ///
+    /// Returns `None` if no request to the bucket has been made recently.
+    pub async fn bucket(&self, method: &Method, path: &Path) -> Option<BucketInfo> {
+        let buckets = self.buckets.lock().await;
+        let bucket = buckets.buckets.get(&buckets.key(method, path))?;
+
+        Some(bucket.info(buckets.hash(method, path).cloned()).await)
+    }
+
+    /// Take a snapshot of the buckets and the global ratelimit.
+    pub async fn snapshot(&self) -> RatelimiterSnapshot {
+        let buckets = self.buckets.lock().await;
+
+        let mut entries = buckets
+            .buckets
+            .iter()
+            .map(|(key, bucket)| {
+                let hash = match key {
+                    BucketKey::Hash { hash, .. } => Some(hash),
+                    BucketKey::Path(..) => None,
+                };
+
+                (hash, bucket)
+            })
+            .collect::<Vec<_>>();
+
+        // Several keys can point to the same bucket while it's being merged,
+        // so list each bucket once, with its hash if one of its keys has it.
+        entries.sort_by_key(|(hash, _)| hash.is_none());
+
+        {
+            let mut seen = HashSet::with_capacity(entries.len());
+            entries.retain(|(_, bucket)| seen.insert(Arc::as_ptr(bucket)));
+        }
+
+        let mut infos = Vec::with_capacity(entries.len());
+
+        for (hash, bucket) in entries {
+            infos.push(bucket.info(hash.cloned()).await);
+        }
+
+        RatelimiterSnapshot {
+            buckets: infos,
+            global_locked: self.global.is_locked(),
+        }
+    }
+
+    /// Provide an estimate for the time left until a method and path can be
+    /// used without being ratelimited.
+    ///
+    /// This method is not guaranteed to be accurate and may return
+    /// None if either no ratelimit is known or buckets are remaining.
+    pub async fn time_until_available(&self, method: &Method, path: &Path) -> Option<Duration> {
+        let buckets = self.buckets.lock().await;
+        let bucket = buckets.buckets.get(&buckets.key(method, path))?;
+
+        match bucket.time_remaining().await {
+            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
+            TimeRemaining::Some(duration) => Some(duration),
+        }
+    }
+
+    async fn entry(
+        &self,
+        method: Method,
+        path: Path,
+        tx: Sender<Sender<Option<RatelimitHeaders>>>,
+    ) -> (Arc<Bucket>, bool) {
+        // nb: not realisically point of contention
+        let mut buckets = self.buckets.lock().await;
+        let key = buckets.key(&method, &path);
+
+        match buckets.buckets.entry(key) {
+            Entry::Occupied(bucket) => {
+                tracing::debug!("got existing bucket: {:?}", path);
+
+                let bucket = bucket.into_mut();
+                bucket.queue.push(tx);
+                tracing::debug!("added request into bucket queue: {:?}", path);
+
+                (Arc::clone(&bucket), false)
+            }
+            Entry::Vacant(entry) => {
+                tracing::debug!("making new bucket for path: {:?}", path);
+                let bucket = Bucket::new(method, path.clone());
+                bucket.queue.push(tx);
+
+                let bucket = Arc::new(bucket);
+                entry.insert(Arc::clone(&bucket));
+
+                #[cfg(feature = "metrics")]
+                buckets.record_metrics();
+
+                (bucket, true)
+            }
+        }
+    }
+}
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn ticket(&self, method: Method, path: Path) -> GetTicketFuture {
+        let ratelimiter = self.clone();
+
+        async move {
+            let rx = ratelimiter.get(method, path).await;
+            let tx = rx.await?;
+
+            Ok(TicketSender::new(tx))
+        }
+        .boxed()
+    }
+
+    fn time_until_available<'a>(
+        &'a self,
+        method: &'a Method,
+        path: &'a Path,
+    ) -> TimeUntilAvailableFuture<'a> {
+        async move { Ok(InMemoryRatelimiter::time_until_available(self, method, path).await) }
+            .boxed()
+    }
+
+    fn snapshot(&self) -> SnapshotFuture<'_> {
+        async move { Ok(Some(InMemoryRatelimiter::snapshot(self).await)) }.boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bucket::Bucket, BucketKey, Buckets, InMemoryRatelimiter};
+    use crate::{
+        ratelimiting::{RatelimitHeaders, Ratelimiter},
+        routing::Path,
+    };
+    use futures_util::future::FutureExt;
+    use hyper::Method;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, sync::Arc, time::Duration};
+    use tokio::time::timeout;
+
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Ratelimiter, Send, Sync);
+
+    #[test]
+    fn test_bucket_keys() {
+        let mut buckets = Buckets::default();
+        assert_eq!(
+            BucketKey::Path(Method::GET, Path::ChannelsIdMessages(1)),
+            buckets.key(&Method::GET, &Path::ChannelsIdMessages(1))
+        );
+
+        buckets.learn_hash(&Method::GET, &Path::ChannelsIdMessages(1), "abc");
+
+        // The hash applies to the route regardless of the major parameter.
+        assert_eq!(
+            BucketKey::Hash {
+                hash: "abc".to_owned(),
+                major_parameter: Some(2),
+            },
+            buckets.key(&Method::GET, &Path::ChannelsIdMessages(2))
+        );
+        assert_eq!(
+            BucketKey::Path(Method::GET, Path::ChannelsIdMessagesId(Method::GET, 1)),
+            buckets.key(&Method::GET, &Path::ChannelsIdMessagesId(Method::GET, 1))
+        );
+
+        buckets.learn_hash(
+            &Method::DELETE,
+            &Path::ChannelsIdMessagesId(Method::DELETE, 1),
+            "def",
+        );
+        assert_eq!(
+            BucketKey::Path(Method::GET, Path::ChannelsIdMessagesId(Method::GET, 1)),
+            buckets.key(&Method::GET, &Path::ChannelsIdMessagesId(Method::GET, 1))
+        );
+        assert_eq!(
+            BucketKey::Hash {
+                hash: "def".to_owned(),
+                major_parameter: Some(1),
+            },
+            buckets.key(
+                &Method::DELETE,
+                &Path::ChannelsIdMessagesId(Method::DELETE, 1)
+            )
+        );
+    }
+
+    #[test]
+    fn test_bucket_keys_per_method() {
+        let mut buckets = Buckets::default();
+        let path = Path::ChannelsIdMessages(1);
+
+        // Requests with different methods to the same path don't share a
+        // bucket before or after their hashes are known.
+        assert_ne!(
+            buckets.key(&Method::GET, &path),
+            buckets.key(&Method::POST, &path)
+        );
+
+        buckets.learn_hash(&Method::GET, &path, "abc");
+        assert_eq!(
+            BucketKey::Path(Method::POST, path.clone()),
+            buckets.key(&Method::POST, &path)
+        );
+
+        buckets.learn_hash(&Method::POST, &path, "def");
+        assert_eq!(
+            BucketKey::Hash {
+                hash: "abc".to_owned(),
+                major_parameter: Some(1),
+            },
+            buckets.key(&Method::GET, &path)
+        );
+        assert_eq!(
+            BucketKey::Hash {
+                hash: "def".to_owned(),
+                major_parameter: Some(1),
+            },
+            buckets.key(&Method::POST, &path)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_buckets_per_method() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsIdMessages(1);
+
+        // A pending GET request doesn't hold up a POST request to the same
+        // path.
+        let _get = ratelimiter.ticket(Method::GET, path.clone()).await?;
+        let _post = timeout(
+            Duration::from_secs(1),
+            ratelimiter.ticket(Method::POST, path.clone()),
+        )
+        .await
+        .expect("POST request is queued in its own bucket")?;
+
+        let snapshot = ratelimiter.snapshot().await;
+        assert_eq!(2, snapshot.buckets.len());
+        assert!(ratelimiter.bucket(&Method::GET, &path).await.is_some());
+        assert!(ratelimiter.bucket(&Method::PATCH, &path).await.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        assert!(ratelimiter.snapshot().await.buckets.is_empty());
+
+        let ticket = ratelimiter
+            .ticket(Method::POST, Path::ChannelsIdTyping(1))
+            .await?;
+
+        // The second request waits for the headers of the first.
+        let mut second = ratelimiter.ticket(Method::POST, Path::ChannelsIdTyping(1));
+        assert!((&mut second).now_or_never().is_none());
+
+        let snapshot = Ratelimiter::snapshot(&ratelimiter).await?.unwrap();
+        assert!(!snapshot.global_locked);
+        assert_eq!(1, snapshot.buckets.len());
+        assert_eq!(1, snapshot.queued());
+
+        let bucket = &snapshot.buckets[0];
+        assert_eq!(Method::POST, bucket.method);
+        assert_eq!(Path::ChannelsIdTyping(1), bucket.path);
+        assert_eq!(None, bucket.hash);
+        assert_eq!(None, bucket.limit);
+        assert_eq!(None, bucket.reset_at);
+
+        ticket
+            .headers(Some(RatelimitHeaders::Present {
+                bucket: Some("abc".to_owned()),
+                global: false,
+                limit: 5,
+                remaining: 4,
+                reset: 0,
+                reset_after: 1000,
+            }))
+            .unwrap();
+        second.await?.headers(None).unwrap();
+
+        let bucket = ratelimiter
+            .bucket(&Method::POST, &Path::ChannelsIdTyping(1))
+            .await
+            .unwrap();
+        assert_eq!(Some("abc"), bucket.hash.as_deref());
+        assert_eq!(Some(5), bucket.limit);
+        assert_eq!(0, bucket.queued);
+        assert!(bucket.reset_at.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merged_buckets_share_limit() -> Result<(), Box<dyn Error + Send + Sync>> {
+        fn headers(remaining: u64, reset_after: u64) -> Option<RatelimitHeaders> {
+            Some(RatelimitHeaders::Present {
+                bucket: Some("abc".to_owned()),
+                global: false,
+                limit: 2,
+                remaining,
+                reset: 0,
+                reset_after,
+            })
+        }
+
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        let messages = ratelimiter
+            .ticket(Method::GET, Path::ChannelsIdMessages(1))
+            .await?;
+        let pins = ratelimiter
+            .ticket(Method::GET, Path::ChannelsIdPins(1))
+            .await?;
+
+        let mut second_messages = ratelimiter.ticket(Method::GET, Path::ChannelsIdMessages(1));
+        let mut second_pins = ratelimiter.ticket(Method::GET, Path::ChannelsIdPins(1));
+        assert!((&mut second_messages).now_or_never().is_none());
+        assert!((&mut second_pins).now_or_never().is_none());
+
+        // Both paths share the bucket, which has no requests left until it
+        // resets.
+        pins.headers(headers(0, 500)).unwrap();
+        messages.headers(headers(0, 500)).unwrap();
+
+        assert!(timeout(Duration::from_millis(200), &mut second_messages)
+            .await
+            .is_err());
+        assert!(timeout(Duration::from_millis(1), &mut second_pins)
+            .await
+            .is_err());
+
+        // Once the bucket resets the requests queued on both paths are sent
+        // one after the other.
+        let second_pins = timeout(Duration::from_secs(2), second_pins).await??;
+        assert!((&mut second_messages).now_or_never().is_none());
+        second_pins.headers(headers(1, 500)).unwrap();
+
+        timeout(Duration::from_secs(2), second_messages)
+            .await??
+            .headers(None)
+            .unwrap();
+
+        let snapshot = Ratelimiter::snapshot(&ratelimiter).await?.unwrap();
+        assert_eq!(1, snapshot.buckets.len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_dedupes_buckets() {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let bucket = Arc::new(Bucket::new(Method::GET, Path::ChannelsIdMessages(1)));
+
+        {
+            let mut buckets = ratelimiter.buckets.lock().await;
+            buckets.buckets.insert(
+                BucketKey::Path(Method::GET, Path::ChannelsIdMessages(1)),
+                Arc::clone(&bucket),
+            );
+            buckets.buckets.insert(
+                BucketKey::Hash {
+                    hash: "abc".to_owned(),
+                    major_parameter: Some(1),
+                },
+                bucket,
+            );
+        }
+
+        let snapshot = ratelimiter.snapshot().await;
+        assert_eq!(1, snapshot.buckets.len());
+        assert_eq!(Some("abc"), snapshot.buckets[0].hash.as_deref());
+    }
+}
@@ -1,124 +1,125 @@
+//! Ratelimiting of requests made by the client.
+//!
+//! Requests are ratelimited by a [`Ratelimiter`], which the client asks for a
+//! ticket before making each request. The default ratelimiter is the
+//! [`InMemoryRatelimiter`]. Other ratelimiters are configured via
+//! [`ClientBuilder::ratelimiter`].
+//!
+//! [`ClientBuilder::ratelimiter`]: crate::client::ClientBuilder::ratelimiter
+
 pub mod error;
 
-mod bucket;
 mod headers;
+mod in_memory;
+#[cfg(feature = "redis-ratelimiter")]
+mod redis;
+mod snapshot;
 
 pub use self::{
     error::{RatelimitError, RatelimitResult},
     headers::RatelimitHeaders,
+    in_memory::InMemoryRatelimiter,
+    snapshot::{BucketInfo, RatelimiterSnapshot},
 };
 
-use crate::routing::Path;
-use bucket::{Bucket, BucketQueueTask, TimeRemaining};
-use futures_channel::oneshot::{self, Receiver, Sender};
-use futures_util::lock::Mutex;
-use std::{
-    collections::hash_map::{Entry, HashMap},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
-    },
-    time::Duration,
-};
-
-/// Global lock. We use a pair to avoid actually locking the mutex every check.
-/// This allows futures to only wait on the global lock when a global ratelimit
-/// is in place by, in turn, waiting for a guard, and then each immediately
-/// dropping it.
-#[derive(Debug, Default)]
-struct GlobalLockPair(Mutex<()>, AtomicBool);
-
-impl GlobalLockPair {
-    pub fn lock(&self) {
-        self.1.store(true, Ordering::Release);
-    }
+#[cfg(feature = "redis-ratelimiter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-ratelimiter")))]
+pub use self::redis::{RedisRatelimiter, RedisRatelimiterError};
 
-    pub fn unlock(&self) {
-        self.1.store(false, Ordering::Release);
-    }
+use crate::routing::Path;
+use futures_channel::oneshot::Sender;
+use futures_util::future::{self, FutureExt};
+use hyper::Method;
+use std::{error::Error, fmt::Debug, future::Future, pin::Pin, time::Duration};
+
+/// Error returned by a [`Ratelimiter`].
+pub type GenericError = Box<dyn Error + Send + Sync>;
+
+/// Future resolving to a ticket allowing a request to be made.
+pub type GetTicketFuture =
+    Pin<Box<dyn Future<Output = Result<TicketSender, GenericError>> + Send + 'static>>;
+
+/// Future resolving to an estimate of the time until a path is available.
+pub type TimeUntilAvailableFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Option<Duration>, GenericError>> + Send + 'a>>;
+
+/// Future resolving to a snapshot of the state of a ratelimiter.
+pub type SnapshotFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Option<RatelimiterSnapshot>, GenericError>> + Send + 'a>>;
+
+/// Ratelimiter deciding when requests may be made.
+///
+/// Before making a request the client asks the ratelimiter for a ticket for
+/// the method and path of the request, and makes the request once the ticket
+/// is granted. The response's ratelimit headers are then sent back to the
+/// ratelimiter via the [`TicketSender`], so it can update the bucket of the
+/// request and the global ratelimit.
+///
+/// Implementations can keep their state in memory, like the
+/// [`InMemoryRatelimiter`], or share it across processes.
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Wait until a request with a method to a path may be made.
+    ///
+    /// The returned future resolves once the request may be made, with a
+    /// sender to pass the ratelimit headers of the response to.
+    fn ticket(&self, method: Method, path: Path) -> GetTicketFuture;
 
-    pub fn is_locked(&self) -> bool {
-        self.1.load(Ordering::Relaxed)
+    /// Provide an estimate for the time left until a method and path can be
+    /// used without being ratelimited.
+    ///
+    /// Returns `None` if either no ratelimit is known or the bucket of the
+    /// method and path has requests remaining.
+    fn time_until_available<'a>(
+        &'a self,
+        method: &'a Method,
+        path: &'a Path,
+    ) -> TimeUntilAvailableFuture<'a>;
+
+    /// Take a snapshot of the state of the ratelimiter, such as the number of
+    /// requests queued in each bucket, for debugging and monitoring.
+    ///
+    /// Returns `None` if the ratelimiter doesn't support snapshots, which is
+    /// what the default implementation does.
+    fn snapshot(&self) -> SnapshotFuture<'_> {
+        future::ready(Ok(None)).boxed()
     }
 }
 
-#[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
-    global: Arc<GlobalLockPair>,
-}
+/// Sender of the ratelimit headers of a response to the ratelimiter that
+/// granted the request's ticket.
+#[derive(Debug)]
+pub struct TicketSender(Sender<Option<RatelimitHeaders>>);
 
-impl Ratelimiter {
-    /// Create a new ratelimiter.
+impl TicketSender {
+    /// Create a new ticket sender sending headers via a channel.
     ///
-    /// Most users won't need to use this directly. If you're creating your own
-    /// HTTP proxy then this is good to use for your own ratelimiting.
-    pub fn new() -> Self {
-        Self::default()
+    /// This is used by implementations of [`Ratelimiter`] to receive the
+    /// headers of the response.
+    pub fn new(tx: Sender<Option<RatelimitHeaders>>) -> Self {
+        Self(tx)
     }
 
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
-        tracing::debug!("getting bucket for path: {:?}", path);
-
-        let (tx, rx) = oneshot::channel();
-        let (bucket, fresh) = self.entry(path.clone(), tx).await;
-
-        if fresh {
-            tokio::spawn(
-                BucketQueueTask::new(
-                    bucket,
-                    Arc::clone(&self.buckets),
-                    Arc::clone(&self.global),
-                    path,
-                )
-                .run(),
-            );
-        }
-
-        rx
-    }
-
-    /// Provide an estimate for the time left until a path can be used
-    /// without being ratelimited.
+    /// Send the ratelimit headers of the response to the ratelimiter.
+    ///
+    /// Send `None` if the request failed or the headers couldn't be parsed.
     ///
-    /// This method is not guaranteed to be accurate and may return
-    /// None if either no ratelimit is known or buckets are remaining.
-    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
-        let buckets = self.buckets.lock().await;
-        match buckets.get(path)?.time_remaining().await {
-            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
-            TimeRemaining::Some(duration) => Some(duration),
-        }
+    /// # Errors
+    ///
+    /// Returns the headers if the ratelimiter stopped waiting for them.
+    pub fn headers(
+        self,
+        headers: Option<RatelimitHeaders>,
+    ) -> Result<(), Option<RatelimitHeaders>> {
+        self.0.send(headers)
     }
+}
 
-    async fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
-        // nb: not realisically point of contention
-        let mut buckets = self.buckets.lock().await;
-
-        match buckets.entry(path.clone()) {
-            Entry::Occupied(bucket) => {
-                tracing::debug!("got existing bucket: {:?}", path);
-
-                let bucket = bucket.into_mut();
-                bucket.queue.push(tx);
-                tracing::debug!("added request into bucket queue: {:?}", path);
-
-                (Arc::clone(&bucket), false)
-            }
-            Entry::Vacant(entry) => {
-                tracing::debug!("making new bucket for path: {:?}", path);
-                let bucket = Bucket::new(path.clone());
-                bucket.queue.push(tx);
-
-                let bucket = Arc::new(bucket);
-                entry.insert(Arc::clone(&bucket));
-
-                (bucket, true)
-            }
-        }
-    }
+#[cfg(test)]
+mod tests {
+    use super::{Ratelimiter, TicketSender};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
+
+    assert_obj_safe!(Ratelimiter);
+    assert_impl_all!(TicketSender: Debug, Send, Sync);
 }
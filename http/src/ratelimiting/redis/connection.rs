@@ -0,0 +1,216 @@
+//! Minimal client for the Redis serialization protocol.
+
+use super::RedisRatelimiterError;
+use futures_util::future::{BoxFuture, FutureExt};
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+
+/// Value replied by the server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    Array(Vec<Value>),
+    Bulk(Vec<u8>),
+    Int(i64),
+    Nil,
+    Status(String),
+}
+
+impl Value {
+    /// Return the value as an integer, parsing bulk strings.
+    pub fn into_int(self) -> Result<i64, RedisRatelimiterError> {
+        match self {
+            Self::Int(int) => Ok(int),
+            Self::Bulk(bytes) => std::str::from_utf8(&bytes)
+                .ok()
+                .and_then(|text| text.parse().ok())
+                .ok_or_else(|| RedisRatelimiterError::Protocol {
+                    message: "bulk string isn't an integer".to_owned(),
+                }),
+            other => Err(RedisRatelimiterError::Protocol {
+                message: format!("expected an integer, got {:?}", other),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Connection {
+    stream: BufReader<TcpStream>,
+}
+
+impl Connection {
+    pub async fn connect(address: &str) -> Result<Self, RedisRatelimiterError> {
+        let stream = TcpStream::connect(address)
+            .await
+            .map_err(|source| RedisRatelimiterError::Io { source })?;
+
+        Ok(Self {
+            stream: BufReader::new(stream),
+        })
+    }
+
+    /// Send a command and read its reply.
+    ///
+    /// Error replies are returned as [`RedisRatelimiterError::Server`].
+    pub async fn command(&mut self, args: &[&[u8]]) -> Result<Value, RedisRatelimiterError> {
+        self.stream
+            .get_mut()
+            .write_all(&encode(args))
+            .await
+            .map_err(|source| RedisRatelimiterError::Io { source })?;
+
+        read_value(&mut self.stream).await
+    }
+}
+
+/// Encode a command as an array of bulk strings.
+pub fn encode(args: &[&[u8]]) -> Vec<u8> {
+    let mut buf = format!("*{}\r\n", args.len()).into_bytes();
+
+    for arg in args {
+        buf.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+        buf.extend_from_slice(arg);
+        buf.extend_from_slice(b"\r\n");
+    }
+
+    buf
+}
+
+/// Read a value from a stream.
+pub fn read_value<R: AsyncBufRead + Send + Unpin>(
+    reader: &mut R,
+) -> BoxFuture<'_, Result<Value, RedisRatelimiterError>> {
+    async move {
+        let line = read_line(reader).await?;
+        let mut chars = line.chars();
+        let kind = chars.next();
+        let rest = chars.as_str();
+
+        match kind {
+            Some('+') => Ok(Value::Status(rest.to_owned())),
+            Some('-') => Err(RedisRatelimiterError::Server {
+                message: rest.to_owned(),
+            }),
+            Some(':') => Ok(Value::Int(parse_int(rest)?)),
+            Some('$') => {
+                let len = parse_int(rest)?;
+
+                if len < 0 {
+                    return Ok(Value::Nil);
+                }
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let mut bytes = vec![0; len as usize + 2];
+                reader
+                    .read_exact(&mut bytes)
+                    .await
+                    .map_err(|source| RedisRatelimiterError::Io { source })?;
+                bytes.truncate(bytes.len() - 2);
+
+                Ok(Value::Bulk(bytes))
+            }
+            Some('*') => {
+                let len = parse_int(rest)?;
+
+                if len < 0 {
+                    return Ok(Value::Nil);
+                }
+
+                let mut values = Vec::new();
+
+                for _ in 0..len {
+                    values.push(read_value(reader).await?);
+                }
+
+                Ok(Value::Array(values))
+            }
+            _ => Err(RedisRatelimiterError::Protocol {
+                message: format!("unknown reply type: {:?}", line),
+            }),
+        }
+    }
+    .boxed()
+}
+
+async fn read_line<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+) -> Result<String, RedisRatelimiterError> {
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .await
+        .map_err(|source| RedisRatelimiterError::Io { source })?;
+
+    if !line.ends_with("\r\n") || line.len() < 3 {
+        return Err(RedisRatelimiterError::Protocol {
+            message: "connection closed or reply line malformed".to_owned(),
+        });
+    }
+
+    line.truncate(line.len() - 2);
+
+    Ok(line)
+}
+
+fn parse_int(text: &str) -> Result<i64, RedisRatelimiterError> {
+    text.parse().map_err(|_| RedisRatelimiterError::Protocol {
+        message: format!("invalid integer: {:?}", text),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode, read_value, Value};
+    use crate::ratelimiting::RedisRatelimiterError;
+
+    async fn read(mut bytes: &[u8]) -> Result<Value, RedisRatelimiterError> {
+        read_value(&mut bytes).await
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            b"*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$0\r\n\r\n".to_vec(),
+            encode(&[b"SET", b"key", b""])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_values() {
+        assert_eq!(
+            Value::Status("OK".to_owned()),
+            read(b"+OK\r\n").await.unwrap()
+        );
+        assert_eq!(Value::Int(-12), read(b":-12\r\n").await.unwrap());
+        assert_eq!(Value::Nil, read(b"$-1\r\n").await.unwrap());
+        assert_eq!(
+            Value::Bulk(b"a\r\nb".to_vec()),
+            read(b"$4\r\na\r\nb\r\n").await.unwrap()
+        );
+        assert_eq!(
+            Value::Array(vec![Value::Int(1), Value::Bulk(b"two".to_vec())]),
+            read(b"*2\r\n:1\r\n$3\r\ntwo\r\n").await.unwrap()
+        );
+        assert!(matches!(
+            read(b"-ERR unknown command\r\n").await,
+            Err(RedisRatelimiterError::Server { message }) if message == "ERR unknown command"
+        ));
+        assert!(matches!(
+            read(b"?\r\n").await,
+            Err(RedisRatelimiterError::Protocol { .. })
+        ));
+        assert!(matches!(
+            read(b"").await,
+            Err(RedisRatelimiterError::Protocol { .. })
+        ));
+    }
+
+    #[test]
+    fn test_into_int() {
+        assert_eq!(5, Value::Bulk(b"5".to_vec()).into_int().unwrap());
+        assert_eq!(5, Value::Int(5).into_int().unwrap());
+        assert!(Value::Nil.into_int().is_err());
+    }
+}
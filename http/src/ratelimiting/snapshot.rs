@@ -0,0 +1,66 @@
+use crate::routing::Path;
+use hyper::Method;
+use std::time::{Duration, Instant};
+
+/// Information about a ratelimit bucket.
+///
+/// This is returned by [`InMemoryRatelimiter::bucket`] and included in
+/// [`RatelimiterSnapshot`]s.
+///
+/// [`InMemoryRatelimiter::bucket`]: super::InMemoryRatelimiter::bucket
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BucketInfo {
+    /// Hash of the bucket sent by Discord, if known.
+    pub hash: Option<String>,
+    /// Number of requests that can be made per period, if known.
+    pub limit: Option<u64>,
+    /// Method of the request that created the bucket.
+    pub method: Method,
+    /// Path of the request that created the bucket.
+    ///
+    /// Buckets with a known hash are shared by all routes with the hash and
+    /// the same major parameter.
+    pub path: Path,
+    /// Length of the period after which the bucket resets, if known.
+    pub period: Option<Duration>,
+    /// Number of requests waiting in the bucket's queue.
+    pub queued: usize,
+    /// Number of requests remaining in the current period, if known.
+    pub remaining: Option<u64>,
+    /// When the bucket resets, if a period has started.
+    pub reset_at: Option<Instant>,
+    /// Time until the bucket resets, if a period has started.
+    pub time_remaining: Option<Duration>,
+}
+
+/// Snapshot of the state of a ratelimiter.
+///
+/// This is returned by [`Ratelimiter::snapshot`].
+///
+/// [`Ratelimiter::snapshot`]: super::Ratelimiter::snapshot
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RatelimiterSnapshot {
+    /// Buckets that requests have been made to recently.
+    pub buckets: Vec<BucketInfo>,
+    /// Whether requests are blocked by a global ratelimit.
+    pub global_locked: bool,
+}
+
+impl RatelimiterSnapshot {
+    /// Return the total number of requests waiting in the queues of buckets.
+    pub fn queued(&self) -> usize {
+        self.buckets.iter().map(|bucket| bucket.queued).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketInfo, RatelimiterSnapshot};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(BucketInfo: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(RatelimiterSnapshot: Clone, Debug, Eq, PartialEq, Send, Sync);
+}
@@ -0,0 +1,262 @@
+use super::multipart::Form;
+use bytes::Bytes;
+use serde::Serialize;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+use tokio::io::AsyncRead;
+use twilight_model::id::AttachmentId;
+
+/// Reader uploading the contents of an attachment.
+pub type Reader = Pin<Box<dyn AsyncRead + Send + 'static>>;
+
+/// Source of the contents of an attachment.
+#[derive(Clone)]
+pub enum AttachmentSource {
+    /// Contents are in memory.
+    Bytes(Bytes),
+    /// Contents are read while the request is sent.
+    ///
+    /// The reader is shared between clones and taken by the first upload.
+    Reader(Arc<Mutex<Option<Reader>>>),
+}
+
+impl Debug for AttachmentSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Reader(_) => f.debug_tuple("Reader").finish(),
+        }
+    }
+}
+
+/// File to upload as an attachment of a message.
+///
+/// Attachments can be added to created messages, executed webhooks, and
+/// updated messages of either.
+///
+/// The contents of an attachment are either in memory, created via
+/// [`from_bytes`], or are read from an asynchronous reader while the request
+/// is being sent, created via [`from_reader`]. Reading from a reader avoids
+/// buffering large files, but the request can't be retried if it fails since
+/// the reader can only be read once.
+///
+/// # Examples
+///
+/// Upload a video read from a reader as a spoiler, alongside an image from
+/// memory:
+///
+/// ```rust,no_run
+/// use twilight_http::{request::Attachment, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// // Any reader can be used, such as a `tokio::fs::File`.
+/// let reader = std::io::Cursor::new(vec![0; 1024]);
+/// let video = Attachment::from_reader("video.mp4", reader).spoiler(true);
+/// let image = Attachment::from_bytes("image.png", vec![0x89, 0x50, 0x4E, 0x47])
+///     .content_type("image/png")
+///     .description("a very small image");
+///
+/// client
+///     .create_message(ChannelId(1))
+///     .attachments(vec![video, image])
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`from_bytes`]: Self::from_bytes
+/// [`from_reader`]: Self::from_reader
+#[derive(Clone, Debug)]
+pub struct Attachment {
+    content_type: Option<String>,
+    description: Option<String>,
+    filename: String,
+    source: AttachmentSource,
+    spoiler: bool,
+}
+
+impl Attachment {
+    /// Prefix of the filenames of attachments marked as spoilers.
+    pub const SPOILER_PREFIX: &'static str = "SPOILER_";
+
+    /// Create an attachment from contents in memory.
+    pub fn from_bytes(filename: impl Into<String>, bytes: impl Into<Bytes>) -> Self {
+        Self::new(filename.into(), AttachmentSource::Bytes(bytes.into()))
+    }
+
+    /// Create an attachment whose contents are read from a reader while the
+    /// request is sent.
+    ///
+    /// Clones of the attachment share the reader, so only one of them can be
+    /// uploaded.
+    pub fn from_reader(
+        filename: impl Into<String>,
+        reader: impl AsyncRead + Send + 'static,
+    ) -> Self {
+        let reader: Reader = Box::pin(reader);
+
+        Self::new(
+            filename.into(),
+            AttachmentSource::Reader(Arc::new(Mutex::new(Some(reader)))),
+        )
+    }
+
+    fn new(filename: String, source: AttachmentSource) -> Self {
+        Self {
+            content_type: None,
+            description: None,
+            filename,
+            source,
+            spoiler: false,
+        }
+    }
+
+    /// Set the MIME type of the contents, such as `image/png`.
+    ///
+    /// Discord determines the type from the contents if this isn't set.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type.replace(content_type.into());
+
+        self
+    }
+
+    /// Set the description of the attachment, used as alt text.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description.replace(description.into());
+
+        self
+    }
+
+    /// Set whether to mark the attachment as a spoiler.
+    ///
+    /// Spoilers have their filename prefixed with [`SPOILER_PREFIX`].
+    ///
+    /// [`SPOILER_PREFIX`]: Self::SPOILER_PREFIX
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+
+        self
+    }
+
+    /// Name of the file that is uploaded, including the spoiler prefix.
+    pub fn filename(&self) -> String {
+        if self.spoiler && !self.filename.starts_with(Self::SPOILER_PREFIX) {
+            format!("{}{}", Self::SPOILER_PREFIX, self.filename)
+        } else {
+            self.filename.clone()
+        }
+    }
+
+    /// Add the attachment to a form as a file with the given field name.
+    pub(crate) fn add_to_form(&self, form: &mut Form, name: &[u8]) {
+        form.file(
+            name,
+            self.filename().as_bytes(),
+            self.content_type.as_deref().map(str::as_bytes),
+            self.source.clone(),
+        );
+    }
+}
+
+/// Attachment in the `attachments` field of a message's JSON payload.
+///
+/// New attachments are referenced by the index of their file in the form,
+/// while existing attachments are referenced by their ID.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct PartialAttachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filename: Option<String>,
+    id: u64,
+}
+
+impl PartialAttachment {
+    /// Reference an existing attachment of a message to keep it.
+    pub(crate) fn existing(id: AttachmentId) -> Self {
+        Self {
+            description: None,
+            filename: None,
+            id: id.0,
+        }
+    }
+}
+
+/// Create the entries of the payload's `attachments` field for new
+/// attachments, followed by the existing attachments to keep.
+pub(crate) fn partials(
+    attachments: &[Attachment],
+    existing: Option<&[PartialAttachment]>,
+) -> Vec<PartialAttachment> {
+    attachments
+        .iter()
+        .enumerate()
+        .map(|(index, attachment)| PartialAttachment {
+            description: attachment.description.clone(),
+            filename: Some(attachment.filename()),
+            id: index as u64,
+        })
+        .chain(existing.unwrap_or_default().iter().cloned())
+        .collect()
+}
+
+/// Create a multipart form containing the attachments and the JSON payload.
+pub(crate) fn form(attachments: &[Attachment], payload_json: &[u8]) -> Form {
+    let mut form = Form::new();
+
+    for (index, attachment) in attachments.iter().enumerate() {
+        attachment.add_to_form(&mut form, format!("files[{}]", index).as_bytes());
+    }
+
+    form.part(b"payload_json", payload_json);
+
+    form
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{partials, Attachment, PartialAttachment};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::AttachmentId;
+
+    assert_impl_all!(Attachment: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_spoiler_filename() {
+        let attachment = Attachment::from_bytes("cat.png", Vec::new());
+        assert_eq!("cat.png", attachment.filename());
+
+        let attachment = attachment.spoiler(true);
+        assert_eq!("SPOILER_cat.png", attachment.filename());
+
+        let attachment = Attachment::from_bytes("SPOILER_cat.png", Vec::new()).spoiler(true);
+        assert_eq!("SPOILER_cat.png", attachment.filename());
+    }
+
+    #[test]
+    fn test_partials() {
+        let attachments = [
+            Attachment::from_bytes("a.txt", "a").description("first"),
+            Attachment::from_bytes("b.txt", "b").spoiler(true),
+        ];
+        let existing = [PartialAttachment::existing(AttachmentId(5))];
+
+        let value = serde_json::to_value(partials(&attachments, Some(&existing))).unwrap();
+
+        assert_eq!(
+            serde_json::json!([
+                {"description": "first", "filename": "a.txt", "id": 0},
+                {"filename": "SPOILER_b.txt", "id": 1},
+                {"id": 5},
+            ]),
+            value,
+        );
+    }
+}
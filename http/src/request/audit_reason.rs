@@ -35,6 +35,14 @@ mod private {
     impl<'a> Sealed for CreateEmoji<'a> {}
     impl<'a> Sealed for DeleteEmoji<'a> {}
     impl<'a> Sealed for UpdateEmoji<'a> {}
+    impl<'a> Sealed for CreateAutoModerationRule<'a> {}
+    impl<'a> Sealed for CreateGuildScheduledEvent<'a> {}
+    impl<'a> Sealed for CreateGuildSticker<'a> {}
+    impl<'a> Sealed for DeleteGuildSticker<'a> {}
+    impl<'a> Sealed for DeleteAutoModerationRule<'a> {}
+    impl<'a> Sealed for UpdateAutoModerationRule<'a> {}
+    impl<'a> Sealed for UpdateGuildScheduledEvent<'a> {}
+    impl<'a> Sealed for UpdateGuildSticker<'a> {}
     impl<'a> Sealed for CreateGuildIntegration<'a> {}
     impl<'a> Sealed for DeleteGuildIntegration<'a> {}
     impl<'a> Sealed for UpdateGuildIntegration<'a> {}
@@ -111,6 +119,14 @@ mod test {
     assert_impl_all!(CreateEmoji<'_>: AuditLogReason);
     assert_impl_all!(DeleteEmoji<'_>: AuditLogReason);
     assert_impl_all!(UpdateEmoji<'_>: AuditLogReason);
+    assert_impl_all!(CreateAutoModerationRule<'_>: AuditLogReason);
+    assert_impl_all!(CreateGuildScheduledEvent<'_>: AuditLogReason);
+    assert_impl_all!(CreateGuildSticker<'_>: AuditLogReason);
+    assert_impl_all!(DeleteGuildSticker<'_>: AuditLogReason);
+    assert_impl_all!(DeleteAutoModerationRule<'_>: AuditLogReason);
+    assert_impl_all!(UpdateAutoModerationRule<'_>: AuditLogReason);
+    assert_impl_all!(UpdateGuildScheduledEvent<'_>: AuditLogReason);
+    assert_impl_all!(UpdateGuildSticker<'_>: AuditLogReason);
     assert_impl_all!(CreateGuildIntegration<'_>: AuditLogReason);
     assert_impl_all!(DeleteGuildIntegration<'_>: AuditLogReason);
     assert_impl_all!(UpdateGuildIntegration<'_>: AuditLogReason);
@@ -37,6 +37,28 @@ pub struct AllowedMentions {
     replied_user: bool,
 }
 
+impl AllowedMentions {
+    /// Explicitly allow mentions of users, unless mentions of all users are
+    /// already parsed.
+    ///
+    /// Discord rejects explicit IDs for types that are also parsed.
+    pub(crate) fn allow_users(&mut self, users: impl IntoIterator<Item = UserId>) {
+        if !self.parse.contains(&ParseTypes::Users) {
+            self.users.get_or_insert_with(Vec::new).extend(users);
+        }
+    }
+
+    /// Explicitly allow mentions of roles, unless mentions of all roles are
+    /// already parsed.
+    ///
+    /// Discord rejects explicit IDs for types that are also parsed.
+    pub(crate) fn allow_roles(&mut self, roles: impl IntoIterator<Item = RoleId>) {
+        if !self.parse.contains(&ParseTypes::Roles) {
+            self.roles.get_or_insert_with(Vec::new).extend(roles);
+        }
+    }
+}
+
 pub trait VisitAllowedMentionsEveryone: Sized {
     fn visit(self, _: &mut AllowedMentions) {}
 }
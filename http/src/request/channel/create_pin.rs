@@ -4,7 +4,7 @@ use twilight_model::id::{ChannelId, MessageId};
 /// Create a new pin in a channel.
 pub struct CreatePin<'a> {
     channel_id: ChannelId,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
     reason: Option<String>,
@@ -53,4 +53,4 @@ impl<'a> AuditLogReason for CreatePin<'a> {
     }
 }
 
-poll_req!(CreatePin<'_>, ());
+poll_req!(CreatePin<'_>, EmptyBody);
@@ -4,7 +4,7 @@ use twilight_model::id::ChannelId;
 /// Fire a Typing Start event in the channel.
 pub struct CreateTypingTrigger<'a> {
     channel_id: ChannelId,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
 }
 
@@ -28,4 +28,4 @@ impl<'a> CreateTypingTrigger<'a> {
     }
 }
 
-poll_req!(CreateTypingTrigger<'_>, ());
+poll_req!(CreateTypingTrigger<'_>, EmptyBody);
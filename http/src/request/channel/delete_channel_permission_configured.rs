@@ -6,7 +6,7 @@ use twilight_model::id::ChannelId;
 /// The `target_id` is a `u64`, but it should point to a `RoleId` or a `UserId`.
 pub struct DeleteChannelPermissionConfigured<'a> {
     channel_id: ChannelId,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     reason: Option<String>,
     target_id: u64,
@@ -55,4 +55,4 @@ impl<'a> AuditLogReason for DeleteChannelPermissionConfigured<'a> {
     }
 }
 
-poll_req!(DeleteChannelPermissionConfigured<'_>, ());
+poll_req!(DeleteChannelPermissionConfigured<'_>, EmptyBody);
@@ -4,7 +4,7 @@ use twilight_model::id::{ChannelId, MessageId};
 /// Delete a pin in a channel, by ID.
 pub struct DeletePin<'a> {
     channel_id: ChannelId,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
     reason: Option<String>,
@@ -53,4 +53,4 @@ impl<'a> AuditLogReason for DeletePin<'a> {
     }
 }
 
-poll_req!(DeletePin<'_>, ());
+poll_req!(DeletePin<'_>, EmptyBody);
@@ -22,7 +22,7 @@ use twilight_model::{channel::Channel, id::ChannelId};
 /// ```
 pub struct GetChannel<'a> {
     channel_id: ChannelId,
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, Channel>>,
     http: &'a Client,
 }
 
@@ -36,12 +36,11 @@ impl<'a> GetChannel<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetChannel {
-                    channel_id: self.channel_id.0,
-                },
-            ))));
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetChannel {
+                channel_id: self.channel_id.0,
+            },
+        ))));
 
         Ok(())
     }
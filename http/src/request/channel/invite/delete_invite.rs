@@ -3,7 +3,7 @@ use crate::request::prelude::*;
 /// Delete an invite by its code.
 pub struct DeleteInvite<'a> {
     code: String,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     reason: Option<String>,
 }
@@ -48,4 +48,4 @@ impl<'a> AuditLogReason for DeleteInvite<'a> {
     }
 }
 
-poll_req!(DeleteInvite<'_>, ());
+poll_req!(DeleteInvite<'_>, EmptyBody);
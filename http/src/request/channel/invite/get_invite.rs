@@ -30,7 +30,7 @@ struct GetInviteFields {
 pub struct GetInvite<'a> {
     code: String,
     fields: GetInviteFields,
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, Invite>>,
     http: &'a Client,
 }
 
@@ -52,13 +52,12 @@ impl<'a> GetInvite<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetInvite {
-                    code: self.code.clone(),
-                    with_counts: self.fields.with_counts,
-                },
-            ))));
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetInvite {
+                code: self.code.clone(),
+                with_counts: self.fields.with_counts,
+            },
+        ))));
 
         Ok(())
     }
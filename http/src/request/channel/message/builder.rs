@@ -0,0 +1,432 @@
+//! Create messages to send with [`CreateMessage`] or [`ExecuteWebhook`].
+//!
+//! [`CreateMessage`]: super::CreateMessage
+//! [`ExecuteWebhook`]: crate::request::channel::webhook::ExecuteWebhook
+
+use super::super::allowed_mentions::AllowedMentions;
+use crate::request::validate::{self, EmbedValidationError};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_mention::Mention;
+use twilight_model::{
+    channel::{embed::Embed, message::MessageFlags},
+    id::{MessageId, RoleId, UserId},
+};
+
+/// Error building a message.
+///
+/// This is returned from [`MessageBuilder::build`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum MessageBuildError {
+    /// Content is over 2000 UTF-16 characters.
+    ContentInvalid {
+        /// Provided content.
+        content: String,
+    },
+    /// One of the embeds is too large.
+    EmbedTooLarge {
+        /// Provided embed.
+        embed: Box<Embed>,
+        /// The source of the error.
+        source: EmbedValidationError,
+    },
+    /// More than 10 embeds were provided.
+    TooManyEmbeds {
+        /// Provided embeds.
+        embeds: Vec<Embed>,
+    },
+}
+
+impl Display for MessageBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ContentInvalid { .. } => f.write_str("the message content is invalid"),
+            Self::EmbedTooLarge { .. } => f.write_str("the embed's contents are too long"),
+            Self::TooManyEmbeds { .. } => f.write_str("more than 10 embeds were provided"),
+        }
+    }
+}
+
+impl Error for MessageBuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ContentInvalid { .. } | Self::TooManyEmbeds { .. } => None,
+            Self::EmbedTooLarge { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Validated message built by a [`MessageBuilder`].
+///
+/// Pass it to [`CreateMessage::message`] or [`ExecuteWebhook::message`] to
+/// send it.
+///
+/// [`CreateMessage::message`]: super::CreateMessage::message
+/// [`ExecuteWebhook::message`]: crate::request::channel::webhook::ExecuteWebhook::message
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BuiltMessage {
+    pub(crate) allowed_mentions: Option<AllowedMentions>,
+    pub(crate) content: Option<String>,
+    pub(crate) embeds: Vec<Embed>,
+    pub(crate) fail_if_not_exists: Option<bool>,
+    pub(crate) flags: Option<MessageFlags>,
+    pub(crate) nonce: Option<u64>,
+    pub(crate) reply: Option<MessageId>,
+    pub(crate) tts: Option<bool>,
+}
+
+/// Create a message with a builder.
+///
+/// Mentions added via [`mention_user`] and [`mention_role`] are formatted
+/// into the content and explicitly allowed, so that only those mentions
+/// notify their targets unless other mentions are allowed via
+/// [`allowed_mentions`].
+///
+/// # Examples
+///
+/// Reply to a message, mentioning its author:
+///
+/// ```rust,no_run
+/// use twilight_embed_builder::EmbedBuilder;
+/// use twilight_http::{request::channel::message::MessageBuilder, Client};
+/// use twilight_model::id::{ChannelId, MessageId, UserId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let message = MessageBuilder::new()
+///     .content("hey ")
+///     .mention_user(UserId(1))
+///     .content_push(", here are the results:")
+///     .embed(EmbedBuilder::new().description("all tests pass")?.build()?)
+///     .reply(MessageId(2))
+///     .fail_if_not_exists(false)
+///     .build()?;
+///
+/// client.create_message(ChannelId(3)).message(message).await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`allowed_mentions`]: Self::allowed_mentions
+/// [`mention_role`]: Self::mention_role
+/// [`mention_user`]: Self::mention_user
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "must be built into a message"]
+pub struct MessageBuilder {
+    message: BuiltMessage,
+    roles: Vec<RoleId>,
+    users: Vec<UserId>,
+}
+
+impl MessageBuilder {
+    /// The maximum number of UTF-16 code points that can be in the content.
+    pub const CONTENT_LENGTH_LIMIT: usize = 2000;
+
+    /// The maximum number of embeds that can be in a message.
+    pub const EMBED_COUNT_LIMIT: usize = 10;
+
+    /// Create a new default message builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build this into a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MessageBuildError::ContentInvalid`] if the content is longer
+    /// than [`CONTENT_LENGTH_LIMIT`].
+    ///
+    /// Returns [`MessageBuildError::EmbedTooLarge`] if one of the embeds is
+    /// too large.
+    ///
+    /// Returns [`MessageBuildError::TooManyEmbeds`] if there are more embeds
+    /// than [`EMBED_COUNT_LIMIT`].
+    ///
+    /// [`CONTENT_LENGTH_LIMIT`]: Self::CONTENT_LENGTH_LIMIT
+    /// [`EMBED_COUNT_LIMIT`]: Self::EMBED_COUNT_LIMIT
+    pub fn build(self) -> Result<BuiltMessage, MessageBuildError> {
+        let mut message = self.message;
+
+        if let Some(content) = message.content.take() {
+            if !validate::content_limit(&content) {
+                return Err(MessageBuildError::ContentInvalid { content });
+            }
+
+            message.content.replace(content);
+        }
+
+        if message.embeds.len() > Self::EMBED_COUNT_LIMIT {
+            return Err(MessageBuildError::TooManyEmbeds {
+                embeds: message.embeds,
+            });
+        }
+
+        if let Some(idx) = message
+            .embeds
+            .iter()
+            .position(|embed| validate::embed(embed).is_err())
+        {
+            let embed = message.embeds.swap_remove(idx);
+            // The embed was just found to be invalid.
+            let source = validate::embed(&embed).unwrap_err();
+
+            return Err(MessageBuildError::EmbedTooLarge {
+                embed: Box::new(embed),
+                source,
+            });
+        }
+
+        if !self.users.is_empty() || !self.roles.is_empty() {
+            let allowed = message
+                .allowed_mentions
+                .get_or_insert_with(AllowedMentions::default);
+
+            if !self.users.is_empty() {
+                allowed.allow_users(self.users);
+            }
+
+            if !self.roles.is_empty() {
+                allowed.allow_roles(self.roles);
+            }
+        }
+
+        Ok(message)
+    }
+
+    /// Set the allowed mentions of the message.
+    ///
+    /// Use [`AllowedMentionsBuilder::build_solo`] to create them. Mentions
+    /// added via [`mention_user`] and [`mention_role`] are added to them.
+    ///
+    /// If not set, the client's default allowed mentions are used unless
+    /// mentions are added via the builder.
+    ///
+    /// [`AllowedMentionsBuilder::build_solo`]: super::super::allowed_mentions::AllowedMentionsBuilder::build_solo
+    /// [`mention_role`]: Self::mention_role
+    /// [`mention_user`]: Self::mention_user
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.message.allowed_mentions.replace(allowed_mentions);
+
+        self
+    }
+
+    /// Set the content of the message, replacing any previous content.
+    ///
+    /// The maximum length is 2000 UTF-16 characters, which is checked when
+    /// the message is built.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.message.content.replace(content.into());
+
+        self
+    }
+
+    /// Append to the content of the message.
+    pub fn content_push(mut self, content: impl AsRef<str>) -> Self {
+        self.message
+            .content
+            .get_or_insert_with(String::new)
+            .push_str(content.as_ref());
+
+        self
+    }
+
+    /// Add an embed to the message.
+    ///
+    /// At most 10 embeds can be added, which is checked when the message is
+    /// built.
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.message.embeds.push(embed);
+
+        self
+    }
+
+    /// Whether to fail sending the message if the message it replies to
+    /// doesn't exist.
+    ///
+    /// Defaults to true. Only used if the message is a [`reply`].
+    ///
+    /// [`reply`]: Self::reply
+    pub fn fail_if_not_exists(mut self, fail_if_not_exists: bool) -> Self {
+        self.message.fail_if_not_exists.replace(fail_if_not_exists);
+
+        self
+    }
+
+    /// Set the flags of the message.
+    ///
+    /// Only [`MessageFlags::SUPPRESS_EMBEDS`] can be set.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.message.flags.replace(flags);
+
+        self
+    }
+
+    /// Append a mention of a role to the content and allow it to notify the
+    /// role's members.
+    pub fn mention_role(mut self, role_id: RoleId) -> Self {
+        self = self.content_push(role_id.mention().to_string());
+
+        if !self.roles.contains(&role_id) {
+            self.roles.push(role_id);
+        }
+
+        self
+    }
+
+    /// Append a mention of a user to the content and allow it to notify the
+    /// user.
+    pub fn mention_user(mut self, user_id: UserId) -> Self {
+        self = self.content_push(user_id.mention().to_string());
+
+        if !self.users.contains(&user_id) {
+            self.users.push(user_id);
+        }
+
+        self
+    }
+
+    /// Attach a nonce to the message, for optimistic message sending.
+    ///
+    /// Not used by webhooks.
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.message.nonce.replace(nonce);
+
+        self
+    }
+
+    /// Reply to another message in the same channel.
+    ///
+    /// Not used by webhooks, which can't reply to messages.
+    pub fn reply(mut self, message_id: MessageId) -> Self {
+        self.message.reply.replace(message_id);
+
+        self
+    }
+
+    /// Specify true if the message is TTS.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.message.tts.replace(tts);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuiltMessage, MessageBuildError, MessageBuilder};
+    use crate::request::channel::allowed_mentions::AllowedMentionsBuilder;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        channel::embed::Embed,
+        id::{MessageId, RoleId, UserId},
+    };
+
+    assert_impl_all!(BuiltMessage: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MessageBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MessageBuildError: Clone, Debug, Error, Send, Sync);
+    assert_fields!(MessageBuildError::ContentInvalid: content);
+    assert_fields!(MessageBuildError::EmbedTooLarge: embed, source);
+    assert_fields!(MessageBuildError::TooManyEmbeds: embeds);
+
+    fn embed() -> Embed {
+        Embed {
+            author: None,
+            color: None,
+            description: Some("description".to_owned()),
+            fields: Vec::new(),
+            footer: None,
+            image: None,
+            kind: "rich".to_owned(),
+            provider: None,
+            thumbnail: None,
+            timestamp: None,
+            title: None,
+            url: None,
+            video: None,
+        }
+    }
+
+    #[test]
+    fn test_mentions() {
+        let message = MessageBuilder::new()
+            .content("hey ")
+            .mention_user(UserId(1))
+            .content_push(" and ")
+            .mention_role(RoleId(2))
+            .mention_user(UserId(1))
+            .build()
+            .unwrap();
+
+        assert_eq!(Some("hey <@1> and <@&2><@1>"), message.content.as_deref());
+        let allowed = AllowedMentionsBuilder::new()
+            .parse_specific_users(vec![UserId(1)])
+            .parse_specific_roles(vec![RoleId(2)])
+            .build_solo();
+        assert_eq!(Some(allowed), message.allowed_mentions);
+    }
+
+    #[test]
+    fn test_mentions_parsed() {
+        let message = MessageBuilder::new()
+            .allowed_mentions(AllowedMentionsBuilder::new().parse_users().build_solo())
+            .mention_user(UserId(1))
+            .mention_role(RoleId(2))
+            .build()
+            .unwrap();
+
+        let allowed = AllowedMentionsBuilder::new()
+            .parse_users()
+            .parse_specific_roles(vec![RoleId(2)])
+            .build_solo();
+        assert_eq!(Some(allowed), message.allowed_mentions);
+    }
+
+    #[test]
+    fn test_reply() {
+        let message = MessageBuilder::new()
+            .fail_if_not_exists(false)
+            .reply(MessageId(1))
+            .build()
+            .unwrap();
+
+        assert_eq!(Some(MessageId(1)), message.reply);
+        assert_eq!(Some(false), message.fail_if_not_exists);
+        assert!(message.allowed_mentions.is_none());
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(matches!(
+            MessageBuilder::new().content("a".repeat(2001)).build(),
+            Err(MessageBuildError::ContentInvalid { content }) if content.len() == 2001
+        ));
+
+        let too_large = Embed {
+            description: Some("a".repeat(6001)),
+            ..embed()
+        };
+        assert!(matches!(
+            MessageBuilder::new()
+                .embed(embed())
+                .embed(too_large)
+                .build(),
+            Err(MessageBuildError::EmbedTooLarge { .. })
+        ));
+
+        let mut builder = MessageBuilder::new();
+        for _ in 0..MessageBuilder::EMBED_COUNT_LIMIT {
+            builder = builder.embed(embed());
+        }
+        assert!(builder.clone().build().is_ok());
+        assert!(matches!(
+            builder.embed(embed()).build(),
+            Err(MessageBuildError::TooManyEmbeds { embeds }) if embeds.len() == 11
+        ));
+    }
+}
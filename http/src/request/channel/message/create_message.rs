@@ -1,12 +1,22 @@
-use super::super::allowed_mentions::{AllowedMentions, AllowedMentionsBuilder, Unspecified};
-use crate::request::{multipart::Form, prelude::*};
+use super::{
+    super::allowed_mentions::{AllowedMentions, AllowedMentionsBuilder, Unspecified},
+    BuiltMessage,
+};
+use crate::request::{
+    attachment::{self, PartialAttachment},
+    prelude::*,
+    Attachment,
+};
 use std::{
-    collections::HashMap,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::{embed::Embed, message::MessageReference, Message},
+    channel::{
+        embed::Embed,
+        message::{sticker::StickerId, MessageFlags},
+        Message,
+    },
     id::{ChannelId, MessageId},
 };
 
@@ -26,6 +36,11 @@ pub enum CreateMessageError {
         /// The source of the error.
         source: EmbedValidationError,
     },
+    /// Returned when more than 3 stickers are provided.
+    StickersInvalid {
+        /// Provided sticker IDs.
+        sticker_ids: Vec<StickerId>,
+    },
 }
 
 impl Display for CreateMessageError {
@@ -33,6 +48,7 @@ impl Display for CreateMessageError {
         match self {
             Self::ContentInvalid { .. } => f.write_str("the message content is invalid"),
             Self::EmbedTooLarge { .. } => f.write_str("the embed's contents are too long"),
+            Self::StickersInvalid { .. } => f.write_str("more than 3 stickers were provided"),
         }
     }
 }
@@ -40,20 +56,35 @@ impl Display for CreateMessageError {
 impl Error for CreateMessageError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::ContentInvalid { .. } => None,
+            Self::ContentInvalid { .. } | Self::StickersInvalid { .. } => None,
             Self::EmbedTooLarge { source, .. } => Some(source),
         }
     }
 }
 
+/// Reference to the message that a message replies to.
+#[derive(Serialize)]
+struct CreateMessageReference {
+    channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fail_if_not_exists: Option<bool>,
+    message_id: MessageId,
+}
+
 #[derive(Default, Serialize)]
 pub(crate) struct CreateMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<PartialAttachment>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embed: Option<Embed>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    message_reference: Option<MessageReference>,
+    embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<MessageFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_reference: Option<CreateMessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
     nonce: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,6 +92,8 @@ pub(crate) struct CreateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_mentions: Option<AllowedMentions>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    sticker_ids: Option<Vec<StickerId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
 }
 
@@ -85,7 +118,7 @@ pub(crate) struct CreateMessageFields {
 /// # Ok(()) }
 /// ```
 pub struct CreateMessage<'a> {
-    attachments: HashMap<String, Vec<u8>>,
+    attachments: Vec<Attachment>,
     channel_id: ChannelId,
     pub(crate) fields: CreateMessageFields,
     fut: Option<Pending<'a, Message>>,
@@ -95,7 +128,7 @@ pub struct CreateMessage<'a> {
 impl<'a> CreateMessage<'a> {
     pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
         Self {
-            attachments: HashMap::new(),
+            attachments: Vec::new(),
             channel_id,
             fields: CreateMessageFields {
                 allowed_mentions: http.default_allowed_mentions(),
@@ -113,23 +146,18 @@ impl<'a> CreateMessage<'a> {
         AllowedMentionsBuilder::for_builder(self)
     }
 
-    /// Attach a new file to the message.
+    /// Attach a file to the message.
     ///
-    /// The file is raw binary data. It can be an image, or any other kind of file.
-    pub fn attachment(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.attachments.insert(name.into(), file.into());
+    /// Refer to [`Attachment`] for more information.
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
 
         self
     }
 
-    /// Insert multiple attachments into the message.
-    pub fn attachments<N: Into<String>, F: Into<Vec<u8>>>(
-        mut self,
-        attachments: impl IntoIterator<Item = (N, F)>,
-    ) -> Self {
-        for (name, file) in attachments {
-            self = self.attachment(name, file);
-        }
+    /// Attach multiple files to the message.
+    pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
+        self.attachments.extend(attachments);
 
         self
     }
@@ -184,6 +212,55 @@ impl<'a> CreateMessage<'a> {
         Ok(self)
     }
 
+    /// Set the content, embeds, allowed mentions, reply, and other fields of
+    /// the message from a message built by a [`MessageBuilder`].
+    ///
+    /// Fields not set in the built message are left unchanged. Embeds replace
+    /// an embed set via [`embed`].
+    ///
+    /// Refer to [`MessageBuilder`] for an example.
+    ///
+    /// [`MessageBuilder`]: super::MessageBuilder
+    /// [`embed`]: Self::embed
+    pub fn message(mut self, message: BuiltMessage) -> Self {
+        if let Some(allowed_mentions) = message.allowed_mentions {
+            self.fields.allowed_mentions.replace(allowed_mentions);
+        }
+
+        if let Some(content) = message.content {
+            self.fields.content.replace(content);
+        }
+
+        if !message.embeds.is_empty() {
+            self.fields.embed.take();
+            self.fields.embeds.replace(message.embeds);
+        }
+
+        if let Some(flags) = message.flags {
+            self.fields.flags.replace(flags);
+        }
+
+        if let Some(nonce) = message.nonce {
+            self.fields.nonce.replace(nonce);
+        }
+
+        if let Some(message_id) = message.reply {
+            self.fields
+                .message_reference
+                .replace(CreateMessageReference {
+                    channel_id: self.channel_id,
+                    fail_if_not_exists: message.fail_if_not_exists,
+                    message_id,
+                });
+        }
+
+        if let Some(tts) = message.tts {
+            self.fields.tts.replace(tts);
+        }
+
+        self
+    }
+
     /// Attach a nonce to the message, for optimistic message sending.
     pub fn nonce(mut self, nonce: u64) -> Self {
         self.fields.nonce.replace(nonce);
@@ -202,18 +279,46 @@ impl<'a> CreateMessage<'a> {
 
     /// Specify the ID of another message to create a reply to.
     pub fn reply(mut self, other: MessageId) -> Self {
-        self.fields.message_reference.replace(MessageReference {
-            // This struct only needs the message_id, but as we also have
-            // access to the channel_id we send that, as it will be verified
-            // by Discord.
-            channel_id: Some(self.channel_id),
-            guild_id: None,
-            message_id: Some(other),
-        });
+        self.fields
+            .message_reference
+            .replace(CreateMessageReference {
+                // This struct only needs the message_id, but as we also have
+                // access to the channel_id we send that, as it will be verified
+                // by Discord.
+                channel_id: self.channel_id,
+                fail_if_not_exists: None,
+                message_id: other,
+            });
 
         self
     }
 
+    /// Set the stickers to send in the message.
+    ///
+    /// At most 3 stickers can be sent. Guild stickers can only be sent in
+    /// the guild they belong to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateMessageError::StickersInvalid`] if more than 3 stickers
+    /// are provided.
+    pub fn sticker_ids(
+        self,
+        sticker_ids: impl Into<Vec<StickerId>>,
+    ) -> Result<Self, CreateMessageError> {
+        self._sticker_ids(sticker_ids.into())
+    }
+
+    fn _sticker_ids(mut self, sticker_ids: Vec<StickerId>) -> Result<Self, CreateMessageError> {
+        if !validate::sticker_ids(&sticker_ids) {
+            return Err(CreateMessageError::StickersInvalid { sticker_ids });
+        }
+
+        self.fields.sticker_ids.replace(sticker_ids);
+
+        Ok(self)
+    }
+
     /// Specify true if the message is TTS.
     pub fn tts(mut self, tts: bool) -> Self {
         self.fields.tts.replace(tts);
@@ -222,32 +327,22 @@ impl<'a> CreateMessage<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut.replace(Box::pin(self.http.request(
-            if self.attachments.is_empty() {
-                Request::from((
-                    crate::json_to_vec(&self.fields)?,
-                    Route::CreateMessage {
-                        channel_id: self.channel_id.0,
-                    },
-                ))
-            } else {
-                let mut multipart = Form::new();
-
-                for (index, (name, file)) in self.attachments.drain().enumerate() {
-                    multipart.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
-                }
-
-                let body = crate::json_to_vec(&self.fields)?;
-                multipart.part(b"payload_json", &body);
-
-                Request::from((
-                    multipart,
-                    Route::CreateMessage {
-                        channel_id: self.channel_id.0,
-                    },
-                ))
-            },
-        )));
+        let route = Route::CreateMessage {
+            channel_id: self.channel_id.0,
+        };
+
+        let request = if self.attachments.is_empty() {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        } else {
+            self.fields
+                .attachments
+                .replace(attachment::partials(&self.attachments, None));
+            let payload_json = crate::json_to_vec(&self.fields)?;
+
+            Request::from((attachment::form(&self.attachments, &payload_json), route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
@@ -4,7 +4,7 @@ use twilight_model::id::{ChannelId, MessageId};
 /// Delete a message by [`ChannelId`] and [`MessageId`].
 pub struct DeleteMessage<'a> {
     channel_id: ChannelId,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
     reason: Option<String>,
@@ -53,4 +53,4 @@ impl<'a> AuditLogReason for DeleteMessage<'a> {
     }
 }
 
-poll_req!(DeleteMessage<'_>, ());
+poll_req!(DeleteMessage<'_>, EmptyBody);
@@ -16,7 +16,7 @@ struct DeleteMessagesFields {
 pub struct DeleteMessages<'a> {
     channel_id: ChannelId,
     fields: DeleteMessagesFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     reason: Option<String>,
 }
@@ -72,4 +72,4 @@ impl<'a> AuditLogReason for DeleteMessages<'a> {
     }
 }
 
-poll_req!(DeleteMessages<'_>, ());
+poll_req!(DeleteMessages<'_>, EmptyBody);
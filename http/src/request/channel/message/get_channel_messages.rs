@@ -1,5 +1,5 @@
 use super::GetChannelMessagesConfigured;
-use crate::request::prelude::*;
+use crate::request::{prelude::*, Paginate};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -140,6 +140,64 @@ impl<'a> GetChannelMessages<'a> {
         Ok(self)
     }
 
+    /// Paginate through all messages of the channel, from newest to oldest.
+    ///
+    /// Pages of the [`limit`] set on the request are requested, or 100
+    /// messages if no limit was set. To start from a message, set it with
+    /// [`before`] or [`after`] and paginate the configured request.
+    ///
+    /// # Examples
+    ///
+    /// Get the 500 most recent messages of channel `123`:
+    ///
+    /// ```rust,no_run
+    /// use futures_util::stream::TryStreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::ChannelId;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let messages = client
+    ///     .channel_messages(ChannelId(123))
+    ///     .paginate()
+    ///     .total(500)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginate<'a, Message> {
+        let channel_id = self.channel_id;
+        let http = self.http;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_CHANNEL_MESSAGES_LIMIT_MAX);
+
+        Paginate::new(
+            None,
+            page_size,
+            |message| message.id.0,
+            move |before, limit| {
+                let request = GetChannelMessagesConfigured::new(
+                    http,
+                    channel_id,
+                    None,
+                    None,
+                    before.map(MessageId),
+                    Some(limit),
+                );
+
+                Box::pin(async move { request.await?.model() })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetMessages {
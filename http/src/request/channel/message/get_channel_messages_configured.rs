@@ -1,4 +1,4 @@
-use crate::request::prelude::*;
+use crate::request::{prelude::*, Paginate};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -17,12 +17,16 @@ pub enum GetChannelMessagesConfiguredError {
         /// Provided maximum number of messages to retrieve.
         limit: u64,
     },
+    /// Messages around a message were paginated, which can't be continued
+    /// in a single direction.
+    PaginateAround,
 }
 
 impl Display for GetChannelMessagesConfiguredError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+            Self::PaginateAround => f.write_str("messages around a message can not be paginated"),
         }
     }
 }
@@ -88,6 +92,83 @@ impl<'a> GetChannelMessagesConfigured<'a> {
         Ok(self)
     }
 
+    /// Paginate through the messages of the channel, starting from the
+    /// configured message.
+    ///
+    /// Messages before the message are paginated from newest to oldest, and
+    /// messages after it page by page towards the newest message. Pages of
+    /// the [`limit`] set on the request are requested, or 100 messages if no
+    /// limit was set.
+    ///
+    /// # Examples
+    ///
+    /// Get the 500 messages of channel `123` sent before message `456`:
+    ///
+    /// ```rust,no_run
+    /// use futures_util::stream::TryStreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::{ChannelId, MessageId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let messages = client
+    ///     .channel_messages(ChannelId(123))
+    ///     .before(MessageId(456))
+    ///     .paginate()?
+    ///     .total(500)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetChannelMessagesConfiguredError::PaginateAround`] if the
+    /// request gets messages around a message.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Result<Paginate<'a, Message>, GetChannelMessagesConfiguredError> {
+        if self.around.is_some() {
+            return Err(GetChannelMessagesConfiguredError::PaginateAround);
+        }
+
+        let after = self.after.is_some();
+        let channel_id = self.channel_id;
+        let http = self.http;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_CHANNEL_MESSAGES_LIMIT_MAX);
+
+        let cursor = self.after.or(self.before).map(|id| id.0);
+
+        let paginate = Paginate::<Message>::new(
+            cursor,
+            page_size,
+            |message| message.id.0,
+            move |cursor, limit| {
+                let cursor = cursor.map(MessageId);
+                let (after, before) = if after {
+                    (cursor, None)
+                } else {
+                    (None, cursor)
+                };
+
+                let request = Self::new(http, channel_id, after, None, before, Some(limit));
+
+                Box::pin(async move { request.await?.model() })
+            },
+        );
+
+        Ok(if after {
+            paginate.ascending()
+        } else {
+            paginate
+        })
+    }
+
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetMessages {
@@ -1,3 +1,4 @@
+pub mod builder;
 pub mod create_message;
 pub mod crosspost_message;
 pub mod get_channel_messages;
@@ -7,12 +8,18 @@ pub mod update_message;
 mod delete_message;
 mod delete_messages;
 mod get_message;
+mod splitter;
 
 pub use self::{
-    create_message::CreateMessage, crosspost_message::CrosspostMessage,
-    delete_message::DeleteMessage, delete_messages::DeleteMessages,
+    builder::{BuiltMessage, MessageBuilder},
+    create_message::CreateMessage,
+    crosspost_message::CrosspostMessage,
+    delete_message::DeleteMessage,
+    delete_messages::DeleteMessages,
     get_channel_messages::GetChannelMessages,
-    get_channel_messages_configured::GetChannelMessagesConfigured, get_message::GetMessage,
+    get_channel_messages_configured::GetChannelMessagesConfigured,
+    get_message::GetMessage,
+    splitter::MessageSplitter,
     update_message::UpdateMessage,
 };
 pub use super::super::validate::EmbedValidationError;
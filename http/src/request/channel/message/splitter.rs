@@ -0,0 +1,362 @@
+//! Split long content into multiple messages.
+
+use super::{BuiltMessage, MessageBuilder};
+use crate::{client::Client, error::Result};
+use std::iter::FusedIterator;
+use twilight_mention::parse::{MentionType, ParseMention};
+use twilight_model::{channel::Message, id::ChannelId};
+
+/// Marker opening and closing code blocks.
+const FENCE: &str = "```";
+
+/// Split content into chunks that each fit in a message.
+///
+/// Chunks are at most [`MessageBuilder::CONTENT_LENGTH_LIMIT`] characters
+/// long. Content is preferably split at newlines, then at other whitespace,
+/// and never inside of mentions. The separating newline or whitespace is not
+/// included in either chunk.
+///
+/// Code blocks split across chunks are closed at the end of a chunk and
+/// reopened with the same language at the start of the next one.
+///
+/// # Examples
+///
+/// Split the output of a command and send it to a channel:
+///
+/// ```rust,no_run
+/// use twilight_http::{request::channel::message::MessageSplitter, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let output = "a very long log\n".repeat(500);
+/// let messages = MessageSplitter::new(&output)
+///     .send(&client, ChannelId(1))
+///     .await?;
+///
+/// println!("sent {} messages", messages.len());
+/// # Ok(()) }
+/// ```
+///
+/// Iterate over the chunks to send them in another way:
+///
+/// ```rust
+/// use twilight_http::request::channel::message::MessageSplitter;
+///
+/// let content = format!("```rust\n{}```", "let x = 1;\n".repeat(300));
+///
+/// for chunk in MessageSplitter::new(&content) {
+///     assert!(chunk.chars().count() <= 2000);
+///     assert!(chunk.starts_with("```rust\n"));
+///     assert!(chunk.ends_with("```"));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MessageSplitter<'a> {
+    /// Language of the code block open at the end of the last chunk, if any.
+    fence: Option<&'a str>,
+    limit: usize,
+    remaining: &'a str,
+}
+
+impl<'a> MessageSplitter<'a> {
+    /// Create a new splitter for some content.
+    pub const fn new(content: &'a str) -> Self {
+        Self::with_limit(content, MessageBuilder::CONTENT_LENGTH_LIMIT)
+    }
+
+    const fn with_limit(content: &'a str, limit: usize) -> Self {
+        Self {
+            fence: None,
+            limit,
+            remaining: content,
+        }
+    }
+
+    /// Send each chunk as a message to a channel, in order.
+    ///
+    /// The messages use the client's default allowed mentions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending a message fails. Messages sent before the
+    /// failure are not deleted.
+    pub async fn send(self, http: &Client, channel_id: ChannelId) -> Result<Vec<Message>> {
+        let mut messages = Vec::new();
+
+        for content in self {
+            // Chunks are already within the content length limit.
+            let message = BuiltMessage {
+                content: Some(content),
+                ..BuiltMessage::default()
+            };
+            let response = http.create_message(channel_id).message(message).await?;
+
+            messages.push(response.model()?);
+        }
+
+        Ok(messages)
+    }
+}
+
+impl Iterator for MessageSplitter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining.trim().is_empty() {
+                self.remaining = "";
+
+                return None;
+            }
+
+            let mut chunk = String::new();
+
+            if let Some(language) = self.fence {
+                chunk.push_str(FENCE);
+                chunk.push_str(language);
+                chunk.push('\n');
+            }
+
+            let prefix_len = chunk.chars().count();
+
+            if prefix_len + self.remaining.chars().count() <= self.limit {
+                chunk.push_str(self.remaining);
+                self.remaining = "";
+
+                return Some(chunk);
+            }
+
+            let budget = self.limit.saturating_sub(prefix_len).max(1);
+            let (mut text, mut rest) = split(self.remaining, budget);
+            let mut open = fence(self.fence, text);
+
+            // Leave room to close a code block left open by the chunk.
+            if open.is_some() && text.chars().count() + FENCE.len() + 1 > budget {
+                let budget = budget.saturating_sub(FENCE.len() + 1).max(1);
+                let (shorter, shorter_rest) = split(self.remaining, budget);
+                text = shorter;
+                rest = shorter_rest;
+                open = fence(self.fence, text);
+            }
+
+            self.fence = open;
+            self.remaining = rest;
+
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            chunk.push_str(text);
+
+            if self.fence.is_some() {
+                chunk.push('\n');
+                chunk.push_str(FENCE);
+            }
+
+            return Some(chunk);
+        }
+    }
+}
+
+impl FusedIterator for MessageSplitter<'_> {}
+
+/// Split content into a head of at most `budget` characters and the rest,
+/// dropping the separating newline or whitespace.
+fn split(content: &str, budget: usize) -> (&str, &str) {
+    let max = content
+        .char_indices()
+        .nth(budget)
+        .map_or(content.len(), |(idx, _)| idx);
+    let head = &content[..max];
+
+    let (mut end, mut separator_len) = if let Some(idx) = head.rfind('\n').filter(|idx| *idx > 0) {
+        (idx, 1)
+    } else if let Some((idx, c)) = head
+        .char_indices()
+        .rev()
+        .find(|(idx, c)| *idx > 0 && c.is_whitespace())
+    {
+        (idx, c.len_utf8())
+    } else {
+        (max, 0)
+    };
+
+    // Keep the opening line of a code block in one chunk, so that its
+    // language isn't split off.
+    if let Some(idx) = content
+        .match_indices(FENCE)
+        .map(|(idx, _)| idx)
+        .take_while(|idx| *idx < end)
+        .last()
+    {
+        let marker_end = idx + FENCE.len();
+        let line_end = marker_end + language(&content[marker_end..]).len();
+
+        if idx > 0 && (end < marker_end || (line_end > marker_end && end <= line_end)) {
+            end = idx;
+            separator_len = 0;
+        }
+    }
+
+    // Mention indexes are inclusive of the closing `>`.
+    for (_, start, mention_end) in MentionType::iter(content) {
+        if start >= end {
+            break;
+        }
+
+        if end <= mention_end {
+            // A mention at the very start can't be moved to the next chunk,
+            // so it is cut if it doesn't fit.
+            end = if start > 0 {
+                start
+            } else {
+                max.min(mention_end + 1)
+            };
+            separator_len = 0;
+
+            break;
+        }
+    }
+
+    (&content[..end], &content[end + separator_len..])
+}
+
+/// Language of the code block open at the end of some text, given the
+/// language of the code block open at its start.
+///
+/// Code blocks without a language have an empty one.
+fn fence<'a>(mut open: Option<&'a str>, text: &'a str) -> Option<&'a str> {
+    let mut rest = text;
+
+    while let Some(idx) = rest.find(FENCE) {
+        rest = &rest[idx + FENCE.len()..];
+
+        open = if open.is_some() {
+            None
+        } else {
+            // The language is only used if it is on its own line, which may
+            // end with the text.
+            let language = language(rest);
+            let after = &rest[language.len()..];
+
+            if after.is_empty() || after.starts_with('\n') {
+                Some(language)
+            } else {
+                Some("")
+            }
+        };
+    }
+
+    open
+}
+
+/// Language at the start of the text following a code block's opening marker.
+fn language(text: &str) -> &str {
+    let len = text
+        .find(|c: char| !(c.is_alphanumeric() || "+-#_.".contains(c)))
+        .unwrap_or(text.len());
+
+    &text[..len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageSplitter;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(MessageSplitter<'_>: Clone, Debug, Iterator, Send, Sync);
+
+    fn split(content: &str, limit: usize) -> Vec<String> {
+        MessageSplitter::with_limit(content, limit).collect()
+    }
+
+    #[test]
+    fn test_short() {
+        assert_eq!(vec!["hello"], split("hello", 2000));
+        assert!(split("", 2000).is_empty());
+        assert!(split(" \n ", 2000).is_empty());
+    }
+
+    #[test]
+    fn test_limit() {
+        let content = "a".repeat(4500);
+        let chunks = MessageSplitter::new(&content).collect::<Vec<_>>();
+        assert_eq!(3, chunks.len());
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 2000));
+        assert_eq!(content, chunks.concat());
+
+        // Characters are counted, not bytes.
+        let content = "é".repeat(2000);
+        assert_eq!(vec![content.clone()], split(&content, 2000));
+    }
+
+    #[test]
+    fn test_boundaries() {
+        assert_eq!(
+            vec!["one", "two three", "four"],
+            split("one\ntwo three four", 12),
+        );
+        assert_eq!(
+            vec!["one two", "three four", "five"],
+            split("one two three four five", 12),
+        );
+        assert_eq!(vec!["aaaaa", "aaaaa", "a"], split("aaaaaaaaaaa", 5));
+    }
+
+    #[test]
+    fn test_mentions() {
+        assert_eq!(vec!["hey", "<@123456>"], split("hey <@123456>", 10));
+        assert_eq!(vec!["hey", "<@&123456>!"], split("hey<@&123456>!", 12));
+
+        // Mentions longer than a chunk are cut.
+        let chunks = split("<@123456>a", 5);
+        assert_eq!(vec!["<@123", "456>a"], chunks);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 5));
+
+        let chunks = split("<@123456> <@&654321>", 8);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 8));
+        assert_eq!("<@123456><@&654321>", chunks.concat());
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        let content = "intro\n```rust\nlet a = 1;\nlet b = 2;\n```\noutro";
+        let chunks = split(content, 30);
+        assert_eq!(
+            vec![
+                "intro\n```rust\nlet a = 1;\n```",
+                "```rust\nlet b = 2;\n```\noutro",
+            ],
+            chunks,
+        );
+
+        let content = "```\nlet a = 1;\nlet b = 2;\n```";
+        assert_eq!(
+            vec!["```\nlet a = 1;\n```", "```\nlet b = 2;\n```"],
+            split(content, 20),
+        );
+
+        let content = format!("```py\n{}```", "x = 1 # one\n".repeat(20));
+        let chunks = split(&content, 30);
+        assert!(chunks.len() > 1);
+
+        for chunk in chunks {
+            assert!(chunk.chars().count() <= 30);
+            assert!(chunk.starts_with("```py\n"));
+            assert!(chunk.ends_with("```"));
+        }
+
+        // The opening line of a code block isn't split, keeping its language.
+        let content = "abcdefghijklmnopq```rust\nlet a = 1;\n```";
+        let expected = vec!["abcdefghijklmnopq", "```rust\nlet a = 1;\n```"];
+        assert_eq!(expected, split(content, 22));
+        assert_eq!(expected, split(content, 24));
+
+        // Inline code doesn't leave a code block open.
+        assert_eq!(vec!["```a b```", "c d"], split("```a b``` c d", 10),);
+    }
+}
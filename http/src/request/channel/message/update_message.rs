@@ -1,11 +1,16 @@
-use crate::request::{channel::allowed_mentions::AllowedMentions, prelude::*};
+use crate::request::{
+    attachment::{self, PartialAttachment},
+    channel::allowed_mentions::AllowedMentions,
+    prelude::*,
+    Attachment,
+};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
     channel::{embed::Embed, message::MessageFlags, Message},
-    id::{ChannelId, MessageId},
+    id::{AttachmentId, ChannelId, MessageId},
 };
 
 /// The error created when a message can not be updated as configured.
@@ -48,6 +53,8 @@ impl Error for UpdateMessageError {
 struct UpdateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<PartialAttachment>>,
     // We don't serialize if this is Option::None, to avoid overwriting the
     // field without meaning to.
     //
@@ -104,7 +111,25 @@ struct UpdateMessageFields {
 ///     .await?;
 /// # Ok(()) }
 /// ```
+///
+/// Add an attachment, keeping one of the message's existing attachments:
+///
+/// ```rust,no_run
+/// # use twilight_http::Client;
+/// use twilight_http::request::Attachment;
+/// use twilight_model::id::{AttachmentId, ChannelId, MessageId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// # let client = Client::new("my token");
+/// client.update_message(ChannelId(1), MessageId(2))
+///     .attachment(Attachment::from_bytes("notes.txt", "new notes"))
+///     .keep_attachment_ids(vec![AttachmentId(3)])
+///     .await?;
+/// # Ok(()) }
+/// ```
 pub struct UpdateMessage<'a> {
+    attachments: Vec<Attachment>,
     channel_id: ChannelId,
     fields: UpdateMessageFields,
     fut: Option<Pending<'a, Message>>,
@@ -115,6 +140,7 @@ pub struct UpdateMessage<'a> {
 impl<'a> UpdateMessage<'a> {
     pub(crate) fn new(http: &'a Client, channel_id: ChannelId, message_id: MessageId) -> Self {
         Self {
+            attachments: Vec::new(),
             channel_id,
             fields: UpdateMessageFields::default(),
             fut: None,
@@ -123,6 +149,50 @@ impl<'a> UpdateMessage<'a> {
         }
     }
 
+    /// Attach a new file to the message.
+    ///
+    /// Existing attachments of the message are removed unless they are kept
+    /// via [`keep_attachment_ids`].
+    ///
+    /// Refer to [`Attachment`] for more information.
+    ///
+    /// [`keep_attachment_ids`]: Self::keep_attachment_ids
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+
+        self
+    }
+
+    /// Attach multiple new files to the message.
+    ///
+    /// Existing attachments of the message are removed unless they are kept
+    /// via [`keep_attachment_ids`].
+    ///
+    /// [`keep_attachment_ids`]: Self::keep_attachment_ids
+    pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
+        self.attachments.extend(attachments);
+
+        self
+    }
+
+    /// Specify the existing attachments of the message to keep.
+    ///
+    /// Attachments that aren't in the list are removed. Pass an empty list to
+    /// remove all existing attachments.
+    pub fn keep_attachment_ids(
+        mut self,
+        attachment_ids: impl IntoIterator<Item = AttachmentId>,
+    ) -> Self {
+        self.fields.attachments.replace(
+            attachment_ids
+                .into_iter()
+                .map(PartialAttachment::existing)
+                .collect(),
+        );
+
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -205,13 +275,23 @@ impl<'a> UpdateMessage<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut.replace(Box::pin(self.http.request(Request::from((
-            crate::json_to_vec(&self.fields)?,
-            Route::UpdateMessage {
-                channel_id: self.channel_id.0,
-                message_id: self.message_id.0,
-            },
-        )))));
+        let route = Route::UpdateMessage {
+            channel_id: self.channel_id.0,
+            message_id: self.message_id.0,
+        };
+
+        let request = if self.attachments.is_empty() {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        } else {
+            let partials =
+                attachment::partials(&self.attachments, self.fields.attachments.as_deref());
+            self.fields.attachments.replace(partials);
+            let payload_json = crate::json_to_vec(&self.fields)?;
+
+            Request::from((attachment::form(&self.attachments, &payload_json), route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
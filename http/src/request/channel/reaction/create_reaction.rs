@@ -28,7 +28,7 @@ use twilight_model::id::{ChannelId, MessageId};
 pub struct CreateReaction<'a> {
     channel_id: ChannelId,
     emoji: String,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
 }
@@ -66,7 +66,7 @@ impl<'a> CreateReaction<'a> {
     }
 }
 
-poll_req!(CreateReaction<'_>, ());
+poll_req!(CreateReaction<'_>, EmptyBody);
 
 #[cfg(test)]
 mod tests {
@@ -5,7 +5,7 @@ use twilight_model::id::{ChannelId, MessageId};
 pub struct DeleteAllReaction<'a> {
     channel_id: ChannelId,
     emoji: String,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
 }
@@ -39,4 +39,4 @@ impl<'a> DeleteAllReaction<'a> {
     }
 }
 
-poll_req!(DeleteAllReaction<'_>, ());
+poll_req!(DeleteAllReaction<'_>, EmptyBody);
@@ -4,7 +4,7 @@ use twilight_model::id::{ChannelId, MessageId};
 /// Delete all reactions by all users on a message.
 pub struct DeleteAllReactions<'a> {
     channel_id: ChannelId,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
 }
@@ -31,4 +31,4 @@ impl<'a> DeleteAllReactions<'a> {
     }
 }
 
-poll_req!(DeleteAllReactions<'_>, ());
+poll_req!(DeleteAllReactions<'_>, EmptyBody);
@@ -5,7 +5,7 @@ use twilight_model::id::{ChannelId, MessageId};
 pub struct DeleteReaction<'a> {
     channel_id: ChannelId,
     emoji: String,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
     target_user: String,
@@ -43,4 +43,4 @@ impl<'a> DeleteReaction<'a> {
     }
 }
 
-poll_req!(DeleteReaction<'_>, ());
+poll_req!(DeleteReaction<'_>, EmptyBody);
@@ -1,4 +1,4 @@
-use crate::request::prelude::*;
+use crate::request::{prelude::*, Paginate};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -98,6 +98,49 @@ impl<'a> GetReactions<'a> {
         Ok(self)
     }
 
+    /// Paginate through all users that reacted with the emoji, ordered by ID.
+    ///
+    /// Pagination starts after the user set via [`after`] and stops at the
+    /// user set via [`before`], if any. Pages of the [`limit`] set on the
+    /// request are requested, or 100 users if no limit was set.
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginate<'a, User> {
+        let before = self.fields.before;
+        let channel_id = self.channel_id;
+        let emoji = self.emoji;
+        let http = self.http;
+        let message_id = self.message_id;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_REACTIONS_LIMIT_MAX);
+
+        Paginate::new(
+            self.fields.after.map(|after| after.0),
+            page_size,
+            |user| user.id.0,
+            move |after, limit| {
+                let request = Self {
+                    channel_id,
+                    emoji: emoji.clone(),
+                    fields: GetReactionsFields {
+                        after: after.map(UserId),
+                        before,
+                        limit: Some(limit),
+                    },
+                    fut: None,
+                    http,
+                    message_id,
+                };
+
+                Box::pin(async move { request.await?.model() })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetReactionUsers {
@@ -17,7 +17,7 @@ struct UpdateChannelPermissionConfiguredFields {
 pub struct UpdateChannelPermissionConfigured<'a> {
     channel_id: ChannelId,
     fields: UpdateChannelPermissionConfiguredFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     target_id: u64,
     reason: Option<String>,
@@ -94,7 +94,7 @@ impl<'a> AuditLogReason for UpdateChannelPermissionConfigured<'a> {
     }
 }
 
-poll_req!(UpdateChannelPermissionConfigured<'_>, ());
+poll_req!(UpdateChannelPermissionConfigured<'_>, EmptyBody);
 
 #[cfg(test)]
 mod tests {
@@ -8,7 +8,7 @@ struct DeleteWebhookParams {
 /// Delete a webhook by its ID.
 pub struct DeleteWebhook<'a> {
     fields: DeleteWebhookParams,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     id: WebhookId,
     reason: Option<String>,
@@ -64,4 +64,4 @@ impl<'a> AuditLogReason for DeleteWebhook<'a> {
     }
 }
 
-poll_req!(DeleteWebhook<'_>, ());
+poll_req!(DeleteWebhook<'_>, EmptyBody);
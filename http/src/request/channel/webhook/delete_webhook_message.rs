@@ -2,6 +2,7 @@ use crate::{
     client::Client,
     error::Result,
     request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
+    response::EmptyBody,
     routing::Route,
 };
 use twilight_model::id::{MessageId, WebhookId};
@@ -25,7 +26,7 @@ use twilight_model::id::{MessageId, WebhookId};
 /// # Ok(()) }
 /// ```
 pub struct DeleteWebhookMessage<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
     reason: Option<String>,
@@ -82,7 +83,7 @@ impl<'a> AuditLogReason for DeleteWebhookMessage<'a> {
     }
 }
 
-poll_req!(DeleteWebhookMessage<'_>, ());
+poll_req!(DeleteWebhookMessage<'_>, EmptyBody);
 
 #[cfg(test)]
 mod tests {
@@ -1,12 +1,21 @@
-use crate::request::prelude::*;
-use futures_util::future::TryFutureExt;
+use super::ExecuteWebhookAndWait;
+use crate::request::{
+    attachment::{self, PartialAttachment},
+    channel::{allowed_mentions::AllowedMentions, message::BuiltMessage},
+    prelude::*,
+    Attachment,
+};
 use twilight_model::{
-    channel::{embed::Embed, Message},
-    id::WebhookId,
+    channel::{embed::Embed, message::MessageFlags},
+    id::{ChannelId, WebhookId},
 };
 
 #[derive(Default, Serialize)]
-struct ExecuteWebhookFields {
+pub(super) struct ExecuteWebhookFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<PartialAttachment>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -14,7 +23,7 @@ struct ExecuteWebhookFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    file: Option<Vec<u8>>,
+    flags: Option<MessageFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,7 +36,10 @@ struct ExecuteWebhookFields {
 
 /// Executes a webhook, sending a message to its channel.
 ///
-/// You can only specify one of [`content`], [`embeds`], or [`file`].
+/// You can only specify one of [`content`], [`embeds`], or [`attachment`].
+///
+/// The response has no body unless the request waits for the message to be
+/// sent via [`wait`].
 ///
 /// # Examples
 ///
@@ -47,13 +59,16 @@ struct ExecuteWebhookFields {
 /// # Ok(()) }
 /// ```
 ///
+/// [`attachment`]: Self::attachment
 /// [`content`]: Self::content
 /// [`embeds`]: Self::embeds
-/// [`file`]: Self::file
+/// [`wait`]: Self::wait
 pub struct ExecuteWebhook<'a> {
+    attachments: Vec<Attachment>,
     fields: ExecuteWebhookFields,
-    fut: Option<Pending<'a, Option<Message>>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
+    thread_id: Option<ChannelId>,
     token: String,
     webhook_id: WebhookId,
 }
@@ -61,14 +76,32 @@ pub struct ExecuteWebhook<'a> {
 impl<'a> ExecuteWebhook<'a> {
     pub(crate) fn new(http: &'a Client, webhook_id: WebhookId, token: impl Into<String>) -> Self {
         Self {
+            attachments: Vec::new(),
             fields: ExecuteWebhookFields::default(),
             fut: None,
             http,
+            thread_id: None,
             token: token.into(),
             webhook_id,
         }
     }
 
+    /// Attach a file to the webhook's message.
+    ///
+    /// Refer to [`Attachment`] for more information.
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+
+        self
+    }
+
+    /// Attach multiple files to the webhook's message.
+    pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
+        self.attachments.extend(attachments);
+
+        self
+    }
+
     /// The URL of the avatar of the webhook.
     pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
         self.fields.avatar_url.replace(avatar_url.into());
@@ -92,9 +125,33 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
-    /// Attach a file to the webhook.
-    pub fn file(mut self, file: impl Into<Vec<u8>>) -> Self {
-        self.fields.file.replace(file.into());
+    /// Set the content, embeds, allowed mentions, and other fields of the
+    /// webhook's message from a message built by a [`MessageBuilder`].
+    ///
+    /// Fields not set in the built message are left unchanged. Webhooks can't
+    /// reply to messages or use nonces, so those are ignored.
+    ///
+    /// [`MessageBuilder`]: crate::request::channel::message::MessageBuilder
+    pub fn message(mut self, message: BuiltMessage) -> Self {
+        if let Some(allowed_mentions) = message.allowed_mentions {
+            self.fields.allowed_mentions.replace(allowed_mentions);
+        }
+
+        if let Some(content) = message.content {
+            self.fields.content.replace(content);
+        }
+
+        if !message.embeds.is_empty() {
+            self.fields.embeds.replace(message.embeds);
+        }
+
+        if let Some(flags) = message.flags {
+            self.fields.flags.replace(flags);
+        }
+
+        if let Some(tts) = message.tts {
+            self.fields.tts.replace(tts);
+        }
 
         self
     }
@@ -108,6 +165,15 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Send the message to a thread in the webhook's channel.
+    ///
+    /// The thread is unarchived if it is archived.
+    pub fn thread_id(mut self, thread_id: ChannelId) -> Self {
+        self.thread_id.replace(thread_id);
+
+        self
+    }
+
     /// Specify true if the message is TTS.
     pub fn tts(mut self, tts: bool) -> Self {
         self.fields.tts.replace(tts);
@@ -122,38 +188,58 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
-    /// If true, wait for the message to send before sending a response. See [Discord Docs/Execute
-    /// Webhook]
+    /// Wait for the message to send before sending a response, so that the
+    /// response contains the message. See [Discord Docs/Execute Webhook]
     ///
     /// [Discord Docs/Execute Webhook]: https://discord.com/developers/docs/resources/webhook#execute-webhook-querystring-params
-    pub fn wait(mut self, wait: bool) -> Self {
-        self.fields.wait.replace(wait);
-
-        self
+    pub fn wait(mut self) -> ExecuteWebhookAndWait<'a> {
+        self.fields.wait.replace(true);
+
+        ExecuteWebhookAndWait::new(
+            self.http,
+            self.attachments,
+            self.fields,
+            self.thread_id,
+            self.token,
+            self.webhook_id,
+        )
     }
 
     fn start(&mut self) -> Result<()> {
-        let request = Request::from((
-            crate::json_to_vec(&self.fields)?,
-            Route::ExecuteWebhook {
-                token: self.token.to_owned(),
-                wait: self.fields.wait,
-                webhook_id: self.webhook_id.0,
-            },
-        ));
-
-        match self.fields.wait {
-            Some(true) => {
-                self.fut.replace(Box::pin(self.http.request(request)));
-            }
-            _ => {
-                self.fut
-                    .replace(Box::pin(self.http.verify(request).map_ok(|_| None)));
-            }
-        }
+        let route = Route::ExecuteWebhook {
+            thread_id: self.thread_id.map(|id| id.0),
+            token: self.token.to_owned(),
+            wait: self.fields.wait,
+            webhook_id: self.webhook_id.0,
+        };
+        let request = request(&mut self.fields, &self.attachments, route)?;
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
 }
 
-poll_req!(ExecuteWebhook<'_>, Option<Message>);
+/// Create the request executing a webhook, sending a multipart form if there
+/// are attachments.
+pub(super) fn request(
+    fields: &mut ExecuteWebhookFields,
+    attachments: &[Attachment],
+    route: Route,
+) -> Result<Request> {
+    if attachments.is_empty() {
+        return Ok(Request::from((crate::json_to_vec(fields)?, route)));
+    }
+
+    fields
+        .attachments
+        .replace(attachment::partials(attachments, None));
+    let payload_json = crate::json_to_vec(fields)?;
+
+    Ok(Request::from((
+        attachment::form(attachments, &payload_json),
+        route,
+    )))
+}
+
+poll_req!(ExecuteWebhook<'_>, EmptyBody);
@@ -0,0 +1,81 @@
+use super::execute_webhook::{self, ExecuteWebhookFields};
+use crate::request::{prelude::*, Attachment};
+use twilight_model::{
+    channel::Message,
+    id::{ChannelId, WebhookId},
+};
+
+/// Execute a webhook, waiting for the message to be sent so that the response
+/// contains it.
+///
+/// Created via [`ExecuteWebhook::wait`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::WebhookId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+/// let id = WebhookId(432);
+///
+/// let message = client
+///     .execute_webhook(id, "webhook token")
+///     .content("Pinkie...")
+///     .wait()
+///     .await?
+///     .model()?;
+///
+/// println!("sent message {}", message.id);
+/// # Ok(()) }
+/// ```
+///
+/// [`ExecuteWebhook::wait`]: super::ExecuteWebhook::wait
+pub struct ExecuteWebhookAndWait<'a> {
+    attachments: Vec<Attachment>,
+    fields: ExecuteWebhookFields,
+    fut: Option<Pending<'a, Message>>,
+    http: &'a Client,
+    thread_id: Option<ChannelId>,
+    token: String,
+    webhook_id: WebhookId,
+}
+
+impl<'a> ExecuteWebhookAndWait<'a> {
+    pub(super) fn new(
+        http: &'a Client,
+        attachments: Vec<Attachment>,
+        fields: ExecuteWebhookFields,
+        thread_id: Option<ChannelId>,
+        token: String,
+        webhook_id: WebhookId,
+    ) -> Self {
+        Self {
+            attachments,
+            fields,
+            fut: None,
+            http,
+            thread_id,
+            token,
+            webhook_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::ExecuteWebhook {
+            thread_id: self.thread_id.map(|id| id.0),
+            token: self.token.clone(),
+            wait: Some(true),
+            webhook_id: self.webhook_id.0,
+        };
+        let request = execute_webhook::request(&mut self.fields, &self.attachments, route)?;
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(ExecuteWebhookAndWait<'_>, Message);
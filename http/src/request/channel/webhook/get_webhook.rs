@@ -9,7 +9,7 @@ struct GetWebhookFields {
 /// Get a webhook by ID.
 pub struct GetWebhook<'a> {
     fields: GetWebhookFields,
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, Webhook>>,
     http: &'a Client,
     id: WebhookId,
 }
@@ -32,13 +32,12 @@ impl<'a> GetWebhook<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetWebhook {
-                    token: self.fields.token.clone(),
-                    webhook_id: self.id.0,
-                },
-            ))));
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetWebhook {
+                token: self.fields.token.clone(),
+                webhook_id: self.id.0,
+            },
+        ))));
 
         Ok(())
     }
@@ -0,0 +1,65 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    channel::Message,
+    id::{MessageId, WebhookId},
+};
+
+/// Get a message created by a webhook.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::{MessageId, WebhookId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let response = client
+///     .webhook_message(WebhookId(1), "token here", MessageId(2))
+///     .await?;
+///
+/// if let Some(response) = response {
+///     println!("content: {}", response.model()?.content);
+/// }
+/// # Ok(()) }
+/// ```
+pub struct GetWebhookMessage<'a> {
+    fut: Option<Pending<'a, Message>>,
+    http: &'a Client,
+    message_id: MessageId,
+    token: String,
+    webhook_id: WebhookId,
+}
+
+impl<'a> GetWebhookMessage<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        webhook_id: WebhookId,
+        token: impl Into<String>,
+        message_id: MessageId,
+    ) -> Self {
+        Self {
+            fut: None,
+            http,
+            message_id,
+            token: token.into(),
+            webhook_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetWebhookMessage {
+                message_id: self.message_id.0,
+                token: self.token.clone(),
+                webhook_id: self.webhook_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(opt, GetWebhookMessage<'_>, Message);
@@ -5,10 +5,12 @@ use crate::{
     error::Result,
     request::{
         self,
+        attachment::{self, PartialAttachment},
         channel::allowed_mentions::AllowedMentions,
         validate::{self, EmbedValidationError},
-        AuditLogReason, AuditLogReasonError, Pending, Request,
+        Attachment, AuditLogReason, AuditLogReasonError, Pending, Request,
     },
+    response::EmptyBody,
     routing::Route,
 };
 use serde::Serialize;
@@ -18,7 +20,7 @@ use std::{
 };
 use twilight_model::{
     channel::embed::Embed,
-    id::{MessageId, WebhookId},
+    id::{AttachmentId, MessageId, WebhookId},
 };
 
 /// A webhook's message can not be updated as configured.
@@ -78,6 +80,8 @@ impl Error for UpdateWebhookMessageError {
 struct UpdateWebhookMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<PartialAttachment>>,
     #[allow(clippy::option_option)]
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<Option<String>>,
@@ -117,8 +121,9 @@ struct UpdateWebhookMessageFields {
 ///
 /// [`DeleteWebhookMessage`]: super::DeleteWebhookMessage
 pub struct UpdateWebhookMessage<'a> {
+    attachments: Vec<Attachment>,
     fields: UpdateWebhookMessageFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     http: &'a Client,
     message_id: MessageId,
     reason: Option<String>,
@@ -137,6 +142,7 @@ impl<'a> UpdateWebhookMessage<'a> {
         message_id: MessageId,
     ) -> Self {
         Self {
+            attachments: Vec::new(),
             fields: UpdateWebhookMessageFields {
                 allowed_mentions: http.default_allowed_mentions(),
                 ..UpdateWebhookMessageFields::default()
@@ -157,6 +163,50 @@ impl<'a> UpdateWebhookMessage<'a> {
         self
     }
 
+    /// Attach a new file to the message.
+    ///
+    /// Existing attachments of the message are removed unless they are kept
+    /// via [`keep_attachment_ids`].
+    ///
+    /// Refer to [`Attachment`] for more information.
+    ///
+    /// [`keep_attachment_ids`]: Self::keep_attachment_ids
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+
+        self
+    }
+
+    /// Attach multiple new files to the message.
+    ///
+    /// Existing attachments of the message are removed unless they are kept
+    /// via [`keep_attachment_ids`].
+    ///
+    /// [`keep_attachment_ids`]: Self::keep_attachment_ids
+    pub fn attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
+        self.attachments.extend(attachments);
+
+        self
+    }
+
+    /// Specify the existing attachments of the message to keep.
+    ///
+    /// Attachments that aren't in the list are removed. Pass an empty list to
+    /// remove all existing attachments.
+    pub fn keep_attachment_ids(
+        mut self,
+        attachment_ids: impl IntoIterator<Item = AttachmentId>,
+    ) -> Self {
+        self.fields.attachments.replace(
+            attachment_ids
+                .into_iter()
+                .map(PartialAttachment::existing)
+                .collect(),
+        );
+
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -254,20 +304,29 @@ impl<'a> UpdateWebhookMessage<'a> {
         Ok(self)
     }
 
-    fn request(&self) -> Result<Request> {
-        let body = crate::json_to_vec(&self.fields)?;
+    fn request(&mut self) -> Result<Request> {
         let route = Route::UpdateWebhookMessage {
             message_id: self.message_id.0,
             token: self.token.clone(),
             webhook_id: self.webhook_id.0,
         };
 
-        Ok(if let Some(reason) = &self.reason {
-            let headers = request::audit_header(&reason)?;
-            Request::from((body, headers, route))
+        let mut request = if self.attachments.is_empty() {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
         } else {
-            Request::from((body, route))
-        })
+            let partials =
+                attachment::partials(&self.attachments, self.fields.attachments.as_deref());
+            self.fields.attachments.replace(partials);
+            let payload_json = crate::json_to_vec(&self.fields)?;
+
+            Request::from((attachment::form(&self.attachments, &payload_json), route))
+        };
+
+        if let Some(reason) = &self.reason {
+            request.headers.replace(request::audit_header(&reason)?);
+        }
+
+        Ok(request)
     }
 
     fn start(&mut self) -> Result<()> {
@@ -287,7 +346,7 @@ impl<'a> AuditLogReason for UpdateWebhookMessage<'a> {
     }
 }
 
-poll_req!(UpdateWebhookMessage<'_>, ());
+poll_req!(UpdateWebhookMessage<'_>, EmptyBody);
 
 #[cfg(test)]
 mod tests {
@@ -302,7 +361,7 @@ mod tests {
     #[test]
     fn test_request() {
         let client = Client::new("token");
-        let builder = UpdateWebhookMessage::new(&client, WebhookId(1), "token", MessageId(2))
+        let mut builder = UpdateWebhookMessage::new(&client, WebhookId(1), "token", MessageId(2))
             .content(Some("test".to_owned()))
             .expect("'test' content couldn't be set")
             .reason("reason")
@@ -311,6 +370,7 @@ mod tests {
 
         let body = crate::json_to_vec(&UpdateWebhookMessageFields {
             allowed_mentions: None,
+            attachments: None,
             content: Some(Some("test".to_owned())),
             embeds: None,
         })
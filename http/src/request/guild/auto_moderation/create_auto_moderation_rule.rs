@@ -0,0 +1,249 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::auto_moderation::{
+        AutoModerationAction, AutoModerationEventType, AutoModerationRule,
+        AutoModerationTriggerMetadata, AutoModerationTriggerType,
+    },
+    id::{ChannelId, GuildId, RoleId},
+};
+
+/// The error returned when the auto moderation rule can not be created as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateAutoModerationRuleError {
+    /// More than 50 channels are exempt from the rule.
+    ExemptChannelsInvalid {
+        /// Provided channels.
+        channels: Vec<ChannelId>,
+    },
+    /// More than 20 roles are exempt from the rule.
+    ExemptRolesInvalid {
+        /// Provided roles.
+        roles: Vec<RoleId>,
+    },
+}
+
+impl Display for CreateAutoModerationRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ExemptChannelsInvalid { .. } => {
+                f.write_str("more than 50 channels are exempt from the rule")
+            }
+            Self::ExemptRolesInvalid { .. } => {
+                f.write_str("more than 20 roles are exempt from the rule")
+            }
+        }
+    }
+}
+
+impl Error for CreateAutoModerationRuleError {}
+
+#[derive(Serialize)]
+struct CreateAutoModerationRuleFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actions: Option<Vec<AutoModerationAction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    event_type: AutoModerationEventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_channels: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_roles: Option<Vec<RoleId>>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger_metadata: Option<AutoModerationTriggerMetadata>,
+    trigger_type: AutoModerationTriggerType,
+}
+
+/// Create an auto moderation rule in a guild.
+///
+/// Which fields of the [`trigger_metadata`] are relevant depends on the
+/// trigger type of the rule. Discord limits the number of rules of each
+/// trigger type a guild may have.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// # Examples
+///
+/// Block messages containing spoilers for the ending, and alert the
+/// moderators about them:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     guild::auto_moderation::{
+///         AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType,
+///         AutoModerationEventType, AutoModerationTriggerMetadata, AutoModerationTriggerType,
+///     },
+///     id::{ChannelId, GuildId},
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let rule = client
+///     .create_auto_moderation_rule(
+///         GuildId(1),
+///         "no spoilers",
+///         AutoModerationEventType::MessageSend,
+///         AutoModerationTriggerType::Keyword,
+///     )
+///     .actions(vec![
+///         AutoModerationAction {
+///             kind: AutoModerationActionType::BlockMessage,
+///             metadata: None,
+///         },
+///         AutoModerationAction {
+///             kind: AutoModerationActionType::SendAlertMessage,
+///             metadata: Some(AutoModerationActionMetadata {
+///                 channel_id: Some(ChannelId(2)),
+///                 ..AutoModerationActionMetadata::default()
+///             }),
+///         },
+///     ])
+///     .enabled(true)
+///     .trigger_metadata(AutoModerationTriggerMetadata {
+///         keyword_filter: Some(vec!["*ending*".to_owned()]),
+///         ..AutoModerationTriggerMetadata::default()
+///     })
+///     .await?
+///     .model()?;
+/// # Ok(()) }
+/// ```
+///
+/// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+/// [`trigger_metadata`]: Self::trigger_metadata
+pub struct CreateAutoModerationRule<'a> {
+    fields: CreateAutoModerationRuleFields,
+    fut: Option<Pending<'a, AutoModerationRule>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> CreateAutoModerationRule<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        event_type: AutoModerationEventType,
+        trigger_type: AutoModerationTriggerType,
+    ) -> Self {
+        Self {
+            fields: CreateAutoModerationRuleFields {
+                actions: None,
+                enabled: None,
+                event_type,
+                exempt_channels: None,
+                exempt_roles: None,
+                name: name.into(),
+                trigger_metadata: None,
+                trigger_type,
+            },
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        }
+    }
+
+    /// Set the actions taken when the rule is triggered.
+    pub fn actions(mut self, actions: Vec<AutoModerationAction>) -> Self {
+        self.fields.actions.replace(actions);
+
+        self
+    }
+
+    /// Set whether the rule is enabled.
+    ///
+    /// Discord defaults this to `false`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.fields.enabled.replace(enabled);
+
+        self
+    }
+
+    /// Set the channels which are exempt from the rule.
+    ///
+    /// At most 50 channels may be exempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateAutoModerationRuleError::ExemptChannelsInvalid`] if
+    /// more than 50 channels are provided.
+    pub fn exempt_channels(
+        mut self,
+        channels: Vec<ChannelId>,
+    ) -> Result<Self, CreateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_channels(&channels) {
+            return Err(CreateAutoModerationRuleError::ExemptChannelsInvalid { channels });
+        }
+
+        self.fields.exempt_channels.replace(channels);
+
+        Ok(self)
+    }
+
+    /// Set the roles which are exempt from the rule.
+    ///
+    /// At most 20 roles may be exempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateAutoModerationRuleError::ExemptRolesInvalid`] if more
+    /// than 20 roles are provided.
+    pub fn exempt_roles(
+        mut self,
+        roles: Vec<RoleId>,
+    ) -> Result<Self, CreateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_roles(&roles) {
+            return Err(CreateAutoModerationRuleError::ExemptRolesInvalid { roles });
+        }
+
+        self.fields.exempt_roles.replace(roles);
+
+        Ok(self)
+    }
+
+    /// Set the additional data used to determine whether the rule is
+    /// triggered.
+    pub fn trigger_metadata(mut self, trigger_metadata: AutoModerationTriggerMetadata) -> Self {
+        self.fields.trigger_metadata.replace(trigger_metadata);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let body = crate::json_to_vec(&self.fields)?;
+        let route = Route::CreateAutoModerationRule {
+            guild_id: self.guild_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            Request::from((body, audit_header(&reason)?, route))
+        } else {
+            Request::from((body, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for CreateAutoModerationRule<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(CreateAutoModerationRule<'_>, AutoModerationRule);
@@ -0,0 +1,35 @@
+use crate::request::prelude::*;
+use twilight_model::{guild::auto_moderation::AutoModerationRule, id::GuildId};
+
+/// Get the auto moderation rules of a guild.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+pub struct GetGuildAutoModerationRules<'a> {
+    fut: Option<Pending<'a, Vec<AutoModerationRule>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildAutoModerationRules<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildAutoModerationRules {
+                guild_id: self.guild_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildAutoModerationRules<'_>, Vec<AutoModerationRule>);
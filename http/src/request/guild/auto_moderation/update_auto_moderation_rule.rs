@@ -0,0 +1,202 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::auto_moderation::{
+        AutoModerationAction, AutoModerationEventType, AutoModerationRule,
+        AutoModerationTriggerMetadata,
+    },
+    id::{AutoModerationRuleId, ChannelId, GuildId, RoleId},
+};
+
+/// The error returned when the auto moderation rule can not be updated as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateAutoModerationRuleError {
+    /// More than 50 channels are exempt from the rule.
+    ExemptChannelsInvalid {
+        /// Provided channels.
+        channels: Vec<ChannelId>,
+    },
+    /// More than 20 roles are exempt from the rule.
+    ExemptRolesInvalid {
+        /// Provided roles.
+        roles: Vec<RoleId>,
+    },
+}
+
+impl Display for UpdateAutoModerationRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ExemptChannelsInvalid { .. } => {
+                f.write_str("more than 50 channels are exempt from the rule")
+            }
+            Self::ExemptRolesInvalid { .. } => {
+                f.write_str("more than 20 roles are exempt from the rule")
+            }
+        }
+    }
+}
+
+impl Error for UpdateAutoModerationRuleError {}
+
+#[derive(Default, Serialize)]
+struct UpdateAutoModerationRuleFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actions: Option<Vec<AutoModerationAction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_type: Option<AutoModerationEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_channels: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_roles: Option<Vec<RoleId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger_metadata: Option<AutoModerationTriggerMetadata>,
+}
+
+/// Update an auto moderation rule of a guild.
+///
+/// The trigger type of a rule can't be changed.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+pub struct UpdateAutoModerationRule<'a> {
+    auto_moderation_rule_id: AutoModerationRuleId,
+    fields: UpdateAutoModerationRuleFields,
+    fut: Option<Pending<'a, AutoModerationRule>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> UpdateAutoModerationRule<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> Self {
+        Self {
+            auto_moderation_rule_id,
+            fields: UpdateAutoModerationRuleFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        }
+    }
+
+    /// Set the actions taken when the rule is triggered.
+    pub fn actions(mut self, actions: Vec<AutoModerationAction>) -> Self {
+        self.fields.actions.replace(actions);
+
+        self
+    }
+
+    /// Set whether the rule is enabled.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.fields.enabled.replace(enabled);
+
+        self
+    }
+
+    /// Set the context in which the rule is checked.
+    pub fn event_type(mut self, event_type: AutoModerationEventType) -> Self {
+        self.fields.event_type.replace(event_type);
+
+        self
+    }
+
+    /// Set the channels which are exempt from the rule.
+    ///
+    /// At most 50 channels may be exempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateAutoModerationRuleError::ExemptChannelsInvalid`] if
+    /// more than 50 channels are provided.
+    pub fn exempt_channels(
+        mut self,
+        channels: Vec<ChannelId>,
+    ) -> Result<Self, UpdateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_channels(&channels) {
+            return Err(UpdateAutoModerationRuleError::ExemptChannelsInvalid { channels });
+        }
+
+        self.fields.exempt_channels.replace(channels);
+
+        Ok(self)
+    }
+
+    /// Set the roles which are exempt from the rule.
+    ///
+    /// At most 20 roles may be exempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateAutoModerationRuleError::ExemptRolesInvalid`] if more
+    /// than 20 roles are provided.
+    pub fn exempt_roles(
+        mut self,
+        roles: Vec<RoleId>,
+    ) -> Result<Self, UpdateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_roles(&roles) {
+            return Err(UpdateAutoModerationRuleError::ExemptRolesInvalid { roles });
+        }
+
+        self.fields.exempt_roles.replace(roles);
+
+        Ok(self)
+    }
+
+    /// Set the name of the rule.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.fields.name.replace(name.into());
+
+        self
+    }
+
+    /// Set the additional data used to determine whether the rule is
+    /// triggered.
+    pub fn trigger_metadata(mut self, trigger_metadata: AutoModerationTriggerMetadata) -> Self {
+        self.fields.trigger_metadata.replace(trigger_metadata);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let body = crate::json_to_vec(&self.fields)?;
+        let route = Route::UpdateAutoModerationRule {
+            auto_moderation_rule_id: self.auto_moderation_rule_id.0,
+            guild_id: self.guild_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            Request::from((body, audit_header(&reason)?, route))
+        } else {
+            Request::from((body, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for UpdateAutoModerationRule<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(UpdateAutoModerationRule<'_>, AutoModerationRule);
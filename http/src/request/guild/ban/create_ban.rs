@@ -60,7 +60,7 @@ struct CreateBanFields {
 /// ```
 pub struct CreateBan<'a> {
     fields: CreateBanFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     user_id: UserId,
@@ -119,4 +119,4 @@ impl<'a> AuditLogReason for CreateBan<'a> {
     }
 }
 
-poll_req!(CreateBan<'_>, ());
+poll_req!(CreateBan<'_>, EmptyBody);
@@ -22,7 +22,7 @@ use twilight_model::id::{GuildId, UserId};
 /// # Ok(()) }
 /// ```
 pub struct DeleteBan<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     user_id: UserId,
@@ -72,4 +72,4 @@ impl<'a> AuditLogReason for DeleteBan<'a> {
     }
 }
 
-poll_req!(DeleteBan<'_>, ());
+poll_req!(DeleteBan<'_>, EmptyBody);
@@ -1,8 +1,46 @@
-use crate::request::prelude::*;
-use twilight_model::{guild::Ban, id::GuildId};
+use crate::request::{prelude::*, Paginate};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::Ban,
+    id::{GuildId, UserId},
+};
+
+/// The error created when the bans can not be retrieved as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetBansError {
+    /// The limit is either 0 or more than 1000.
+    LimitInvalid {
+        /// Provided maximum number of bans to retrieve.
+        limit: u64,
+    },
+}
+
+impl Display for GetBansError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        }
+    }
+}
+
+impl Error for GetBansError {}
+
+#[derive(Default)]
+struct GetBansFields {
+    after: Option<UserId>,
+    before: Option<UserId>,
+    limit: Option<u64>,
+}
 
 /// Retrieve the bans for a guild.
 ///
+/// Discord returns all bans if no limit is set. Otherwise the upper limit to
+/// this request is 1000, and the bans are ordered by user ID.
+///
 /// # Examples
 ///
 /// Retrieve the bans for guild `1`:
@@ -21,6 +59,7 @@ use twilight_model::{guild::Ban, id::GuildId};
 /// # Ok(()) }
 /// ```
 pub struct GetBans<'a> {
+    fields: GetBansFields,
     fut: Option<Pending<'a, Vec<Ban>>>,
     guild_id: GuildId,
     http: &'a Client,
@@ -29,17 +68,98 @@ pub struct GetBans<'a> {
 impl<'a> GetBans<'a> {
     pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
         Self {
+            fields: GetBansFields::default(),
             fut: None,
             guild_id,
             http,
         }
     }
 
+    /// Get bans of users after this ID.
+    pub fn after(mut self, after: UserId) -> Self {
+        self.fields.after.replace(after);
+
+        self
+    }
+
+    /// Get bans of users before this ID.
+    pub fn before(mut self, before: UserId) -> Self {
+        self.fields.before.replace(before);
+
+        self
+    }
+
+    /// Set the maximum number of bans to retrieve.
+    ///
+    /// The minimum is 1 and the maximum is 1000.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetBansError::LimitInvalid`] if the limit is 0 or greater
+    /// than 1000.
+    pub fn limit(mut self, limit: u64) -> Result<Self, GetBansError> {
+        if !validate::get_guild_bans_limit(limit) {
+            return Err(GetBansError::LimitInvalid { limit });
+        }
+
+        self.fields.limit.replace(limit);
+
+        Ok(self)
+    }
+
+    /// Paginate through all bans of the guild, ordered by user ID.
+    ///
+    /// Pagination starts after the user set via [`after`] and stops at the
+    /// user set via [`before`], if any. Pages of the [`limit`] set on the
+    /// request are requested, or 1000 bans if no limit was set.
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginate<'a, Ban> {
+        let before = self.fields.before;
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_GUILD_BANS_LIMIT_MAX);
+
+        Paginate::new(
+            self.fields.after.map(|after| after.0),
+            page_size,
+            |ban| ban.user.id.0,
+            move |after, limit| {
+                let mut request = Self::new(http, guild_id);
+                request.fields = GetBansFields {
+                    after: after.map(UserId),
+                    before,
+                    limit: Some(limit),
+                };
+
+                Box::pin(async move { request.await?.model() })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request(Request::from(Route::GetBans {
+        let fields = &self.fields;
+
+        let route = if fields.after.is_none() && fields.before.is_none() && fields.limit.is_none() {
+            Route::GetBans {
                 guild_id: self.guild_id.0,
-            }))));
+            }
+        } else {
+            Route::GetBansWithParameters {
+                after: fields.after.map(|x| x.0),
+                before: fields.before.map(|x| x.0),
+                guild_id: self.guild_id.0,
+                limit: fields.limit,
+            }
+        };
+
+        self.fut
+            .replace(Box::pin(self.http.request(Request::from(route))));
 
         Ok(())
     }
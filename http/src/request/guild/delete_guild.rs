@@ -3,7 +3,7 @@ use twilight_model::id::GuildId;
 
 /// Delete a guild permanently. The user must be the owner.
 pub struct DeleteGuild<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
 }
@@ -28,4 +28,4 @@ impl<'a> DeleteGuild<'a> {
     }
 }
 
-poll_req!(DeleteGuild<'_>, ());
+poll_req!(DeleteGuild<'_>, EmptyBody);
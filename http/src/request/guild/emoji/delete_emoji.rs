@@ -4,7 +4,7 @@ use twilight_model::id::{EmojiId, GuildId};
 /// Delete an emoji in a guild, by id.
 pub struct DeleteEmoji<'a> {
     emoji_id: EmojiId,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     reason: Option<String>,
@@ -53,4 +53,4 @@ impl<'a> AuditLogReason for DeleteEmoji<'a> {
     }
 }
 
-poll_req!(DeleteEmoji<'_>, ());
+poll_req!(DeleteEmoji<'_>, EmptyBody);
@@ -26,7 +26,7 @@ use twilight_model::{
 /// ```
 pub struct GetEmoji<'a> {
     emoji_id: EmojiId,
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, Emoji>>,
     guild_id: GuildId,
     http: &'a Client,
 }
@@ -42,13 +42,12 @@ impl<'a> GetEmoji<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetEmoji {
-                    emoji_id: self.emoji_id.0,
-                    guild_id: self.guild_id.0,
-                },
-            ))));
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetEmoji {
+                emoji_id: self.emoji_id.0,
+                guild_id: self.guild_id.0,
+            },
+        ))));
 
         Ok(())
     }
@@ -1,10 +1,10 @@
-use crate::request::prelude::*;
+use crate::request::{prelude::*, Paginate};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    guild::audit_log::{AuditLog, AuditLogEvent},
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEvent},
     id::{GuildId, UserId},
 };
 
@@ -114,6 +114,52 @@ impl<'a> GetAuditLog<'a> {
         self
     }
 
+    /// Paginate through all audit log entries matching the filters, from
+    /// newest to oldest.
+    ///
+    /// Pagination starts before the entry set via [`before`], if any. Pages
+    /// of the [`limit`] set on the request are requested, or 100 entries if
+    /// no limit was set.
+    ///
+    /// Only the entries are yielded; the users, webhooks, and integrations
+    /// referenced by them must be requested separately.
+    ///
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginate<'a, AuditLogEntry> {
+        let action_type = self.fields.action_type;
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_AUDIT_LOG_LIMIT_MAX);
+        let user_id = self.fields.user_id;
+
+        Paginate::new(
+            self.fields.before,
+            page_size,
+            |entry| entry.id.0,
+            move |before, limit| {
+                let mut request = Self::new(http, guild_id);
+                request.fields = GetAuditLogFields {
+                    action_type,
+                    before,
+                    limit: Some(limit),
+                    user_id,
+                };
+
+                Box::pin(async move {
+                    Ok(request
+                        .await?
+                        .model()?
+                        .map(|audit_log| audit_log.audit_log_entries)
+                        .unwrap_or_default())
+                })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetAuditLogs {
@@ -0,0 +1,31 @@
+use crate::request::prelude::*;
+use twilight_model::{guild::MembershipScreening, id::GuildId};
+
+/// Get a guild's membership screening form.
+pub struct GetGuildMembershipScreening<'a> {
+    fut: Option<Pending<'a, MembershipScreening>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildMembershipScreening<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildMembershipScreening {
+                guild_id: self.guild_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildMembershipScreening<'_>, MembershipScreening);
@@ -0,0 +1,31 @@
+use crate::request::prelude::*;
+use twilight_model::{guild::WelcomeScreen, id::GuildId};
+
+/// Get a guild's welcome screen.
+pub struct GetGuildWelcomeScreen<'a> {
+    fut: Option<Pending<'a, WelcomeScreen>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildWelcomeScreen<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildWelcomeScreen {
+                guild_id: self.guild_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildWelcomeScreen<'_>, WelcomeScreen);
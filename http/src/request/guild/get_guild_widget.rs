@@ -7,7 +7,7 @@ use twilight_model::{guild::GuildWidget, id::GuildId};
 ///
 /// [the discord docs]: https://discord.com/developers/docs/resources/guild#get-guild-widget
 pub struct GetGuildWidget<'a> {
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, GuildWidget>>,
     guild_id: GuildId,
     http: &'a Client,
 }
@@ -22,12 +22,11 @@ impl<'a> GetGuildWidget<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetGuildWidget {
-                    guild_id: self.guild_id.0,
-                },
-            ))));
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildWidget {
+                guild_id: self.guild_id.0,
+            },
+        ))));
 
         Ok(())
     }
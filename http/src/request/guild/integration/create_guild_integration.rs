@@ -15,7 +15,7 @@ struct CreateGuildIntegrationFields {
 /// [the discord docs]: https://discord.com/developers/docs/resources/guild#create-guild-integration
 pub struct CreateGuildIntegration<'a> {
     fields: CreateGuildIntegrationFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     reason: Option<String>,
@@ -74,4 +74,4 @@ impl<'a> AuditLogReason for CreateGuildIntegration<'a> {
     }
 }
 
-poll_req!(CreateGuildIntegration<'_>, ());
+poll_req!(CreateGuildIntegration<'_>, EmptyBody);
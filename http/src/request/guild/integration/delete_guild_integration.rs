@@ -3,7 +3,7 @@ use twilight_model::id::{GuildId, IntegrationId};
 
 /// Delete an integration for a guild, by the integration's id.
 pub struct DeleteGuildIntegration<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     integration_id: IntegrationId,
@@ -53,4 +53,4 @@ impl<'a> AuditLogReason for DeleteGuildIntegration<'a> {
     }
 }
 
-poll_req!(DeleteGuildIntegration<'_>, ());
+poll_req!(DeleteGuildIntegration<'_>, EmptyBody);
@@ -3,7 +3,7 @@ use twilight_model::id::{GuildId, IntegrationId};
 
 /// Synchronize a guild's integration by its id.
 pub struct SyncGuildIntegration<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     integration_id: IntegrationId,
@@ -31,4 +31,4 @@ impl<'a> SyncGuildIntegration<'a> {
     }
 }
 
-poll_req!(SyncGuildIntegration<'_>, ());
+poll_req!(SyncGuildIntegration<'_>, EmptyBody);
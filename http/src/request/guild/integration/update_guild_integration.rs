@@ -18,7 +18,7 @@ struct UpdateGuildIntegrationFields {
 /// [the discord docs]: https://discord.com/developers/docs/resources/guild#modify-guild-integrationb
 pub struct UpdateGuildIntegration<'a> {
     fields: UpdateGuildIntegrationFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     integration_id: IntegrationId,
@@ -100,4 +100,4 @@ impl<'a> AuditLogReason for UpdateGuildIntegration<'a> {
     }
 }
 
-poll_req!(UpdateGuildIntegration<'_>, ());
+poll_req!(UpdateGuildIntegration<'_>, EmptyBody);
@@ -1,4 +1,5 @@
-use crate::request::prelude::*;
+use crate::{request::prelude::*, response::Response};
+use hyper::StatusCode;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -45,7 +46,7 @@ struct AddGuildMemberFields {
 
 pub struct AddGuildMember<'a> {
     fields: AddGuildMemberFields,
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, PartialMember>>,
     guild_id: GuildId,
     http: &'a Client,
     user_id: UserId,
@@ -56,6 +57,9 @@ pub struct AddGuildMember<'a> {
 /// An access token for the user with `guilds.join` scope is required. All other
 /// fields are optional. Refer to [the discord docs] for more information.
 ///
+/// Resolves to `None` if the user is already a member of the guild, in which
+/// case Discord responds with a `204 No Content`.
+///
 /// # Errors
 ///
 /// Returns [`AddGuildMemberError::NicknameInvalid`] if the nickname is too
@@ -142,36 +146,26 @@ impl<'a> AddGuildMember<'a> {
             },
         ));
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
 }
 
 impl Future for AddGuildMember<'_> {
-    type Output = Result<Option<PartialMember>>;
+    type Output = Result<Option<Response<PartialMember>>>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         loop {
             if let Some(fut) = self.as_mut().fut.as_mut() {
-                let bytes = match fut.as_mut().poll(cx) {
-                    Poll::Ready(Ok(bytes)) => bytes,
-                    Poll::Ready(Err(why)) => return Poll::Ready(Err(why)),
-                    Poll::Pending => return Poll::Pending,
+                return match fut.as_mut().poll(cx) {
+                    Poll::Ready(Ok(response)) if response.status() == StatusCode::NO_CONTENT => {
+                        Poll::Ready(Ok(None))
+                    }
+                    Poll::Ready(Ok(response)) => Poll::Ready(Ok(Some(response))),
+                    Poll::Ready(Err(why)) => Poll::Ready(Err(why)),
+                    Poll::Pending => Poll::Pending,
                 };
-
-                let mut bytes = bytes.as_ref().to_vec();
-
-                if bytes.is_empty() {
-                    return Poll::Ready(Ok(None));
-                }
-
-                return Poll::Ready(crate::json_from_slice(&mut bytes).map(Some).map_err(
-                    |source| crate::Error::Parsing {
-                        body: bytes,
-                        source,
-                    },
-                ));
             }
 
             if let Err(why) = self.as_mut().start() {
@@ -23,7 +23,7 @@ use twilight_model::id::{GuildId, RoleId, UserId};
 /// # Ok(()) }
 /// ```
 pub struct AddRoleToMember<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     role_id: RoleId,
@@ -82,4 +82,4 @@ impl<'a> AuditLogReason for AddRoleToMember<'a> {
     }
 }
 
-poll_req!(AddRoleToMember<'_>, ());
+poll_req!(AddRoleToMember<'_>, EmptyBody);
@@ -1,23 +1,14 @@
-use crate::request::prelude::*;
-use bytes::Bytes;
-use serde::de::DeserializeSeed;
+use crate::request::{prelude::*, Paginate};
+use futures_util::future::TryFutureExt;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    future::Future,
-    pin::Pin,
-    task::{Context, Poll},
 };
 use twilight_model::{
-    guild::member::{Member, MemberDeserializer},
+    guild::member::Member,
     id::{GuildId, UserId},
 };
 
-#[cfg(not(feature = "simd-json"))]
-use serde_json::Value;
-#[cfg(feature = "simd-json")]
-use simd_json::value::OwnedValue as Value;
-
 /// The error created when the members can not be fetched as configured.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -74,7 +65,7 @@ struct GetGuildMembersFields {
 /// Returns [`GetGuildMembersError::LimitInvalid`] if the limit is invalid.
 pub struct GetGuildMembers<'a> {
     fields: GetGuildMembersFields,
-    fut: Option<Pending<'a, Bytes>>,
+    fut: Option<Pending<'a, MemberListBody>>,
     guild_id: GuildId,
     http: &'a Client,
 }
@@ -121,47 +112,60 @@ impl<'a> GetGuildMembers<'a> {
         self
     }
 
+    /// Paginate through all members of the guild, ordered by user ID.
+    ///
+    /// Pagination starts after the user set via [`after`], if any. Pages of
+    /// the [`limit`] set on the request are requested, or 1000 members if no
+    /// limit was set.
+    ///
+    /// [`after`]: Self::after
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginate<'a, Member> {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_GUILD_MEMBERS_LIMIT_MAX);
+        let presences = self.fields.presences;
+
+        Paginate::new(
+            self.fields.after.map(|after| after.0),
+            page_size,
+            |member| member.user.id.0,
+            move |after, limit| {
+                let mut request = Self::new(http, guild_id);
+                request.fields = GetGuildMembersFields {
+                    after: after.map(UserId),
+                    limit: Some(limit),
+                    presences,
+                };
+
+                Box::pin(async move { request.await?.model() })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetGuildMembers {
-                    after: self.fields.after.map(|x| x.0),
-                    guild_id: self.guild_id.0,
-                    limit: self.fields.limit,
-                    presences: self.fields.presences,
-                },
-            ))));
+        let guild_id = self.guild_id;
+        let fut = self
+            .http
+            .request(Request::from(Route::GetGuildMembers {
+                after: self.fields.after.map(|x| x.0),
+                guild_id: guild_id.0,
+                limit: self.fields.limit,
+                presences: self.fields.presences,
+            }))
+            .map_ok(move |mut response| {
+                response.set_guild_id(guild_id);
+
+                response
+            });
+
+        self.fut.replace(Box::pin(fut));
 
         Ok(())
     }
 }
 
-impl Future for GetGuildMembers<'_> {
-    type Output = Result<Vec<Member>>;
-
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if self.fut.is_none() {
-            self.as_mut().start()?;
-        }
-
-        let fut = self.fut.as_mut().expect("future is created");
-
-        match fut.as_mut().poll(cx) {
-            Poll::Ready(res) => {
-                let bytes = res?;
-                let mut members = Vec::new();
-
-                let mut bytes = bytes.as_ref().to_vec();
-                let values = crate::json_from_slice::<Vec<Value>>(&mut bytes)?;
-
-                for value in values {
-                    let member_deserializer = MemberDeserializer::new(self.guild_id);
-                    members.push(member_deserializer.deserialize(value)?);
-                }
-
-                Poll::Ready(Ok(members))
-            }
-            Poll::Pending => Poll::Pending,
-        }
-    }
-}
+poll_req!(GetGuildMembers<'_>, MemberListBody);
@@ -3,7 +3,7 @@ use twilight_model::id::{GuildId, UserId};
 
 /// Kick a member from a guild, by their id.
 pub struct RemoveMember<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     user_id: UserId,
@@ -53,4 +53,4 @@ impl<'a> AuditLogReason for RemoveMember<'a> {
     }
 }
 
-poll_req!(RemoveMember<'_>, ());
+poll_req!(RemoveMember<'_>, EmptyBody);
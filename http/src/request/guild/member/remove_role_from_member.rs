@@ -3,7 +3,7 @@ use twilight_model::id::{GuildId, RoleId, UserId};
 
 /// Remove a role from a member in a guild, by id.
 pub struct RemoveRoleFromMember<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     role_id: RoleId,
@@ -62,4 +62,4 @@ impl<'a> AuditLogReason for RemoveRoleFromMember<'a> {
     }
 }
 
-poll_req!(RemoveRoleFromMember<'_>, ());
+poll_req!(RemoveRoleFromMember<'_>, EmptyBody);
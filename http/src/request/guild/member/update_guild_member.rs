@@ -51,7 +51,7 @@ struct UpdateGuildMemberFields {
 /// [the discord docs]: https://discord.com/developers/docs/resources/guild#modify-guild-member
 pub struct UpdateGuildMember<'a> {
     fields: UpdateGuildMemberFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     user_id: UserId,
@@ -160,4 +160,4 @@ impl<'a> AuditLogReason for UpdateGuildMember<'a> {
     }
 }
 
-poll_req!(UpdateGuildMember<'_>, ());
+poll_req!(UpdateGuildMember<'_>, EmptyBody);
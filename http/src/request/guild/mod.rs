@@ -1,3 +1,4 @@
+pub mod auto_moderation;
 pub mod ban;
 pub mod create_guild;
 pub mod create_guild_channel;
@@ -14,23 +15,29 @@ mod delete_guild;
 mod get_guild;
 mod get_guild_channels;
 mod get_guild_invites;
+mod get_guild_membership_screening;
 mod get_guild_preview;
 mod get_guild_vanity_url;
 mod get_guild_voice_regions;
 mod get_guild_webhooks;
+mod get_guild_welcome_screen;
 mod get_guild_widget;
 mod update_current_user_nick;
 mod update_guild_channel_positions;
+mod update_guild_membership_screening;
+mod update_guild_welcome_screen;
 mod update_guild_widget;
 
 pub use self::{
     create_guild::CreateGuild, create_guild_channel::CreateGuildChannel,
     create_guild_prune::CreateGuildPrune, delete_guild::DeleteGuild, get_audit_log::GetAuditLog,
     get_guild::GetGuild, get_guild_channels::GetGuildChannels, get_guild_invites::GetGuildInvites,
+    get_guild_membership_screening::GetGuildMembershipScreening,
     get_guild_preview::GetGuildPreview, get_guild_prune_count::GetGuildPruneCount,
     get_guild_vanity_url::GetGuildVanityUrl, get_guild_voice_regions::GetGuildVoiceRegions,
-    get_guild_webhooks::GetGuildWebhooks, get_guild_widget::GetGuildWidget,
-    update_current_user_nick::UpdateCurrentUserNick, update_guild::UpdateGuild,
-    update_guild_channel_positions::UpdateGuildChannelPositions,
-    update_guild_widget::UpdateGuildWidget,
+    get_guild_webhooks::GetGuildWebhooks, get_guild_welcome_screen::GetGuildWelcomeScreen,
+    get_guild_widget::GetGuildWidget, update_current_user_nick::UpdateCurrentUserNick,
+    update_guild::UpdateGuild, update_guild_channel_positions::UpdateGuildChannelPositions,
+    update_guild_membership_screening::UpdateGuildMembershipScreening,
+    update_guild_welcome_screen::UpdateGuildWelcomeScreen, update_guild_widget::UpdateGuildWidget,
 };
@@ -3,7 +3,7 @@ use twilight_model::id::{GuildId, RoleId};
 
 /// Delete a role in a guild, by id.
 pub struct DeleteRole<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     role_id: RoleId,
@@ -53,4 +53,4 @@ impl<'a> AuditLogReason for DeleteRole<'a> {
     }
 }
 
-poll_req!(DeleteRole<'_>, ());
+poll_req!(DeleteRole<'_>, EmptyBody);
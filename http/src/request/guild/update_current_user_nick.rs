@@ -9,7 +9,7 @@ struct UpdateCurrentUserNickFields {
 /// Changes the user's nickname in a guild.
 pub struct UpdateCurrentUserNick<'a> {
     fields: UpdateCurrentUserNickFields,
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
 }
@@ -36,4 +36,4 @@ impl<'a> UpdateCurrentUserNick<'a> {
     }
 }
 
-poll_req!(UpdateCurrentUserNick<'_>, ());
+poll_req!(UpdateCurrentUserNick<'_>, EmptyBody);
@@ -11,7 +11,7 @@ struct Position {
 ///
 /// The minimum amount of channels to modify, is a swap between two channels.
 pub struct UpdateGuildChannelPositions<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
     positions: Vec<Position>,
@@ -47,4 +47,4 @@ impl<'a> UpdateGuildChannelPositions<'a> {
     }
 }
 
-poll_req!(UpdateGuildChannelPositions<'_>, ());
+poll_req!(UpdateGuildChannelPositions<'_>, EmptyBody);
@@ -0,0 +1,80 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    guild::{MembershipScreening, MembershipScreeningField},
+    id::GuildId,
+};
+
+#[derive(Default, Serialize)]
+struct UpdateGuildMembershipScreeningFields {
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    /// Discord expects the fields as a JSON encoded string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form_fields: Option<String>,
+}
+
+/// Update a guild's membership screening form.
+///
+/// Requires the guild to have community features enabled.
+pub struct UpdateGuildMembershipScreening<'a> {
+    fields: UpdateGuildMembershipScreeningFields,
+    form_fields: Option<Vec<MembershipScreeningField>>,
+    fut: Option<Pending<'a, MembershipScreening>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> UpdateGuildMembershipScreening<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fields: UpdateGuildMembershipScreeningFields::default(),
+            form_fields: None,
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Set the description of the guild shown on the screening form.
+    pub fn description(mut self, description: impl Into<Option<String>>) -> Self {
+        self.fields.description.replace(description.into());
+
+        self
+    }
+
+    /// Set whether new members have to pass the screening.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.fields.enabled.replace(enabled);
+
+        self
+    }
+
+    /// Set the fields of the screening form.
+    pub fn form_fields(mut self, form_fields: Vec<MembershipScreeningField>) -> Self {
+        self.form_fields.replace(form_fields);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        if let Some(form_fields) = &self.form_fields {
+            self.fields
+                .form_fields
+                .replace(crate::json_to_string(form_fields)?);
+        }
+
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateGuildMembershipScreening {
+                guild_id: self.guild_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateGuildMembershipScreening<'_>, MembershipScreening);
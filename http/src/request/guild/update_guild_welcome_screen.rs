@@ -0,0 +1,71 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    guild::{WelcomeScreen, WelcomeScreenChannel},
+    id::GuildId,
+};
+
+#[derive(Default, Serialize)]
+struct UpdateGuildWelcomeScreenFields {
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    welcome_channels: Option<Vec<WelcomeScreenChannel>>,
+}
+
+/// Update a guild's welcome screen.
+///
+/// Requires the guild to have community features enabled.
+pub struct UpdateGuildWelcomeScreen<'a> {
+    fields: UpdateGuildWelcomeScreenFields,
+    fut: Option<Pending<'a, WelcomeScreen>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> UpdateGuildWelcomeScreen<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fields: UpdateGuildWelcomeScreenFields::default(),
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Set the description of the guild shown on the welcome screen.
+    pub fn description(mut self, description: impl Into<Option<String>>) -> Self {
+        self.fields.description.replace(description.into());
+
+        self
+    }
+
+    /// Set whether the welcome screen is shown to new members.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.fields.enabled.replace(enabled);
+
+        self
+    }
+
+    /// Set the channels shown on the welcome screen.
+    pub fn welcome_channels(mut self, welcome_channels: Vec<WelcomeScreenChannel>) -> Self {
+        self.fields.welcome_channels.replace(welcome_channels);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateGuildWelcomeScreen {
+                guild_id: self.guild_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateGuildWelcomeScreen<'_>, WelcomeScreen);
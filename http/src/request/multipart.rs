@@ -1,9 +1,30 @@
+use super::attachment::{AttachmentSource, Reader};
+use bytes::Bytes;
+use futures_util::{
+    future,
+    stream::{self, BoxStream, Stream, StreamExt},
+};
 use rand::{distributions::Alphanumeric, Rng};
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+use tokio::io::ReadBuf;
 
-#[derive(Debug)]
+/// Number of bytes read from a reader at a time.
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Chunk of the body of a form.
+#[derive(Clone, Debug)]
+enum Chunk {
+    /// Bytes in memory, such as headers and in-memory files.
+    Bytes(Bytes),
+    /// File contents read while the body is streamed.
+    Source(AttachmentSource),
+}
+
+#[derive(Clone, Debug)]
 pub struct Form {
     boundary: [u8; 15],
     buffer: Vec<u8>,
+    chunks: Vec<Chunk>,
 }
 
 impl Form {
@@ -11,12 +32,23 @@ impl Form {
         Self::default()
     }
 
-    pub fn build(mut self) -> Vec<u8> {
-        self.buffer.extend(b"\r\n");
-        self.boundary();
-        self.buffer.extend(b"--");
+    /// Length of the body in bytes.
+    ///
+    /// Returns `None` if a file is read from a reader, in which case the
+    /// length isn't known up front.
+    pub fn content_length(&self) -> Option<u64> {
+        let mut length = self.buffer.len() + Self::TRAILER_LEN;
+
+        for chunk in &self.chunks {
+            match chunk {
+                Chunk::Bytes(bytes) | Chunk::Source(AttachmentSource::Bytes(bytes)) => {
+                    length += bytes.len();
+                }
+                Chunk::Source(AttachmentSource::Reader(_)) => return None,
+            }
+        }
 
-        self.buffer
+        Some(length as u64)
     }
 
     pub fn content_type(&self) -> Vec<u8> {
@@ -29,11 +61,32 @@ impl Form {
         content_type
     }
 
-    pub fn file(&mut self, name: &[u8], filename: &[u8], data: &[u8]) -> &mut Self {
+    /// Whether the body can be sent more than once.
+    pub fn is_replayable(&self) -> bool {
+        self.chunks
+            .iter()
+            .all(|chunk| !matches!(chunk, Chunk::Source(AttachmentSource::Reader(_))))
+    }
+
+    pub fn file(
+        &mut self,
+        name: &[u8],
+        filename: &[u8],
+        content_type: Option<&[u8]>,
+        source: AttachmentSource,
+    ) -> &mut Self {
         self.start();
         self.name(name);
         self.filename(filename);
-        self.data(data);
+
+        if let Some(content_type) = content_type {
+            self.buffer.extend(b"\r\nContent-Type: ");
+            self.escaped(content_type);
+        }
+
+        self.buffer.extend(b"\r\n\r\n");
+        self.flush();
+        self.chunks.push(Chunk::Source(source));
 
         self
     }
@@ -46,6 +99,41 @@ impl Form {
         self
     }
 
+    /// Stream the body of the form.
+    ///
+    /// Files are read from their readers as the stream is polled.
+    pub fn stream(mut self) -> impl Stream<Item = IoResult<Bytes>> + Send + 'static {
+        self.buffer.extend(b"\r\n");
+        self.boundary();
+        self.buffer.extend(b"--");
+        self.flush();
+
+        stream::iter(self.chunks).flat_map(|chunk| -> BoxStream<'static, IoResult<Bytes>> {
+            match chunk {
+                Chunk::Bytes(bytes) | Chunk::Source(AttachmentSource::Bytes(bytes)) => {
+                    stream::once(future::ready(Ok(bytes))).boxed()
+                }
+                Chunk::Source(AttachmentSource::Reader(reader)) => {
+                    let reader = reader.lock().ok().and_then(|mut reader| reader.take());
+
+                    if let Some(reader) = reader {
+                        read(reader).boxed()
+                    } else {
+                        let error = IoError::new(
+                            IoErrorKind::Other,
+                            "attachment reader was already consumed",
+                        );
+
+                        stream::once(future::ready(Err(error))).boxed()
+                    }
+                }
+            }
+        })
+    }
+
+    /// Length of the trailing boundary added when the form is streamed.
+    const TRAILER_LEN: usize = 2 + 2 + 15 + 2;
+
     fn start(&mut self) {
         self.buffer.extend(b"\r\n");
         self.boundary();
@@ -59,20 +147,46 @@ impl Form {
 
     fn filename(&mut self, filename: &[u8]) {
         self.buffer.extend(br#"; filename=""#);
-        self.buffer.extend(filename);
+        self.escaped(filename);
         self.buffer.push(b'"');
     }
 
     fn name(&mut self, name: &[u8]) {
         self.buffer.extend(br#"; name=""#);
-        self.buffer.extend(name);
+        self.escaped(name);
         self.buffer.push(b'"');
     }
 
+    /// Add a header value, percent-encoding quotes and line breaks so that it
+    /// can't end the value or the headers of the part.
+    ///
+    /// Refer to [RFC 7578 § 4.2] and the [HTML standard] for the encoding.
+    ///
+    /// [RFC 7578 § 4.2]: https://datatracker.ietf.org/doc/html/rfc7578#section-4.2
+    /// [HTML standard]: https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart-form-data
+    fn escaped(&mut self, value: &[u8]) {
+        for byte in value {
+            match byte {
+                b'\r' => self.buffer.extend(b"%0D"),
+                b'\n' => self.buffer.extend(b"%0A"),
+                b'"' => self.buffer.extend(b"%22"),
+                byte => self.buffer.push(*byte),
+            }
+        }
+    }
+
     fn data(&mut self, data: &[u8]) {
         self.buffer.extend(b"\r\n\r\n");
         self.buffer.extend(data);
     }
+
+    /// Move the buffered bytes into a chunk.
+    fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            let bytes = Bytes::from(std::mem::take(&mut self.buffer));
+            self.chunks.push(Chunk::Bytes(bytes));
+        }
+    }
 }
 
 impl Default for Form {
@@ -87,6 +201,124 @@ impl Default for Form {
         Self {
             boundary,
             buffer: Vec::new(),
+            chunks: Vec::new(),
+        }
+    }
+}
+
+/// Stream the contents of a reader in chunks.
+fn read(reader: Reader) -> impl Stream<Item = IoResult<Bytes>> + Send + 'static {
+    stream::try_unfold(reader, |mut reader| async move {
+        let mut buffer = vec![0; READ_CHUNK_SIZE];
+
+        let filled = future::poll_fn(|cx| {
+            let mut buf = ReadBuf::new(&mut buffer);
+
+            reader
+                .as_mut()
+                .poll_read(cx, &mut buf)
+                .map_ok(|()| buf.filled().len())
+        })
+        .await?;
+
+        if filled == 0 {
+            return Ok(None);
         }
+
+        buffer.truncate(filled);
+
+        Ok(Some((Bytes::from(buffer), reader)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Form;
+    use crate::request::attachment::{AttachmentSource, Reader};
+    use bytes::Bytes;
+    use futures_util::stream::TryStreamExt;
+    use std::sync::{Arc, Mutex};
+
+    async fn collect(form: Form) -> Vec<u8> {
+        form.stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap()
+            .concat()
+    }
+
+    #[tokio::test]
+    async fn test_bytes_length() {
+        let mut form = Form::new();
+        form.file(
+            b"files[0]",
+            b"a.txt",
+            Some(&b"text/plain"[..]),
+            AttachmentSource::Bytes(Bytes::from_static(b"hello")),
+        );
+        form.part(b"payload_json", b"{}");
+
+        assert!(form.is_replayable());
+        let length = form.content_length().unwrap();
+        let body = collect(form.clone()).await;
+
+        assert_eq!(length, body.len() as u64);
+
+        let expected = format!(
+            "\r\n--{boundary}\r\nContent-Disposition: form-data; name=\"files[0]\"; \
+             filename=\"a.txt\"\r\nContent-Type: text/plain\r\n\r\nhello\
+             \r\n--{boundary}\r\nContent-Disposition: form-data; name=\"payload_json\"\
+             \r\n\r\n{{}}\r\n--{boundary}--",
+            boundary = std::str::from_utf8(&form.boundary).unwrap(),
+        );
+        assert_eq!(expected.as_bytes(), body.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_escaped_headers() {
+        let mut form = Form::new();
+        form.file(
+            b"files[0]",
+            b"a.txt\"\r\nContent-Type: text/html\r\n\r\n<script>",
+            Some(&b"text/plain\r\nX-Injected: \"yes\""[..]),
+            AttachmentSource::Bytes(Bytes::from_static(b"hello")),
+        );
+
+        let body = collect(form.clone()).await;
+
+        let expected = format!(
+            "\r\n--{boundary}\r\nContent-Disposition: form-data; name=\"files[0]\"; \
+             filename=\"a.txt%22%0D%0AContent-Type: text/html%0D%0A%0D%0A<script>\"\
+             \r\nContent-Type: text/plain%0D%0AX-Injected: %22yes%22\r\n\r\nhello\
+             \r\n--{boundary}--",
+            boundary = std::str::from_utf8(&form.boundary).unwrap(),
+        );
+        assert_eq!(expected.as_bytes(), body.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_reader() {
+        let contents = vec![7; super::READ_CHUNK_SIZE * 2 + 1];
+        let reader: Reader = Box::pin(std::io::Cursor::new(contents.clone()));
+        let shared = Arc::new(Mutex::new(Some(reader)));
+
+        let mut form = Form::new();
+        form.file(
+            b"files[0]",
+            b"a.bin",
+            None,
+            AttachmentSource::Reader(Arc::clone(&shared)),
+        );
+
+        assert!(!form.is_replayable());
+        assert!(form.content_length().is_none());
+
+        let body = collect(form.clone()).await;
+        assert!(body
+            .windows(contents.len())
+            .any(|window| window == contents.as_slice()));
+
+        // The reader was taken by the first upload.
+        assert!(form.stream().try_collect::<Vec<_>>().await.is_err());
     }
 }
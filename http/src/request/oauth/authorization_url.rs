@@ -0,0 +1,221 @@
+use super::{encode_params, join_scopes};
+use twilight_model::{
+    guild::Permissions,
+    id::{ApplicationId, GuildId},
+    oauth::Scope,
+};
+
+/// Whether users are prompted to authorize an application again.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AuthorizationPrompt {
+    /// Always prompt users, even if they already authorized the application.
+    Consent,
+    /// Skip the prompt if users already authorized the application with the
+    /// requested scopes.
+    None,
+}
+
+impl AuthorizationPrompt {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Consent => "consent",
+            Self::None => "none",
+        }
+    }
+}
+
+/// URL users are sent to in order to authorize an application via the `OAuth2`
+/// authorization code flow.
+///
+/// Once authorized, users are redirected to the [redirect URI] with a `code`
+/// query parameter, which can be exchanged for an access token via
+/// [`Client::exchange_code`].
+///
+/// # Examples
+///
+/// Create a URL for users to log in with their identity and guilds:
+///
+/// ```rust
+/// use twilight_http::request::oauth::AuthorizationUrl;
+/// use twilight_model::{id::ApplicationId, oauth::Scope};
+///
+/// let url = AuthorizationUrl::new(ApplicationId(1))
+///     .redirect_uri("https://example.com/callback")
+///     .scopes(vec![Scope::Identify, Scope::Guilds])
+///     .state("a random value")
+///     .build();
+///
+/// assert_eq!(
+///     "https://discord.com/api/oauth2/authorize?response_type=code&client_id=1\
+///      &scope=identify%20guilds&redirect_uri=https%3A%2F%2Fexample%2Ecom%2Fcallback\
+///      &state=a%20random%20value",
+///     url,
+/// );
+/// ```
+///
+/// [`Client::exchange_code`]: crate::Client::exchange_code
+/// [redirect URI]: Self::redirect_uri
+#[derive(Clone, Debug)]
+pub struct AuthorizationUrl {
+    client_id: ApplicationId,
+    disable_guild_select: bool,
+    guild_id: Option<GuildId>,
+    permissions: Option<Permissions>,
+    prompt: Option<AuthorizationPrompt>,
+    redirect_uri: Option<String>,
+    scopes: Vec<Scope>,
+    state: Option<String>,
+}
+
+impl AuthorizationUrl {
+    /// Base URL of the authorization page.
+    pub const BASE: &'static str = "https://discord.com/api/oauth2/authorize";
+
+    /// Create a new authorization URL for an application.
+    pub fn new(client_id: ApplicationId) -> Self {
+        Self {
+            client_id,
+            disable_guild_select: false,
+            guild_id: None,
+            permissions: None,
+            prompt: None,
+            redirect_uri: None,
+            scopes: Vec::new(),
+            state: None,
+        }
+    }
+
+    /// Build the URL.
+    pub fn build(&self) -> String {
+        let client_id = self.client_id.to_string();
+        let scopes = join_scopes(&self.scopes);
+        let guild_id = self.guild_id.map(|id| id.to_string());
+        let permissions = self.permissions.map(|p| p.bits().to_string());
+
+        let mut params = vec![
+            ("response_type", "code"),
+            ("client_id", client_id.as_str()),
+            ("scope", scopes.as_str()),
+        ];
+
+        if let Some(redirect_uri) = self.redirect_uri.as_deref() {
+            params.push(("redirect_uri", redirect_uri));
+        }
+
+        if let Some(state) = self.state.as_deref() {
+            params.push(("state", state));
+        }
+
+        if let Some(prompt) = self.prompt {
+            params.push(("prompt", prompt.name()));
+        }
+
+        if let Some(permissions) = permissions.as_deref() {
+            params.push(("permissions", permissions));
+        }
+
+        if let Some(guild_id) = guild_id.as_deref() {
+            params.push(("guild_id", guild_id));
+        }
+
+        if self.disable_guild_select {
+            params.push(("disable_guild_select", "true"));
+        }
+
+        format!("{}?{}", Self::BASE, encode_params(&params))
+    }
+
+    /// Set whether users can't change the guild pre-selected via
+    /// [`guild_id`].
+    ///
+    /// [`guild_id`]: Self::guild_id
+    pub fn disable_guild_select(mut self, disable_guild_select: bool) -> Self {
+        self.disable_guild_select = disable_guild_select;
+
+        self
+    }
+
+    /// Set the guild to pre-select when adding a bot via the [`Bot`] scope.
+    ///
+    /// [`Bot`]: Scope::Bot
+    pub fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id.replace(guild_id);
+
+        self
+    }
+
+    /// Set the permissions to request for a bot added via the [`Bot`] scope.
+    ///
+    /// [`Bot`]: Scope::Bot
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions.replace(permissions);
+
+        self
+    }
+
+    /// Set whether users are prompted to authorize the application again.
+    pub fn prompt(mut self, prompt: AuthorizationPrompt) -> Self {
+        self.prompt.replace(prompt);
+
+        self
+    }
+
+    /// Set the URI users are redirected to after authorizing the application.
+    ///
+    /// Must be one of the redirect URIs registered for the application.
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+        self.redirect_uri.replace(redirect_uri.into());
+
+        self
+    }
+
+    /// Set the scopes to request.
+    pub fn scopes(mut self, scopes: impl IntoIterator<Item = Scope>) -> Self {
+        self.scopes = scopes.into_iter().collect();
+
+        self
+    }
+
+    /// Set a value that is passed back to the redirect URI unchanged.
+    ///
+    /// A unique value per authorization should be used to prevent cross-site
+    /// request forgery.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state.replace(state.into());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizationPrompt, AuthorizationUrl};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        guild::Permissions,
+        id::{ApplicationId, GuildId},
+        oauth::Scope,
+    };
+
+    assert_impl_all!(AuthorizationUrl: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_bot() {
+        let url = AuthorizationUrl::new(ApplicationId(1))
+            .scopes(vec![Scope::Bot, Scope::ApplicationsCommands])
+            .permissions(Permissions::SEND_MESSAGES)
+            .guild_id(GuildId(2))
+            .disable_guild_select(true)
+            .prompt(AuthorizationPrompt::Consent)
+            .build();
+
+        assert_eq!(
+            "https://discord.com/api/oauth2/authorize?response_type=code&client_id=1\
+             &scope=bot%20applications%2Ecommands&prompt=consent&permissions=2048\
+             &guild_id=2&disable_guild_select=true",
+            url,
+        );
+    }
+}
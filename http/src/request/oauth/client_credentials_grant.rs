@@ -0,0 +1,55 @@
+use super::{join_scopes, Credentials};
+use crate::request::prelude::*;
+use twilight_model::{
+    id::ApplicationId,
+    oauth::{AccessToken, Scope},
+};
+
+/// Get an access token for the user owning the application via the client
+/// credentials grant.
+///
+/// This is mostly useful for testing. If the application is owned by a team
+/// then the scopes are limited to [`Identify`] and
+/// [`ApplicationsCommandsUpdate`]. The access token has no refresh token.
+///
+/// [`ApplicationsCommandsUpdate`]: twilight_model::oauth::Scope::ApplicationsCommandsUpdate
+/// [`Identify`]: twilight_model::oauth::Scope::Identify
+pub struct ClientCredentialsGrant<'a> {
+    credentials: Credentials,
+    fut: Option<Pending<'a, AccessToken>>,
+    http: &'a Client,
+    scopes: Vec<Scope>,
+}
+
+impl<'a> ClientCredentialsGrant<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        scopes: impl IntoIterator<Item = Scope>,
+    ) -> Self {
+        Self {
+            credentials: Credentials {
+                client_id,
+                client_secret: client_secret.into(),
+            },
+            fut: None,
+            http,
+            scopes: scopes.into_iter().collect(),
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let scopes = join_scopes(&self.scopes);
+        let request = self.credentials.request(
+            &[("grant_type", "client_credentials"), ("scope", &scopes)],
+            Route::CreateOauthToken,
+        )?;
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(ClientCredentialsGrant<'_>, AccessToken);
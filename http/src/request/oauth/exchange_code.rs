@@ -0,0 +1,81 @@
+use super::Credentials;
+use crate::request::prelude::*;
+use twilight_model::{id::ApplicationId, oauth::AccessToken};
+
+/// Exchange an authorization code for an access token.
+///
+/// The code is received by the redirect URI once a user authorized the
+/// application via an [`AuthorizationUrl`]. The redirect URI must match the
+/// one used in the authorization URL.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::ApplicationId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::builder().build();
+///
+/// let token = client
+///     .exchange_code(
+///         ApplicationId(1),
+///         "client secret",
+///         "code from the redirect",
+///         "https://example.com/callback",
+///     )
+///     .await?
+///     .model()?;
+///
+/// let user_client = Client::new(format!("Bearer {}", token.access_token));
+/// let guilds = user_client.current_user_guilds().await?.model()?;
+/// # Ok(()) }
+/// ```
+///
+/// [`AuthorizationUrl`]: super::AuthorizationUrl
+pub struct ExchangeCode<'a> {
+    code: String,
+    credentials: Credentials,
+    fut: Option<Pending<'a, AccessToken>>,
+    http: &'a Client,
+    redirect_uri: String,
+}
+
+impl<'a> ExchangeCode<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        code: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            credentials: Credentials {
+                client_id,
+                client_secret: client_secret.into(),
+            },
+            fut: None,
+            http,
+            redirect_uri: redirect_uri.into(),
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = self.credentials.request(
+            &[
+                ("grant_type", "authorization_code"),
+                ("code", &self.code),
+                ("redirect_uri", &self.redirect_uri),
+            ],
+            Route::CreateOauthToken,
+        )?;
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(ExchangeCode<'_>, AccessToken);
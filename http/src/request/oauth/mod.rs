@@ -0,0 +1,153 @@
+//! `OAuth2` authorization flows and management of access tokens.
+//!
+//! Users are sent to an [`AuthorizationUrl`] to authorize the application.
+//! The authorization code Discord redirects them back with is then exchanged
+//! for an [`AccessToken`] via [`Client::exchange_code`]. Access tokens can be
+//! refreshed via [`Client::refresh_access_token`] once they expire, and
+//! revoked via [`Client::revoke_token`].
+//!
+//! Requests on behalf of the user are made by a client using the access token
+//! as a `Bearer ` token, such as getting the [guilds of the user]. Bots can
+//! add the user to a guild via [`Client::add_guild_member`] using an access
+//! token with the [`GuildsJoin`] scope.
+//!
+//! [`AccessToken`]: twilight_model::oauth::AccessToken
+//! [`Client::add_guild_member`]: crate::Client::add_guild_member
+//! [`Client::exchange_code`]: crate::Client::exchange_code
+//! [`Client::refresh_access_token`]: crate::Client::refresh_access_token
+//! [`Client::revoke_token`]: crate::Client::revoke_token
+//! [`GuildsJoin`]: twilight_model::oauth::Scope::GuildsJoin
+//! [guilds of the user]: crate::Client::current_user_guilds
+
+mod authorization_url;
+mod client_credentials_grant;
+mod exchange_code;
+mod refresh_access_token;
+mod revoke_token;
+
+pub use self::{
+    authorization_url::{AuthorizationPrompt, AuthorizationUrl},
+    client_credentials_grant::ClientCredentialsGrant,
+    exchange_code::ExchangeCode,
+    refresh_access_token::RefreshAccessToken,
+    revoke_token::RevokeToken,
+};
+
+use crate::{
+    error::{Error, Result},
+    request::Request,
+    routing::Route,
+};
+use hyper::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use percent_encoding::{utf8_percent_encode, PercentEncode, NON_ALPHANUMERIC};
+use twilight_model::{id::ApplicationId, oauth::Scope};
+
+/// Credentials of an application, used to authenticate token requests.
+#[derive(Clone, Debug)]
+struct Credentials {
+    client_id: ApplicationId,
+    client_secret: String,
+}
+
+impl Credentials {
+    /// Create a request to an `OAuth2` token endpoint with URL encoded
+    /// parameters.
+    ///
+    /// The request is authenticated with the credentials via HTTP basic
+    /// authentication instead of the client's token. The credentials are
+    /// encoded like parameters before being joined, as required by
+    /// [RFC 6749 § 2.3.1].
+    ///
+    /// [RFC 6749 § 2.3.1]: https://datatracker.ietf.org/doc/html/rfc6749#section-2.3.1
+    fn request(&self, params: &[(&str, &str)], route: Route) -> Result<Request> {
+        let credentials = format!(
+            "{}:{}",
+            encode(&self.client_id.to_string()),
+            encode(&self.client_secret),
+        );
+        let authorization = format!("Basic {}", base64::encode(credentials));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&authorization).map_err(|source| Error::CreatingHeader {
+                name: AUTHORIZATION.to_string(),
+                source,
+            })?,
+        );
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+
+        Ok(Request::from((
+            encode_params(params).into_bytes(),
+            headers,
+            route,
+        )))
+    }
+}
+
+/// Percent-encode a value of an `application/x-www-form-urlencoded` string.
+fn encode(value: &str) -> PercentEncode<'_> {
+    utf8_percent_encode(value, NON_ALPHANUMERIC)
+}
+
+/// Encode parameters as an `application/x-www-form-urlencoded` string.
+fn encode_params(params: &[(&str, &str)]) -> String {
+    params
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Join scopes into the space-separated list used by the API.
+fn join_scopes(scopes: &[Scope]) -> String {
+    scopes
+        .iter()
+        .map(|scope| scope.name())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_params, join_scopes, Credentials};
+    use crate::routing::Route;
+    use hyper::header::AUTHORIZATION;
+    use twilight_model::{id::ApplicationId, oauth::Scope};
+
+    #[test]
+    fn test_credentials_encoded() {
+        let credentials = Credentials {
+            client_id: ApplicationId(1),
+            client_secret: "a:b c+".to_owned(),
+        };
+
+        let request = credentials.request(&[], Route::CreateOauthToken).unwrap();
+        let authorization = request.headers.unwrap()[AUTHORIZATION].clone();
+
+        // "1:a%3Ab%20c%2B"
+        assert_eq!("Basic MTphJTNBYiUyMGMlMkI=", authorization);
+    }
+
+    #[test]
+    fn test_encode_params() {
+        assert_eq!(
+            "grant_type=authorization%5Fcode&redirect_uri=https%3A%2F%2Fexample%2Ecom%2F",
+            encode_params(&[
+                ("grant_type", "authorization_code"),
+                ("redirect_uri", "https://example.com/"),
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_join_scopes() {
+        assert_eq!(
+            "identify guilds.join",
+            join_scopes(&[Scope::Identify, Scope::GuildsJoin]),
+        );
+    }
+}
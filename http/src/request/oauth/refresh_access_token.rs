@@ -0,0 +1,49 @@
+use super::Credentials;
+use crate::request::prelude::*;
+use twilight_model::{id::ApplicationId, oauth::AccessToken};
+
+/// Refresh an access token using its refresh token.
+///
+/// The previous access token is invalidated and a new refresh token is
+/// returned alongside the new access token.
+pub struct RefreshAccessToken<'a> {
+    credentials: Credentials,
+    fut: Option<Pending<'a, AccessToken>>,
+    http: &'a Client,
+    refresh_token: String,
+}
+
+impl<'a> RefreshAccessToken<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> Self {
+        Self {
+            credentials: Credentials {
+                client_id,
+                client_secret: client_secret.into(),
+            },
+            fut: None,
+            http,
+            refresh_token: refresh_token.into(),
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = self.credentials.request(
+            &[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", &self.refresh_token),
+            ],
+            Route::CreateOauthToken,
+        )?;
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(RefreshAccessToken<'_>, AccessToken);
@@ -0,0 +1,45 @@
+use super::Credentials;
+use crate::request::prelude::*;
+use twilight_model::id::ApplicationId;
+
+/// Revoke an access token or refresh token.
+///
+/// Revoking either token of an authorization revokes the authorization as a
+/// whole.
+pub struct RevokeToken<'a> {
+    credentials: Credentials,
+    fut: Option<Pending<'a, EmptyBody>>,
+    http: &'a Client,
+    token: String,
+}
+
+impl<'a> RevokeToken<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            credentials: Credentials {
+                client_id,
+                client_secret: client_secret.into(),
+            },
+            fut: None,
+            http,
+            token: token.into(),
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = self
+            .credentials
+            .request(&[("token", &self.token)], Route::RevokeOauthToken)?;
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(RevokeToken<'_>, EmptyBody);
@@ -0,0 +1,301 @@
+use crate::error::Result;
+use futures_util::stream::Stream;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    vec::IntoIter,
+};
+
+/// Future resolving to the items of a page.
+pub(crate) type PendingPage<'a, T> = Pin<Box<dyn Future<Output = Result<Vec<T>>> + Send + 'a>>;
+
+/// Function requesting a page of items, given the cursor and the maximum
+/// number of items to get.
+type FetchPage<'a, T> = dyn FnMut(Option<u64>, u64) -> PendingPage<'a, T> + Send + 'a;
+
+/// Stream of the items of a paginated request.
+///
+/// Successive pages are requested using the ID of the last item of the
+/// previous page as the cursor, until a page is shorter than requested or the
+/// [`total`] number of items has been yielded. If a request fails then its
+/// error is yielded and the stream ends.
+///
+/// Pages are requested with the maximum allowed number of items, unless the
+/// request was configured with a smaller limit.
+///
+/// Created via the `paginate` method of requests, such as
+/// [`GetChannelMessages::paginate`].
+///
+/// # Examples
+///
+/// Collect the IDs of all members of a guild:
+///
+/// ```rust,no_run
+/// use futures_util::stream::TryStreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::id::GuildId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let members = client
+///     .guild_members(GuildId(1))
+///     .paginate()
+///     .try_collect::<Vec<_>>()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`GetChannelMessages::paginate`]: super::channel::message::GetChannelMessages::paginate
+/// [`total`]: Self::total
+pub struct Paginate<'a, T> {
+    cursor: Option<u64>,
+    cursor_of: fn(&T) -> u64,
+    done: bool,
+    ascending: bool,
+    fetch: Box<FetchPage<'a, T>>,
+    fut: Option<PendingPage<'a, T>>,
+    page: IntoIter<T>,
+    page_size: u64,
+    requested: u64,
+    total: Option<u64>,
+    yielded: u64,
+}
+
+impl<'a, T> Paginate<'a, T> {
+    pub(crate) fn new(
+        cursor: Option<u64>,
+        page_size: u64,
+        cursor_of: fn(&T) -> u64,
+        fetch: impl FnMut(Option<u64>, u64) -> PendingPage<'a, T> + Send + 'a,
+    ) -> Self {
+        Self {
+            cursor,
+            cursor_of,
+            done: false,
+            ascending: false,
+            fetch: Box::new(fetch),
+            fut: None,
+            page: Vec::new().into_iter(),
+            page_size,
+            requested: 0,
+            total: None,
+            yielded: 0,
+        }
+    }
+
+    /// Paginate towards newer items, using the highest cursor of a page
+    /// rather than the one of its last item.
+    ///
+    /// Used for endpoints that return pages in descending order while
+    /// paginating with an `after` cursor.
+    pub(crate) fn ascending(mut self) -> Self {
+        self.ascending = true;
+
+        self
+    }
+
+    /// Set the maximum total number of items to yield.
+    ///
+    /// The last page is requested with only as many items as are still
+    /// needed.
+    pub fn total(mut self, total: u64) -> Self {
+        self.total.replace(total);
+
+        self
+    }
+
+    /// Number of items to request in the next page.
+    fn next_limit(&self) -> u64 {
+        self.total.map_or(self.page_size, |total| {
+            total.saturating_sub(self.yielded).min(self.page_size)
+        })
+    }
+}
+
+impl<T> Debug for Paginate<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Paginate")
+            .field("cursor", &self.cursor)
+            .field("done", &self.done)
+            .field("page_size", &self.page_size)
+            .field("total", &self.total)
+            .field("yielded", &self.yielded)
+            .finish()
+    }
+}
+
+// The items are never pinned, only the boxed future of the current page.
+impl<T> Unpin for Paginate<'_, T> {}
+
+impl<T> Stream for Paginate<'_, T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.total.map_or(false, |total| self.yielded >= total) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = self.page.next() {
+                self.yielded += 1;
+
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            if self.fut.is_none() {
+                let limit = self.next_limit();
+                let cursor = self.cursor;
+                let fut = (self.fetch)(cursor, limit);
+
+                self.requested = limit;
+                self.fut.replace(fut);
+            }
+
+            let fut = self.fut.as_mut().expect("future is created");
+
+            let page = match fut.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => page,
+                Poll::Ready(Err(source)) => {
+                    self.done = true;
+                    self.fut.take();
+
+                    return Poll::Ready(Some(Err(source)));
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            self.fut.take();
+
+            // A short page is the last one.
+            if (page.len() as u64) < self.requested {
+                self.done = true;
+            }
+
+            let cursor = if self.ascending {
+                page.iter().map(self.cursor_of).max()
+            } else {
+                page.last().map(self.cursor_of)
+            };
+
+            if cursor.is_some() {
+                self.cursor = cursor;
+            } else {
+                self.done = true;
+            }
+
+            self.page = page.into_iter();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Paginate;
+    use futures_util::stream::{Stream, TryStreamExt};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::TryFrom,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+
+    type Requests = Arc<Mutex<Vec<(u64, u64)>>>;
+
+    assert_impl_all!(Paginate<'_, u64>: Debug, Send, Stream, Unpin);
+
+    /// Create a stream over the IDs from 1 to `count`, recording the cursor
+    /// and limit of each requested page.
+    fn ids(count: u64, page_size: u64) -> (Paginate<'static, u64>, Requests) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        let paginate = Paginate::new(
+            None,
+            page_size,
+            |id| *id,
+            move |after, limit| {
+                let after = after.unwrap_or(0);
+                recorded.lock().unwrap().push((after, limit));
+                let page = (after + 1..=count)
+                    .take(usize::try_from(limit).unwrap())
+                    .collect();
+
+                Box::pin(async move { Ok(page) })
+            },
+        );
+
+        (paginate, requests)
+    }
+
+    #[tokio::test]
+    async fn test_paginate_until_short_page() {
+        let (paginate, requests) = ids(5, 2);
+        let items = paginate.try_collect::<Vec<_>>().await.unwrap();
+
+        assert_eq!(vec![1, 2, 3, 4, 5], items);
+        assert_eq!(vec![(0, 2), (2, 2), (4, 2)], *requests.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_until_empty_page() {
+        let (paginate, requests) = ids(4, 2);
+        let items = paginate.try_collect::<Vec<_>>().await.unwrap();
+
+        assert_eq!(vec![1, 2, 3, 4], items);
+        assert_eq!(vec![(0, 2), (2, 2), (4, 2)], *requests.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_total() {
+        let (paginate, requests) = ids(10, 3);
+        let items = paginate.total(5).try_collect::<Vec<_>>().await.unwrap();
+
+        assert_eq!(vec![1, 2, 3, 4, 5], items);
+        assert_eq!(vec![(0, 3), (3, 2)], *requests.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_ascending() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        // Pages are returned newest first, like messages after a cursor.
+        let paginate = Paginate::new(
+            Some(0),
+            2,
+            |id| *id,
+            move |after, limit| {
+                let after = after.unwrap_or(0);
+                recorded.lock().unwrap().push((after, limit));
+                let mut page = (after + 1..=5)
+                    .take(usize::try_from(limit).unwrap())
+                    .collect::<Vec<_>>();
+                page.reverse();
+
+                Box::pin(async move { Ok(page) })
+            },
+        )
+        .ascending();
+        let items = paginate.try_collect::<Vec<_>>().await.unwrap();
+
+        assert_eq!(vec![2, 1, 4, 3, 5], items);
+        assert_eq!(vec![(0, 2), (2, 2), (4, 2)], *requests.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_total_zero() {
+        let (paginate, requests) = ids(10, 3);
+        let items = paginate.total(0).try_collect::<Vec<_>>().await.unwrap();
+
+        assert!(items.is_empty());
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
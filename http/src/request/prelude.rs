@@ -1,12 +1,21 @@
-pub(super) use super::{audit_header, validate, Pending, PendingOption, Request};
+pub(super) use super::{audit_header, validate, Pending, Request};
 pub use super::{
     audit_reason::{AuditLogReason, AuditLogReasonError},
     channel::{invite::*, message::*, reaction::*, webhook::*, *},
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_voice_regions::GetVoiceRegions,
-    guild::{ban::*, emoji::*, integration::*, member::*, role::*, *},
+    guild::{auto_moderation::*, ban::*, emoji::*, integration::*, member::*, role::*, *},
+    oauth::*,
+    scheduled_event::*,
+    sticker::*,
+    template::*,
     user::*,
 };
-pub(super) use crate::{client::Client, error::Result, routing::Route};
+pub(super) use crate::{
+    client::Client,
+    error::Result,
+    response::{EmptyBody, MemberBody, MemberListBody},
+    routing::Route,
+};
 pub(super) use serde::Serialize;
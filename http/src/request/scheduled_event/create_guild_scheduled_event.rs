@@ -0,0 +1,229 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{ChannelId, GuildId},
+    scheduled_event::{
+        EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule,
+    },
+};
+
+/// The error returned when the scheduled event can not be created as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildScheduledEventError {
+    /// The description is empty or more than 1000 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The name is empty or more than 100 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for CreateGuildScheduledEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => {
+                f.write_str("the scheduled event description is invalid")
+            }
+            Self::NameInvalid { .. } => f.write_str("the scheduled event name is invalid"),
+        }
+    }
+}
+
+impl Error for CreateGuildScheduledEventError {}
+
+#[derive(Serialize)]
+struct CreateGuildScheduledEventFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadata>,
+    entity_type: EntityType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    name: String,
+    privacy_level: PrivacyLevel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<RecurrenceRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<String>,
+    scheduled_start_time: String,
+}
+
+/// Create a scheduled event in a guild.
+///
+/// Events held in a [`StageInstance`] or [`Voice`] channel require a
+/// [`channel_id`], while [`External`] events require a [`location`] and a
+/// [`scheduled_end_time`].
+///
+/// Requires the [`MANAGE_EVENTS`] permission.
+///
+/// [`External`]: EntityType::External
+/// [`MANAGE_EVENTS`]: twilight_model::guild::Permissions::MANAGE_EVENTS
+/// [`StageInstance`]: EntityType::StageInstance
+/// [`Voice`]: EntityType::Voice
+/// [`channel_id`]: Self::channel_id
+/// [`location`]: Self::location
+/// [`scheduled_end_time`]: Self::scheduled_end_time
+pub struct CreateGuildScheduledEvent<'a> {
+    fields: CreateGuildScheduledEventFields,
+    fut: Option<Pending<'a, GuildScheduledEvent>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> CreateGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        entity_type: EntityType,
+        scheduled_start_time: impl Into<String>,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        Self::_new(
+            http,
+            guild_id,
+            name.into(),
+            entity_type,
+            scheduled_start_time.into(),
+        )
+    }
+
+    fn _new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: String,
+        entity_type: EntityType,
+        scheduled_start_time: String,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        if !validate::scheduled_event_name(&name) {
+            return Err(CreateGuildScheduledEventError::NameInvalid { name });
+        }
+
+        Ok(Self {
+            fields: CreateGuildScheduledEventFields {
+                channel_id: None,
+                description: None,
+                entity_metadata: None,
+                entity_type,
+                image: None,
+                name,
+                privacy_level: PrivacyLevel::GuildOnly,
+                recurrence_rule: None,
+                scheduled_end_time: None,
+                scheduled_start_time,
+            },
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        })
+    }
+
+    /// Set the ID of the stage or voice channel the event is held in.
+    pub fn channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.fields.channel_id.replace(channel_id);
+
+        self
+    }
+
+    /// Set the description of the event.
+    ///
+    /// The description must be between 1 and 1000 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildScheduledEventError::DescriptionInvalid`] if the
+    /// description is invalid.
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, CreateGuildScheduledEventError> {
+        if !validate::scheduled_event_description(&description) {
+            return Err(CreateGuildScheduledEventError::DescriptionInvalid { description });
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the cover image of the event.
+    ///
+    /// This must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}` where `{type}` is the image MIME
+    /// type and `{data}` is the base64-encoded image.
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.fields.image.replace(image.into());
+
+        self
+    }
+
+    /// Set the location of an event held outside of Discord.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.fields.entity_metadata.replace(EntityMetadata {
+            location: Some(location.into()),
+        });
+
+        self
+    }
+
+    /// Set the rule defining how often the event recurs.
+    pub fn recurrence_rule(mut self, recurrence_rule: RecurrenceRule) -> Self {
+        self.fields.recurrence_rule.replace(recurrence_rule);
+
+        self
+    }
+
+    /// Set the timestamp of when the event is scheduled to end.
+    pub fn scheduled_end_time(mut self, scheduled_end_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_end_time
+            .replace(scheduled_end_time.into());
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let body = crate::json_to_vec(&self.fields)?;
+        let route = Route::CreateGuildScheduledEvent {
+            guild_id: self.guild_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            Request::from((body, audit_header(&reason)?, route))
+        } else {
+            Request::from((body, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for CreateGuildScheduledEvent<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(CreateGuildScheduledEvent<'_>, GuildScheduledEvent);
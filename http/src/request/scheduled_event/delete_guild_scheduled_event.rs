@@ -0,0 +1,42 @@
+use crate::request::prelude::*;
+use twilight_model::id::{GuildId, ScheduledEventId};
+
+/// Delete a scheduled event of a guild.
+///
+/// Requires the [`MANAGE_EVENTS`] permission.
+///
+/// [`MANAGE_EVENTS`]: twilight_model::guild::Permissions::MANAGE_EVENTS
+pub struct DeleteGuildScheduledEvent<'a> {
+    fut: Option<Pending<'a, EmptyBody>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> DeleteGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.verify(Request::from(
+            Route::DeleteGuildScheduledEvent {
+                guild_id: self.guild_id.0,
+                scheduled_event_id: self.scheduled_event_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(DeleteGuildScheduledEvent<'_>, EmptyBody);
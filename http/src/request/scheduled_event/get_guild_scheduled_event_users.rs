@@ -0,0 +1,163 @@
+use crate::request::{prelude::*, Paginate};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{GuildId, ScheduledEventId, UserId},
+    scheduled_event::GuildScheduledEventUser,
+};
+
+/// The error returned when the users can not be fetched as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetGuildScheduledEventUsersError {
+    /// The limit is either 0 or more than 100.
+    LimitInvalid {
+        /// Provided limit.
+        limit: u64,
+    },
+}
+
+impl Display for GetGuildScheduledEventUsersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        }
+    }
+}
+
+impl Error for GetGuildScheduledEventUsersError {}
+
+#[derive(Default)]
+struct GetGuildScheduledEventUsersFields {
+    after: Option<UserId>,
+    before: Option<UserId>,
+    limit: Option<u64>,
+    with_member: Option<bool>,
+}
+
+/// Get the users subscribed to a scheduled event of a guild, ordered by user
+/// ID.
+///
+/// The upper limit to this request is 100. Discord defaults the limit to 100.
+pub struct GetGuildScheduledEventUsers<'a> {
+    fields: GetGuildScheduledEventUsersFields,
+    fut: Option<Pending<'a, Vec<GuildScheduledEventUser>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> GetGuildScheduledEventUsers<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: GetGuildScheduledEventUsersFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Set the user ID to get users after.
+    pub fn after(mut self, after: UserId) -> Self {
+        self.fields.after.replace(after);
+
+        self
+    }
+
+    /// Set the user ID to get users before.
+    pub fn before(mut self, before: UserId) -> Self {
+        self.fields.before.replace(before);
+
+        self
+    }
+
+    /// Set the maximum number of users to retrieve.
+    ///
+    /// The limit must be greater than 0 and at most 100.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetGuildScheduledEventUsersError::LimitInvalid`] if the
+    /// limit is invalid.
+    pub fn limit(mut self, limit: u64) -> Result<Self, GetGuildScheduledEventUsersError> {
+        if !validate::get_scheduled_event_users_limit(limit) {
+            return Err(GetGuildScheduledEventUsersError::LimitInvalid { limit });
+        }
+
+        self.fields.limit.replace(limit);
+
+        Ok(self)
+    }
+
+    /// Set whether to include the guild member data of the users.
+    pub fn with_member(mut self, with_member: bool) -> Self {
+        self.fields.with_member.replace(with_member);
+
+        self
+    }
+
+    /// Paginate through all users subscribed to the event, ordered by user
+    /// ID.
+    ///
+    /// Pagination starts after the user set via [`after`], if any, and
+    /// ignores the user set via [`before`]. Pages of the [`limit`] set on the
+    /// request are requested, or 100 users if no limit was set.
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginate<'a, GuildScheduledEventUser> {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let scheduled_event_id = self.scheduled_event_id;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_SCHEDULED_EVENT_USERS_LIMIT_MAX);
+        let with_member = self.fields.with_member;
+
+        Paginate::new(
+            self.fields.after.map(|after| after.0),
+            page_size,
+            |user| user.user.id.0,
+            move |after, limit| {
+                let mut request = Self::new(http, guild_id, scheduled_event_id);
+                request.fields = GetGuildScheduledEventUsersFields {
+                    after: after.map(UserId),
+                    before: None,
+                    limit: Some(limit),
+                    with_member,
+                };
+
+                Box::pin(async move { request.await?.model() })
+            },
+        )
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildScheduledEventUsers {
+                after: self.fields.after.map(|x| x.0),
+                before: self.fields.before.map(|x| x.0),
+                guild_id: self.guild_id.0,
+                limit: self.fields.limit,
+                scheduled_event_id: self.scheduled_event_id.0,
+                with_member: self.fields.with_member,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(
+    GetGuildScheduledEventUsers<'_>,
+    Vec<GuildScheduledEventUser>
+);
@@ -0,0 +1,265 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{ChannelId, GuildId, ScheduledEventId},
+    scheduled_event::{
+        EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule, Status,
+    },
+};
+
+/// The error returned when the scheduled event can not be updated as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateGuildScheduledEventError {
+    /// The description is empty or more than 1000 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The name is empty or more than 100 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for UpdateGuildScheduledEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => {
+                f.write_str("the scheduled event description is invalid")
+            }
+            Self::NameInvalid { .. } => f.write_str("the scheduled event name is invalid"),
+        }
+    }
+}
+
+impl Error for UpdateGuildScheduledEventError {}
+
+#[derive(Default, Serialize)]
+struct UpdateGuildScheduledEventFields {
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<Option<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_type: Option<EntityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<Option<RecurrenceRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+}
+
+/// Update a scheduled event of a guild.
+///
+/// Changing the entity type to [`External`] requires setting the
+/// [`channel_id`] to `None` and setting a [`location`] and a
+/// [`scheduled_end_time`].
+///
+/// Requires the [`MANAGE_EVENTS`] permission.
+///
+/// [`External`]: EntityType::External
+/// [`MANAGE_EVENTS`]: twilight_model::guild::Permissions::MANAGE_EVENTS
+/// [`channel_id`]: Self::channel_id
+/// [`location`]: Self::location
+/// [`scheduled_end_time`]: Self::scheduled_end_time
+pub struct UpdateGuildScheduledEvent<'a> {
+    fields: UpdateGuildScheduledEventFields,
+    fut: Option<Pending<'a, GuildScheduledEvent>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> UpdateGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: UpdateGuildScheduledEventFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+            scheduled_event_id,
+        }
+    }
+
+    /// Set the ID of the stage or voice channel the event is held in.
+    ///
+    /// Set to `None` when changing the event to an [`External`] event.
+    ///
+    /// [`External`]: EntityType::External
+    pub fn channel_id(mut self, channel_id: impl Into<Option<ChannelId>>) -> Self {
+        self.fields.channel_id.replace(channel_id.into());
+
+        self
+    }
+
+    /// Set the description of the event.
+    ///
+    /// The description must be between 1 and 1000 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildScheduledEventError::DescriptionInvalid`] if the
+    /// description is invalid.
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, UpdateGuildScheduledEventError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, UpdateGuildScheduledEventError> {
+        if !validate::scheduled_event_description(&description) {
+            return Err(UpdateGuildScheduledEventError::DescriptionInvalid { description });
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the type of entity the event is held in.
+    pub fn entity_type(mut self, entity_type: EntityType) -> Self {
+        self.fields.entity_type.replace(entity_type);
+
+        self
+    }
+
+    /// Set the cover image of the event.
+    ///
+    /// This must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}` where `{type}` is the image MIME
+    /// type and `{data}` is the base64-encoded image.
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.fields.image.replace(image.into());
+
+        self
+    }
+
+    /// Set the location of an event held outside of Discord.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.fields.entity_metadata.replace(EntityMetadata {
+            location: Some(location.into()),
+        });
+
+        self
+    }
+
+    /// Set the name of the event.
+    ///
+    /// The name must be between 1 and 100 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildScheduledEventError::NameInvalid`] if the name is
+    /// invalid.
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateGuildScheduledEventError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateGuildScheduledEventError> {
+        if !validate::scheduled_event_name(&name) {
+            return Err(UpdateGuildScheduledEventError::NameInvalid { name });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    /// Set who can see the event.
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level.replace(privacy_level);
+
+        self
+    }
+
+    /// Set the rule defining how often the event recurs.
+    ///
+    /// Set to `None` to stop the event from recurring.
+    pub fn recurrence_rule(mut self, recurrence_rule: impl Into<Option<RecurrenceRule>>) -> Self {
+        self.fields.recurrence_rule.replace(recurrence_rule.into());
+
+        self
+    }
+
+    /// Set the timestamp of when the event is scheduled to end.
+    pub fn scheduled_end_time(mut self, scheduled_end_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_end_time
+            .replace(scheduled_end_time.into());
+
+        self
+    }
+
+    /// Set the timestamp of when the event is scheduled to start.
+    pub fn scheduled_start_time(mut self, scheduled_start_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_start_time
+            .replace(scheduled_start_time.into());
+
+        self
+    }
+
+    /// Set the status of the event, such as to start or cancel it.
+    ///
+    /// Refer to [`Status`] for the allowed transitions.
+    pub fn status(mut self, status: Status) -> Self {
+        self.fields.status.replace(status);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let body = crate::json_to_vec(&self.fields)?;
+        let route = Route::UpdateGuildScheduledEvent {
+            guild_id: self.guild_id.0,
+            scheduled_event_id: self.scheduled_event_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            Request::from((body, audit_header(&reason)?, route))
+        } else {
+            Request::from((body, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for UpdateGuildScheduledEvent<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(UpdateGuildScheduledEvent<'_>, GuildScheduledEvent);
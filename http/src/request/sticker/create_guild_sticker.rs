@@ -0,0 +1,155 @@
+use crate::request::{multipart::Form, prelude::*, Attachment};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{channel::message::sticker::Sticker, id::GuildId};
+
+/// The error returned when the sticker can not be created as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildStickerError {
+    /// The description is 1 character or more than 100 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The name is fewer than 2 characters or more than 30 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The tags are fewer than 2 characters or more than 200 characters.
+    TagsInvalid {
+        /// Provided tags.
+        tags: String,
+    },
+}
+
+impl Display for CreateGuildStickerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => f.write_str("the sticker description is invalid"),
+            Self::NameInvalid { .. } => f.write_str("the sticker name is invalid"),
+            Self::TagsInvalid { .. } => f.write_str("the sticker tags are invalid"),
+        }
+    }
+}
+
+impl Error for CreateGuildStickerError {}
+
+/// Upload a sticker to a guild.
+///
+/// The file must be a PNG, APNG, or Lottie JSON file of at most 500 KiB. The
+/// tags are the name of a unicode emoji used for autocompletion.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+pub struct CreateGuildSticker<'a> {
+    description: String,
+    file: Attachment,
+    fut: Option<Pending<'a, Sticker>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    name: String,
+    reason: Option<String>,
+    tags: String,
+}
+
+impl<'a> CreateGuildSticker<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        tags: impl Into<String>,
+        file: Attachment,
+    ) -> Result<Self, CreateGuildStickerError> {
+        Self::_new(http, guild_id, name.into(), tags.into(), file)
+    }
+
+    fn _new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: String,
+        tags: String,
+        file: Attachment,
+    ) -> Result<Self, CreateGuildStickerError> {
+        if !validate::sticker_name(&name) {
+            return Err(CreateGuildStickerError::NameInvalid { name });
+        }
+
+        if !validate::sticker_tags(&tags) {
+            return Err(CreateGuildStickerError::TagsInvalid { tags });
+        }
+
+        Ok(Self {
+            description: String::new(),
+            file,
+            fut: None,
+            guild_id,
+            http,
+            name,
+            reason: None,
+            tags,
+        })
+    }
+
+    /// Set the description of the sticker.
+    ///
+    /// The description must be empty or between 2 and 100 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildStickerError::DescriptionInvalid`] if the
+    /// description is invalid.
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, CreateGuildStickerError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, CreateGuildStickerError> {
+        if !validate::sticker_description(&description) {
+            return Err(CreateGuildStickerError::DescriptionInvalid { description });
+        }
+
+        self.description = description;
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let mut form = Form::new();
+        form.part(b"name", self.name.as_bytes());
+        form.part(b"description", self.description.as_bytes());
+        form.part(b"tags", self.tags.as_bytes());
+        self.file.add_to_form(&mut form, b"file");
+
+        let route = Route::CreateGuildSticker {
+            guild_id: self.guild_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            Request::from((form, audit_header(&reason)?, route))
+        } else {
+            Request::from((form, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for CreateGuildSticker<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(CreateGuildSticker<'_>, Sticker);
@@ -0,0 +1,55 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::message::sticker::StickerId, id::GuildId};
+
+/// Delete a sticker of a guild.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+pub struct DeleteGuildSticker<'a> {
+    fut: Option<Pending<'a, EmptyBody>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+    sticker_id: StickerId,
+}
+
+impl<'a> DeleteGuildSticker<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+            sticker_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::DeleteGuildSticker {
+            guild_id: self.guild_id.0,
+            sticker_id: self.sticker_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            Request::from((audit_header(&reason)?, route))
+        } else {
+            Request::from(route)
+        };
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for DeleteGuildSticker<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(DeleteGuildSticker<'_>, EmptyBody);
@@ -0,0 +1,42 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    channel::message::sticker::{Sticker, StickerId},
+    id::GuildId,
+};
+
+/// Get a sticker of a guild.
+///
+/// The sticker only contains the user who uploaded it if the current user has
+/// the [`MANAGE_EMOJIS`] permission.
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+pub struct GetGuildSticker<'a> {
+    fut: Option<Pending<'a, Sticker>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    sticker_id: StickerId,
+}
+
+impl<'a> GetGuildSticker<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            sticker_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildSticker {
+                guild_id: self.guild_id.0,
+                sticker_id: self.sticker_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(opt, GetGuildSticker<'_>, Sticker);
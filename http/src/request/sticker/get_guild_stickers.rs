@@ -0,0 +1,36 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::message::sticker::Sticker, id::GuildId};
+
+/// Get the stickers of a guild.
+///
+/// The stickers only contain the user who uploaded them if the current user
+/// has the [`MANAGE_EMOJIS`] permission.
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+pub struct GetGuildStickers<'a> {
+    fut: Option<Pending<'a, Vec<Sticker>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildStickers<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildStickers {
+                guild_id: self.guild_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildStickers<'_>, Vec<Sticker>);
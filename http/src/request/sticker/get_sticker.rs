@@ -0,0 +1,33 @@
+use crate::request::prelude::*;
+use twilight_model::channel::message::sticker::{Sticker, StickerId};
+
+/// Get a sticker by its ID.
+///
+/// The sticker may be a standard sticker or a guild sticker.
+pub struct GetSticker<'a> {
+    fut: Option<Pending<'a, Sticker>>,
+    http: &'a Client,
+    sticker_id: StickerId,
+}
+
+impl<'a> GetSticker<'a> {
+    pub(crate) fn new(http: &'a Client, sticker_id: StickerId) -> Self {
+        Self {
+            fut: None,
+            http,
+            sticker_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetSticker {
+                sticker_id: self.sticker_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(opt, GetSticker<'_>, Sticker);
@@ -0,0 +1,17 @@
+mod create_guild_sticker;
+mod delete_guild_sticker;
+mod get_guild_sticker;
+mod get_guild_stickers;
+mod get_nitro_sticker_packs;
+mod get_sticker;
+mod update_guild_sticker;
+
+pub use self::{
+    create_guild_sticker::{CreateGuildSticker, CreateGuildStickerError},
+    delete_guild_sticker::DeleteGuildSticker,
+    get_guild_sticker::GetGuildSticker,
+    get_guild_stickers::GetGuildStickers,
+    get_nitro_sticker_packs::{GetNitroStickerPacks, StickerPackListing},
+    get_sticker::GetSticker,
+    update_guild_sticker::{UpdateGuildSticker, UpdateGuildStickerError},
+};
@@ -0,0 +1,178 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::message::sticker::{Sticker, StickerId},
+    id::GuildId,
+};
+
+/// The error returned when the sticker can not be updated as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateGuildStickerError {
+    /// The description is 1 character or more than 100 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The name is fewer than 2 characters or more than 30 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The tags are fewer than 2 characters or more than 200 characters.
+    TagsInvalid {
+        /// Provided tags.
+        tags: String,
+    },
+}
+
+impl Display for UpdateGuildStickerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => f.write_str("the sticker description is invalid"),
+            Self::NameInvalid { .. } => f.write_str("the sticker name is invalid"),
+            Self::TagsInvalid { .. } => f.write_str("the sticker tags are invalid"),
+        }
+    }
+}
+
+impl Error for UpdateGuildStickerError {}
+
+#[derive(Default, Serialize)]
+struct UpdateGuildStickerFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<String>,
+}
+
+/// Update a sticker of a guild.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+pub struct UpdateGuildSticker<'a> {
+    fields: UpdateGuildStickerFields,
+    fut: Option<Pending<'a, Sticker>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+    sticker_id: StickerId,
+}
+
+impl<'a> UpdateGuildSticker<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            fields: UpdateGuildStickerFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+            sticker_id,
+        }
+    }
+
+    /// Set the description of the sticker.
+    ///
+    /// The description must be empty or between 2 and 100 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildStickerError::DescriptionInvalid`] if the
+    /// description is invalid.
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, UpdateGuildStickerError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, UpdateGuildStickerError> {
+        if !validate::sticker_description(&description) {
+            return Err(UpdateGuildStickerError::DescriptionInvalid { description });
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the name of the sticker.
+    ///
+    /// The name must be between 2 and 30 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildStickerError::NameInvalid`] if the name is
+    /// invalid.
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateGuildStickerError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateGuildStickerError> {
+        if !validate::sticker_name(&name) {
+            return Err(UpdateGuildStickerError::NameInvalid { name });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    /// Set the tags of the sticker, the name of a unicode emoji used for
+    /// autocompletion.
+    ///
+    /// The tags must be between 2 and 200 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildStickerError::TagsInvalid`] if the tags are
+    /// invalid.
+    pub fn tags(self, tags: impl Into<String>) -> Result<Self, UpdateGuildStickerError> {
+        self._tags(tags.into())
+    }
+
+    fn _tags(mut self, tags: String) -> Result<Self, UpdateGuildStickerError> {
+        if !validate::sticker_tags(&tags) {
+            return Err(UpdateGuildStickerError::TagsInvalid { tags });
+        }
+
+        self.fields.tags.replace(tags);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let body = crate::json_to_vec(&self.fields)?;
+        let route = Route::UpdateGuildSticker {
+            guild_id: self.guild_id.0,
+            sticker_id: self.sticker_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            Request::from((body, audit_header(&reason)?, route))
+        } else {
+            Request::from((body, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for UpdateGuildSticker<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(UpdateGuildSticker<'_>, Sticker);
@@ -0,0 +1,116 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::guild::PartialGuild;
+
+/// The error returned when the guild can not be created as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildFromTemplateError {
+    /// The name of the guild is either fewer than 2 UTF-16 characters or more
+    /// than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for CreateGuildFromTemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NameInvalid { .. } => f.write_str("the guild name is invalid"),
+        }
+    }
+}
+
+impl Error for CreateGuildFromTemplateError {}
+
+#[derive(Serialize)]
+struct CreateGuildFromTemplateFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+    name: String,
+}
+
+/// Create a new guild based on a template.
+///
+/// This endpoint can only be used by bots in less than 10 guilds.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let guild = client
+///     .create_guild_from_template("hgM48av5Q69A", "event server")?
+///     .await?
+///     .model()?;
+///
+/// println!("created guild {}", guild.id);
+/// # Ok(()) }
+/// ```
+pub struct CreateGuildFromTemplate<'a> {
+    fields: CreateGuildFromTemplateFields,
+    fut: Option<Pending<'a, PartialGuild>>,
+    http: &'a Client,
+    template_code: String,
+}
+
+impl<'a> CreateGuildFromTemplate<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        template_code: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Result<Self, CreateGuildFromTemplateError> {
+        Self::_new(http, template_code.into(), name.into())
+    }
+
+    fn _new(
+        http: &'a Client,
+        template_code: String,
+        name: String,
+    ) -> Result<Self, CreateGuildFromTemplateError> {
+        if !validate::guild_name(&name) {
+            return Err(CreateGuildFromTemplateError::NameInvalid { name });
+        }
+
+        Ok(Self {
+            fields: CreateGuildFromTemplateFields { icon: None, name },
+            fut: None,
+            http,
+            template_code,
+        })
+    }
+
+    /// Set the icon.
+    ///
+    /// This must be a Data URI, in the form of `data:image/{type};base64,{data}` where `{type}` is
+    /// the image MIME type and `{data}` is the base64-encoded image. Refer to [the discord docs]
+    /// for more information.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/reference#image-data
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.fields.icon.replace(icon.into());
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateGuildFromTemplate {
+                template_code: self.template_code.clone(),
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateGuildFromTemplate<'_>, PartialGuild);
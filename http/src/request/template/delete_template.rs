@@ -0,0 +1,42 @@
+use crate::request::prelude::*;
+use twilight_model::{id::GuildId, template::Template};
+
+/// Delete a template of a guild, resolving to the deleted template.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+pub struct DeleteTemplate<'a> {
+    fut: Option<Pending<'a, Template>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    template_code: String,
+}
+
+impl<'a> DeleteTemplate<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            template_code: template_code.into(),
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::DeleteTemplate {
+                guild_id: self.guild_id.0,
+                template_code: self.template_code.clone(),
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(DeleteTemplate<'_>, Template);
@@ -0,0 +1,139 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{id::GuildId, template::Template};
+
+/// The error returned when the template can not be updated as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateTemplateError {
+    /// The description is more than 120 UTF-16 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The name is either fewer than 1 UTF-16 character or more than 100
+    /// UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for UpdateTemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => f.write_str("the template description is invalid"),
+            Self::NameInvalid { .. } => f.write_str("the template name is invalid"),
+        }
+    }
+}
+
+impl Error for UpdateTemplateError {}
+
+#[derive(Default, Serialize)]
+struct UpdateTemplateFields {
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+/// Update the metadata of a template.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+pub struct UpdateTemplate<'a> {
+    fields: UpdateTemplateFields,
+    fut: Option<Pending<'a, Template>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    template_code: String,
+}
+
+impl<'a> UpdateTemplate<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> Self {
+        Self {
+            fields: UpdateTemplateFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            template_code: template_code.into(),
+        }
+    }
+
+    /// Set the description of the template.
+    ///
+    /// Pass `None` to remove the description. The maximum length is 120
+    /// UTF-16 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateTemplateError::DescriptionInvalid`] if the description
+    /// is too long.
+    pub fn description(
+        self,
+        description: impl Into<Option<String>>,
+    ) -> Result<Self, UpdateTemplateError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: Option<String>) -> Result<Self, UpdateTemplateError> {
+        if let Some(description_ref) = description.as_ref() {
+            if !validate::template_description(description_ref) {
+                return Err(UpdateTemplateError::DescriptionInvalid {
+                    description: description.expect("description is known to be some"),
+                });
+            }
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the name of the template.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateTemplateError::NameInvalid`] if the name length is too
+    /// short or too long.
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateTemplateError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateTemplateError> {
+        if !validate::template_name(&name) {
+            return Err(UpdateTemplateError::NameInvalid { name });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateTemplate {
+                guild_id: self.guild_id.0,
+                template_code: self.template_code.clone(),
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateTemplate<'_>, Template);
@@ -1,4 +1,4 @@
-use crate::request::prelude::*;
+use crate::request::{prelude::*, Paginate};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -109,6 +109,40 @@ impl<'a> GetCurrentUserGuilds<'a> {
         Ok(self)
     }
 
+    /// Paginate through all guilds of the current user, ordered by ID.
+    ///
+    /// Pagination starts after the guild set via [`after`] and stops at the
+    /// guild set via [`before`], if any. Pages of the [`limit`] set on the
+    /// request are requested, or 100 guilds if no limit was set.
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginate<'a, CurrentUserGuild> {
+        let before = self.fields.before;
+        let http = self.http;
+        let page_size = self
+            .fields
+            .limit
+            .unwrap_or(validate::GET_CURRENT_USER_GUILDS_LIMIT_MAX);
+
+        Paginate::new(
+            self.fields.after.map(|after| after.0),
+            page_size,
+            |guild| guild.id.0,
+            move |after, limit| {
+                let mut request = Self::new(http);
+                request.fields = GetCurrentUserGuildsFields {
+                    after: after.map(GuildId),
+                    before,
+                    limit: Some(limit),
+                };
+
+                Box::pin(async move { request.await?.model() })
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetGuilds {
@@ -3,7 +3,7 @@ use twilight_model::id::GuildId;
 
 /// Leave a guild by id.
 pub struct LeaveGuild<'a> {
-    fut: Option<Pending<'a, ()>>,
+    fut: Option<Pending<'a, EmptyBody>>,
     guild_id: GuildId,
     http: &'a Client,
 }
@@ -28,4 +28,4 @@ impl<'a> LeaveGuild<'a> {
     }
 }
 
-poll_req!(LeaveGuild<'_>, ());
+poll_req!(LeaveGuild<'_>, EmptyBody);
@@ -7,7 +7,10 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::embed::Embed;
+use twilight_model::{
+    channel::{embed::Embed, message::sticker::StickerId},
+    id::{ChannelId, RoleId},
+};
 
 /// An embed is not valid.
 ///
@@ -163,6 +166,16 @@ impl Display for EmbedValidationError {
 
 impl Error for EmbedValidationError {}
 
+pub fn auto_moderation_exempt_channels(value: &[ChannelId]) -> bool {
+    // <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-auto-moderation-rule-structure>
+    value.len() <= 50
+}
+
+pub fn auto_moderation_exempt_roles(value: &[RoleId]) -> bool {
+    // <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-auto-moderation-rule-structure>
+    value.len() <= 20
+}
+
 pub fn ban_delete_message_days(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/guild#create-guild-ban-query-string-params>
     value <= 7
@@ -264,29 +277,62 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
     Ok(())
 }
 
+/// Maximum number of audit log entries that can be retrieved at once.
+pub const GET_AUDIT_LOG_LIMIT_MAX: u64 = 100;
+
+/// Maximum number of messages that can be retrieved at once.
+pub const GET_CHANNEL_MESSAGES_LIMIT_MAX: u64 = 100;
+
+/// Maximum number of guilds of the current user that can be retrieved at
+/// once.
+pub const GET_CURRENT_USER_GUILDS_LIMIT_MAX: u64 = 100;
+
+/// Maximum number of bans that can be retrieved at once.
+pub const GET_GUILD_BANS_LIMIT_MAX: u64 = 1000;
+
+/// Maximum number of members that can be retrieved at once.
+pub const GET_GUILD_MEMBERS_LIMIT_MAX: u64 = 1000;
+
+/// Maximum number of users of a reaction that can be retrieved at once.
+pub const GET_REACTIONS_LIMIT_MAX: u64 = 100;
+
+/// Maximum number of users subscribed to a scheduled event that can be
+/// retrieved at once.
+pub const GET_SCHEDULED_EVENT_USERS_LIMIT_MAX: u64 = 100;
+
 pub fn get_audit_log_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/audit-log#get-guild-audit-log-query-string-parameters>
-    (1..=100).contains(&value)
+    (1..=GET_AUDIT_LOG_LIMIT_MAX).contains(&value)
 }
 
 pub fn get_channel_messages_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/channel#get-channel-messages-query-string-params>
-    (1..=100).contains(&value)
+    (1..=GET_CHANNEL_MESSAGES_LIMIT_MAX).contains(&value)
 }
 
 pub fn get_current_user_guilds_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/user#get-current-user-guilds-query-string-params>
-    (1..=100).contains(&value)
+    (1..=GET_CURRENT_USER_GUILDS_LIMIT_MAX).contains(&value)
+}
+
+pub fn get_guild_bans_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild#get-guild-bans-query-string-params>
+    (1..=GET_GUILD_BANS_LIMIT_MAX).contains(&value)
 }
 
 pub fn get_guild_members_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/guild#list-guild-members-query-string-params>
-    (1..=1000).contains(&value)
+    (1..=GET_GUILD_MEMBERS_LIMIT_MAX).contains(&value)
 }
 
 pub fn get_reactions_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/channel#get-reactions-query-string-params>
-    (1..=100).contains(&value)
+    (1..=GET_REACTIONS_LIMIT_MAX).contains(&value)
+}
+
+pub fn get_scheduled_event_users_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users-query-string-params>
+    (1..=GET_SCHEDULED_EVENT_USERS_LIMIT_MAX).contains(&value)
 }
 
 pub fn guild_name(value: impl AsRef<str>) -> bool {
@@ -316,6 +362,86 @@ fn _nickname(value: &str) -> bool {
     (1..=32).contains(&len)
 }
 
+pub fn scheduled_event_description(value: impl AsRef<str>) -> bool {
+    _scheduled_event_description(value.as_ref())
+}
+
+fn _scheduled_event_description(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-structure>
+    (1..=1000).contains(&len)
+}
+
+pub fn scheduled_event_name(value: impl AsRef<str>) -> bool {
+    _scheduled_event_name(value.as_ref())
+}
+
+fn _scheduled_event_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-structure>
+    (1..=100).contains(&len)
+}
+
+pub fn sticker_description(value: impl AsRef<str>) -> bool {
+    _sticker_description(value.as_ref())
+}
+
+fn _sticker_description(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    len == 0 || (2..=100).contains(&len)
+}
+
+pub fn sticker_name(value: impl AsRef<str>) -> bool {
+    _sticker_name(value.as_ref())
+}
+
+fn _sticker_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    (2..=30).contains(&len)
+}
+
+pub fn sticker_tags(value: impl AsRef<str>) -> bool {
+    _sticker_tags(value.as_ref())
+}
+
+fn _sticker_tags(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    (2..=200).contains(&len)
+}
+
+pub fn sticker_ids(value: &[StickerId]) -> bool {
+    // <https://discord.com/developers/docs/resources/channel#create-message-jsonform-params>
+    value.len() <= 3
+}
+
+pub fn template_description(value: impl AsRef<str>) -> bool {
+    _template_description(value.as_ref())
+}
+
+fn _template_description(value: &str) -> bool {
+    // <https://discord.com/developers/docs/resources/template#create-guild-template-json-params>
+    value.chars().count() <= 120
+}
+
+pub fn template_name(value: impl AsRef<str>) -> bool {
+    _template_name(value.as_ref())
+}
+
+fn _template_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/template#create-guild-template-json-params>
+    (1..=100).contains(&len)
+}
+
 pub fn username(value: impl AsRef<str>) -> bool {
     // <https://discordapp.com/developers/docs/resources/user#usernames-and-nicknames>
     _username(value.as_ref())
@@ -350,6 +476,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_auto_moderation_exempt_channels() {
+        assert!(auto_moderation_exempt_channels(&[]));
+        assert!(auto_moderation_exempt_channels(&[ChannelId(1); 50]));
+
+        assert!(!auto_moderation_exempt_channels(&[ChannelId(1); 51]));
+    }
+
+    #[test]
+    fn test_auto_moderation_exempt_roles() {
+        assert!(auto_moderation_exempt_roles(&[]));
+        assert!(auto_moderation_exempt_roles(&[RoleId(1); 20]));
+
+        assert!(!auto_moderation_exempt_roles(&[RoleId(1); 21]));
+    }
+
     #[test]
     fn test_ban_delete_message_days() {
         assert!(ban_delete_message_days(0));
@@ -591,6 +733,15 @@ mod tests {
         assert!(!get_current_user_guilds_limit(101));
     }
 
+    #[test]
+    fn test_get_guild_bans_limit() {
+        assert!(get_guild_bans_limit(1));
+        assert!(get_guild_bans_limit(1000));
+
+        assert!(!get_guild_bans_limit(0));
+        assert!(!get_guild_bans_limit(1001));
+    }
+
     #[test]
     fn test_get_guild_members_limit() {
         assert!(get_guild_members_limit(1));
@@ -609,6 +760,15 @@ mod tests {
         assert!(!get_reactions_limit(101));
     }
 
+    #[test]
+    fn test_get_scheduled_event_users_limit() {
+        assert!(get_scheduled_event_users_limit(1));
+        assert!(get_scheduled_event_users_limit(100));
+
+        assert!(!get_scheduled_event_users_limit(0));
+        assert!(!get_scheduled_event_users_limit(101));
+    }
+
     #[test]
     fn test_guild_name() {
         assert!(guild_name("aa"));
@@ -635,6 +795,82 @@ mod tests {
         assert!(!nickname("a".repeat(33)));
     }
 
+    #[test]
+    fn test_scheduled_event_description() {
+        assert!(scheduled_event_description("a"));
+        assert!(scheduled_event_description("a".repeat(1000)));
+
+        assert!(!scheduled_event_description(""));
+        assert!(!scheduled_event_description("a".repeat(1001)));
+    }
+
+    #[test]
+    fn test_scheduled_event_name() {
+        assert!(scheduled_event_name("a"));
+        assert!(scheduled_event_name("a".repeat(100)));
+
+        assert!(!scheduled_event_name(""));
+        assert!(!scheduled_event_name("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_sticker_description() {
+        assert!(sticker_description(""));
+        assert!(sticker_description("aa"));
+        assert!(sticker_description("a".repeat(100)));
+
+        assert!(!sticker_description("a"));
+        assert!(!sticker_description("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_sticker_ids() {
+        assert!(sticker_ids(&[]));
+        assert!(sticker_ids(&[StickerId(1), StickerId(2), StickerId(3)]));
+
+        assert!(!sticker_ids(&[
+            StickerId(1),
+            StickerId(2),
+            StickerId(3),
+            StickerId(4)
+        ]));
+    }
+
+    #[test]
+    fn test_sticker_name() {
+        assert!(sticker_name("aa"));
+        assert!(sticker_name("a".repeat(30)));
+
+        assert!(!sticker_name("a"));
+        assert!(!sticker_name("a".repeat(31)));
+    }
+
+    #[test]
+    fn test_sticker_tags() {
+        assert!(sticker_tags("aa"));
+        assert!(sticker_tags("a".repeat(200)));
+
+        assert!(!sticker_tags("a"));
+        assert!(!sticker_tags("a".repeat(201)));
+    }
+
+    #[test]
+    fn test_template_description() {
+        assert!(template_description(""));
+        assert!(template_description("a".repeat(120)));
+
+        assert!(!template_description("a".repeat(121)));
+    }
+
+    #[test]
+    fn test_template_name() {
+        assert!(template_name("a"));
+        assert!(template_name("a".repeat(100)));
+
+        assert!(!template_name(""));
+        assert!(!template_name("a".repeat(101)));
+    }
+
     #[test]
     fn test_username() {
         assert!(username("aa"));
@@ -0,0 +1,299 @@
+//! Responses to requests, deserialized into models on demand.
+//!
+//! Requests resolve to a [`Response`], which contains the status, headers,
+//! and body of the response to the request. The body is only deserialized
+//! when [`Response::model`] is called, so requests whose bodies aren't needed
+//! don't pay for deserialization.
+//!
+//! # Examples
+//!
+//! Create a reaction, reading how many more reactions can be created before
+//! the ratelimit bucket is exhausted:
+//!
+//! ```rust,no_run
+//! use twilight_http::{ratelimiting::RatelimitHeaders, request::channel::reaction::RequestReactionType, Client};
+//! use twilight_model::id::{ChannelId, MessageId};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("my token");
+//!
+//! let emoji = RequestReactionType::Unicode {
+//!     name: "🌃".to_owned(),
+//! };
+//! let response = client
+//!     .create_reaction(ChannelId(1), MessageId(2), emoji)
+//!     .await?;
+//!
+//! if let Ok(RatelimitHeaders::Present { remaining, .. }) = response.ratelimit_headers() {
+//!     println!("{} reactions remaining", remaining);
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    ratelimiting::{error::RatelimitResult, RatelimitHeaders},
+};
+use bytes::Bytes;
+use hyper::{header::HeaderValue, HeaderMap, StatusCode};
+use serde::de::{DeserializeOwned, DeserializeSeed};
+use std::{
+    convert::TryFrom,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+use twilight_model::{
+    guild::member::{Member, MemberDeserializer},
+    id::GuildId,
+};
+
+#[cfg(not(feature = "simd-json"))]
+use serde_json::Value;
+#[cfg(feature = "simd-json")]
+use simd_json::value::OwnedValue as Value;
+
+/// Marker for responses without a body, such as `204 No Content` responses.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct EmptyBody;
+
+/// Marker for responses containing a guild member.
+///
+/// The [`Response::model`] of these responses is a [`Member`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct MemberBody;
+
+/// Marker for responses containing a list of guild members.
+///
+/// The [`Response::model`] of these responses is a list of [`Member`]s.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct MemberListBody;
+
+/// Response to a request.
+///
+/// Contains the status, headers, and raw body of the response. The body can
+/// be deserialized into the model of the request via [`model`].
+///
+/// [`model`]: Self::model
+pub struct Response<T> {
+    bytes: Bytes,
+    guild_id: Option<GuildId>,
+    headers: HeaderMap<HeaderValue>,
+    phantom: PhantomData<fn() -> T>,
+    status: StatusCode,
+}
+
+impl<T> Response<T> {
+    pub(crate) fn new(status: StatusCode, headers: HeaderMap<HeaderValue>, bytes: Bytes) -> Self {
+        Self {
+            bytes,
+            guild_id: None,
+            headers,
+            phantom: PhantomData,
+            status,
+        }
+    }
+
+    /// Set the ID of the guild the members of the response belong to.
+    pub(crate) fn set_guild_id(&mut self, guild_id: GuildId) {
+        self.guild_id.replace(guild_id);
+    }
+
+    /// Status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Headers of the response.
+    pub fn headers(&self) -> &HeaderMap<HeaderValue> {
+        &self.headers
+    }
+
+    /// Parse the ratelimit headers of the response.
+    ///
+    /// Responses of routes that aren't ratelimited have
+    /// [`RatelimitHeaders::None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RatelimitError`] if some of the headers are missing or
+    /// invalid.
+    ///
+    /// [`RatelimitError`]: crate::ratelimiting::error::RatelimitError
+    pub fn ratelimit_headers(&self) -> RatelimitResult<RatelimitHeaders> {
+        RatelimitHeaders::try_from(&self.headers)
+    }
+
+    /// Raw body of the response.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consume the response, returning its raw body.
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl<T: DeserializeOwned> Response<T> {
+    /// Deserialize the body into the model of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parsing`] if the body isn't a valid model.
+    pub fn model(&self) -> Result<T> {
+        // simd-json deserializes in place, so the body has to be copied.
+        let mut bytes = self.bytes.to_vec();
+
+        crate::json_from_slice(&mut bytes).map_err(|source| Error::Parsing {
+            body: self.bytes.to_vec(),
+            source,
+        })
+    }
+}
+
+impl Response<MemberBody> {
+    /// Deserialize the body into a member.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the body isn't a valid member.
+    ///
+    /// Returns [`Error::MemberGuildIdMissing`] if the response wasn't
+    /// returned by a request for a member.
+    pub fn model(&self) -> Result<Member> {
+        let guild_id = self.guild_id.ok_or(Error::MemberGuildIdMissing)?;
+
+        let mut bytes = self.bytes.to_vec();
+        let value = crate::json_from_slice::<Value>(&mut bytes)?;
+
+        Ok(MemberDeserializer::new(guild_id).deserialize(value)?)
+    }
+}
+
+impl Response<MemberListBody> {
+    /// Deserialize the body into a list of members.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the body isn't a valid list of members.
+    ///
+    /// Returns [`Error::MemberGuildIdMissing`] if the response wasn't
+    /// returned by a request for members.
+    pub fn model(&self) -> Result<Vec<Member>> {
+        let guild_id = self.guild_id.ok_or(Error::MemberGuildIdMissing)?;
+
+        let mut bytes = self.bytes.to_vec();
+        let values = crate::json_from_slice::<Vec<Value>>(&mut bytes)?;
+        let mut members = Vec::with_capacity(values.len());
+
+        for value in values {
+            members.push(MemberDeserializer::new(guild_id).deserialize(value)?);
+        }
+
+        Ok(members)
+    }
+}
+
+impl<T> Clone for Response<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            guild_id: self.guild_id,
+            headers: self.headers.clone(),
+            phantom: PhantomData,
+            status: self.status,
+        }
+    }
+}
+
+impl<T> Debug for Response<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Response")
+            .field("bytes", &self.bytes)
+            .field("guild_id", &self.guild_id)
+            .field("headers", &self.headers)
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmptyBody, MemberBody, MemberListBody, Response};
+    use crate::{error::Error, ratelimiting::RatelimitHeaders};
+    use bytes::Bytes;
+    use hyper::{header::HeaderValue, HeaderMap, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{id::GuildId, user::User};
+
+    assert_impl_all!(Response<EmptyBody>: Clone, Debug, Send, Sync);
+    assert_impl_all!(Response<MemberBody>: Clone, Debug, Send, Sync);
+    assert_impl_all!(Response<MemberListBody>: Clone, Debug, Send, Sync);
+
+    const USER: &str = r#"{"avatar":null,"discriminator":"0001","id":"1","username":"twilight"}"#;
+
+    #[test]
+    fn test_model() {
+        let response = Response::<User>::new(StatusCode::OK, HeaderMap::new(), Bytes::from(USER));
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(USER.as_bytes(), response.bytes());
+        assert_eq!("twilight", response.model().unwrap().name);
+    }
+
+    #[test]
+    fn test_model_invalid() {
+        let response =
+            Response::<User>::new(StatusCode::OK, HeaderMap::new(), Bytes::from_static(b"{}"));
+
+        assert!(response.model().is_err());
+    }
+
+    #[test]
+    fn test_member_model() {
+        let body = format!(
+            r#"[{{"deaf":false,"joined_at":"2021-01-01T00:00:00+00:00","mute":false,"roles":[],"user":{}}}]"#,
+            USER,
+        );
+        let mut response =
+            Response::<MemberListBody>::new(StatusCode::OK, HeaderMap::new(), Bytes::from(body));
+        response.set_guild_id(GuildId(2));
+
+        let members = response.model().unwrap();
+        assert_eq!(1, members.len());
+        assert_eq!(GuildId(2), members[0].guild_id);
+    }
+
+    #[test]
+    fn test_member_model_without_guild_id() {
+        let response =
+            Response::<MemberListBody>::new(StatusCode::OK, HeaderMap::new(), Bytes::from("[]"));
+
+        assert!(matches!(response.model(), Err(Error::MemberGuildIdMissing)));
+    }
+
+    #[test]
+    fn test_ratelimit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-global", HeaderValue::from_static("true"));
+        headers.insert("x-ratelimit-reset-after", HeaderValue::from_static("5"));
+
+        let response =
+            Response::<EmptyBody>::new(StatusCode::TOO_MANY_REQUESTS, headers, Bytes::new());
+        assert!(matches!(
+            response.ratelimit_headers(),
+            Ok(RatelimitHeaders::GlobalLimited { reset_after: 5 })
+        ));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("5"));
+
+        let response = Response::<EmptyBody>::new(StatusCode::OK, headers, Bytes::new());
+        assert!(response.ratelimit_headers().is_err());
+    }
+}
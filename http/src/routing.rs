@@ -98,6 +98,10 @@ pub enum Path {
     GuildsIdBans(u64),
     GuildsIdBansId(u64),
     GuildsIdAuditLogs(u64),
+    /// Operating on a guild's auto moderation rules.
+    GuildsIdAutoModerationRules(u64),
+    /// Operating on an auto moderation rule of a guild.
+    GuildsIdAutoModerationRulesId(u64),
     GuildsIdBansUserId(u64),
     GuildsIdChannels(u64),
     GuildsIdWidget(u64),
@@ -109,6 +113,8 @@ pub enum Path {
     GuildsIdInvites(u64),
     GuildsIdMembers(u64),
     GuildsIdMembersId(u64),
+    /// Operating on a guild's membership screening form.
+    GuildsIdMemberVerification(u64),
     GuildsIdMembersIdRolesId(u64),
     GuildsIdMembersMeNick(u64),
     GuildsIdPreview(u64),
@@ -116,11 +122,37 @@ pub enum Path {
     GuildsIdRegions(u64),
     GuildsIdRoles(u64),
     GuildsIdRolesId(u64),
+    /// Operating on a guild's scheduled events.
+    GuildsIdScheduledEvents(u64),
+    /// Operating on a scheduled event of a guild.
+    GuildsIdScheduledEventsId(u64),
+    /// Operating on the users subscribed to a scheduled event of a guild.
+    GuildsIdScheduledEventsIdUsers(u64),
+    /// Operating on a guild's stickers.
+    GuildsIdStickers(u64),
+    /// Operating on a sticker of a guild.
+    GuildsIdStickersId(u64),
+    /// Operating on a guild's templates.
+    GuildsIdTemplates(u64),
+    /// Operating on a template of a guild.
+    GuildsIdTemplatesCode(u64),
     GuildsIdVanityUrl(u64),
     GuildsIdWebhooks(u64),
+    /// Operating on a guild's welcome screen.
+    GuildsIdWelcomeScreen(u64),
+    /// Operating on a template by its code.
+    GuildsTemplatesCode,
     InvitesCode,
     UsersId,
     OauthApplicationsMe,
+    /// Operating on `OAuth2` access tokens.
+    OauthToken,
+    /// Operating on the revocation of `OAuth2` access tokens.
+    OauthTokenRevoke,
+    /// Operating on the sticker packs available to Nitro subscribers.
+    StickerPacks,
+    /// Operating on a sticker by its ID.
+    Stickers,
     UsersIdConnections,
     UsersIdChannels,
     /// Operating on the state of a guild that the user is in.
@@ -191,8 +223,13 @@ impl FromStr for Path {
             ["gateway"] => Gateway,
             ["gateway", "bot"] => GatewayBot,
             ["guilds"] => Guilds,
+            ["guilds", "templates", _] => GuildsTemplatesCode,
             ["guilds", id] => GuildsId(id.parse()?),
             ["guilds", id, "bans"] => GuildsIdBans(id.parse()?),
+            ["guilds", id, "auto-moderation", "rules"] => GuildsIdAutoModerationRules(id.parse()?),
+            ["guilds", id, "auto-moderation", "rules", _] => {
+                GuildsIdAutoModerationRulesId(id.parse()?)
+            }
             ["guilds", id, "bans", _] => GuildsIdBansUserId(id.parse()?),
             ["guilds", id, "channels"] => GuildsIdChannels(id.parse()?),
             ["guilds", id, "widget"] => GuildsIdWidget(id.parse()?),
@@ -211,10 +248,25 @@ impl FromStr for Path {
             ["guilds", id, "regions"] => GuildsIdRegions(id.parse()?),
             ["guilds", id, "roles"] => GuildsIdRoles(id.parse()?),
             ["guilds", id, "roles", _] => GuildsIdRolesId(id.parse()?),
+            ["guilds", id, "scheduled-events"] => GuildsIdScheduledEvents(id.parse()?),
+            ["guilds", id, "scheduled-events", _] => GuildsIdScheduledEventsId(id.parse()?),
+            ["guilds", id, "scheduled-events", _, "users"] => {
+                GuildsIdScheduledEventsIdUsers(id.parse()?)
+            }
+            ["guilds", id, "stickers"] => GuildsIdStickers(id.parse()?),
+            ["guilds", id, "stickers", _] => GuildsIdStickersId(id.parse()?),
+            ["guilds", id, "templates"] => GuildsIdTemplates(id.parse()?),
+            ["guilds", id, "templates", _] => GuildsIdTemplatesCode(id.parse()?),
             ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(id.parse()?),
+            ["guilds", id, "member-verification"] => GuildsIdMemberVerification(id.parse()?),
+            ["guilds", id, "welcome-screen"] => GuildsIdWelcomeScreen(id.parse()?),
             ["guilds", id, "webhooks"] => GuildsIdWebhooks(id.parse()?),
             ["invites", _] => InvitesCode,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
+            ["sticker-packs"] => StickerPacks,
+            ["stickers", _] => Stickers,
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
@@ -222,6 +274,7 @@ impl FromStr for Path {
             ["users", _, "guilds", _] => UsersIdGuildsId,
             ["voice", "regions"] => VoiceRegions,
             ["webhooks", id] | ["webhooks", id, _] => WebhooksId(id.parse()?),
+            ["webhooks", id, _, "messages", _] => WebhooksIdTokenMessageId(id.parse()?),
             _ => return Err(PathParseError::NoMatch),
         })
     }
@@ -241,6 +294,94 @@ impl TryFrom<(Method, &str)> for Path {
     }
 }
 
+impl Path {
+    /// Return the major parameter of the path, if any.
+    ///
+    /// Major parameters are the IDs of the channel, guild, or webhook a path
+    /// operates on. Paths sharing a ratelimit bucket hash are still limited
+    /// separately for each major parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::routing::Path;
+    ///
+    /// assert_eq!(Some(123), Path::ChannelsIdMessages(123).major_parameter());
+    /// assert!(Path::Gateway.major_parameter().is_none());
+    /// ```
+    pub fn major_parameter(&self) -> Option<u64> {
+        match self {
+            Self::ChannelsId(id)
+            | Self::ChannelsIdInvites(id)
+            | Self::ChannelsIdMessages(id)
+            | Self::ChannelsIdMessagesBulkDelete(id)
+            | Self::ChannelsIdMessagesId(_, id)
+            | Self::ChannelsIdMessagesIdCrosspost(id)
+            | Self::ChannelsIdMessagesIdReactions(id)
+            | Self::ChannelsIdMessagesIdReactionsUserIdType(id)
+            | Self::ChannelsIdPermissionsOverwriteId(id)
+            | Self::ChannelsIdPins(id)
+            | Self::ChannelsIdPinsMessageId(id)
+            | Self::ChannelsIdTyping(id)
+            | Self::ChannelsIdWebhooks(id)
+            | Self::ChannelsIdFollowers(id)
+            | Self::GuildsId(id)
+            | Self::GuildsIdBans(id)
+            | Self::GuildsIdBansId(id)
+            | Self::GuildsIdAuditLogs(id)
+            | Self::GuildsIdBansUserId(id)
+            | Self::GuildsIdAutoModerationRules(id)
+            | Self::GuildsIdAutoModerationRulesId(id)
+            | Self::GuildsIdChannels(id)
+            | Self::GuildsIdWidget(id)
+            | Self::GuildsIdEmojis(id)
+            | Self::GuildsIdEmojisId(id)
+            | Self::GuildsIdIntegrations(id)
+            | Self::GuildsIdIntegrationsId(id)
+            | Self::GuildsIdIntegrationsIdSync(id)
+            | Self::GuildsIdInvites(id)
+            | Self::GuildsIdMembers(id)
+            | Self::GuildsIdMembersId(id)
+            | Self::GuildsIdMemberVerification(id)
+            | Self::GuildsIdMembersIdRolesId(id)
+            | Self::GuildsIdMembersMeNick(id)
+            | Self::GuildsIdPreview(id)
+            | Self::GuildsIdPrune(id)
+            | Self::GuildsIdRegions(id)
+            | Self::GuildsIdRoles(id)
+            | Self::GuildsIdRolesId(id)
+            | Self::GuildsIdScheduledEvents(id)
+            | Self::GuildsIdScheduledEventsId(id)
+            | Self::GuildsIdScheduledEventsIdUsers(id)
+            | Self::GuildsIdStickers(id)
+            | Self::GuildsIdStickersId(id)
+            | Self::GuildsIdTemplates(id)
+            | Self::GuildsIdTemplatesCode(id)
+            | Self::GuildsIdVanityUrl(id)
+            | Self::GuildsIdWebhooks(id)
+            | Self::GuildsIdWelcomeScreen(id)
+            | Self::WebhooksIdTokenMessageId(id)
+            | Self::WebhooksId(id) => Some(*id),
+            Self::Gateway
+            | Self::GatewayBot
+            | Self::Guilds
+            | Self::GuildsTemplatesCode
+            | Self::InvitesCode
+            | Self::UsersId
+            | Self::OauthApplicationsMe
+            | Self::OauthToken
+            | Self::OauthTokenRevoke
+            | Self::StickerPacks
+            | Self::Stickers
+            | Self::UsersIdConnections
+            | Self::UsersIdChannels
+            | Self::UsersIdGuilds
+            | Self::UsersIdGuildsId
+            | Self::VoiceRegions => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum Route {
@@ -255,6 +396,11 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to create an auto moderation rule in a guild.
+    CreateAutoModerationRule {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create a ban on a user in a guild.
     CreateBan {
         /// The number of days' worth of the user's messages to delete in the
@@ -279,6 +425,11 @@ pub enum Route {
     },
     /// Route information to create a guild.
     CreateGuild,
+    /// Route information to create a guild from a template.
+    CreateGuildFromTemplate {
+        /// Code of the template.
+        template_code: String,
+    },
     /// Route information to create a guild's integration.
     CreateGuildIntegration {
         /// The ID of the guild.
@@ -299,6 +450,16 @@ pub enum Route {
         /// pruned.
         include_roles: Vec<u64>,
     },
+    /// Route information to create a scheduled event in a guild.
+    CreateGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to create a sticker in a guild.
+    CreateGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create an invite to a channel.
     CreateInvite {
         /// The ID of the channel.
@@ -309,6 +470,8 @@ pub enum Route {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to create an `OAuth2` access token.
+    CreateOauthToken,
     /// Route information to create a private channel.
     CreatePrivateChannel,
     /// Route information to create a reaction on a message.
@@ -325,6 +488,11 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a template from a guild.
+    CreateTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create a typing trigger in a channel.
     CreateTypingTrigger {
         /// The ID of the channel.
@@ -342,6 +510,13 @@ pub enum Route {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to delete an auto moderation rule in a guild.
+    DeleteAutoModerationRule {
+        /// ID of the auto moderation rule.
+        auto_moderation_rule_id: u64,
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to delete a ban on a user in a guild.
     DeleteBan {
         /// The ID of the guild.
@@ -373,6 +548,20 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to delete a scheduled event in a guild.
+    DeleteGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
+    /// Route information to delete a sticker in a guild.
+    DeleteGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
     /// Route information to delete an invite.
     DeleteInvite {
         /// The unique invite code.
@@ -433,6 +622,13 @@ pub enum Route {
         /// The ID of the role.
         role_id: u64,
     },
+    /// Route information to delete a template of a guild.
+    DeleteTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// Code of the template.
+        template_code: String,
+    },
     /// Route information to delete a message created by a webhook.
     DeleteWebhookMessage {
         message_id: u64,
@@ -448,6 +644,8 @@ pub enum Route {
     },
     /// Route information to execute a webhook by ID and token.
     ExecuteWebhook {
+        /// ID of the thread to send the message to.
+        thread_id: Option<u64>,
         /// The token of the webhook.
         token: String,
         /// Whether to wait for a message response.
@@ -473,6 +671,13 @@ pub enum Route {
         /// The ID of the user, if specified.
         user_id: Option<u64>,
     },
+    /// Route information to get an auto moderation rule of a guild.
+    GetAutoModerationRule {
+        /// ID of the auto moderation rule.
+        auto_moderation_rule_id: u64,
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get information about a single ban in a guild.
     GetBan {
         /// The ID of the guild.
@@ -485,6 +690,17 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a guild's bans with parameters.
+    GetBansWithParameters {
+        /// The minimum ID of users to get the bans of.
+        after: Option<u64>,
+        /// The maximum ID of users to get the bans of.
+        before: Option<u64>,
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The maximum number of bans to get.
+        limit: Option<u64>,
+    },
     /// Route information to get a channel.
     GetChannel {
         /// The ID of the channel.
@@ -532,6 +748,11 @@ pub enum Route {
         /// guild.
         with_counts: bool,
     },
+    /// Route information to get the auto moderation rules of a guild.
+    GetGuildAutoModerationRules {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's widget.
     GetGuildWidget {
         /// The ID of the guild.
@@ -582,11 +803,65 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a sticker in a guild.
+    GetGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
+    /// Route information to get a scheduled event in a guild.
+    GetGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the number of users subscribed to the event.
+        with_user_count: bool,
+    },
+    /// Route information to get the users subscribed to a scheduled event in
+    /// a guild.
+    GetGuildScheduledEventUsers {
+        /// Get users after this user ID.
+        after: Option<u64>,
+        /// Get users before this user ID.
+        before: Option<u64>,
+        /// ID of the guild.
+        guild_id: u64,
+        /// Maximum number of users to get.
+        limit: Option<u64>,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the guild member data of the users.
+        with_member: Option<bool>,
+    },
+    /// Route information to get a guild's scheduled events.
+    GetGuildScheduledEvents {
+        /// ID of the guild.
+        guild_id: u64,
+        /// Whether to include the number of users subscribed to each event.
+        with_user_count: bool,
+    },
+    /// Route information to get a guild's stickers.
+    GetGuildStickers {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's vanity URL.
     GetGuildVanityUrl {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a guild's membership screening form.
+    GetGuildMembershipScreening {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to get a guild's welcome screen.
+    GetGuildWelcomeScreen {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's available voice regions.
     GetGuildVoiceRegions {
         /// The ID of the guild.
@@ -640,6 +915,9 @@ pub enum Route {
         /// The maximum number of messages to get.
         limit: Option<u64>,
     },
+    /// Route information to get the sticker packs available to Nitro
+    /// subscribers.
+    GetNitroStickerPacks,
     /// Route information to get a channel's pins.
     GetPins {
         /// The ID of the channel.
@@ -661,6 +939,21 @@ pub enum Route {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to get a sticker by its ID.
+    GetSticker {
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
+    /// Route information to get a template.
+    GetTemplate {
+        /// Code of the template.
+        template_code: String,
+    },
+    /// Route information to get the templates of a guild.
+    GetTemplates {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get the current user.
     GetUser {
         /// The ID of the target user. This can be `@me` to specify the current
@@ -681,6 +974,15 @@ pub enum Route {
         /// The ID of the webhook.
         webhook_id: u64,
     },
+    /// Route information to get a message created by a webhook.
+    GetWebhookMessage {
+        /// ID of the message.
+        message_id: u64,
+        /// Token of the webhook.
+        token: String,
+        /// ID of the webhook.
+        webhook_id: u64,
+    },
     /// Route information to leave the guild.
     LeaveGuild {
         /// The ID of the guild.
@@ -709,6 +1011,8 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to revoke an `OAuth2` access or refresh token.
+    RevokeOauthToken,
     /// Route information to sync a guild's integration.
     SyncGuildIntegration {
         /// The ID of the guild.
@@ -716,6 +1020,14 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to sync a template to the current state of its
+    /// guild.
+    SyncTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// Code of the template.
+        template_code: String,
+    },
     /// Route information to unpin a message from a channel.
     UnpinMessage {
         /// The ID of the channel.
@@ -723,6 +1035,13 @@ pub enum Route {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to update an auto moderation rule in a guild.
+    UpdateAutoModerationRule {
+        /// ID of the auto moderation rule.
+        auto_moderation_rule_id: u64,
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a channel, such as a guild channel or group.
     UpdateChannel {
         /// The ID of the channel.
@@ -752,6 +1071,16 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update a guild's membership screening form.
+    UpdateGuildMembershipScreening {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to update a guild's welcome screen.
+    UpdateGuildWelcomeScreen {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a guild's integration.
     UpdateGuildIntegration {
         /// The ID of the guild.
@@ -759,6 +1088,20 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to update a scheduled event in a guild.
+    UpdateGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
+    /// Route information to update a sticker in a guild.
+    UpdateGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
     /// Route information to update a member.
     UpdateMember {
         /// The ID of the guild.
@@ -798,6 +1141,13 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update the metadata of a template.
+    UpdateTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// Code of the template.
+        template_code: String,
+    },
     /// Route information to update a message created by a webhook.
     UpdateWebhookMessage {
         message_id: u64,
@@ -838,6 +1188,11 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::CreateAutoModerationRule { guild_id } => (
+                Method::POST,
+                Path::GuildsIdAutoModerationRules(guild_id),
+                format!("guilds/{}/auto-moderation/rules", guild_id).into(),
+            ),
             Self::CreateBan {
                 guild_id,
                 delete_message_days,
@@ -870,6 +1225,11 @@ impl Route {
                 format!("guilds/{}/emojis", guild_id).into(),
             ),
             Self::CreateGuild => (Method::POST, Path::Guilds, "guilds".into()),
+            Self::CreateGuildFromTemplate { template_code } => (
+                Method::POST,
+                Path::GuildsTemplatesCode,
+                format!("guilds/templates/{}", template_code).into(),
+            ),
             Self::CreateGuildIntegration { guild_id } => (
                 Method::POST,
                 Path::GuildsIdIntegrationsId(guild_id),
@@ -905,6 +1265,16 @@ impl Route {
 
                 (Method::POST, Path::GuildsIdPrune(guild_id), path.into())
             }
+            Self::CreateGuildScheduledEvent { guild_id } => (
+                Method::POST,
+                Path::GuildsIdScheduledEvents(guild_id),
+                format!("guilds/{}/scheduled-events", guild_id).into(),
+            ),
+            Self::CreateGuildSticker { guild_id } => (
+                Method::POST,
+                Path::GuildsIdStickers(guild_id),
+                format!("guilds/{}/stickers", guild_id).into(),
+            ),
             Self::CreateInvite { channel_id } => (
                 Method::POST,
                 Path::ChannelsIdInvites(channel_id),
@@ -915,6 +1285,7 @@ impl Route {
                 Path::ChannelsIdMessages(channel_id),
                 format!("channels/{}/messages", channel_id).into(),
             ),
+            Self::CreateOauthToken => (Method::POST, Path::OauthToken, "oauth2/token".into()),
             Self::CreatePrivateChannel => (
                 Method::POST,
                 Path::UsersIdChannels,
@@ -938,6 +1309,11 @@ impl Route {
                 Path::GuildsIdRoles(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::CreateTemplate { guild_id } => (
+                Method::POST,
+                Path::GuildsIdTemplates(guild_id),
+                format!("guilds/{}/templates", guild_id).into(),
+            ),
             Self::CreateTypingTrigger { channel_id } => (
                 Method::POST,
                 Path::ChannelsIdTyping(channel_id),
@@ -956,6 +1332,18 @@ impl Route {
                 Path::ChannelsIdMessagesIdCrosspost(channel_id),
                 format!("channels/{}/messages/{}/crosspost", channel_id, message_id).into(),
             ),
+            Self::DeleteAutoModerationRule {
+                auto_moderation_rule_id,
+                guild_id,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdAutoModerationRulesId(guild_id),
+                format!(
+                    "guilds/{}/auto-moderation/rules/{}",
+                    guild_id, auto_moderation_rule_id
+                )
+                .into(),
+            ),
             Self::DeleteBan { guild_id, user_id } => (
                 Method::DELETE,
                 Path::GuildsIdBansUserId(guild_id),
@@ -984,6 +1372,26 @@ impl Route {
                 Path::GuildsIdIntegrationsId(guild_id),
                 format!("guilds/{}/integrations/{}", guild_id, integration_id).into(),
             ),
+            Self::DeleteGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}",
+                    guild_id, scheduled_event_id
+                )
+                .into(),
+            ),
+            Self::DeleteGuildSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdStickersId(guild_id),
+                format!("guilds/{}/stickers/{}", guild_id, sticker_id).into(),
+            ),
             Self::DeleteInvite { code } => (
                 Method::DELETE,
                 Path::InvitesCode,
@@ -1050,6 +1458,14 @@ impl Route {
                 Path::GuildsIdRolesId(guild_id),
                 format!("guilds/{}/roles/{}", guild_id, role_id).into(),
             ),
+            Self::DeleteTemplate {
+                guild_id,
+                template_code,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdTemplatesCode(guild_id),
+                format!("guilds/{}/templates/{}", guild_id, template_code).into(),
+            ),
             Self::DeleteWebhookMessage {
                 message_id,
                 token,
@@ -1070,14 +1486,21 @@ impl Route {
                 (Method::DELETE, Path::WebhooksId(webhook_id), path.into())
             }
             Self::ExecuteWebhook {
+                thread_id,
                 token,
                 wait,
                 webhook_id,
             } => {
                 let mut path = format!("webhooks/{}/{}", webhook_id, token);
+                let mut separator = '?';
 
                 if let Some(wait) = wait {
-                    let _ = write!(path, "?wait={}", wait);
+                    let _ = write!(path, "{}wait={}", separator, wait);
+                    separator = '&';
+                }
+
+                if let Some(thread_id) = thread_id {
+                    let _ = write!(path, "{}thread_id={}", separator, thread_id);
                 }
 
                 (Method::POST, Path::WebhooksId(webhook_id), path.into())
@@ -1114,6 +1537,18 @@ impl Route {
 
                 (Method::GET, Path::GuildsIdAuditLogs(guild_id), path.into())
             }
+            Self::GetAutoModerationRule {
+                auto_moderation_rule_id,
+                guild_id,
+            } => (
+                Method::GET,
+                Path::GuildsIdAutoModerationRulesId(guild_id),
+                format!(
+                    "guilds/{}/auto-moderation/rules/{}",
+                    guild_id, auto_moderation_rule_id
+                )
+                .into(),
+            ),
             Self::GetBan { guild_id, user_id } => (
                 Method::GET,
                 Path::GuildsIdBansId(guild_id),
@@ -1124,6 +1559,28 @@ impl Route {
                 Path::GuildsIdBans(guild_id),
                 format!("guilds/{}/bans", guild_id).into(),
             ),
+            Self::GetBansWithParameters {
+                after,
+                before,
+                guild_id,
+                limit,
+            } => {
+                let mut path = format!("guilds/{}/bans?", guild_id);
+
+                if let Some(after) = after {
+                    let _ = write!(path, "after={}", after);
+                }
+
+                if let Some(before) = before {
+                    let _ = write!(path, "&before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (Method::GET, Path::GuildsIdBans(guild_id), path.into())
+            }
             Self::GetGatewayBot => (Method::GET, Path::GatewayBot, "gateway/bot".into()),
             Self::GetChannel { channel_id } => (
                 Method::GET,
@@ -1171,6 +1628,11 @@ impl Route {
                 }
                 (Method::GET, Path::GuildsId(guild_id), path.into())
             }
+            Self::GetGuildAutoModerationRules { guild_id } => (
+                Method::GET,
+                Path::GuildsIdAutoModerationRules(guild_id),
+                format!("guilds/{}/auto-moderation/rules", guild_id).into(),
+            ),
             Self::GetGuildWidget { guild_id } => (
                 Method::GET,
                 Path::GuildsIdWidget(guild_id),
@@ -1243,11 +1705,94 @@ impl Route {
                 Path::GuildsIdRoles(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::GetGuildSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                Method::GET,
+                Path::GuildsIdStickersId(guild_id),
+                format!("guilds/{}/stickers/{}", guild_id, sticker_id).into(),
+            ),
+            Self::GetGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+                with_user_count,
+            } => (
+                Method::GET,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}?with_user_count={}",
+                    guild_id, scheduled_event_id, with_user_count
+                )
+                .into(),
+            ),
+            Self::GetGuildScheduledEventUsers {
+                after,
+                before,
+                guild_id,
+                limit,
+                scheduled_event_id,
+                with_member,
+            } => {
+                let mut path = format!(
+                    "guilds/{}/scheduled-events/{}/users?",
+                    guild_id, scheduled_event_id
+                );
+
+                if let Some(after) = after {
+                    let _ = write!(path, "after={}", after);
+                }
+
+                if let Some(before) = before {
+                    let _ = write!(path, "&before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                if let Some(with_member) = with_member {
+                    let _ = write!(path, "&with_member={}", with_member);
+                }
+
+                (
+                    Method::GET,
+                    Path::GuildsIdScheduledEventsIdUsers(guild_id),
+                    path.into(),
+                )
+            }
+            Self::GetGuildScheduledEvents {
+                guild_id,
+                with_user_count,
+            } => (
+                Method::GET,
+                Path::GuildsIdScheduledEvents(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events?with_user_count={}",
+                    guild_id, with_user_count
+                )
+                .into(),
+            ),
+            Self::GetGuildStickers { guild_id } => (
+                Method::GET,
+                Path::GuildsIdStickers(guild_id),
+                format!("guilds/{}/stickers", guild_id).into(),
+            ),
             Self::GetGuildVanityUrl { guild_id } => (
                 Method::GET,
                 Path::GuildsIdVanityUrl(guild_id),
                 format!("guilds/{}/vanity-url", guild_id).into(),
             ),
+            Self::GetGuildMembershipScreening { guild_id } => (
+                Method::GET,
+                Path::GuildsIdMemberVerification(guild_id),
+                format!("guilds/{}/member-verification", guild_id).into(),
+            ),
+            Self::GetGuildWelcomeScreen { guild_id } => (
+                Method::GET,
+                Path::GuildsIdWelcomeScreen(guild_id),
+                format!("guilds/{}/welcome-screen", guild_id).into(),
+            ),
             Self::GetGuildVoiceRegions { guild_id } => (
                 Method::GET,
                 Path::GuildsIdRegions(guild_id),
@@ -1328,6 +1873,7 @@ impl Route {
                     path.into(),
                 )
             }
+            Self::GetNitroStickerPacks => (Method::GET, Path::StickerPacks, "sticker-packs".into()),
             Self::GetPins { channel_id } => (
                 Method::GET,
                 Path::ChannelsIdPins(channel_id),
@@ -1351,7 +1897,7 @@ impl Route {
                 }
 
                 if let Some(before) = before {
-                    let _ = write!(path, "before={}", before);
+                    let _ = write!(path, "&before={}", before);
                 }
 
                 if let Some(limit) = limit {
@@ -1374,6 +1920,21 @@ impl Route {
                 Path::UsersIdChannels,
                 "users/@me/channels".into(),
             ),
+            Self::GetSticker { sticker_id } => (
+                Method::GET,
+                Path::Stickers,
+                format!("stickers/{}", sticker_id).into(),
+            ),
+            Self::GetTemplate { template_code } => (
+                Method::GET,
+                Path::GuildsTemplatesCode,
+                format!("guilds/templates/{}", template_code).into(),
+            ),
+            Self::GetTemplates { guild_id } => (
+                Method::GET,
+                Path::GuildsIdTemplates(guild_id),
+                format!("guilds/{}/templates", guild_id).into(),
+            ),
             Self::GetUser { target_user } => (
                 Method::GET,
                 Path::UsersId,
@@ -1390,6 +1951,15 @@ impl Route {
 
                 (Method::GET, Path::WebhooksId(webhook_id), path.into())
             }
+            Self::GetWebhookMessage {
+                message_id,
+                token,
+                webhook_id,
+            } => (
+                Method::GET,
+                Path::WebhooksIdTokenMessageId(webhook_id),
+                format!("webhooks/{}/{}/messages/{}", webhook_id, token, message_id).into(),
+            ),
             Self::LeaveGuild { guild_id } => (
                 Method::DELETE,
                 Path::UsersIdGuildsId,
@@ -1417,6 +1987,11 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::RevokeOauthToken => (
+                Method::POST,
+                Path::OauthTokenRevoke,
+                "oauth2/token/revoke".into(),
+            ),
             Self::SyncGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1425,6 +2000,14 @@ impl Route {
                 Path::GuildsIdIntegrationsIdSync(guild_id),
                 format!("guilds/{}/integrations/{}/sync", guild_id, integration_id).into(),
             ),
+            Self::SyncTemplate {
+                guild_id,
+                template_code,
+            } => (
+                Method::PUT,
+                Path::GuildsIdTemplatesCode(guild_id),
+                format!("guilds/{}/templates/{}", guild_id, template_code).into(),
+            ),
             Self::UnpinMessage {
                 channel_id,
                 message_id,
@@ -1433,6 +2016,18 @@ impl Route {
                 Path::ChannelsIdPinsMessageId(channel_id),
                 format!("channels/{}/pins/{}", channel_id, message_id).into(),
             ),
+            Self::UpdateAutoModerationRule {
+                auto_moderation_rule_id,
+                guild_id,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdAutoModerationRulesId(guild_id),
+                format!(
+                    "guilds/{}/auto-moderation/rules/{}",
+                    guild_id, auto_moderation_rule_id
+                )
+                .into(),
+            ),
             Self::UpdateChannel { channel_id } => (
                 Method::PATCH,
                 Path::ChannelsId(channel_id),
@@ -1459,6 +2054,16 @@ impl Route {
                 Path::GuildsIdWidget(guild_id),
                 format!("guilds/{}/widget", guild_id).into(),
             ),
+            Self::UpdateGuildMembershipScreening { guild_id } => (
+                Method::PATCH,
+                Path::GuildsIdMemberVerification(guild_id),
+                format!("guilds/{}/member-verification", guild_id).into(),
+            ),
+            Self::UpdateGuildWelcomeScreen { guild_id } => (
+                Method::PATCH,
+                Path::GuildsIdWelcomeScreen(guild_id),
+                format!("guilds/{}/welcome-screen", guild_id).into(),
+            ),
             Self::UpdateGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1467,6 +2072,26 @@ impl Route {
                 Path::GuildsIdIntegrationsId(guild_id),
                 format!("guilds/{}/integrations/{}", guild_id, integration_id,).into(),
             ),
+            Self::UpdateGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}",
+                    guild_id, scheduled_event_id
+                )
+                .into(),
+            ),
+            Self::UpdateGuildSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdStickersId(guild_id),
+                format!("guilds/{}/stickers/{}", guild_id, sticker_id).into(),
+            ),
             Self::UpdateMember { guild_id, user_id } => (
                 Method::PATCH,
                 Path::GuildsIdMembersId(guild_id),
@@ -1503,6 +2128,14 @@ impl Route {
                 Path::GuildsIdRolesId(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::UpdateTemplate {
+                guild_id,
+                template_code,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdTemplatesCode(guild_id),
+                format!("guilds/{}/templates/{}", guild_id, template_code).into(),
+            ),
             Self::UpdateWebhookMessage {
                 message_id,
                 token,
@@ -1528,10 +2161,51 @@ impl Route {
 
 #[cfg(test)]
 mod tests {
-    use super::{Path, PathParseError};
+    use super::{Path, PathParseError, Route};
     use hyper::Method;
     use std::{convert::TryFrom, error::Error, str::FromStr};
 
+    #[test]
+    fn test_get_reaction_users_query() {
+        let route = Route::GetReactionUsers {
+            after: Some(1),
+            before: Some(2),
+            channel_id: 3,
+            emoji: "a".to_owned(),
+            limit: Some(4),
+            message_id: 5,
+        };
+
+        let (_, _, path) = route.into_parts();
+        assert_eq!(
+            "channels/3/messages/5/reactions/a?after=1&before=2&limit=4",
+            path
+        );
+    }
+
+    #[test]
+    fn test_execute_webhook_query() {
+        let route = |thread_id, wait| {
+            let (_, _, path) = Route::ExecuteWebhook {
+                thread_id,
+                token: "token".to_owned(),
+                wait,
+                webhook_id: 1,
+            }
+            .into_parts();
+
+            path
+        };
+
+        assert_eq!("webhooks/1/token", route(None, None));
+        assert_eq!("webhooks/1/token?wait=true", route(None, Some(true)));
+        assert_eq!("webhooks/1/token?thread_id=2", route(Some(2), None));
+        assert_eq!(
+            "webhooks/1/token?wait=true&thread_id=2",
+            route(Some(2), Some(true))
+        );
+    }
+
     #[test]
     fn test_path_prefix_unimportant() -> Result<(), Box<dyn Error>> {
         assert_eq!(Path::Guilds, Path::from_str("guilds")?);
@@ -1545,6 +2219,57 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::GuildsTemplatesCode,
+            Path::from_str("/guilds/templates/abc")?
+        );
+        assert_eq!(
+            Path::GuildsIdTemplatesCode(123),
+            Path::from_str("/guilds/123/templates/abc")?
+        );
+        assert_eq!(Path::OauthToken, Path::from_str("/oauth2/token")?);
+        assert_eq!(
+            Path::OauthTokenRevoke,
+            Path::from_str("/oauth2/token/revoke")?
+        );
+        assert_eq!(
+            Path::GuildsIdStickersId(123),
+            Path::from_str("/guilds/123/stickers/456")?
+        );
+        assert_eq!(Path::StickerPacks, Path::from_str("/sticker-packs")?);
+        assert_eq!(
+            Path::GuildsIdScheduledEvents(123),
+            Path::from_str("/guilds/123/scheduled-events")?
+        );
+        assert_eq!(
+            Path::GuildsIdScheduledEventsId(123),
+            Path::from_str("/guilds/123/scheduled-events/456")?
+        );
+        assert_eq!(
+            Path::GuildsIdScheduledEventsIdUsers(123),
+            Path::from_str("/guilds/123/scheduled-events/456/users")?
+        );
+        assert_eq!(
+            Path::GuildsIdAutoModerationRules(123),
+            Path::from_str("/guilds/123/auto-moderation/rules")?
+        );
+        assert_eq!(
+            Path::GuildsIdAutoModerationRulesId(123),
+            Path::from_str("/guilds/123/auto-moderation/rules/456")?
+        );
+        assert_eq!(Path::Stickers, Path::from_str("/stickers/123")?);
+        assert_eq!(
+            Path::GuildsIdMemberVerification(123),
+            Path::from_str("/guilds/123/member-verification")?
+        );
+        assert_eq!(
+            Path::GuildsIdWelcomeScreen(123),
+            Path::from_str("/guilds/123/welcome-screen")?
+        );
+        assert_eq!(
+            Path::WebhooksIdTokenMessageId(123),
+            Path::from_str("/webhooks/123/token/messages/456")?
+        );
 
         Ok(())
     }
@@ -0,0 +1,1374 @@
+use futures_util::stream::TryStreamExt;
+use hyper::{Method, StatusCode};
+use serde_json::json;
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+use twilight_http::{
+    api_error::ApiError,
+    client::{RetryPolicy, WebhookClient},
+    ratelimiting::{InMemoryRatelimiter, RatelimitHeaders},
+    request::{
+        channel::message::{
+            get_channel_messages_configured::GetChannelMessagesConfiguredError, MessageBuilder,
+            MessageSplitter,
+        },
+        Attachment, AuditLogReason,
+    },
+    routing::Path,
+    Client, Error as HttpError,
+};
+use twilight_http_mock::{MockBucket, MockHttp, MockResponse};
+use twilight_model::{
+    channel::message::{sticker::StickerId, MessageFlags},
+    guild::{
+        auto_moderation::{
+            AutoModerationAction, AutoModerationActionType, AutoModerationEventType,
+            AutoModerationTriggerMetadata, AutoModerationTriggerType,
+        },
+        MembershipScreeningField, MembershipScreeningFieldType, WelcomeScreenChannel,
+    },
+    id::{
+        ApplicationId, AttachmentId, AutoModerationRuleId, ChannelId, GuildId, MessageId, RoleId,
+        ScheduledEventId, UserId, WebhookId,
+    },
+    oauth::Scope,
+    scheduled_event::{EntityType, Status},
+};
+
+fn client(mock: &MockHttp) -> Client {
+    Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .token("token")
+        .build()
+}
+
+fn message() -> serde_json::Value {
+    json!({
+        "attachments": [],
+        "author": {
+            "avatar": null,
+            "discriminator": "0000",
+            "id": "1",
+            "username": "alerts",
+        },
+        "channel_id": "3",
+        "content": "disk usage is at 90%",
+        "edited_timestamp": null,
+        "embeds": [],
+        "id": "4",
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2021-01-01T00:00:00.000000+00:00",
+        "tts": false,
+        "type": 0,
+        "webhook_id": "1",
+    })
+}
+
+#[tokio::test]
+async fn test_canned_response() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::GET,
+        "users/@me",
+        MockResponse::json(&json!({
+            "avatar": null,
+            "bot": true,
+            "discriminator": "0001",
+            "id": "1",
+            "mfa_enabled": false,
+            "username": "twilight",
+        })),
+    );
+
+    let user = client(&mock).current_user().await?.model()?;
+    assert_eq!("twilight", user.name);
+
+    let requests = mock.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!(Path::UsersId, requests[0].path);
+    assert_eq!("users/@me", requests[0].route);
+    assert_eq!("Bot token", requests[0].headers["authorization"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_response() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::builder()
+        .bucket(
+            Path::ChannelsIdTyping(1),
+            MockBucket::new(5, Duration::from_secs(5)),
+        )
+        .bind()?;
+    mock.respond(
+        Method::POST,
+        "channels/1/typing",
+        MockResponse::empty().header("x-test", "1"),
+    );
+
+    let response = client(&mock).create_typing_trigger(ChannelId(1)).await?;
+    assert_eq!(StatusCode::NO_CONTENT, response.status());
+    assert_eq!("1", response.headers()["x-test"]);
+    assert!(response.bytes().is_empty());
+    assert!(matches!(
+        response.ratelimit_headers()?,
+        RatelimitHeaders::Present {
+            limit: 5,
+            remaining: 4,
+            ..
+        }
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_route() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+
+    let error = client(&mock)
+        .create_typing_trigger(ChannelId(1))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        HttpError::Response { status, .. } if status == StatusCode::NOT_FOUND
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ratelimiter_waits_for_bucket() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::builder()
+        .bucket(
+            Path::ChannelsIdTyping(1),
+            MockBucket::new(2, Duration::from_millis(500)),
+        )
+        .bind()?;
+    mock.respond(Method::POST, "channels/1/typing", MockResponse::empty());
+
+    let client = client(&mock);
+    let start = Instant::now();
+
+    for _ in 0..3 {
+        client.create_typing_trigger(ChannelId(1)).await?;
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(400));
+
+    let requests = mock.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests
+        .iter()
+        .all(|request| request.status == StatusCode::NO_CONTENT));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ratelimiter_merges_buckets_sharing_hash() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::builder()
+        .bucket(
+            Path::UsersId,
+            MockBucket::new(2, Duration::from_millis(500)).hash("shared"),
+        )
+        .bucket(
+            Path::UsersIdGuilds,
+            MockBucket::new(2, Duration::from_millis(500)).hash("shared"),
+        )
+        .bind()?;
+    mock.respond(
+        Method::GET,
+        "users/@me",
+        MockResponse::json(&json!({
+            "avatar": null,
+            "bot": true,
+            "discriminator": "0001",
+            "id": "1",
+            "mfa_enabled": false,
+            "username": "twilight",
+        })),
+    );
+    mock.respond(
+        Method::GET,
+        "users/@me/guilds",
+        MockResponse::json(&json!([])),
+    );
+
+    let ratelimiter = InMemoryRatelimiter::new();
+    let client = Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .ratelimiter(Some(Box::new(ratelimiter.clone())))
+        .token("token")
+        .build();
+
+    client.current_user().await?;
+    client.current_user_guilds().await?;
+
+    // Headers are processed by the ratelimiter after the response is returned.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    for path in &[Path::UsersId, Path::UsersIdGuilds] {
+        let info = ratelimiter.bucket(&Method::GET, path).await.unwrap();
+        assert_eq!(Some("shared"), info.hash.as_deref());
+        assert_eq!(Some(2), info.limit);
+        assert_eq!(Some(0), info.remaining);
+    }
+
+    // The shared bucket is exhausted, so the request waits for it to reset.
+    let start = Instant::now();
+    client.current_user().await?;
+    assert!(start.elapsed() >= Duration::from_millis(300));
+
+    let requests = mock.requests();
+    assert_eq!(3, requests.len());
+    assert!(requests
+        .iter()
+        .all(|request| request.status == StatusCode::OK));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bucket_exhausted_without_ratelimiter() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::builder()
+        .default_bucket(MockBucket::new(1, Duration::from_secs(5)))
+        .bind()?;
+    mock.respond(Method::POST, "channels/1/typing", MockResponse::empty());
+
+    let client = Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .ratelimiter(None)
+        .token("token")
+        .build();
+
+    client.create_typing_trigger(ChannelId(1)).await?;
+    let error = client
+        .create_typing_trigger(ChannelId(1))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        HttpError::Response {
+            error: ApiError::Ratelimited(ref ratelimited),
+            status,
+            ..
+        } if status == StatusCode::TOO_MANY_REQUESTS && !ratelimited.global && ratelimited.retry_after > 4.0
+    ));
+
+    // Other paths have their own buckets.
+    mock.respond(Method::POST, "channels/2/typing", MockResponse::empty());
+    client.create_typing_trigger(ChannelId(2)).await?;
+
+    let requests = mock.requests();
+    assert_eq!(StatusCode::TOO_MANY_REQUESTS, requests[1].status);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_global_ratelimit() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(Method::POST, "channels/1/typing", MockResponse::empty());
+    mock.global_ratelimit(Duration::from_millis(200));
+
+    let client = client(&mock);
+    let error = client
+        .create_typing_trigger(ChannelId(1))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        HttpError::Response {
+            error: ApiError::Ratelimited(ref ratelimited),
+            ..
+        } if ratelimited.global
+    ));
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    client.create_typing_trigger(ChannelId(1)).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_server_errors() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::GET,
+        "users/@me",
+        MockResponse::status(StatusCode::BAD_GATEWAY),
+    );
+    mock.respond(
+        Method::POST,
+        "channels/1/typing",
+        MockResponse::status(StatusCode::GATEWAY_TIMEOUT),
+    );
+
+    let policy = RetryPolicy::new()
+        .max_attempts(3)
+        .backoff(Duration::from_millis(10), Duration::from_millis(50));
+    let client = Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .retry_policy(policy.clone())
+        .token("token")
+        .build();
+
+    assert!(client.current_user().await.is_err());
+    assert_eq!(3, mock.requests().len());
+
+    // Non-idempotent requests aren't retried on server errors by default.
+    assert!(client.create_typing_trigger(ChannelId(1)).await.is_err());
+    assert_eq!(4, mock.requests().len());
+
+    let client = Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .retry_policy(policy.retry_non_idempotent(true))
+        .token("token")
+        .build();
+
+    assert!(client.create_typing_trigger(ChannelId(1)).await.is_err());
+    assert_eq!(7, mock.requests().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_unexpected_ratelimit() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::builder()
+        .default_bucket(MockBucket::new(1, Duration::from_millis(300)))
+        .bind()?;
+    mock.respond(Method::POST, "channels/1/typing", MockResponse::empty());
+
+    let client = Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .ratelimiter(None)
+        .retry_policy(RetryPolicy::new().max_attempts(2))
+        .token("token")
+        .build();
+
+    client.create_typing_trigger(ChannelId(1)).await?;
+    client.create_typing_trigger(ChannelId(1)).await?;
+
+    let statuses = mock
+        .requests()
+        .into_iter()
+        .map(|request| request.status)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            StatusCode::NO_CONTENT,
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::NO_CONTENT,
+        ],
+        statuses
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_request_threshold() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "channels/1/typing",
+        MockResponse::error(StatusCode::FORBIDDEN, 50013, "Missing Permissions"),
+    );
+
+    let client = Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .invalid_request_threshold(Some(2))
+        .token("token")
+        .build();
+
+    for _ in 0..2 {
+        let error = client
+            .create_typing_trigger(ChannelId(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            HttpError::Response { status, .. } if status == StatusCode::FORBIDDEN
+        ));
+    }
+
+    assert_eq!(Some(2), client.invalid_request_count());
+
+    let error = client
+        .create_typing_trigger(ChannelId(1))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        HttpError::InvalidRequestLimit { retry_after } if retry_after > Duration::from_secs(590)
+    ));
+    assert_eq!(2, mock.requests().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_guild_member() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::PUT,
+        "guilds/1/members/2",
+        MockResponse::json(&json!({
+            "deaf": false,
+            "joined_at": "2021-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "nick": null,
+            "roles": ["3"],
+        }))
+        .with_status(StatusCode::CREATED),
+    );
+    mock.respond(Method::PUT, "guilds/1/members/4", MockResponse::empty());
+
+    let client = client(&mock);
+
+    let member = client
+        .add_guild_member(GuildId(1), UserId(2), "access token")
+        .await?
+        .expect("new members are returned")
+        .model()?;
+    assert_eq!(vec![RoleId(3)], member.roles);
+
+    // Users who are already members get a `204 No Content`.
+    let response = client
+        .add_guild_member(GuildId(1), UserId(4), "access token")
+        .await?;
+    assert!(response.is_none());
+
+    Ok(())
+}
+
+fn ban(user_id: u64) -> serde_json::Value {
+    json!({
+        "reason": null,
+        "user": {
+            "avatar": null,
+            "discriminator": "0001",
+            "id": user_id.to_string(),
+            "username": "banned",
+        },
+    })
+}
+
+#[tokio::test]
+async fn test_paginate() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::GET,
+        "guilds/1/bans",
+        MockResponse::json(&json!([ban(2), ban(3)])),
+    );
+    mock.respond(
+        Method::GET,
+        "guilds/1/bans",
+        MockResponse::json(&json!([ban(4)])),
+    );
+
+    let bans = client(&mock)
+        .bans(GuildId(1))
+        .after(UserId(1))
+        .limit(2)?
+        .paginate()
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let ids = bans.iter().map(|ban| ban.user.id).collect::<Vec<_>>();
+    assert_eq!(vec![UserId(2), UserId(3), UserId(4)], ids);
+
+    let requests = mock.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!("guilds/1/bans?after=1&limit=2", requests[0].route);
+    assert_eq!("guilds/1/bans?after=3&limit=2", requests[1].route);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_paginate_messages() -> Result<(), Box<dyn Error>> {
+    let message_with_id = |id: u64| {
+        let mut message = message();
+        message["id"] = json!(id.to_string());
+
+        message
+    };
+
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::GET,
+        "channels/3/messages",
+        MockResponse::json(&json!([message_with_id(9), message_with_id(8)])),
+    );
+    mock.respond(
+        Method::GET,
+        "channels/3/messages",
+        MockResponse::json(&json!([message_with_id(7)])),
+    );
+    mock.respond(
+        Method::GET,
+        "channels/3/messages",
+        MockResponse::json(&json!([message_with_id(12), message_with_id(11)])),
+    );
+    mock.respond(
+        Method::GET,
+        "channels/3/messages",
+        MockResponse::json(&json!([])),
+    );
+
+    let client = client(&mock);
+
+    let before = client
+        .channel_messages(ChannelId(3))
+        .before(MessageId(10))
+        .limit(2)?
+        .paginate()?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let ids = before
+        .iter()
+        .map(|message| message.id.0)
+        .collect::<Vec<_>>();
+    assert_eq!(vec![9, 8, 7], ids);
+
+    let after = client
+        .channel_messages(ChannelId(3))
+        .after(MessageId(10))
+        .limit(2)?
+        .paginate()?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let ids = after.iter().map(|message| message.id.0).collect::<Vec<_>>();
+    assert_eq!(vec![12, 11], ids);
+
+    assert!(matches!(
+        client
+            .channel_messages(ChannelId(3))
+            .around(MessageId(10))
+            .paginate(),
+        Err(GetChannelMessagesConfiguredError::PaginateAround)
+    ));
+
+    let routes = mock
+        .requests()
+        .into_iter()
+        .map(|request| request.route)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            "channels/3/messages?&before=10&limit=2",
+            "channels/3/messages?&before=8&limit=2",
+            "channels/3/messages?after=10&limit=2",
+            "channels/3/messages?after=12&limit=2",
+        ],
+        routes
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_paginate_total() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::GET,
+        "guilds/1/bans",
+        MockResponse::json(&json!([ban(1), ban(2), ban(3)])),
+    );
+
+    let bans = client(&mock)
+        .bans(GuildId(1))
+        .limit(3)?
+        .paginate()
+        .total(3)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(3, bans.len());
+
+    // The cap is reached with the first page, so no further page is
+    // requested.
+    assert_eq!(1, mock.requests().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_attachments() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::PATCH,
+        "webhooks/1/token/messages/2",
+        MockResponse::empty(),
+    );
+
+    let reader = std::io::Cursor::new(b"streamed contents".to_vec());
+
+    client(&mock)
+        .update_webhook_message(WebhookId(1), "token", MessageId(2))
+        .attachment(Attachment::from_reader("a.txt", reader).content_type("text/plain"))
+        .attachment(
+            Attachment::from_bytes("b.png", &b"buffered contents"[..])
+                .description("an image")
+                .spoiler(true),
+        )
+        .keep_attachment_ids(vec![AttachmentId(3)])
+        .await?;
+
+    let requests = mock.requests();
+    assert_eq!(1, requests.len());
+
+    let request = &requests[0];
+    let content_type = request.headers["content-type"].to_str()?;
+    assert!(content_type.starts_with("multipart/form-data; boundary="));
+    // Forms with files read from a reader are streamed without a length.
+    assert!(request.headers.get("content-length").is_none());
+
+    let body = String::from_utf8(request.body.clone())?;
+    assert!(body.contains(
+        "name=\"files[0]\"; filename=\"a.txt\"\r\nContent-Type: text/plain\r\n\r\nstreamed contents"
+    ));
+    assert!(body.contains("name=\"files[1]\"; filename=\"SPOILER_b.png\"\r\n\r\nbuffered contents"));
+    assert!(body.contains(
+        r#"{"attachments":[{"filename":"a.txt","id":0},{"description":"an image","filename":"SPOILER_b.png","id":1},{"id":3}]"#
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_attachment_reader_not_retried() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "webhooks/1/token",
+        MockResponse::status(StatusCode::BAD_GATEWAY),
+    );
+
+    let policy = RetryPolicy::new()
+        .max_attempts(3)
+        .backoff(Duration::from_millis(10), Duration::from_millis(50))
+        .retry_non_idempotent(true);
+    let client = Client::builder()
+        .proxy(mock.proxy_url(), true)
+        .retry_policy(policy)
+        .token("token")
+        .build();
+
+    let attachment = Attachment::from_bytes("a.txt", &b"contents"[..]);
+    assert!(client
+        .execute_webhook(WebhookId(1), "token")
+        .attachment(attachment)
+        .await
+        .is_err());
+    assert_eq!(3, mock.requests().len());
+
+    let reader = std::io::Cursor::new(b"contents".to_vec());
+    assert!(client
+        .execute_webhook(WebhookId(1), "token")
+        .attachment(Attachment::from_reader("a.txt", reader))
+        .await
+        .is_err());
+    assert_eq!(4, mock.requests().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_templates() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::PUT,
+        "guilds/1/templates/abc",
+        MockResponse::json(&json!({
+            "code": "abc",
+            "created_at": "2021-01-01T00:00:00+00:00",
+            "creator": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "2",
+                "username": "twilight",
+            },
+            "creator_id": "2",
+            "description": null,
+            "is_dirty": null,
+            "name": "events",
+            "serialized_source_guild": {
+                "afk_channel_id": null,
+                "afk_timeout": 300,
+                "channels": [],
+                "default_message_notifications": 0,
+                "description": null,
+                "explicit_content_filter": 0,
+                "icon_hash": null,
+                "name": "event server",
+                "preferred_locale": "en-US",
+                "roles": [],
+                "system_channel_flags": 0,
+                "system_channel_id": null,
+                "verification_level": 0,
+            },
+            "source_guild_id": "1",
+            "updated_at": "2021-01-02T00:00:00+00:00",
+            "usage_count": 3,
+        })),
+    );
+
+    let client = client(&mock);
+
+    assert!(client.get_template("missing").await?.is_none());
+
+    let template = client.sync_template(GuildId(1), "abc").await?.model()?;
+    assert_eq!("events", template.name);
+    assert_eq!(GuildId(1), template.source_guild_id);
+
+    let requests = mock.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!(Path::GuildsTemplatesCode, requests[0].path);
+    assert_eq!("guilds/templates/missing", requests[0].route);
+    assert_eq!(Path::GuildsIdTemplatesCode(1), requests[1].path);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_oauth_token() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "oauth2/token",
+        MockResponse::status(StatusCode::UNAUTHORIZED),
+    );
+    mock.respond(
+        Method::POST,
+        "oauth2/token",
+        MockResponse::json(&json!({
+            "access_token": "access",
+            "expires_in": 604_800,
+            "refresh_token": "refresh",
+            "scope": "identify guilds",
+            "token_type": "Bearer",
+        })),
+    );
+
+    let client = client(&mock);
+
+    // Invalid client credentials don't invalidate the client's own token.
+    assert!(client
+        .exchange_code(ApplicationId(1), "wrong secret", "code", "https://a.b/c")
+        .await
+        .is_err());
+
+    let token = client
+        .exchange_code(ApplicationId(1), "secret", "code", "https://a.b/c")
+        .await?
+        .model()?;
+    assert_eq!("access", token.access_token);
+    assert_eq!(Some("refresh"), token.refresh_token.as_deref());
+    assert_eq!(vec![Scope::Identify, Scope::Guilds], token.scopes);
+
+    let requests = mock.requests();
+    assert_eq!(2, requests.len());
+    let request = &requests[1];
+    assert_eq!(Path::OauthToken, request.path);
+    assert_eq!(
+        "Basic MTpzZWNyZXQ=",
+        request.headers["authorization"].to_str()?
+    );
+    assert_eq!(
+        "application/x-www-form-urlencoded",
+        request.headers["content-type"].to_str()?
+    );
+    assert_eq!(
+        "grant_type=authorization%5Fcode&code=code&redirect_uri=https%3A%2F%2Fa%2Eb%2Fc",
+        std::str::from_utf8(&request.body)?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stickers() -> Result<(), Box<dyn Error>> {
+    let sticker = json!({
+        "available": true,
+        "description": "a cat",
+        "format_type": 1,
+        "guild_id": "1",
+        "id": "2",
+        "name": "cat",
+        "tags": "cat",
+        "type": 2,
+    });
+
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "guilds/1/stickers",
+        MockResponse::json(&sticker),
+    );
+    mock.respond(
+        Method::GET,
+        "sticker-packs",
+        MockResponse::json(&json!({
+            "sticker_packs": [{
+                "banner_asset_id": "3",
+                "cover_sticker_id": "2",
+                "description": "cats",
+                "id": "4",
+                "name": "cats",
+                "sku_id": "5",
+                "stickers": [sticker],
+            }],
+        })),
+    );
+    mock.respond(Method::POST, "channels/6/messages", MockResponse::empty());
+
+    let client = client(&mock);
+
+    let file = Attachment::from_bytes("cat.png", &b"png contents"[..]).content_type("image/png");
+    let created = client
+        .create_guild_sticker(GuildId(1), "cat", "cat", file)?
+        .description("a cat")?
+        .reason("new sticker")?
+        .await?
+        .model()?;
+    assert_eq!(StickerId(2), created.id);
+    assert_eq!(Some(GuildId(1)), created.guild_id);
+
+    let packs = client.nitro_sticker_packs().await?.model()?;
+    assert_eq!(1, packs.sticker_packs.len());
+    assert_eq!(Some(StickerId(2)), packs.sticker_packs[0].cover_sticker_id);
+
+    assert!(client
+        .create_message(ChannelId(6))
+        .sticker_ids(vec![StickerId(1), StickerId(2), StickerId(3), StickerId(4)])
+        .is_err());
+    client
+        .create_message(ChannelId(6))
+        .sticker_ids(vec![StickerId(2)])?
+        .await?;
+
+    let requests = mock.requests();
+    assert_eq!(3, requests.len());
+
+    let request = &requests[0];
+    assert_eq!(Path::GuildsIdStickers(1), request.path);
+    assert_eq!("new%20sticker", request.headers["x-audit-log-reason"]);
+    assert!(request.headers["content-type"]
+        .to_str()?
+        .starts_with("multipart/form-data; boundary="));
+    let body = String::from_utf8(request.body.clone())?;
+    assert!(body.contains("name=\"name\"\r\n\r\ncat\r\n"));
+    assert!(body.contains("name=\"description\"\r\n\r\na cat\r\n"));
+    assert!(body.contains("name=\"tags\"\r\n\r\ncat\r\n"));
+    assert!(body.contains(
+        "name=\"file\"; filename=\"cat.png\"\r\nContent-Type: image/png\r\n\r\npng contents"
+    ));
+
+    assert_eq!(Path::StickerPacks, requests[1].path);
+    assert_eq!(br#"{"sticker_ids":["2"]}"#, requests[2].body.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_welcome_screen_and_membership_screening() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::PATCH,
+        "guilds/1/welcome-screen",
+        MockResponse::json(&json!({
+            "description": null,
+            "welcome_channels": [{
+                "channel_id": "2",
+                "description": "rules",
+                "emoji_id": null,
+                "emoji_name": null,
+            }],
+        })),
+    );
+    mock.respond(
+        Method::PATCH,
+        "guilds/1/member-verification",
+        MockResponse::json(&json!({
+            "description": "hi",
+            "form_fields": [{
+                "field_type": "TERMS",
+                "label": "rules",
+                "required": true,
+                "values": ["be nice"],
+            }],
+            "version": "2021-01-01T00:00:00.000000+00:00",
+        })),
+    );
+
+    let client = client(&mock);
+
+    let channel = WelcomeScreenChannel {
+        channel_id: ChannelId(2),
+        description: "rules".to_owned(),
+        emoji_id: None,
+        emoji_name: None,
+    };
+    let screen = client
+        .update_guild_welcome_screen(GuildId(1))
+        .enabled(true)
+        .welcome_channels(vec![channel.clone()])
+        .await?
+        .model()?;
+    assert_eq!(vec![channel], screen.welcome_channels);
+
+    let field = MembershipScreeningField {
+        field_type: MembershipScreeningFieldType::Terms,
+        label: "rules".to_owned(),
+        required: true,
+        values: vec!["be nice".to_owned()],
+    };
+    let screening = client
+        .update_guild_membership_screening(GuildId(1))
+        .description("hi".to_owned())
+        .form_fields(vec![field.clone()])
+        .await?
+        .model()?;
+    assert_eq!(vec![field], screening.form_fields);
+
+    let requests = mock.requests();
+    assert_eq!(2, requests.len());
+
+    assert_eq!(Path::GuildsIdWelcomeScreen(1), requests[0].path);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+    assert_eq!(
+        json!({
+            "enabled": true,
+            "welcome_channels": [{
+                "channel_id": "2",
+                "description": "rules",
+                "emoji_id": null,
+                "emoji_name": null,
+            }],
+        }),
+        body,
+    );
+
+    assert_eq!(Path::GuildsIdMemberVerification(1), requests[1].path);
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body)?;
+    assert_eq!(
+        json!({
+            "description": "hi",
+            "form_fields": r#"[{"field_type":"TERMS","label":"rules","required":true,"values":["be nice"]}]"#,
+        }),
+        body,
+    );
+
+    Ok(())
+}
+
+fn scheduled_event_user(user_id: u64) -> serde_json::Value {
+    json!({
+        "guild_scheduled_event_id": "2",
+        "user": {
+            "avatar": null,
+            "discriminator": "0001",
+            "id": user_id.to_string(),
+            "username": "subscriber",
+        },
+    })
+}
+
+#[tokio::test]
+async fn test_scheduled_events() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "guilds/1/scheduled-events",
+        MockResponse::json(&json!({
+            "channel_id": null,
+            "creator_id": "3",
+            "description": null,
+            "entity_id": null,
+            "entity_metadata": {
+                "location": "the park",
+            },
+            "entity_type": 3,
+            "guild_id": "1",
+            "id": "2",
+            "name": "picnic",
+            "privacy_level": 2,
+            "scheduled_end_time": "2021-01-01T02:00:00.000000+00:00",
+            "scheduled_start_time": "2021-01-01T00:00:00.000000+00:00",
+            "status": 1,
+        })),
+    );
+    mock.respond(
+        Method::GET,
+        "guilds/1/scheduled-events/2/users",
+        MockResponse::json(&json!([scheduled_event_user(4), scheduled_event_user(5)])),
+    );
+    mock.respond(
+        Method::GET,
+        "guilds/1/scheduled-events/2/users",
+        MockResponse::json(&json!([scheduled_event_user(6)])),
+    );
+
+    let client = client(&mock);
+
+    let event = client
+        .create_guild_scheduled_event(
+            GuildId(1),
+            "picnic",
+            EntityType::External,
+            "2021-01-01T00:00:00.000000+00:00",
+        )?
+        .location("the park")
+        .scheduled_end_time("2021-01-01T02:00:00.000000+00:00")
+        .await?
+        .model()?;
+    assert_eq!(ScheduledEventId(2), event.id);
+    assert_eq!(Status::Scheduled, event.status);
+
+    let users = client
+        .guild_scheduled_event_users(GuildId(1), ScheduledEventId(2))
+        .limit(2)?
+        .paginate()
+        .try_collect::<Vec<_>>()
+        .await?;
+    let ids = users.iter().map(|user| user.user.id).collect::<Vec<_>>();
+    assert_eq!(vec![UserId(4), UserId(5), UserId(6)], ids);
+
+    let requests = mock.requests();
+    assert_eq!(3, requests.len());
+
+    assert_eq!(Path::GuildsIdScheduledEvents(1), requests[0].path);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+    assert_eq!(
+        json!({
+            "entity_metadata": {
+                "location": "the park",
+            },
+            "entity_type": 3,
+            "name": "picnic",
+            "privacy_level": 2,
+            "scheduled_end_time": "2021-01-01T02:00:00.000000+00:00",
+            "scheduled_start_time": "2021-01-01T00:00:00.000000+00:00",
+        }),
+        body,
+    );
+
+    assert_eq!(
+        "guilds/1/scheduled-events/2/users?&limit=2",
+        requests[1].route
+    );
+    assert_eq!(
+        "guilds/1/scheduled-events/2/users?after=5&limit=2",
+        requests[2].route
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_auto_moderation_rules() -> Result<(), Box<dyn Error>> {
+    let rule = json!({
+        "actions": [{"type": 1}],
+        "creator_id": "3",
+        "enabled": true,
+        "event_type": 1,
+        "exempt_channels": [],
+        "exempt_roles": ["4"],
+        "guild_id": "1",
+        "id": "2",
+        "name": "no spoilers",
+        "trigger_metadata": {
+            "keyword_filter": ["*ending*"],
+        },
+        "trigger_type": 1,
+    });
+
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "guilds/1/auto-moderation/rules",
+        MockResponse::json(&rule),
+    );
+    mock.respond(
+        Method::PATCH,
+        "guilds/1/auto-moderation/rules/2",
+        MockResponse::json(&rule),
+    );
+
+    let client = client(&mock);
+
+    let created = client
+        .create_auto_moderation_rule(
+            GuildId(1),
+            "no spoilers",
+            AutoModerationEventType::MessageSend,
+            AutoModerationTriggerType::Keyword,
+        )
+        .actions(vec![AutoModerationAction {
+            kind: AutoModerationActionType::BlockMessage,
+            metadata: None,
+        }])
+        .enabled(true)
+        .exempt_roles(vec![RoleId(4)])?
+        .trigger_metadata(AutoModerationTriggerMetadata {
+            keyword_filter: Some(vec!["*ending*".to_owned()]),
+            ..AutoModerationTriggerMetadata::default()
+        })
+        .reason("spoilers")?
+        .await?
+        .model()?;
+    assert_eq!(AutoModerationRuleId(2), created.id);
+    assert_eq!(vec![RoleId(4)], created.exempt_roles);
+
+    let updated = client
+        .update_auto_moderation_rule(GuildId(1), AutoModerationRuleId(2))
+        .enabled(false)
+        .await?
+        .model()?;
+    assert_eq!(created, updated);
+
+    assert!(client
+        .update_auto_moderation_rule(GuildId(1), AutoModerationRuleId(2))
+        .exempt_roles(vec![RoleId(4); 21])
+        .is_err());
+
+    let requests = mock.requests();
+    assert_eq!(2, requests.len());
+
+    assert_eq!(Path::GuildsIdAutoModerationRules(1), requests[0].path);
+    assert_eq!("spoilers", requests[0].headers["x-audit-log-reason"]);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+    assert_eq!(
+        json!({
+            "actions": [{"type": 1}],
+            "enabled": true,
+            "event_type": 1,
+            "exempt_roles": ["4"],
+            "name": "no spoilers",
+            "trigger_metadata": {
+                "keyword_filter": ["*ending*"],
+            },
+            "trigger_type": 1,
+        }),
+        body,
+    );
+
+    assert_eq!(Path::GuildsIdAutoModerationRulesId(1), requests[1].path);
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body)?;
+    assert_eq!(json!({"enabled": false}), body);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_webhook_client() -> Result<(), Box<dyn Error>> {
+    let message = json!({
+        "attachments": [],
+        "author": {
+            "avatar": null,
+            "discriminator": "0000",
+            "id": "1",
+            "username": "alerts",
+        },
+        "channel_id": "3",
+        "content": "disk usage is at 90%",
+        "edited_timestamp": null,
+        "embeds": [],
+        "id": "4",
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2021-01-01T00:00:00.000000+00:00",
+        "tts": false,
+        "type": 0,
+        "webhook_id": "1",
+    });
+
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "webhooks/1/token",
+        MockResponse::json(&message),
+    );
+    mock.respond(
+        Method::GET,
+        "webhooks/1/token/messages/4",
+        MockResponse::json(&message),
+    );
+    mock.respond(
+        Method::DELETE,
+        "webhooks/1/token/messages/4",
+        MockResponse::status(StatusCode::UNAUTHORIZED),
+    );
+    mock.respond(
+        Method::DELETE,
+        "webhooks/1/token/messages/4",
+        MockResponse::empty(),
+    );
+
+    // Webhooks are authenticated by their token, so the client has none.
+    let client = Client::builder().proxy(mock.proxy_url(), true).build();
+    let webhook = WebhookClient::with_client(client, WebhookId(1), "token");
+
+    let created = webhook
+        .execute()
+        .content("disk usage is at 90%")
+        .username("alerts")
+        .avatar_url("https://a.b/c.png")
+        .thread_id(ChannelId(3))
+        .wait()
+        .await?
+        .model()?;
+    assert_eq!(MessageId(4), created.id);
+
+    let fetched = webhook
+        .message(MessageId(4))
+        .await?
+        .expect("message exists")
+        .model()?;
+    assert_eq!(created, fetched);
+
+    // An invalid webhook token doesn't invalidate the client.
+    assert!(webhook.delete_message(MessageId(4)).await.is_err());
+    webhook.delete_message(MessageId(4)).await?;
+
+    let requests = mock.requests();
+    assert_eq!(4, requests.len());
+    assert!(requests
+        .iter()
+        .all(|request| !request.headers.contains_key("authorization")));
+
+    assert_eq!(Path::WebhooksId(1), requests[0].path);
+    assert_eq!("webhooks/1/token?wait=true&thread_id=3", requests[0].route);
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+    assert_eq!(
+        json!({
+            "avatar_url": "https://a.b/c.png",
+            "content": "disk usage is at 90%",
+            "username": "alerts",
+            "wait": true,
+        }),
+        body,
+    );
+
+    assert_eq!(Path::WebhooksIdTokenMessageId(1), requests[1].path);
+    assert_eq!("webhooks/1/token/messages/4", requests[1].route);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_message_builder() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "channels/3/messages",
+        MockResponse::json(&message()),
+    );
+    mock.respond(Method::POST, "webhooks/1/token", MockResponse::empty());
+
+    let client = client(&mock);
+
+    let message = MessageBuilder::new()
+        .content("hey ")
+        .mention_user(UserId(2))
+        .reply(MessageId(4))
+        .fail_if_not_exists(false)
+        .flags(MessageFlags::SUPPRESS_EMBEDS)
+        .build()?;
+
+    client
+        .create_message(ChannelId(3))
+        .message(message.clone())
+        .await?;
+    client
+        .execute_webhook(WebhookId(1), "token")
+        .message(message)
+        .await?;
+
+    let requests = mock.requests();
+    assert_eq!(2, requests.len());
+
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+    assert_eq!(
+        json!({
+            "allowed_mentions": {
+                "parse": [],
+                "replied_user": false,
+                "roles": null,
+                "users": ["2"],
+            },
+            "content": "hey <@2>",
+            "flags": 4,
+            "message_reference": {
+                "channel_id": "3",
+                "fail_if_not_exists": false,
+                "message_id": "4",
+            },
+        }),
+        body,
+    );
+
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body)?;
+    assert_eq!(
+        json!({
+            "allowed_mentions": {
+                "parse": [],
+                "replied_user": false,
+                "roles": null,
+                "users": ["2"],
+            },
+            "content": "hey <@2>",
+            "flags": 4,
+        }),
+        body,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_message_splitter() -> Result<(), Box<dyn Error>> {
+    let mock = MockHttp::bind()?;
+    mock.respond(
+        Method::POST,
+        "channels/3/messages",
+        MockResponse::json(&message()),
+    );
+    mock.respond(
+        Method::POST,
+        "channels/3/messages",
+        MockResponse::json(&message()),
+    );
+
+    let client = client(&mock);
+
+    let content = format!("{}\n{}", "a".repeat(1500), "b".repeat(1500));
+    let messages = MessageSplitter::new(&content)
+        .send(&client, ChannelId(3))
+        .await?;
+    assert_eq!(2, messages.len());
+
+    let requests = mock.requests();
+    assert_eq!(2, requests.len());
+
+    for (request, expected) in requests.iter().zip(&["a", "b"]) {
+        let body: serde_json::Value = serde_json::from_slice(&request.body)?;
+        assert_eq!(json!({ "content": expected.repeat(1500) }), body);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! # twilight-interactions
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-interactions` receives interactions, such as slash commands,
+//! over HTTP instead of the gateway.
+//!
+//! Discord sends interactions as `POST` requests to the interactions endpoint
+//! URL of an application. Each request is signed with Ed25519, and must be
+//! verified with the public key of the application via [`verify`]. Discord
+//! checks that endpoints reject requests with invalid signatures, and sends
+//! pings that must be answered with a pong.
+//!
+//! The [`InteractionServer`] does all of this: it verifies requests,
+//! deserializes them into `twilight-model` interactions, answers pings, and
+//! passes other interactions to a handler whose response is sent back.
+//!
+//! ## Features
+//!
+//! ### `server`
+//!
+//! The `server` feature enables the hyper based [`InteractionServer`]. It's
+//! enabled by default; without it only verification is available.
+//!
+//! ## Examples
+//!
+//! Respond to every command with a message:
+//!
+//! ```rust,no_run
+//! use std::{env, net::SocketAddr};
+//! use twilight_interactions::{InteractionServer, PublicKey};
+//! use twilight_model::application::callback::{
+//!     CallbackData, InteractionResponse, InteractionResponseType,
+//! };
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let public_key = env::var("PUBLIC_KEY")?.parse::<PublicKey>()?;
+//!
+//! let server = InteractionServer::new(public_key, |interaction| async move {
+//!     let name = interaction.data.map(|data| data.name).unwrap_or_default();
+//!
+//!     InteractionResponse {
+//!         data: Some(CallbackData {
+//!             content: Some(format!("used the {} command", name)),
+//!             embeds: Vec::new(),
+//!             flags: None,
+//!             tts: None,
+//!         }),
+//!         kind: InteractionResponseType::ChannelMessageWithSource,
+//!     }
+//! });
+//!
+//! server.serve(SocketAddr::from(([0, 0, 0, 0], 3000))).await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod public_key;
+#[cfg(feature = "server")]
+mod server;
+mod verify;
+
+#[cfg(feature = "server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+pub use self::server::{HandlerFuture, InteractionServer};
+pub use self::{
+    public_key::{PublicKey, PublicKeyParseError},
+    verify::{verify, VerifyError, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+};
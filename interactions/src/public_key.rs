@@ -0,0 +1,128 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// Error parsing a [`PublicKey`] from a hex string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PublicKeyParseError {
+    /// The string isn't valid hex.
+    HexInvalid,
+    /// The key isn't 32 bytes long.
+    LengthInvalid {
+        /// Length of the key in bytes.
+        len: usize,
+    },
+}
+
+impl Display for PublicKeyParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::HexInvalid => f.write_str("public key is not valid hex"),
+            Self::LengthInvalid { len } => {
+                write!(f, "public key is {} bytes long instead of 32", len)
+            }
+        }
+    }
+}
+
+impl Error for PublicKeyParseError {}
+
+/// Ed25519 public key of an application, used to verify interactions.
+///
+/// The key is shown as a hex string on the application's page in the
+/// [developer portal], which can be parsed via [`FromStr`].
+///
+/// [developer portal]: https://discord.com/developers/applications
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PublicKey([u8; PublicKey::LENGTH]);
+
+impl PublicKey {
+    /// Length of a public key in bytes.
+    pub const LENGTH: usize = 32;
+
+    /// Create a public key from its bytes.
+    pub const fn new(bytes: [u8; Self::LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// Bytes of the public key.
+    pub const fn as_bytes(&self) -> &[u8; Self::LENGTH] {
+        &self.0
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = PublicKeyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_hex(s).ok_or(PublicKeyParseError::HexInvalid)?;
+
+        if bytes.len() != Self::LENGTH {
+            return Err(PublicKeyParseError::LengthInvalid { len: bytes.len() });
+        }
+
+        let mut key = [0; Self::LENGTH];
+        key.copy_from_slice(&bytes);
+
+        Ok(Self(key))
+    }
+}
+
+/// Decode a hex string, returning `None` if it isn't valid hex.
+pub(crate) fn decode_hex(input: &str) -> Option<Vec<u8>> {
+    fn value(digit: u8) -> Option<u8> {
+        match digit {
+            b'0'..=b'9' => Some(digit - b'0'),
+            b'a'..=b'f' => Some(digit - b'a' + 10),
+            b'A'..=b'F' => Some(digit - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let input = input.as_bytes();
+
+    if input.len() % 2 != 0 {
+        return None;
+    }
+
+    input
+        .chunks(2)
+        .map(|pair| Some(value(pair[0])? << 4 | value(pair[1])?))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_hex, PublicKey, PublicKeyParseError};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, str::FromStr};
+
+    assert_impl_all!(PublicKey: Clone, Copy, Debug, FromStr, Send, Sync);
+    assert_impl_all!(PublicKeyParseError: Error, Send, Sync);
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(Some(vec![0x01, 0xab, 0xFF]), decode_hex("01abFF"));
+        assert_eq!(Some(Vec::new()), decode_hex(""));
+        assert!(decode_hex("abc").is_none());
+        assert!(decode_hex("zz").is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        let hex = "0a".repeat(32);
+        assert_eq!(PublicKey::new([0x0a; 32]), hex.parse().unwrap());
+
+        assert_eq!(
+            PublicKeyParseError::LengthInvalid { len: 2 },
+            "abcd".parse::<PublicKey>().unwrap_err()
+        );
+        assert_eq!(
+            PublicKeyParseError::HexInvalid,
+            "not hex".parse::<PublicKey>().unwrap_err()
+        );
+    }
+}
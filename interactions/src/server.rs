@@ -0,0 +1,280 @@
+use super::{
+    public_key::PublicKey,
+    verify::{self, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+};
+use hyper::{
+    body::HttpBody,
+    header::{HeaderValue, CONTENT_LENGTH, CONTENT_TYPE},
+    server::Server,
+    service::{make_service_fn, service_fn},
+    Body, Error as HyperError, Method, Request, Response, StatusCode,
+};
+use std::{
+    convert::Infallible,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    net::SocketAddr,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use twilight_model::application::{
+    callback::InteractionResponse,
+    interaction::{Interaction, InteractionType},
+};
+
+/// Future returned by a handler, resolving to the response to an interaction.
+pub type HandlerFuture = Pin<Box<dyn Future<Output = InteractionResponse> + Send + 'static>>;
+
+/// Function handling interactions.
+type Handler = dyn Fn(Interaction) -> HandlerFuture + Send + Sync;
+
+/// Server receiving interactions over HTTP.
+///
+/// Requests are verified with the public key of the application and
+/// deserialized into [`Interaction`]s. Pings are responded to with a pong,
+/// while other interactions are passed to the handler, whose response is
+/// sent back to Discord.
+///
+/// Requests with a body longer than [`max_body_length`] are rejected with a
+/// `413 Payload Too Large` before being verified, and requests with a
+/// timestamp further than [`max_timestamp_age`] from the current time are
+/// rejected with a `401 Unauthorized` to prevent them from being replayed.
+///
+/// Refer to the [crate-level] documentation for an example.
+///
+/// The server can be cloned cheaply, with clones sharing the handler.
+///
+/// [crate-level]: crate
+/// [`max_body_length`]: Self::max_body_length
+/// [`max_timestamp_age`]: Self::max_timestamp_age
+#[derive(Clone)]
+pub struct InteractionServer {
+    handler: Arc<Handler>,
+    max_body_length: usize,
+    max_timestamp_age: Duration,
+    public_key: PublicKey,
+}
+
+impl InteractionServer {
+    /// Default maximum length of request bodies in bytes.
+    pub const DEFAULT_MAX_BODY_LENGTH: usize = 1024 * 1024;
+
+    /// Default maximum difference between the timestamp of a request and the
+    /// current time.
+    pub const DEFAULT_MAX_TIMESTAMP_AGE: Duration = Duration::from_secs(5 * 60);
+
+    /// Create a server verifying requests with a public key and passing
+    /// interactions to a handler.
+    pub fn new<F, Fut>(public_key: PublicKey, handler: F) -> Self
+    where
+        F: Fn(Interaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = InteractionResponse> + Send + 'static,
+    {
+        Self {
+            handler: Arc::new(move |interaction| Box::pin(handler(interaction))),
+            max_body_length: Self::DEFAULT_MAX_BODY_LENGTH,
+            max_timestamp_age: Self::DEFAULT_MAX_TIMESTAMP_AGE,
+            public_key,
+        }
+    }
+
+    /// Set the maximum length of request bodies in bytes.
+    ///
+    /// Defaults to [`DEFAULT_MAX_BODY_LENGTH`].
+    ///
+    /// [`DEFAULT_MAX_BODY_LENGTH`]: Self::DEFAULT_MAX_BODY_LENGTH
+    pub fn max_body_length(mut self, max_body_length: usize) -> Self {
+        self.max_body_length = max_body_length;
+
+        self
+    }
+
+    /// Set the maximum difference between the timestamp of a request and the
+    /// current time.
+    ///
+    /// Defaults to [`DEFAULT_MAX_TIMESTAMP_AGE`].
+    ///
+    /// [`DEFAULT_MAX_TIMESTAMP_AGE`]: Self::DEFAULT_MAX_TIMESTAMP_AGE
+    pub fn max_timestamp_age(mut self, max_timestamp_age: Duration) -> Self {
+        self.max_timestamp_age = max_timestamp_age;
+
+        self
+    }
+
+    /// Serve requests on an address until an error occurs.
+    ///
+    /// Interactions are received on any path.
+    ///
+    /// # Errors
+    ///
+    /// Returns a hyper error if binding to the address or serving requests
+    /// failed.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), HyperError> {
+        let make_service = make_service_fn(move |_| {
+            let server = self.clone();
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let server = server.clone();
+
+                    async move { Ok::<_, Infallible>(server.handle(request).await) }
+                }))
+            }
+        });
+
+        let server = Server::try_bind(&addr)?.serve(make_service);
+        tracing::info!("listening on {}", server.local_addr());
+
+        server.await
+    }
+
+    /// Verify a request and handle the interaction it contains, returning
+    /// the response to send.
+    ///
+    /// This can be used to serve interactions from an existing hyper server.
+    pub async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        if request.method() != Method::POST {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let (parts, body) = request.into_parts();
+
+        let header = |name| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+
+        let (signature, timestamp) = match (header(SIGNATURE_HEADER), header(TIMESTAMP_HEADER)) {
+            (Some(signature), Some(timestamp)) => (signature, timestamp),
+            _ => return status(StatusCode::UNAUTHORIZED),
+        };
+
+        if !self.is_fresh(timestamp) {
+            tracing::debug!("request timestamp is not recent: {}", timestamp);
+
+            return status(StatusCode::UNAUTHORIZED);
+        }
+
+        let content_length = parts
+            .headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+
+        if content_length.map_or(false, |length| length > self.max_body_length as u64) {
+            return status(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        let body = match read_body(body, self.max_body_length).await {
+            Ok(Some(body)) => body,
+            Ok(None) => return status(StatusCode::PAYLOAD_TOO_LARGE),
+            Err(source) => {
+                tracing::debug!("reading the request body failed: {}", source);
+
+                return status(StatusCode::BAD_REQUEST);
+            }
+        };
+
+        if let Err(source) = verify::verify(&self.public_key, signature, timestamp, &body) {
+            tracing::debug!("request failed verification: {}", source);
+
+            return status(StatusCode::UNAUTHORIZED);
+        }
+
+        let interaction = match serde_json::from_slice::<Interaction>(&body) {
+            Ok(interaction) => interaction,
+            Err(source) => {
+                tracing::warn!("deserializing the interaction failed: {}", source);
+
+                return status(StatusCode::BAD_REQUEST);
+            }
+        };
+
+        let response = if interaction.kind == InteractionType::Ping {
+            InteractionResponse::PONG
+        } else {
+            (self.handler)(interaction).await
+        };
+
+        match serde_json::to_vec(&response) {
+            Ok(body) => {
+                let mut response = Response::new(Body::from(body));
+                response
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+                response
+            }
+            Err(source) => {
+                tracing::warn!("serializing the response failed: {}", source);
+
+                status(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        }
+    }
+
+    /// Return whether a request's timestamp, in seconds since the Unix
+    /// epoch, is close enough to the current time.
+    fn is_fresh(&self, timestamp: &str) -> bool {
+        let timestamp = match timestamp.parse::<u64>() {
+            Ok(timestamp) => Duration::from_secs(timestamp),
+            Err(_) => return false,
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        now.checked_sub(timestamp)
+            .or_else(|| timestamp.checked_sub(now))
+            .map_or(false, |difference| difference <= self.max_timestamp_age)
+    }
+}
+
+impl Debug for InteractionServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractionServer")
+            .field("handler", &"<fn>")
+            .field("max_body_length", &self.max_body_length)
+            .field("max_timestamp_age", &self.max_timestamp_age)
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+/// Read a body, returning `None` if it is longer than a maximum length.
+async fn read_body(mut body: Body, max_length: usize) -> Result<Option<Vec<u8>>, HyperError> {
+    let mut bytes = Vec::new();
+
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk?;
+
+        if bytes.len() + chunk.len() > max_length {
+            return Ok(None);
+        }
+
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Ok(Some(bytes))
+}
+
+/// Create an empty response with a status code.
+fn status(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InteractionServer;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(InteractionServer: Clone, Debug, Send, Sync);
+}
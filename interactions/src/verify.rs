@@ -0,0 +1,171 @@
+use super::public_key::{decode_hex, PublicKey};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Name of the header containing the hex encoded signature of a request.
+pub const SIGNATURE_HEADER: &str = "x-signature-ed25519";
+
+/// Name of the header containing the timestamp of a request.
+pub const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Error verifying the signature of a request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    /// The signature isn't valid hex.
+    SignatureMalformed,
+    /// The signature doesn't match the timestamp and body of the request.
+    SignatureMismatch,
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::SignatureMalformed => f.write_str("signature is not valid hex"),
+            Self::SignatureMismatch => f.write_str("signature does not match the request"),
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+/// Verify that a request containing an interaction was sent by Discord.
+///
+/// The signature and timestamp are the values of the [`SIGNATURE_HEADER`]
+/// and [`TIMESTAMP_HEADER`] headers of the request, and the body is the raw
+/// body of the request. Requests that fail verification must be responded to
+/// with a `401 Unauthorized`; Discord sends such requests to check that
+/// endpoints verify them.
+///
+/// The timestamp isn't checked against the current time. Requests with an
+/// old timestamp should also be rejected, so that a captured request can't
+/// be replayed.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_interactions::{verify, PublicKey};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let public_key = "6ef8aa5d8d1b18ad3e03dc7e2a1c5b68f6c8c7c9e4f4ea9ba5e3c4a1b4a4b2c8"
+///     .parse::<PublicKey>()?;
+///
+/// let result = verify(&public_key, "not a signature", "1614710000", b"{}");
+/// assert!(result.is_err());
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`VerifyError::SignatureMalformed`] if the signature isn't valid
+/// hex.
+///
+/// Returns [`VerifyError::SignatureMismatch`] if the signature doesn't match
+/// the timestamp and body.
+pub fn verify(
+    public_key: &PublicKey,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8],
+) -> Result<(), VerifyError> {
+    let signature = decode_hex(signature).ok_or(VerifyError::SignatureMalformed)?;
+
+    let mut message = Vec::with_capacity(timestamp.len() + body.len());
+    message.extend_from_slice(timestamp.as_bytes());
+    message.extend_from_slice(body);
+
+    UnparsedPublicKey::new(&ED25519, public_key.as_bytes())
+        .verify(&message, &signature)
+        .map_err(|_| VerifyError::SignatureMismatch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify, VerifyError};
+    use crate::PublicKey;
+    use ring::{
+        rand::{SecureRandom, SystemRandom},
+        signature::{Ed25519KeyPair, KeyPair},
+    };
+
+    fn key_pair() -> Ed25519KeyPair {
+        let mut seed = [0; 32];
+        SystemRandom::new().fill(&mut seed).unwrap();
+
+        Ed25519KeyPair::from_seed_unchecked(&seed).unwrap()
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn public_key(key_pair: &Ed25519KeyPair) -> PublicKey {
+        hex(key_pair.public_key().as_ref()).parse().unwrap()
+    }
+
+    #[test]
+    fn test_verify() {
+        let key_pair = key_pair();
+        let signature = hex(key_pair.sign(b"1614710000{\"type\":1}").as_ref());
+
+        assert!(verify(
+            &public_key(&key_pair),
+            &signature,
+            "1614710000",
+            b"{\"type\":1}"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let key_pair = key_pair();
+        let signature = hex(key_pair.sign(b"1614710000{\"type\":1}").as_ref());
+
+        // Different body.
+        assert_eq!(
+            Err(VerifyError::SignatureMismatch),
+            verify(
+                &public_key(&key_pair),
+                &signature,
+                "1614710000",
+                b"{\"type\":2}"
+            )
+        );
+
+        // Different timestamp.
+        assert_eq!(
+            Err(VerifyError::SignatureMismatch),
+            verify(
+                &public_key(&key_pair),
+                &signature,
+                "1614710001",
+                b"{\"type\":1}"
+            )
+        );
+
+        // Different key.
+        assert_eq!(
+            Err(VerifyError::SignatureMismatch),
+            verify(
+                &public_key(&self::key_pair()),
+                &signature,
+                "1614710000",
+                b"{\"type\":1}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        let key_pair = key_pair();
+
+        assert_eq!(
+            Err(VerifyError::SignatureMalformed),
+            verify(&public_key(&key_pair), "xyz", "1614710000", b"{}")
+        );
+    }
+}
@@ -0,0 +1,233 @@
+#![cfg(feature = "server")]
+
+use hyper::{
+    header::{HeaderValue, CONTENT_LENGTH},
+    Body, Method, Request, StatusCode,
+};
+use ring::{
+    rand::{SecureRandom, SystemRandom},
+    signature::{Ed25519KeyPair, KeyPair},
+};
+use serde_json::{json, Value};
+use std::{
+    error::Error,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_interactions::{InteractionServer, PublicKey, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+use twilight_model::application::{
+    callback::{CallbackData, InteractionResponse, InteractionResponseType},
+    interaction::InteractionType,
+};
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn key_pair() -> Ed25519KeyPair {
+    let mut seed = [0; 32];
+    SystemRandom::new().fill(&mut seed).unwrap();
+
+    Ed25519KeyPair::from_seed_unchecked(&seed).unwrap()
+}
+
+fn server(key_pair: &Ed25519KeyPair) -> InteractionServer {
+    let public_key = hex(key_pair.public_key().as_ref())
+        .parse::<PublicKey>()
+        .unwrap();
+
+    InteractionServer::new(public_key, |interaction| async move {
+        assert_eq!(InteractionType::ApplicationCommand, interaction.kind);
+
+        InteractionResponse {
+            data: Some(CallbackData {
+                content: interaction.data.map(|data| data.name),
+                embeds: Vec::new(),
+                flags: None,
+                tts: None,
+            }),
+            kind: InteractionResponseType::ChannelMessageWithSource,
+        }
+    })
+}
+
+/// Current time in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn request(key_pair: &Ed25519KeyPair, body: &Value) -> Request<Body> {
+    request_at(key_pair, body, now())
+}
+
+fn request_at(key_pair: &Ed25519KeyPair, body: &Value, timestamp: u64) -> Request<Body> {
+    let body = body.to_string();
+    let timestamp = timestamp.to_string();
+
+    let mut message = timestamp.as_bytes().to_vec();
+    message.extend_from_slice(body.as_bytes());
+    let signature = hex(key_pair.sign(&message).as_ref());
+
+    Request::builder()
+        .method(Method::POST)
+        .uri("/interactions")
+        .header(SIGNATURE_HEADER, signature)
+        .header(TIMESTAMP_HEADER, timestamp)
+        .body(Body::from(body))
+        .unwrap()
+}
+
+async fn body(response: hyper::Response<Body>) -> Result<Value, Box<dyn Error>> {
+    let bytes = hyper::body::to_bytes(response.into_body()).await?;
+
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+fn ping() -> Value {
+    json!({
+        "application_id": "1",
+        "id": "2",
+        "token": "token",
+        "type": 1,
+        "version": 1,
+    })
+}
+
+#[tokio::test]
+async fn test_ping() -> Result<(), Box<dyn Error>> {
+    let key_pair = key_pair();
+    let response = server(&key_pair).handle(request(&key_pair, &ping())).await;
+
+    assert_eq!(StatusCode::OK, response.status());
+    assert_eq!("application/json", response.headers()["content-type"]);
+    assert_eq!(json!({"type": 1}), body(response).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_command() -> Result<(), Box<dyn Error>> {
+    let key_pair = key_pair();
+    let interaction = json!({
+        "application_id": "1",
+        "channel_id": "3",
+        "data": {
+            "id": "4",
+            "name": "blep",
+        },
+        "id": "2",
+        "token": "token",
+        "type": 2,
+        "user": {
+            "avatar": null,
+            "discriminator": "0001",
+            "id": "5",
+            "username": "twilight",
+        },
+        "version": 1,
+    });
+
+    let response = server(&key_pair)
+        .handle(request(&key_pair, &interaction))
+        .await;
+
+    assert_eq!(StatusCode::OK, response.status());
+    assert_eq!(
+        json!({"data": {"content": "blep"}, "type": 4}),
+        body(response).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_signature() {
+    let key_pair = key_pair();
+    let server = server(&key_pair);
+
+    // Signed by another key.
+    let response = server.handle(request(&self::key_pair(), &ping())).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+    // Body changed after signing.
+    let mut request = request(&key_pair, &ping());
+    *request.body_mut() = Body::from(r#"{"type":1}"#);
+    let response = server.handle(request).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+    // Missing headers.
+    let request = Request::builder()
+        .method(Method::POST)
+        .body(Body::from(ping().to_string()))
+        .unwrap();
+    let response = server.handle(request).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+}
+
+#[tokio::test]
+async fn test_stale_timestamp() {
+    let key_pair = key_pair();
+    let server = server(&key_pair);
+
+    // Validly signed, but too old to be accepted.
+    let response = server
+        .handle(request_at(&key_pair, &ping(), now() - 10 * 60))
+        .await;
+    assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+    let response = server
+        .handle(request_at(&key_pair, &ping(), now() + 10 * 60))
+        .await;
+    assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+    let response = server
+        .handle(request_at(&key_pair, &ping(), now() - 60))
+        .await;
+    assert_eq!(StatusCode::OK, response.status());
+}
+
+#[tokio::test]
+async fn test_payload_too_large() {
+    let key_pair = key_pair();
+    let server = server(&key_pair).max_body_length(128);
+
+    // Rejected by its content length before the body is read.
+    let mut large = request(&key_pair, &ping());
+    large
+        .headers_mut()
+        .insert(CONTENT_LENGTH, HeaderValue::from_static("1000000"));
+    let response = server.handle(large).await;
+    assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+    // Rejected while streaming a body without a content length.
+    let mut streamed = request(&key_pair, &ping());
+    let (mut sender, body) = Body::channel();
+    *streamed.body_mut() = body;
+    tokio::spawn(async move {
+        for _ in 0..16 {
+            if sender.send_data(vec![b' '; 16].into()).await.is_err() {
+                break;
+            }
+        }
+    });
+    let response = server.handle(streamed).await;
+    assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+    // Bodies within the limit are accepted.
+    let response = server.handle(request(&key_pair, &ping())).await;
+    assert_eq!(StatusCode::OK, response.status());
+}
+
+#[tokio::test]
+async fn test_method_not_allowed() {
+    let key_pair = key_pair();
+    let request = Request::builder()
+        .method(Method::GET)
+        .body(Body::empty())
+        .unwrap();
+
+    let response = server(&key_pair).handle(request).await;
+    assert_eq!(StatusCode::METHOD_NOT_ALLOWED, response.status());
+}
@@ -45,7 +45,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let shard_count = 1u64;
 
         let http = HttpClient::new(&token);
-        let user_id = http.current_user().await?.id;
+        let user_id = http.current_user().await?.model()?.id;
 
         let lavalink = Lavalink::new(user_id, shard_count);
         lavalink.add(lavalink_host, lavalink_auth).await?;
@@ -0,0 +1,63 @@
+mod callback_data;
+mod response_type;
+
+pub use self::{callback_data::CallbackData, response_type::InteractionResponseType};
+
+use serde::{Deserialize, Serialize};
+
+/// Response to an interaction.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct InteractionResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<CallbackData>,
+    #[serde(rename = "type")]
+    pub kind: InteractionResponseType,
+}
+
+impl InteractionResponse {
+    /// Response to a [`Ping`] interaction.
+    ///
+    /// [`Ping`]: crate::application::interaction::InteractionType::Ping
+    pub const PONG: Self = Self {
+        data: None,
+        kind: InteractionResponseType::Pong,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CallbackData, InteractionResponse, InteractionResponseType};
+    use crate::channel::message::MessageFlags;
+    use serde_json::json;
+
+    #[test]
+    fn test_pong() {
+        assert_eq!(
+            json!({"type": 1}),
+            serde_json::to_value(&InteractionResponse::PONG).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_channel_message() {
+        let value = InteractionResponse {
+            data: Some(CallbackData {
+                content: Some("hello".to_owned()),
+                embeds: Vec::new(),
+                flags: Some(MessageFlags::SUPPRESS_EMBEDS),
+                tts: None,
+            }),
+            kind: InteractionResponseType::ChannelMessageWithSource,
+        };
+        let json = json!({
+            "data": {
+                "content": "hello",
+                "flags": 4,
+            },
+            "type": 4,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+}
@@ -0,0 +1,39 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of an [`InteractionResponse`].
+///
+/// [`InteractionResponse`]: super::InteractionResponse
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum InteractionResponseType {
+    /// Acknowledge a [`Ping`].
+    ///
+    /// [`Ping`]: crate::application::interaction::InteractionType::Ping
+    Pong = 1,
+    /// Respond with a message, showing the user's use of the command.
+    ChannelMessageWithSource = 4,
+    /// Acknowledge the interaction, showing a loading state to the user until
+    /// the response is edited.
+    DeferredChannelMessageWithSource = 5,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InteractionResponseType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&InteractionResponseType::Pong, &[Token::U8(1)]);
+        serde_test::assert_tokens(
+            &InteractionResponseType::ChannelMessageWithSource,
+            &[Token::U8(4)],
+        );
+        serde_test::assert_tokens(
+            &InteractionResponseType::DeferredChannelMessageWithSource,
+            &[Token::U8(5)],
+        );
+    }
+}
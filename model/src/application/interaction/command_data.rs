@@ -0,0 +1,13 @@
+use super::CommandDataOption;
+use crate::id::CommandId;
+use serde::{Deserialize, Serialize};
+
+/// Data of an application command interaction.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CommandData {
+    pub id: CommandId,
+    pub name: String,
+    /// Options the user provided, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandDataOption>,
+}
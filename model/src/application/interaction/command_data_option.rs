@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+/// Option of a command provided by the user.
+///
+/// Options of subcommands and subcommand groups contain the nested
+/// [`options`], while other options contain a [`value`].
+///
+/// [`options`]: Self::options
+/// [`value`]: Self::value
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CommandDataOption {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandDataOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<CommandOptionValue>,
+}
+
+/// Value of a [`CommandDataOption`].
+///
+/// Users, channels, and roles are provided as their ID in a string.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum CommandOptionValue {
+    Boolean(bool),
+    Integer(i64),
+    String(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandDataOption, CommandOptionValue};
+    use serde_test::Token;
+
+    #[test]
+    fn test_subcommand() {
+        let value = CommandDataOption {
+            name: "permissions".to_owned(),
+            options: vec![CommandDataOption {
+                name: "user".to_owned(),
+                options: Vec::new(),
+                value: Some(CommandOptionValue::String("1".to_owned())),
+            }],
+            value: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 2,
+                },
+                Token::Str("name"),
+                Token::Str("permissions"),
+                Token::Str("options"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "CommandDataOption",
+                    len: 2,
+                },
+                Token::Str("name"),
+                Token::Str("user"),
+                Token::Str("value"),
+                Token::Some,
+                Token::Str("1"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_values() {
+        serde_test::assert_tokens(&CommandOptionValue::Boolean(true), &[Token::Bool(true)]);
+        serde_test::assert_tokens(&CommandOptionValue::Integer(-5), &[Token::I64(-5)]);
+        serde_test::assert_tokens(
+            &CommandOptionValue::String("text".to_owned()),
+            &[Token::Str("text")],
+        );
+    }
+}
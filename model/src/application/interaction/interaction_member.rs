@@ -0,0 +1,15 @@
+use crate::{guild::Permissions, id::RoleId, user::User};
+use serde::{Deserialize, Serialize};
+
+/// Member of a guild who created an interaction.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct InteractionMember {
+    pub joined_at: Option<String>,
+    pub nick: Option<String>,
+    /// Permissions of the member in the channel of the interaction.
+    pub permissions: Permissions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_since: Option<String>,
+    pub roles: Vec<RoleId>,
+    pub user: User,
+}
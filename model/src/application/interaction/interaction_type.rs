@@ -0,0 +1,30 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of an [`Interaction`].
+///
+/// [`Interaction`]: super::Interaction
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum InteractionType {
+    /// Sent by Discord to check that the endpoint receiving interactions is
+    /// reachable, which must be responded to with a [`Pong`].
+    ///
+    /// [`Pong`]: crate::application::callback::InteractionResponseType::Pong
+    Ping = 1,
+    /// Use of an application command.
+    ApplicationCommand = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InteractionType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&InteractionType::Ping, &[Token::U8(1)]);
+        serde_test::assert_tokens(&InteractionType::ApplicationCommand, &[Token::U8(2)]);
+    }
+}
@@ -0,0 +1,168 @@
+mod command_data;
+mod command_data_option;
+mod interaction_member;
+mod interaction_type;
+
+pub use self::{
+    command_data::CommandData,
+    command_data_option::{CommandDataOption, CommandOptionValue},
+    interaction_member::InteractionMember,
+    interaction_type::InteractionType,
+};
+
+use crate::{
+    id::{ApplicationId, ChannelId, GuildId, InteractionId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Interaction received by an application, such as the use of a command.
+///
+/// Interactions in guilds have a [`member`], while interactions in private
+/// channels have a [`user`].
+///
+/// [`member`]: Self::member
+/// [`user`]: Self::user
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Interaction {
+    pub application_id: ApplicationId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// Data of the command, present for [`ApplicationCommand`] interactions.
+    ///
+    /// [`ApplicationCommand`]: InteractionType::ApplicationCommand
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<CommandData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    pub id: InteractionId,
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<InteractionMember>,
+    /// Token used to respond to the interaction and to send followup
+    /// messages.
+    pub token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    pub version: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CommandData, CommandDataOption, CommandOptionValue, Interaction, InteractionMember,
+        InteractionType,
+    };
+    use crate::{
+        guild::Permissions,
+        id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId, RoleId, UserId},
+        user::User,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_ping() {
+        let value = Interaction {
+            application_id: ApplicationId(1),
+            channel_id: None,
+            data: None,
+            guild_id: None,
+            id: InteractionId(2),
+            kind: InteractionType::Ping,
+            member: None,
+            token: "token".to_owned(),
+            user: None,
+            version: 1,
+        };
+        let json = json!({
+            "application_id": "1",
+            "id": "2",
+            "token": "token",
+            "type": 1,
+            "version": 1,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_application_command() {
+        let value = Interaction {
+            application_id: ApplicationId(1),
+            channel_id: Some(ChannelId(3)),
+            data: Some(CommandData {
+                id: CommandId(4),
+                name: "blep".to_owned(),
+                options: vec![CommandDataOption {
+                    name: "animal".to_owned(),
+                    options: Vec::new(),
+                    value: Some(CommandOptionValue::String("dog".to_owned())),
+                }],
+            }),
+            guild_id: Some(GuildId(5)),
+            id: InteractionId(2),
+            kind: InteractionType::ApplicationCommand,
+            member: Some(InteractionMember {
+                joined_at: Some("2017-03-13T19:19:14.040000+00:00".to_owned()),
+                nick: None,
+                permissions: Permissions::SEND_MESSAGES,
+                premium_since: None,
+                roles: vec![RoleId(6)],
+                user: User {
+                    avatar: None,
+                    bot: false,
+                    discriminator: "0001".to_owned(),
+                    email: None,
+                    flags: None,
+                    id: UserId(7),
+                    locale: None,
+                    mfa_enabled: None,
+                    name: "twilight".to_owned(),
+                    premium_type: None,
+                    public_flags: None,
+                    system: None,
+                    verified: None,
+                },
+            }),
+            token: "token".to_owned(),
+            user: None,
+            version: 1,
+        };
+        let json = json!({
+            "application_id": "1",
+            "channel_id": "3",
+            "data": {
+                "id": "4",
+                "name": "blep",
+                "options": [{
+                    "name": "animal",
+                    "value": "dog",
+                }],
+            },
+            "guild_id": "5",
+            "id": "2",
+            "member": {
+                "joined_at": "2017-03-13T19:19:14.040000+00:00",
+                "nick": null,
+                "permissions": "2048",
+                "roles": ["6"],
+                "user": {
+                    "avatar": null,
+                    "bot": false,
+                    "discriminator": "0001",
+                    "id": "7",
+                    "username": "twilight",
+                },
+            },
+            "token": "token",
+            "type": 2,
+            "version": 1,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+}
@@ -0,0 +1,4 @@
+//! Types for applications receiving interactions, such as slash commands.
+
+pub mod callback;
+pub mod interaction;
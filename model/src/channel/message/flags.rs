@@ -11,6 +11,7 @@ bitflags! {
         const SUPPRESS_EMBEDS = 1 << 2;
         const SOURCE_MESSAGE_DELETED = 1 << 3;
         const URGENT = 1 << 4;
+        const EPHEMERAL = 1 << 6;
     }
 }
 
@@ -131,13 +131,18 @@ mod tests {
             reference: None,
             stickers: vec![Sticker {
                 asset: "foo1".to_owned(),
-                description: "foo2".to_owned(),
+                available: None,
+                description: Some("foo2".to_owned()),
                 format_type: StickerFormatType::Png,
+                guild_id: None,
                 id: StickerId(1),
+                kind: None,
                 name: "sticker name".to_owned(),
-                pack_id: StickerPackId(2),
+                pack_id: Some(StickerPackId(2)),
                 preview_asset: None,
+                sort_value: None,
                 tags: Some("foo,bar,baz".to_owned()),
+                user: None,
             }],
             referenced_message: None,
             timestamp: "2020-02-02T02:02:02.020000+00:00".to_owned(),
@@ -234,6 +239,7 @@ mod tests {
                 Token::Str("asset"),
                 Token::Str("foo1"),
                 Token::Str("description"),
+                Token::Some,
                 Token::Str("foo2"),
                 Token::Str("format_type"),
                 Token::U8(1),
@@ -243,6 +249,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("sticker name"),
                 Token::Str("pack_id"),
+                Token::Some,
                 Token::NewtypeStruct {
                     name: "StickerPackId",
                 },
@@ -334,13 +341,18 @@ mod tests {
             }),
             stickers: vec![Sticker {
                 asset: "foo1".to_owned(),
-                description: "foo2".to_owned(),
+                available: None,
+                description: Some("foo2".to_owned()),
                 format_type: StickerFormatType::Png,
+                guild_id: None,
                 id: StickerId(1),
+                kind: None,
                 name: "sticker name".to_owned(),
-                pack_id: StickerPackId(2),
+                pack_id: Some(StickerPackId(2)),
                 preview_asset: None,
+                sort_value: None,
                 tags: Some("foo,bar,baz".to_owned()),
+                user: None,
             }],
             referenced_message: None,
             timestamp: "2020-02-02T02:02:02.020000+00:00".to_owned(),
@@ -518,6 +530,7 @@ mod tests {
                 Token::Str("asset"),
                 Token::Str("foo1"),
                 Token::Str("description"),
+                Token::Some,
                 Token::Str("foo2"),
                 Token::Str("format_type"),
                 Token::U8(1),
@@ -527,6 +540,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("sticker name"),
                 Token::Str("pack_id"),
+                Token::Some,
                 Token::NewtypeStruct {
                     name: "StickerPackId",
                 },
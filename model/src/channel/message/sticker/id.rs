@@ -8,7 +8,9 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 /// Like all of the IDs in the primary [`crate::id`] crate, these
 /// IDs support deserializing from both integers and strings and serialize into
 /// strings.
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
 pub struct StickerId(#[serde(with = "crate::id::string")] pub u64);
 
 impl Display for StickerId {
@@ -17,6 +19,12 @@ impl Display for StickerId {
     }
 }
 
+impl From<u64> for StickerId {
+    fn from(id: u64) -> Self {
+        StickerId(id)
+    }
+}
+
 /// Unique ID denoting a sticker pack.
 ///
 /// # serde
@@ -24,7 +32,9 @@ impl Display for StickerId {
 /// Like all of the IDs in the primary [`crate::id`] crate, these
 /// IDs support deserializing from both integers and strings and serialize into
 /// strings.
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
 pub struct StickerPackId(#[serde(with = "crate::id::string")] pub u64);
 
 impl Display for StickerPackId {
@@ -33,6 +43,12 @@ impl Display for StickerPackId {
     }
 }
 
+impl From<u64> for StickerPackId {
+    fn from(id: u64) -> Self {
+        StickerPackId(id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{StickerId, StickerPackId};
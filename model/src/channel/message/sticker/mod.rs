@@ -6,52 +6,95 @@
 
 mod id;
 mod kind;
+mod pack;
+mod sticker_type;
 
 pub use self::{
     id::{StickerId, StickerPackId},
     kind::{StickerFormatType, StickerFormatTypeConversionError},
+    pack::StickerPack,
+    sticker_type::StickerType,
 };
 
+use crate::{id::GuildId, user::User};
 use serde::{Deserialize, Serialize};
 
 /// Message sticker.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Sticker {
     /// Hash of the asset.
+    ///
+    /// Deprecated by Discord and empty or missing in new payloads.
+    #[serde(default)]
     pub asset: String,
+    /// Whether the sticker can be used.
+    ///
+    /// Guild stickers may be unavailable when the guild loses boosts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available: Option<bool>,
     /// Description of the sticker.
-    pub description: String,
+    pub description: Option<String>,
     /// Format type.
     pub format_type: StickerFormatType,
+    /// ID of the guild the sticker belongs to, if it's a guild sticker.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
     /// Unique ID of the sticker.
     pub id: StickerId,
+    /// Type of the sticker, if known.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<StickerType>,
     /// Name of the sticker.
     pub name: String,
-    /// Unique ID of the pack the sticker is in.
-    pub pack_id: StickerPackId,
+    /// Unique ID of the pack the sticker is in, if it's a standard sticker.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pack_id: Option<StickerPackId>,
     /// Hash of the preview asset, if it has one.
     pub preview_asset: Option<String>,
+    /// Sort order of the sticker within its pack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_value: Option<u64>,
     /// CSV list of tags the sticker is assigned to, if any.
+    ///
+    /// For guild stickers this is the name of a unicode emoji used for
+    /// autocompletion.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<String>,
+    /// User who uploaded the guild sticker.
+    ///
+    /// Only present if the current user has the [`MANAGE_EMOJIS`] permission.
+    ///
+    /// [`MANAGE_EMOJIS`]: crate::guild::Permissions::MANAGE_EMOJIS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Sticker, StickerFormatType, StickerId, StickerPackId};
+    use super::{Sticker, StickerFormatType, StickerId, StickerPack, StickerPackId, StickerType};
+    use crate::{
+        id::{GuildId, UserId},
+        oauth::id::SkuId,
+        user::User,
+    };
     use serde_test::Token;
 
     #[test]
     fn test_minimal() {
         let value = Sticker {
             asset: "foo1".to_owned(),
-            description: "foo2".to_owned(),
+            available: None,
+            description: Some("foo2".to_owned()),
             format_type: StickerFormatType::Png,
+            guild_id: None,
             id: StickerId(1),
+            kind: None,
             name: "sticker name".to_owned(),
-            pack_id: StickerPackId(2),
+            pack_id: Some(StickerPackId(2)),
             preview_asset: None,
+            sort_value: None,
             tags: Some("foo,bar,baz".to_owned()),
+            user: None,
         };
 
         serde_test::assert_tokens(
@@ -64,6 +107,7 @@ mod tests {
                 Token::Str("asset"),
                 Token::Str("foo1"),
                 Token::Str("description"),
+                Token::Some,
                 Token::Str("foo2"),
                 Token::Str("format_type"),
                 Token::U8(1),
@@ -73,6 +117,7 @@ mod tests {
                 Token::Str("name"),
                 Token::Str("sticker name"),
                 Token::Str("pack_id"),
+                Token::Some,
                 Token::NewtypeStruct {
                     name: "StickerPackId",
                 },
@@ -86,4 +131,138 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_guild_sticker() {
+        let value = Sticker {
+            asset: String::new(),
+            available: Some(true),
+            description: None,
+            format_type: StickerFormatType::Apng,
+            guild_id: Some(GuildId(3)),
+            id: StickerId(1),
+            kind: Some(StickerType::Guild),
+            name: "sticker name".to_owned(),
+            pack_id: None,
+            preview_asset: None,
+            sort_value: None,
+            tags: Some("cat".to_owned()),
+            user: Some(User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(4),
+                locale: None,
+                mfa_enabled: None,
+                name: "user".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            }),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Sticker",
+                    len: 11,
+                },
+                Token::Str("asset"),
+                Token::Str(""),
+                Token::Str("available"),
+                Token::Some,
+                Token::Bool(true),
+                Token::Str("description"),
+                Token::None,
+                Token::Str("format_type"),
+                Token::U8(2),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("3"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::Str("1"),
+                Token::Str("type"),
+                Token::Some,
+                Token::U8(2),
+                Token::Str("name"),
+                Token::Str("sticker name"),
+                Token::Str("preview_asset"),
+                Token::None,
+                Token::Str("tags"),
+                Token::Some,
+                Token::Str("cat"),
+                Token::Str("user"),
+                Token::Some,
+                Token::Struct {
+                    name: "User",
+                    len: 5,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("bot"),
+                Token::Bool(false),
+                Token::Str("discriminator"),
+                Token::Str("0001"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("4"),
+                Token::Str("username"),
+                Token::Str("user"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_sticker_pack() {
+        let value = StickerPack {
+            banner_asset_id: Some("banner".to_owned()),
+            cover_sticker_id: Some(StickerId(1)),
+            description: "a pack".to_owned(),
+            id: StickerPackId(2),
+            name: "pack".to_owned(),
+            sku_id: SkuId(3),
+            stickers: Vec::new(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "StickerPack",
+                    len: 7,
+                },
+                Token::Str("banner_asset_id"),
+                Token::Some,
+                Token::Str("banner"),
+                Token::Str("cover_sticker_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::Str("1"),
+                Token::Str("description"),
+                Token::Str("a pack"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "StickerPackId",
+                },
+                Token::Str("2"),
+                Token::Str("name"),
+                Token::Str("pack"),
+                Token::Str("sku_id"),
+                Token::NewtypeStruct { name: "SkuId" },
+                Token::Str("3"),
+                Token::Str("stickers"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
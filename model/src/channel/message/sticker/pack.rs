@@ -0,0 +1,26 @@
+use super::{Sticker, StickerId, StickerPackId};
+use crate::oauth::id::SkuId;
+use serde::{Deserialize, Serialize};
+
+/// Pack of standard stickers.
+///
+/// See the [Discord documentation] for more information.
+///
+/// [Discord documentation]: https://discord.com/developers/docs/resources/sticker#sticker-pack-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct StickerPack {
+    /// ID of the pack's banner image.
+    pub banner_asset_id: Option<String>,
+    /// ID of the sticker shown as the pack's icon.
+    pub cover_sticker_id: Option<StickerId>,
+    /// Description of the pack.
+    pub description: String,
+    /// Unique ID of the pack.
+    pub id: StickerPackId,
+    /// Name of the pack.
+    pub name: String,
+    /// ID of the pack's SKU.
+    pub sku_id: SkuId,
+    /// Stickers in the pack.
+    pub stickers: Vec<Sticker>,
+}
@@ -0,0 +1,27 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of a [Sticker][`super::Sticker`].
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum StickerType {
+    /// Official sticker in a pack.
+    ///
+    /// Part of Nitro or in a removed purchasable pack.
+    Standard = 1,
+    /// Sticker uploaded to a boosted guild for the guild's members.
+    Guild = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StickerType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&StickerType::Standard, &[Token::U8(1)]);
+        serde_test::assert_tokens(&StickerType::Guild, &[Token::U8(2)]);
+    }
+}
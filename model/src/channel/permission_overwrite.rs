@@ -2,11 +2,7 @@ use crate::{
     guild::Permissions,
     id::{RoleId, UserId},
 };
-use serde::{
-    de::{Deserializer, Error as DeError},
-    ser::SerializeStruct,
-    Deserialize, Serialize, Serializer,
-};
+use serde::{de::Deserializer, ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -26,7 +22,9 @@ pub enum PermissionOverwriteType {
 struct PermissionOverwriteData {
     allow: Permissions,
     deny: Permissions,
-    id: String,
+    // Templates have integer IDs.
+    #[serde(with = "crate::id::string")]
+    id: u64,
     #[serde(rename = "type")]
     kind: PermissionOverwriteTargetType,
 }
@@ -51,13 +49,13 @@ impl<'de> Deserialize<'de> for PermissionOverwrite {
 
         let kind = match data.kind {
             PermissionOverwriteTargetType::Member => {
-                let id = UserId(data.id.parse().map_err(DeError::custom)?);
+                let id = UserId(data.id);
                 tracing::trace!(id = %id.0, kind = ?data.kind);
 
                 PermissionOverwriteType::Member(id)
             }
             PermissionOverwriteTargetType::Role => {
-                let id = RoleId(data.id.parse().map_err(DeError::custom)?);
+                let id = RoleId(data.id);
                 tracing::trace!(id = %id.0, kind = ?data.kind);
 
                 PermissionOverwriteType::Role(id)
@@ -99,7 +97,7 @@ mod tests {
     use super::{
         PermissionOverwrite, PermissionOverwriteTargetType, PermissionOverwriteType, Permissions,
     };
-    use crate::id::UserId;
+    use crate::id::{RoleId, UserId};
     use serde_test::Token;
 
     #[test]
@@ -127,6 +125,21 @@ mod tests {
         assert_eq!(serde_json::to_string_pretty(&overwrite).unwrap(), input);
     }
 
+    #[test]
+    fn test_overwrite_integer_id() {
+        // Overwrites in the snapshots of guild templates have integer IDs.
+        let input = r#"{"allow": "0", "deny": "2048", "id": 0, "type": 0}"#;
+
+        assert_eq!(
+            PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::SEND_MESSAGES,
+                kind: PermissionOverwriteType::Role(RoleId(0)),
+            },
+            serde_json::from_str::<PermissionOverwrite>(input).unwrap(),
+        );
+    }
+
     #[test]
     fn test_overwrite_type_name() {
         serde_test::assert_tokens(&PermissionOverwriteTargetType::Member, &[Token::U8(1)]);
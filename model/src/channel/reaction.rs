@@ -184,6 +184,7 @@ mod tests {
                 joined_at: Some("2020-01-01T00:00:00.000000+00:00".to_owned()),
                 mute: false,
                 nick: Some("typing".to_owned()),
+                pending: false,
                 premium_since: None,
                 roles: vec![RoleId(5)],
                 user: User {
@@ -232,7 +233,7 @@ mod tests {
                 Token::Some,
                 Token::Struct {
                     name: "Member",
-                    len: 8,
+                    len: 9,
                 },
                 Token::Str("deaf"),
                 Token::Bool(false),
@@ -251,6 +252,8 @@ mod tests {
                 Token::Str("nick"),
                 Token::Some,
                 Token::Str("typing"),
+                Token::Str("pending"),
+                Token::Bool(false),
                 Token::Str("roles"),
                 Token::Seq { len: Some(1) },
                 Token::NewtypeStruct { name: "RoleId" },
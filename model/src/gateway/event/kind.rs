@@ -5,6 +5,10 @@ use std::convert::TryFrom;
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EventType {
+    AutoModerationActionExecution,
+    AutoModerationRuleCreate,
+    AutoModerationRuleDelete,
+    AutoModerationRuleUpdate,
     #[serde(rename = "GUILD_BAN_ADD")]
     BanAdd,
     #[serde(rename = "GUILD_BAN_REMOVE")]
@@ -23,6 +27,12 @@ pub enum EventType {
     GuildDelete,
     GuildEmojisUpdate,
     GuildIntegrationsUpdate,
+    GuildScheduledEventCreate,
+    GuildScheduledEventDelete,
+    GuildScheduledEventUpdate,
+    GuildScheduledEventUserAdd,
+    GuildScheduledEventUserRemove,
+    GuildStickersUpdate,
     GuildUpdate,
     InviteCreate,
     InviteDelete,
@@ -63,6 +73,7 @@ pub enum EventType {
     ShardReconnecting,
     ShardPayload,
     ShardResuming,
+    ShardStopped,
     TypingStart,
     UnavailableGuild,
     UserUpdate,
@@ -74,6 +85,10 @@ pub enum EventType {
 impl EventType {
     pub fn name(self) -> Option<&'static str> {
         match self {
+            Self::AutoModerationActionExecution => Some("AUTO_MODERATION_ACTION_EXECUTION"),
+            Self::AutoModerationRuleCreate => Some("AUTO_MODERATION_RULE_CREATE"),
+            Self::AutoModerationRuleDelete => Some("AUTO_MODERATION_RULE_DELETE"),
+            Self::AutoModerationRuleUpdate => Some("AUTO_MODERATION_RULE_UPDATE"),
             Self::BanAdd => Some("GUILD_BAN_ADD"),
             Self::BanRemove => Some("GUILD_BAN_REMOVE"),
             Self::ChannelCreate => Some("CHANNEL_CREATE"),
@@ -85,6 +100,12 @@ impl EventType {
             Self::GuildDelete => Some("GUILD_DELETE"),
             Self::GuildEmojisUpdate => Some("GUILD_EMOJIS_UPDATE"),
             Self::GuildIntegrationsUpdate => Some("GUILD_INTEGRATIONS_UPDATE"),
+            Self::GuildScheduledEventCreate => Some("GUILD_SCHEDULED_EVENT_CREATE"),
+            Self::GuildScheduledEventDelete => Some("GUILD_SCHEDULED_EVENT_DELETE"),
+            Self::GuildScheduledEventUpdate => Some("GUILD_SCHEDULED_EVENT_UPDATE"),
+            Self::GuildScheduledEventUserAdd => Some("GUILD_SCHEDULED_EVENT_USER_ADD"),
+            Self::GuildScheduledEventUserRemove => Some("GUILD_SCHEDULED_EVENT_USER_REMOVE"),
+            Self::GuildStickersUpdate => Some("GUILD_STICKERS_UPDATE"),
             Self::GuildUpdate => Some("GUILD_UPDATE"),
             Self::InviteCreate => Some("INVITE_CREATE"),
             Self::InviteDelete => Some("INVITE_DELETE"),
@@ -124,7 +145,8 @@ impl EventType {
             | Self::ShardIdentifying
             | Self::ShardReconnecting
             | Self::ShardPayload
-            | Self::ShardResuming => None,
+            | Self::ShardResuming
+            | Self::ShardStopped => None,
         }
     }
 }
@@ -134,6 +156,10 @@ impl<'a> TryFrom<&'a str> for EventType {
 
     fn try_from(event_type: &'a str) -> Result<Self, Self::Error> {
         match event_type {
+            "AUTO_MODERATION_ACTION_EXECUTION" => Ok(Self::AutoModerationActionExecution),
+            "AUTO_MODERATION_RULE_CREATE" => Ok(Self::AutoModerationRuleCreate),
+            "AUTO_MODERATION_RULE_DELETE" => Ok(Self::AutoModerationRuleDelete),
+            "AUTO_MODERATION_RULE_UPDATE" => Ok(Self::AutoModerationRuleUpdate),
             "GUILD_BAN_ADD" => Ok(Self::BanAdd),
             "GUILD_BAN_REMOVE" => Ok(Self::BanRemove),
             "CHANNEL_CREATE" => Ok(Self::ChannelCreate),
@@ -145,6 +171,12 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_DELETE" => Ok(Self::GuildDelete),
             "GUILD_EMOJIS_UPDATE" => Ok(Self::GuildEmojisUpdate),
             "GUILD_INTEGRATIONS_UPDATE" => Ok(Self::GuildIntegrationsUpdate),
+            "GUILD_SCHEDULED_EVENT_CREATE" => Ok(Self::GuildScheduledEventCreate),
+            "GUILD_SCHEDULED_EVENT_DELETE" => Ok(Self::GuildScheduledEventDelete),
+            "GUILD_SCHEDULED_EVENT_UPDATE" => Ok(Self::GuildScheduledEventUpdate),
+            "GUILD_SCHEDULED_EVENT_USER_ADD" => Ok(Self::GuildScheduledEventUserAdd),
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE" => Ok(Self::GuildScheduledEventUserRemove),
+            "GUILD_STICKERS_UPDATE" => Ok(Self::GuildStickersUpdate),
             "GUILD_UPDATE" => Ok(Self::GuildUpdate),
             "INVITE_CREATE" => Ok(Self::InviteCreate),
             "INVITE_DELETE" => Ok(Self::InviteDelete),
@@ -195,6 +227,22 @@ mod tests {
 
     #[test]
     fn test_variants() {
+        assert_variant(
+            EventType::AutoModerationActionExecution,
+            "AUTO_MODERATION_ACTION_EXECUTION",
+        );
+        assert_variant(
+            EventType::AutoModerationRuleCreate,
+            "AUTO_MODERATION_RULE_CREATE",
+        );
+        assert_variant(
+            EventType::AutoModerationRuleDelete,
+            "AUTO_MODERATION_RULE_DELETE",
+        );
+        assert_variant(
+            EventType::AutoModerationRuleUpdate,
+            "AUTO_MODERATION_RULE_UPDATE",
+        );
         assert_variant(EventType::BanAdd, "GUILD_BAN_ADD");
         assert_variant(EventType::BanRemove, "GUILD_BAN_REMOVE");
         assert_variant(EventType::ChannelCreate, "CHANNEL_CREATE");
@@ -217,6 +265,27 @@ mod tests {
             EventType::GuildIntegrationsUpdate,
             "GUILD_INTEGRATIONS_UPDATE",
         );
+        assert_variant(
+            EventType::GuildScheduledEventCreate,
+            "GUILD_SCHEDULED_EVENT_CREATE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventDelete,
+            "GUILD_SCHEDULED_EVENT_DELETE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUpdate,
+            "GUILD_SCHEDULED_EVENT_UPDATE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUserAdd,
+            "GUILD_SCHEDULED_EVENT_USER_ADD",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUserRemove,
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE",
+        );
+        assert_variant(EventType::GuildStickersUpdate, "GUILD_STICKERS_UPDATE");
         assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
         assert_variant(EventType::InviteCreate, "INVITE_CREATE");
         assert_variant(EventType::InviteDelete, "INVITE_DELETE");
@@ -249,6 +318,7 @@ mod tests {
         assert_variant(EventType::ShardPayload, "SHARD_PAYLOAD");
         assert_variant(EventType::ShardReconnecting, "SHARD_RECONNECTING");
         assert_variant(EventType::ShardResuming, "SHARD_RESUMING");
+        assert_variant(EventType::ShardStopped, "SHARD_STOPPED");
         assert_variant(EventType::TypingStart, "TYPING_START");
         assert_variant(EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
         assert_variant(EventType::UserUpdate, "USER_UPDATE");
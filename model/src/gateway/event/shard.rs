@@ -1,4 +1,5 @@
 use super::{Event, EventConversionError};
+use crate::gateway::CloseCode;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -65,6 +66,35 @@ pub struct Resuming {
     pub shard_id: u64,
 }
 
+/// Reason that a shard stopped.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum StopReason {
+    /// The gateway closed the connection with a close code that the shard
+    /// can't recover from, such as an invalid token.
+    FatalCloseCode {
+        /// Close code sent by the gateway.
+        code: CloseCode,
+    },
+    /// The shard ran out of attempts to reconnect to the gateway.
+    ReconnectAttemptsExhausted {
+        /// Number of failed attempts.
+        attempts: u32,
+    },
+}
+
+/// Indicator that a shard has stopped and won't reconnect.
+///
+/// This is the last event a shard emits.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Stopped {
+    /// Why the shard stopped.
+    pub reason: StopReason,
+    /// The ID of the shard that stopped.
+    pub shard_id: u64,
+}
+
 /// "Meta" events about a shard's status, not from the gateway.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -86,6 +116,8 @@ pub enum ShardEvent {
     Reconnecting(Reconnecting),
     /// A shard is now in a Resuming stage after a disconnect.
     Resuming(Resuming),
+    /// A shard has stopped and won't reconnect.
+    Stopped(Stopped),
 }
 
 impl TryFrom<Event> for ShardEvent {
@@ -100,6 +132,7 @@ impl TryFrom<Event> for ShardEvent {
             Event::ShardPayload(v) => Self::Payload(v),
             Event::ShardReconnecting(v) => Self::Reconnecting(v),
             Event::ShardResuming(v) => Self::Resuming(v),
+            Event::ShardStopped(v) => Self::Stopped(v),
 
             _ => return Err(EventConversionError::new(event)),
         })
@@ -110,8 +143,9 @@ impl TryFrom<Event> for ShardEvent {
 mod tests {
     use super::{
         Connected, Connecting, Disconnected, Event, Identifying, Payload, Reconnecting, Resuming,
-        ShardEvent,
+        ShardEvent, StopReason, Stopped,
     };
+    use crate::gateway::CloseCode;
     use serde_test::Token;
     use std::convert::TryInto;
 
@@ -274,6 +308,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stopped() {
+        let value = Stopped {
+            reason: StopReason::FatalCloseCode {
+                code: CloseCode::AuthenticationFailed,
+            },
+            shard_id: 4,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Stopped",
+                    len: 2,
+                },
+                Token::Str("reason"),
+                Token::Struct {
+                    name: "StopReason",
+                    len: 2,
+                },
+                Token::Str("kind"),
+                Token::Str("fatal_close_code"),
+                Token::Str("code"),
+                Token::U16(4004),
+                Token::StructEnd,
+                Token::Str("shard_id"),
+                Token::U64(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_stop_reason_attempts_exhausted() {
+        let value = StopReason::ReconnectAttemptsExhausted { attempts: 5 };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "StopReason",
+                    len: 2,
+                },
+                Token::Str("kind"),
+                Token::Str("reconnect_attempts_exhausted"),
+                Token::Str("attempts"),
+                Token::U32(5),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_shard_event_try_from_event() {
         let connected = Event::ShardConnected(Connected {
@@ -333,5 +420,14 @@ mod tests {
             resuming.try_into().unwrap(),
             ShardEvent::Resuming(_)
         ));
+
+        let stopped = Event::ShardStopped(Stopped {
+            reason: StopReason::ReconnectAttemptsExhausted { attempts: 5 },
+            shard_id: 4,
+        });
+        assert!(matches!(
+            stopped.try_into().unwrap(),
+            ShardEvent::Stopped(_)
+        ));
     }
 }
@@ -62,12 +62,14 @@ bitflags! {
         /// [`GUILD_BAN_ADD`]: super::event::Event::BanAdd
         /// [`GUILD_BAN_REMOVE`]: super::event::Event::BanRemove
         const GUILD_BANS = 1 << 2;
-        /// Guild emojis intent.
+        /// Guild emojis and stickers intent.
         ///
         /// Event(s) received:
         ///  - [`GUILD_EMOJIS_UPDATE`]
+        ///  - [`GUILD_STICKERS_UPDATE`]
         ///
         /// [`GUILD_EMOJIS_UPDATE`]: super::event::Event::GuildEmojisUpdate
+        /// [`GUILD_STICKERS_UPDATE`]: super::event::Event::GuildStickersUpdate
         const GUILD_EMOJIS = 1 << 3;
         /// Guild integrations intent.
         ///
@@ -189,6 +191,39 @@ bitflags! {
         /// [`TYPING_START`]: super::event::Event::TypingStart
         /// [`GUILD_MESSAGE_TYPING`]: Self::GUILD_MESSAGE_TYPING
         const DIRECT_MESSAGE_TYPING = 1 << 14;
+        /// Guild scheduled events intent.
+        ///
+        /// Event(s) received:
+        ///  - [`GUILD_SCHEDULED_EVENT_CREATE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_UPDATE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_DELETE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_USER_ADD`]
+        ///  - [`GUILD_SCHEDULED_EVENT_USER_REMOVE`]
+        ///
+        /// [`GUILD_SCHEDULED_EVENT_CREATE`]: super::event::Event::GuildScheduledEventCreate
+        /// [`GUILD_SCHEDULED_EVENT_UPDATE`]: super::event::Event::GuildScheduledEventUpdate
+        /// [`GUILD_SCHEDULED_EVENT_DELETE`]: super::event::Event::GuildScheduledEventDelete
+        /// [`GUILD_SCHEDULED_EVENT_USER_ADD`]: super::event::Event::GuildScheduledEventUserAdd
+        /// [`GUILD_SCHEDULED_EVENT_USER_REMOVE`]: super::event::Event::GuildScheduledEventUserRemove
+        const GUILD_SCHEDULED_EVENTS = 1 << 16;
+        /// Auto moderation configuration intent.
+        ///
+        /// Event(s) received:
+        ///  - [`AUTO_MODERATION_RULE_CREATE`]
+        ///  - [`AUTO_MODERATION_RULE_UPDATE`]
+        ///  - [`AUTO_MODERATION_RULE_DELETE`]
+        ///
+        /// [`AUTO_MODERATION_RULE_CREATE`]: super::event::Event::AutoModerationRuleCreate
+        /// [`AUTO_MODERATION_RULE_UPDATE`]: super::event::Event::AutoModerationRuleUpdate
+        /// [`AUTO_MODERATION_RULE_DELETE`]: super::event::Event::AutoModerationRuleDelete
+        const AUTO_MODERATION_CONFIGURATION = 1 << 20;
+        /// Auto moderation execution intent.
+        ///
+        /// Event(s) received:
+        ///  - [`AUTO_MODERATION_ACTION_EXECUTION`]
+        ///
+        /// [`AUTO_MODERATION_ACTION_EXECUTION`]: super::event::Event::AutoModerationActionExecution
+        const AUTO_MODERATION_EXECUTION = 1 << 21;
     }
 }
 
@@ -229,5 +264,11 @@ mod tests {
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGES, &[Token::U64(1 << 12)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_REACTIONS, &[Token::U64(1 << 13)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_TYPING, &[Token::U64(1 << 14)]);
+        serde_test::assert_tokens(&Intents::GUILD_SCHEDULED_EVENTS, &[Token::U64(1 << 16)]);
+        serde_test::assert_tokens(
+            &Intents::AUTO_MODERATION_CONFIGURATION,
+            &[Token::U64(1 << 20)],
+        );
+        serde_test::assert_tokens(&Intents::AUTO_MODERATION_EXECUTION, &[Token::U64(1 << 21)]);
     }
 }
@@ -0,0 +1,125 @@
+use crate::{
+    guild::auto_moderation::{AutoModerationAction, AutoModerationTriggerType},
+    id::{AutoModerationRuleId, ChannelId, GuildId, MessageId, UserId},
+};
+use serde::{Deserialize, Serialize};
+
+/// An [`AutoModerationRule`] was triggered and one of its actions executed.
+///
+/// [`AutoModerationRule`]: crate::guild::auto_moderation::AutoModerationRule
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationActionExecution {
+    /// Action which was executed.
+    pub action: AutoModerationAction,
+    /// ID of the system message sent as a result of the action.
+    ///
+    /// Only present for actions of type [`SendAlertMessage`].
+    ///
+    /// [`SendAlertMessage`]: crate::guild::auto_moderation::AutoModerationActionType::SendAlertMessage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_system_message_id: Option<MessageId>,
+    /// ID of the channel the content was sent in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// Content which triggered the rule.
+    ///
+    /// May be empty if the bot lacks access to message content.
+    pub content: String,
+    /// ID of the guild the rule belongs to.
+    pub guild_id: GuildId,
+    /// Substring of the content which triggered the rule.
+    pub matched_content: Option<String>,
+    /// Keyword or regex pattern which was matched.
+    pub matched_keyword: Option<String>,
+    /// ID of the message containing the content.
+    ///
+    /// Not present if the message was blocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+    /// ID of the rule which was triggered.
+    pub rule_id: AutoModerationRuleId,
+    /// Type of content which triggered the rule.
+    pub rule_trigger_type: AutoModerationTriggerType,
+    /// ID of the user who sent the content.
+    pub user_id: UserId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoModerationActionExecution;
+    use crate::{
+        guild::auto_moderation::{
+            AutoModerationAction, AutoModerationActionType, AutoModerationTriggerType,
+        },
+        id::{AutoModerationRuleId, ChannelId, GuildId, MessageId, UserId},
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_action_execution() {
+        let value = AutoModerationActionExecution {
+            action: AutoModerationAction {
+                kind: AutoModerationActionType::BlockMessage,
+                metadata: None,
+            },
+            alert_system_message_id: None,
+            channel_id: Some(ChannelId(1)),
+            content: "the ending is great".to_owned(),
+            guild_id: GuildId(2),
+            matched_content: Some("ending".to_owned()),
+            matched_keyword: Some("*ending*".to_owned()),
+            message_id: Some(MessageId(3)),
+            rule_id: AutoModerationRuleId(4),
+            rule_trigger_type: AutoModerationTriggerType::Keyword,
+            user_id: UserId(5),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationActionExecution",
+                    len: 10,
+                },
+                Token::Str("action"),
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::Str("content"),
+                Token::Str("the ending is great"),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("2"),
+                Token::Str("matched_content"),
+                Token::Some,
+                Token::Str("ending"),
+                Token::Str("matched_keyword"),
+                Token::Some,
+                Token::Str("*ending*"),
+                Token::Str("message_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "MessageId" },
+                Token::Str("3"),
+                Token::Str("rule_id"),
+                Token::NewtypeStruct {
+                    name: "AutoModerationRuleId",
+                },
+                Token::Str("4"),
+                Token::Str("rule_trigger_type"),
+                Token::U8(1),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("5"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
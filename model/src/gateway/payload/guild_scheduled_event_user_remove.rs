@@ -0,0 +1,9 @@
+use crate::id::{GuildId, ScheduledEventId, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEventUserRemove {
+    pub guild_id: GuildId,
+    pub guild_scheduled_event_id: ScheduledEventId,
+    pub user_id: UserId,
+}
@@ -311,6 +311,7 @@ mod tests {
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
+                    pending: false,
                     premium_since: None,
                     roles: vec![RoleId(6), RoleId(7)],
                     user: User {
@@ -336,6 +337,7 @@ mod tests {
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
+                    pending: false,
                     premium_since: None,
                     roles: vec![RoleId(6)],
                     user: User {
@@ -361,6 +363,7 @@ mod tests {
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
+                    pending: false,
                     premium_since: None,
                     roles: vec![RoleId(6)],
                     user: User {
@@ -386,6 +389,7 @@ mod tests {
                     joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
                     mute: false,
                     nick: Some("chunk".to_owned()),
+                    pending: false,
                     premium_since: None,
                     roles: vec![RoleId(6)],
                     user: User {
@@ -9,6 +9,9 @@ pub struct MemberUpdate {
     pub guild_id: GuildId,
     pub joined_at: String,
     pub nick: Option<String>,
+    /// Whether the member has yet to pass the guild's membership screening.
+    #[serde(default)]
+    pub pending: bool,
     pub premium_since: Option<String>,
     pub roles: Vec<RoleId>,
     pub user: User,
@@ -41,6 +44,7 @@ mod tests {
             roles: vec![],
             premium_since: None,
             nick: Some("Twilight".to_string()),
+            pending: false,
             joined_at: "2017-02-27T22:21:50.121000+00:00".to_string(),
             guild_id: 1_234.into(),
         };
@@ -50,7 +54,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "MemberUpdate",
-                    len: 6,
+                    len: 7,
                 },
                 Token::Str("guild_id"),
                 Token::NewtypeStruct { name: "GuildId" },
@@ -60,6 +64,8 @@ mod tests {
                 Token::Str("nick"),
                 Token::Some,
                 Token::Str("Twilight"),
+                Token::Str("pending"),
+                Token::Bool(false),
                 Token::Str("premium_since"),
                 Token::None,
                 Token::Str("roles"),
@@ -1,12 +1,767 @@
-use crate::guild::audit_log::AuditLogChangeKey;
-use serde::{Deserialize, Serialize};
-use serde_value::Value;
-
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct AuditLogChange {
-    pub key: AuditLogChangeKey,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_value: Option<Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub old_value: Option<Value>,
+use super::{AuditLogChangeKey, AuditLogChangeRole, AuditLogChangeTypeValue};
+use crate::{
+    channel::permission_overwrite::PermissionOverwrite,
+    guild::{
+        DefaultMessageNotificationLevel, ExplicitContentFilter, IntegrationExpireBehavior,
+        MfaLevel, Permissions, VerificationLevel,
+    },
+    id::{ApplicationId, ChannelId, UserId},
+};
+use serde::{
+    de::{value::Error as ValueError, Error as DeError, IntoDeserializer},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_value::{Value, ValueDeserializer};
+
+/// Change of a field of an entity in an audit log entry.
+///
+/// Each variant is a key of the changed field, containing the old value of
+/// the field, if it had one, and the new value, if it has one.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(remote = "Self", rename_all = "snake_case", tag = "key")]
+pub enum AuditLogChange {
+    /// AFK channel of a guild.
+    AfkChannelId {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<ChannelId>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<ChannelId>,
+    },
+    /// Timeout in seconds before members are moved to the AFK channel.
+    AfkTimeout {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Permissions allowed by a permission overwrite.
+    Allow {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Permissions>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Permissions>,
+    },
+    /// Application of a webhook or bot.
+    ApplicationId {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<ApplicationId>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<ApplicationId>,
+    },
+    /// Hash of an avatar.
+    AvatarHash {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Bitrate of a voice channel.
+    Bitrate {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Channel of an invite or webhook.
+    ChannelId {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<ChannelId>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<ChannelId>,
+    },
+    /// Code of an invite.
+    Code {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Color of a role.
+    Color {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u32>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u32>,
+    },
+    /// Whether a member is server deafened.
+    Deaf {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Default message notification level of a guild.
+    DefaultMessageNotifications {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<DefaultMessageNotificationLevel>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<DefaultMessageNotificationLevel>,
+    },
+    /// Permissions denied by a permission overwrite.
+    Deny {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Permissions>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Permissions>,
+    },
+    /// Whether emoticons of an integration are enabled.
+    EnableEmoticons {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Behavior of an integration when a subscription expires.
+    ExpireBehavior {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<IntegrationExpireBehavior>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<IntegrationExpireBehavior>,
+    },
+    /// Grace period in days of an integration before its expire behavior is applied.
+    ExpireGracePeriod {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Explicit content filter level of a guild.
+    ExplicitContentFilter {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<ExplicitContentFilter>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<ExplicitContentFilter>,
+    },
+    /// Whether a role is displayed separately from online members.
+    Hoist {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Hash of an icon.
+    IconHash {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// ID of the changed entity.
+    Id {
+        /// New value.
+        #[serde(
+            default,
+            rename = "new_value",
+            skip_serializing_if = "Option::is_none",
+            with = "snowflake"
+        )]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(
+            default,
+            rename = "old_value",
+            skip_serializing_if = "Option::is_none",
+            with = "snowflake"
+        )]
+        old: Option<u64>,
+    },
+    /// User who created an invite.
+    InviterId {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<UserId>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<UserId>,
+    },
+    /// Duration in seconds of an invite.
+    MaxAge {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Maximum number of uses of an invite.
+    MaxUses {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Whether a role is mentionable.
+    Mentionable {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Multi-factor authentication requirement of a guild.
+    MfaLevel {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<MfaLevel>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<MfaLevel>,
+    },
+    /// Whether a member is server muted.
+    Mute {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Name of an entity.
+    Name {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Nickname of a member.
+    Nick {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Whether a channel is NSFW.
+    Nsfw {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Owner of a guild.
+    OwnerId {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<UserId>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<UserId>,
+    },
+    /// Permission overwrites of a channel.
+    PermissionOverwrites {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Vec<PermissionOverwrite>>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Vec<PermissionOverwrite>>,
+    },
+    /// Permissions of a role.
+    Permissions {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Permissions>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Permissions>,
+    },
+    /// Position of a channel or role.
+    Position {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Number of days of inactivity after which members are pruned.
+    PruneDeleteDays {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Number of seconds members have to wait between sending messages.
+    RateLimitPerUser {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Roles added to a member.
+    #[serde(rename = "$add")]
+    RoleAdded {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Vec<AuditLogChangeRole>>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Vec<AuditLogChangeRole>>,
+    },
+    /// Roles removed from a member.
+    #[serde(rename = "$remove")]
+    RoleRemoved {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Vec<AuditLogChangeRole>>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Vec<AuditLogChangeRole>>,
+    },
+    /// Voice region of a guild.
+    Region {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Hash of the invite splash of a guild.
+    SplashHash {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Channel receiving system messages of a guild.
+    SystemChannelId {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<ChannelId>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<ChannelId>,
+    },
+    /// Whether an invite grants temporary membership.
+    Temporary {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Topic of a channel.
+    Topic {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Type of a created entity.
+    Type {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<AuditLogChangeTypeValue>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<AuditLogChangeTypeValue>,
+    },
+    /// Number of times an invite has been used.
+    Uses {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<u64>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<u64>,
+    },
+    /// Vanity URL code of a guild.
+    VanityUrlCode {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<String>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<String>,
+    },
+    /// Verification level of a guild.
+    VerificationLevel {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<VerificationLevel>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<VerificationLevel>,
+    },
+    /// Channel of the widget of a guild.
+    WidgetChannelId {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<ChannelId>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<ChannelId>,
+    },
+    /// Whether the widget of a guild is enabled.
+    WidgetEnabled {
+        /// New value.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old value.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
+    /// Change of a field whose key isn't known, with its raw values.
+    #[serde(skip)]
+    Unknown {
+        /// Key of the changed field.
+        key: String,
+        /// New value.
+        new: Option<Value>,
+        /// Old value.
+        old: Option<Value>,
+    },
+}
+
+impl<'de> Deserialize<'de> for AuditLogChange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut map = match Value::deserialize(deserializer)? {
+            Value::Map(map) => map,
+            _ => return Err(DeError::custom("expected an audit log change map")),
+        };
+
+        let key = match map.get(&Value::String("key".to_owned())) {
+            Some(Value::String(key)) => key.clone(),
+            _ => return Err(DeError::missing_field("key")),
+        };
+
+        let is_known = AuditLogChangeKey::deserialize(key.as_str().into_deserializer())
+            .map_err(|_: ValueError| ())
+            .is_ok();
+
+        if is_known {
+            return Self::deserialize(ValueDeserializer::<D::Error>::new(Value::Map(map)));
+        }
+
+        let mut take = |field: &str| {
+            map.remove(&Value::String(field.to_owned()))
+                .filter(|value| !matches!(value, Value::Unit | Value::Option(None)))
+        };
+
+        Ok(Self::Unknown {
+            key,
+            new: take("new_value"),
+            old: take("old_value"),
+        })
+    }
+}
+
+impl Serialize for AuditLogChange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Self::Unknown { key, new, old } = self {
+            let len = 1 + usize::from(new.is_some()) + usize::from(old.is_some());
+            let mut map = serializer.serialize_map(Some(len))?;
+            map.serialize_entry("key", key)?;
+
+            if let Some(new) = new {
+                map.serialize_entry("new_value", new)?;
+            }
+
+            if let Some(old) = old {
+                map.serialize_entry("old_value", old)?;
+            }
+
+            return map.end();
+        }
+
+        Self::serialize(self, serializer)
+    }
+}
+
+impl AuditLogChange {
+    /// Key of the changed field.
+    ///
+    /// Returns `None` for [`Unknown`] changes.
+    ///
+    /// [`Unknown`]: Self::Unknown
+    pub fn key(&self) -> Option<AuditLogChangeKey> {
+        Some(match self {
+            Self::AfkChannelId { .. } => AuditLogChangeKey::AfkChannelId,
+            Self::AfkTimeout { .. } => AuditLogChangeKey::AfkTimeout,
+            Self::Allow { .. } => AuditLogChangeKey::Allow,
+            Self::ApplicationId { .. } => AuditLogChangeKey::ApplicationId,
+            Self::AvatarHash { .. } => AuditLogChangeKey::AvatarHash,
+            Self::Bitrate { .. } => AuditLogChangeKey::Bitrate,
+            Self::ChannelId { .. } => AuditLogChangeKey::ChannelId,
+            Self::Code { .. } => AuditLogChangeKey::Code,
+            Self::Color { .. } => AuditLogChangeKey::Color,
+            Self::Deaf { .. } => AuditLogChangeKey::Deaf,
+            Self::DefaultMessageNotifications { .. } => {
+                AuditLogChangeKey::DefaultMessageNotifications
+            }
+            Self::Deny { .. } => AuditLogChangeKey::Deny,
+            Self::EnableEmoticons { .. } => AuditLogChangeKey::EnableEmoticons,
+            Self::ExpireBehavior { .. } => AuditLogChangeKey::ExpireBehavior,
+            Self::ExpireGracePeriod { .. } => AuditLogChangeKey::ExpireGracePeriod,
+            Self::ExplicitContentFilter { .. } => AuditLogChangeKey::ExplicitContentFilter,
+            Self::Hoist { .. } => AuditLogChangeKey::Hoist,
+            Self::IconHash { .. } => AuditLogChangeKey::IconHash,
+            Self::Id { .. } => AuditLogChangeKey::Id,
+            Self::InviterId { .. } => AuditLogChangeKey::InviterId,
+            Self::MaxAge { .. } => AuditLogChangeKey::MaxAge,
+            Self::MaxUses { .. } => AuditLogChangeKey::MaxUses,
+            Self::Mentionable { .. } => AuditLogChangeKey::Mentionable,
+            Self::MfaLevel { .. } => AuditLogChangeKey::MfaLevel,
+            Self::Mute { .. } => AuditLogChangeKey::Mute,
+            Self::Name { .. } => AuditLogChangeKey::Name,
+            Self::Nick { .. } => AuditLogChangeKey::Nick,
+            Self::Nsfw { .. } => AuditLogChangeKey::Nsfw,
+            Self::OwnerId { .. } => AuditLogChangeKey::OwnerId,
+            Self::PermissionOverwrites { .. } => AuditLogChangeKey::PermissionOverwrites,
+            Self::Permissions { .. } => AuditLogChangeKey::Permissions,
+            Self::Position { .. } => AuditLogChangeKey::Position,
+            Self::PruneDeleteDays { .. } => AuditLogChangeKey::PruneDeleteDays,
+            Self::RateLimitPerUser { .. } => AuditLogChangeKey::RateLimitPerUser,
+            Self::RoleAdded { .. } => AuditLogChangeKey::RoleAdded,
+            Self::RoleRemoved { .. } => AuditLogChangeKey::RoleRemoved,
+            Self::Region { .. } => AuditLogChangeKey::Region,
+            Self::SplashHash { .. } => AuditLogChangeKey::SplashHash,
+            Self::SystemChannelId { .. } => AuditLogChangeKey::SystemChannelId,
+            Self::Temporary { .. } => AuditLogChangeKey::Temporary,
+            Self::Topic { .. } => AuditLogChangeKey::Topic,
+            Self::Type { .. } => AuditLogChangeKey::Type,
+            Self::Uses { .. } => AuditLogChangeKey::Uses,
+            Self::VanityUrlCode { .. } => AuditLogChangeKey::VanityUrlCode,
+            Self::VerificationLevel { .. } => AuditLogChangeKey::VerificationLevel,
+            Self::WidgetChannelId { .. } => AuditLogChangeKey::WidgetChannelId,
+            Self::WidgetEnabled { .. } => AuditLogChangeKey::WidgetEnabled,
+            Self::Unknown { .. } => return None,
+        })
+    }
+}
+
+/// (De)serialize snowflakes of any entity, which aren't typed as a specific ID.
+mod snowflake {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    struct Snowflake(#[serde(with = "crate::id::string")] u64);
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(id) => serializer.collect_str(id),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        Ok(Option::<Snowflake>::deserialize(deserializer)?.map(|snowflake| snowflake.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditLogChange, AuditLogChangeKey, AuditLogChangeRole, AuditLogChangeTypeValue};
+    use crate::{
+        channel::{
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            ChannelType,
+        },
+        guild::Permissions,
+        id::{ChannelId, RoleId, UserId},
+    };
+    use serde_json::{json, Value};
+
+    /// Convert a JSON value into the format-agnostic value of unknown changes.
+    fn raw(value: Value) -> serde_value::Value {
+        serde_value::to_value(value).unwrap()
+    }
+
+    fn round_trip(change: &AuditLogChange, value: &Value) {
+        assert_eq!(value, &serde_json::to_value(change).unwrap());
+        assert_eq!(
+            change,
+            &serde_json::from_value::<AuditLogChange>(value.clone()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_afk_channel_id() {
+        let change = AuditLogChange::AfkChannelId {
+            new: Some(ChannelId(1)),
+            old: None,
+        };
+
+        round_trip(&change, &json!({"key": "afk_channel_id", "new_value": "1"}));
+        assert_eq!(Some(AuditLogChangeKey::AfkChannelId), change.key());
+    }
+
+    #[test]
+    fn test_permissions() {
+        let change = AuditLogChange::Permissions {
+            new: Some(Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL),
+            old: Some(Permissions::VIEW_CHANNEL),
+        };
+
+        round_trip(
+            &change,
+            &json!({"key": "permissions", "new_value": "3072", "old_value": "1024"}),
+        );
+    }
+
+    #[test]
+    fn test_permission_overwrites() {
+        let change = AuditLogChange::PermissionOverwrites {
+            new: Some(vec![PermissionOverwrite {
+                allow: Permissions::SEND_MESSAGES,
+                deny: Permissions::empty(),
+                kind: PermissionOverwriteType::Member(UserId(5)),
+            }]),
+            old: Some(Vec::new()),
+        };
+
+        round_trip(
+            &change,
+            &json!({
+                "key": "permission_overwrites",
+                "new_value": [{
+                    "allow": "2048",
+                    "deny": "0",
+                    "id": "5",
+                    "type": 1,
+                }],
+                "old_value": [],
+            }),
+        );
+    }
+
+    #[test]
+    fn test_role_added() {
+        let change = AuditLogChange::RoleAdded {
+            new: Some(vec![AuditLogChangeRole {
+                id: RoleId(2),
+                name: "moderator".to_owned(),
+            }]),
+            old: None,
+        };
+
+        round_trip(
+            &change,
+            &json!({
+                "key": "$add",
+                "new_value": [{"id": "2", "name": "moderator"}],
+            }),
+        );
+        assert_eq!(Some(AuditLogChangeKey::RoleAdded), change.key());
+    }
+
+    #[test]
+    fn test_id() {
+        let change = AuditLogChange::Id {
+            new: Some(123),
+            old: None,
+        };
+
+        round_trip(&change, &json!({"key": "id", "new_value": "123"}));
+
+        // Integer snowflakes are accepted as well.
+        assert_eq!(
+            change,
+            serde_json::from_value::<AuditLogChange>(json!({"key": "id", "new_value": 123}))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_type() {
+        let change = AuditLogChange::Type {
+            new: Some(AuditLogChangeTypeValue::ChannelType(ChannelType::GuildText)),
+            old: None,
+        };
+        round_trip(&change, &json!({"key": "type", "new_value": 0}));
+
+        let change = AuditLogChange::Type {
+            new: Some(AuditLogChangeTypeValue::String("twitch".to_owned())),
+            old: None,
+        };
+        round_trip(&change, &json!({"key": "type", "new_value": "twitch"}));
+    }
+
+    #[test]
+    fn test_unknown() {
+        let change = AuditLogChange::Unknown {
+            key: "not_a_key".to_owned(),
+            new: Some(raw(json!({"a": 1}))),
+            old: None,
+        };
+
+        round_trip(&change, &json!({"key": "not_a_key", "new_value": {"a": 1}}));
+        assert!(change.key().is_none());
+
+        let change = AuditLogChange::Unknown {
+            key: "another_key".to_owned(),
+            new: Some(raw(json!([1, "2"]))),
+            old: Some(raw(json!(false))),
+        };
+
+        round_trip(
+            &change,
+            &json!({"key": "another_key", "new_value": [1, "2"], "old_value": false}),
+        );
+    }
+
+    #[test]
+    fn test_known_key_invalid_value() {
+        let value = json!({"key": "afk_channel_id", "new_value": {"a": 1}});
+
+        assert!(serde_json::from_value::<AuditLogChange>(value).is_err());
+    }
 }
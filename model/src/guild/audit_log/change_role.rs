@@ -0,0 +1,39 @@
+use crate::id::RoleId;
+use serde::{Deserialize, Serialize};
+
+/// Role added to or removed from a member in an audit log change.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AuditLogChangeRole {
+    pub id: RoleId,
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditLogChangeRole, RoleId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_audit_log_change_role() {
+        let value = AuditLogChangeRole {
+            id: RoleId(1),
+            name: "moderator".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogChangeRole",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "RoleId" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("moderator"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
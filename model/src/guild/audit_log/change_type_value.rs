@@ -0,0 +1,36 @@
+use crate::channel::ChannelType;
+use serde::{Deserialize, Serialize};
+
+/// Type of an entity created in an audit log change.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum AuditLogChangeTypeValue {
+    /// Type of a created channel.
+    ChannelType(ChannelType),
+    /// Type of another entity, such as the service of an integration.
+    String(String),
+    /// Integer type of another entity.
+    Unsigned(u64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditLogChangeTypeValue, ChannelType};
+    use serde_test::Token;
+
+    #[test]
+    fn test_channel_type() {
+        serde_test::assert_tokens(
+            &AuditLogChangeTypeValue::ChannelType(ChannelType::GuildVoice),
+            &[Token::U8(2)],
+        );
+    }
+
+    #[test]
+    fn test_string() {
+        serde_test::assert_tokens(
+            &AuditLogChangeTypeValue::String("twitch".to_owned()),
+            &[Token::Str("twitch")],
+        );
+    }
+}
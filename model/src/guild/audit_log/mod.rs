@@ -1,14 +1,16 @@
 mod change;
 mod change_key;
+mod change_role;
+mod change_type_value;
 mod entry;
 mod event;
 mod optional_entry_info;
 mod partial_integration;
 
 pub use self::{
-    change::AuditLogChange, change_key::AuditLogChangeKey, entry::AuditLogEntry,
-    event::AuditLogEvent, optional_entry_info::AuditLogOptionalEntryInfo,
-    partial_integration::PartialGuildIntegration,
+    change::AuditLogChange, change_key::AuditLogChangeKey, change_role::AuditLogChangeRole,
+    change_type_value::AuditLogChangeTypeValue, entry::AuditLogEntry, event::AuditLogEvent,
+    optional_entry_info::AuditLogOptionalEntryInfo, partial_integration::PartialGuildIntegration,
 };
 
 use crate::{channel::Webhook, user::User};
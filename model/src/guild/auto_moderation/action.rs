@@ -0,0 +1,108 @@
+use super::AutoModerationActionType;
+use crate::id::ChannelId;
+use serde::{Deserialize, Serialize};
+
+/// Action taken when an [`AutoModerationRule`] is triggered.
+///
+/// [`AutoModerationRule`]: super::AutoModerationRule
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationAction {
+    /// Type of the action.
+    #[serde(rename = "type")]
+    pub kind: AutoModerationActionType,
+    /// Additional data used when the action is executed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AutoModerationActionMetadata>,
+}
+
+/// Additional data used when an [`AutoModerationAction`] is executed.
+///
+/// Which fields are relevant depends on the action's
+/// [`AutoModerationActionType`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationActionMetadata {
+    /// Channel to send the alert to.
+    ///
+    /// Used by actions with a type of [`SendAlertMessage`].
+    ///
+    /// [`SendAlertMessage`]: AutoModerationActionType::SendAlertMessage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// Message shown to the member whose message was blocked.
+    ///
+    /// Used by actions with a type of [`BlockMessage`].
+    ///
+    /// [`BlockMessage`]: AutoModerationActionType::BlockMessage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+    /// Duration of the timeout in seconds, at most 4 weeks.
+    ///
+    /// Used by actions with a type of [`Timeout`].
+    ///
+    /// [`Timeout`]: AutoModerationActionType::Timeout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType};
+    use crate::id::ChannelId;
+    use serde_test::Token;
+
+    #[test]
+    fn test_send_alert_message() {
+        let value = AutoModerationAction {
+            kind: AutoModerationActionType::SendAlertMessage,
+            metadata: Some(AutoModerationActionMetadata {
+                channel_id: Some(ChannelId(1)),
+                ..AutoModerationActionMetadata::default()
+            }),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "AutoModerationActionMetadata",
+                    len: 1,
+                },
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_block_message() {
+        let value = AutoModerationAction {
+            kind: AutoModerationActionType::BlockMessage,
+            metadata: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
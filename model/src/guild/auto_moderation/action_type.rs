@@ -0,0 +1,36 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of an [`AutoModerationAction`].
+///
+/// [`AutoModerationAction`]: super::AutoModerationAction
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum AutoModerationActionType {
+    /// Block the content of a message.
+    BlockMessage = 1,
+    /// Send an alert to a channel.
+    SendAlertMessage = 2,
+    /// Time out the user.
+    ///
+    /// Only valid for rules with a trigger type of [`Keyword`] or
+    /// [`MentionSpam`].
+    ///
+    /// [`Keyword`]: super::AutoModerationTriggerType::Keyword
+    /// [`MentionSpam`]: super::AutoModerationTriggerType::MentionSpam
+    Timeout = 3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoModerationActionType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationActionType::BlockMessage, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AutoModerationActionType::SendAlertMessage, &[Token::U8(2)]);
+        serde_test::assert_tokens(&AutoModerationActionType::Timeout, &[Token::U8(3)]);
+    }
+}
@@ -0,0 +1,143 @@
+//! Auto moderation rules, which Discord checks content sent in a guild
+//! against, and the actions taken when they're triggered.
+
+mod action;
+mod action_type;
+mod event_type;
+mod keyword_preset_type;
+mod trigger_metadata;
+mod trigger_type;
+
+pub use self::{
+    action::{AutoModerationAction, AutoModerationActionMetadata},
+    action_type::AutoModerationActionType,
+    event_type::AutoModerationEventType,
+    keyword_preset_type::AutoModerationKeywordPresetType,
+    trigger_metadata::AutoModerationTriggerMetadata,
+    trigger_type::AutoModerationTriggerType,
+};
+
+use crate::id::{AutoModerationRuleId, ChannelId, GuildId, RoleId, UserId};
+use serde::{Deserialize, Serialize};
+
+/// Rule that content sent in a guild is checked against.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationRule {
+    /// Actions taken when the rule is triggered.
+    pub actions: Vec<AutoModerationAction>,
+    /// ID of the user who created the rule.
+    pub creator_id: UserId,
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+    /// Context in which the rule is checked.
+    pub event_type: AutoModerationEventType,
+    /// Channels which are exempt from the rule.
+    pub exempt_channels: Vec<ChannelId>,
+    /// Roles which are exempt from the rule.
+    pub exempt_roles: Vec<RoleId>,
+    /// ID of the guild the rule belongs to.
+    pub guild_id: GuildId,
+    /// ID of the rule.
+    pub id: AutoModerationRuleId,
+    /// Name of the rule.
+    pub name: String,
+    /// Additional data used to determine whether the rule is triggered.
+    pub trigger_metadata: AutoModerationTriggerMetadata,
+    /// Type of content which triggers the rule.
+    pub trigger_type: AutoModerationTriggerType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AutoModerationAction, AutoModerationActionType, AutoModerationEventType,
+        AutoModerationRule, AutoModerationTriggerMetadata, AutoModerationTriggerType,
+    };
+    use crate::id::{AutoModerationRuleId, ChannelId, GuildId, RoleId, UserId};
+    use serde_test::Token;
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_keyword_rule() {
+        let value = AutoModerationRule {
+            actions: vec![AutoModerationAction {
+                kind: AutoModerationActionType::BlockMessage,
+                metadata: None,
+            }],
+            creator_id: UserId(1),
+            enabled: true,
+            event_type: AutoModerationEventType::MessageSend,
+            exempt_channels: vec![ChannelId(2)],
+            exempt_roles: vec![RoleId(3)],
+            guild_id: GuildId(4),
+            id: AutoModerationRuleId(5),
+            name: "no spoilers".to_owned(),
+            trigger_metadata: AutoModerationTriggerMetadata {
+                keyword_filter: Some(vec!["*ending*".to_owned()]),
+                ..AutoModerationTriggerMetadata::default()
+            },
+            trigger_type: AutoModerationTriggerType::Keyword,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationRule",
+                    len: 11,
+                },
+                Token::Str("actions"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("creator_id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("1"),
+                Token::Str("enabled"),
+                Token::Bool(true),
+                Token::Str("event_type"),
+                Token::U8(1),
+                Token::Str("exempt_channels"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::SeqEnd,
+                Token::Str("exempt_roles"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "RoleId" },
+                Token::Str("3"),
+                Token::SeqEnd,
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("4"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "AutoModerationRuleId",
+                },
+                Token::Str("5"),
+                Token::Str("name"),
+                Token::Str("no spoilers"),
+                Token::Str("trigger_metadata"),
+                Token::Struct {
+                    name: "AutoModerationTriggerMetadata",
+                    len: 1,
+                },
+                Token::Str("keyword_filter"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Str("*ending*"),
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::Str("trigger_type"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use super::AutoModerationKeywordPresetType;
+use serde::{Deserialize, Serialize};
+
+/// Additional data used to determine whether an [`AutoModerationRule`] is
+/// triggered.
+///
+/// Which fields are relevant depends on the rule's
+/// [`AutoModerationTriggerType`].
+///
+/// [`AutoModerationRule`]: super::AutoModerationRule
+/// [`AutoModerationTriggerType`]: super::AutoModerationTriggerType
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutoModerationTriggerMetadata {
+    /// Substrings which are exempt from triggering the rule.
+    ///
+    /// Used by rules with a trigger type of [`Keyword`] or
+    /// [`KeywordPreset`].
+    ///
+    /// [`Keyword`]: super::AutoModerationTriggerType::Keyword
+    /// [`KeywordPreset`]: super::AutoModerationTriggerType::KeywordPreset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_list: Option<Vec<String>>,
+    /// Substrings which trigger the rule.
+    ///
+    /// Used by rules with a trigger type of [`Keyword`].
+    ///
+    /// [`Keyword`]: super::AutoModerationTriggerType::Keyword
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword_filter: Option<Vec<String>>,
+    /// Total number of unique role and user mentions allowed per message.
+    ///
+    /// Used by rules with a trigger type of [`MentionSpam`].
+    ///
+    /// [`MentionSpam`]: super::AutoModerationTriggerType::MentionSpam
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mention_total_limit: Option<u8>,
+    /// Predefined lists of words which trigger the rule.
+    ///
+    /// Used by rules with a trigger type of [`KeywordPreset`].
+    ///
+    /// [`KeywordPreset`]: super::AutoModerationTriggerType::KeywordPreset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presets: Option<Vec<AutoModerationKeywordPresetType>>,
+    /// Regular expressions which trigger the rule.
+    ///
+    /// Used by rules with a trigger type of [`Keyword`].
+    ///
+    /// [`Keyword`]: super::AutoModerationTriggerType::Keyword
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regex_patterns: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoModerationKeywordPresetType, AutoModerationTriggerMetadata};
+    use serde_test::Token;
+
+    #[test]
+    fn test_keyword_preset() {
+        let value = AutoModerationTriggerMetadata {
+            allow_list: Some(vec!["heck".to_owned()]),
+            presets: Some(vec![AutoModerationKeywordPresetType::Profanity]),
+            ..AutoModerationTriggerMetadata::default()
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationTriggerMetadata",
+                    len: 2,
+                },
+                Token::Str("allow_list"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Str("heck"),
+                Token::SeqEnd,
+                Token::Str("presets"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::U8(1),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of content which triggers an [`AutoModerationRule`].
+///
+/// [`AutoModerationRule`]: super::AutoModerationRule
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum AutoModerationTriggerType {
+    /// Content contains words or patterns from the rule's keyword filter or
+    /// regex patterns.
+    Keyword = 1,
+    /// Content is considered to be spam.
+    Spam = 3,
+    /// Content contains words from Discord's predefined lists.
+    KeywordPreset = 4,
+    /// Content contains more unique mentions than allowed.
+    MentionSpam = 5,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoModerationTriggerType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationTriggerType::Keyword, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::Spam, &[Token::U8(3)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::KeywordPreset, &[Token::U8(4)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::MentionSpam, &[Token::U8(5)]);
+    }
+}
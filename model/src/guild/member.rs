@@ -20,6 +20,9 @@ pub struct Member {
     pub joined_at: Option<String>,
     pub mute: bool,
     pub nick: Option<String>,
+    /// Whether the member has yet to pass the guild's membership screening.
+    #[serde(default)]
+    pub pending: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub premium_since: Option<String>,
     pub roles: Vec<RoleId>,
@@ -34,6 +37,8 @@ pub(crate) struct MemberIntermediary {
     pub joined_at: Option<String>,
     pub mute: bool,
     pub nick: Option<String>,
+    #[serde(default)]
+    pub pending: bool,
     pub premium_since: Option<String>,
     pub roles: Vec<RoleId>,
     pub user: User,
@@ -83,6 +88,7 @@ impl<'de> Visitor<'de> for MemberVisitor {
             joined_at: member.joined_at,
             mute: member.mute,
             nick: member.nick,
+            pending: member.pending,
             premium_since: member.premium_since,
             roles: member.roles,
             user: member.user,
@@ -184,6 +190,7 @@ mod tests {
             joined_at: Some("timestamp".to_owned()),
             mute: true,
             nick: Some("twilight".to_owned()),
+            pending: true,
             premium_since: Some("timestamp".to_owned()),
             roles: Vec::new(),
             user: User {
@@ -208,7 +215,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Member",
-                    len: 9,
+                    len: 10,
                 },
                 Token::Str("deaf"),
                 Token::Bool(false),
@@ -227,6 +234,8 @@ mod tests {
                 Token::Str("nick"),
                 Token::Some,
                 Token::Str("twilight"),
+                Token::Str("pending"),
+                Token::Bool(true),
                 Token::Str("premium_since"),
                 Token::Some,
                 Token::Str("timestamp"),
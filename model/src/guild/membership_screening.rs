@@ -0,0 +1,75 @@
+use super::MembershipScreeningField;
+use serde::{Deserialize, Serialize};
+
+/// Rules new members of a guild have to agree to before they can interact
+/// with it.
+///
+/// Members who haven't passed the screening yet are [`pending`].
+///
+/// [`pending`]: super::Member::pending
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MembershipScreening {
+    /// Description of the guild shown on the screening form.
+    pub description: Option<String>,
+    /// Fields of the screening form.
+    pub form_fields: Vec<MembershipScreeningField>,
+    /// Timestamp of when the screening was last modified.
+    pub version: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MembershipScreening, MembershipScreeningField};
+    use crate::guild::MembershipScreeningFieldType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_membership_screening() {
+        let value = MembershipScreening {
+            description: None,
+            form_fields: vec![MembershipScreeningField {
+                field_type: MembershipScreeningFieldType::Terms,
+                label: "Read and agree to the server rules".to_owned(),
+                required: true,
+                values: vec!["be nice".to_owned()],
+            }],
+            version: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MembershipScreening",
+                    len: 3,
+                },
+                Token::Str("description"),
+                Token::None,
+                Token::Str("form_fields"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "MembershipScreeningField",
+                    len: 4,
+                },
+                Token::Str("field_type"),
+                Token::UnitVariant {
+                    name: "MembershipScreeningFieldType",
+                    variant: "TERMS",
+                },
+                Token::Str("label"),
+                Token::Str("Read and agree to the server rules"),
+                Token::Str("required"),
+                Token::Bool(true),
+                Token::Str("values"),
+                Token::Seq { len: Some(1) },
+                Token::Str("be nice"),
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("version"),
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use super::MembershipScreeningFieldType;
+use serde::{Deserialize, Serialize};
+
+/// Field of a guild's [`MembershipScreening`] form.
+///
+/// [`MembershipScreening`]: super::MembershipScreening
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MembershipScreeningField {
+    /// Type of the field.
+    pub field_type: MembershipScreeningFieldType,
+    /// Title of the field.
+    pub label: String,
+    /// Whether members have to fill out the field.
+    pub required: bool,
+    /// Values of the field, such as the list of rules.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
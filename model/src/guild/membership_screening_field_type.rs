@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// Type of a [`MembershipScreeningField`].
+///
+/// [`MembershipScreeningField`]: super::MembershipScreeningField
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MembershipScreeningFieldType {
+    /// Members have to agree to the rules listed in the field's values.
+    Terms,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MembershipScreeningFieldType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(
+            &MembershipScreeningFieldType::Terms,
+            &[Token::UnitVariant {
+                name: "MembershipScreeningFieldType",
+                variant: "TERMS",
+            }],
+        );
+    }
+}
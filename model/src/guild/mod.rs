@@ -1,4 +1,5 @@
 pub mod audit_log;
+pub mod auto_moderation;
 pub mod member;
 
 mod ban;
@@ -10,6 +11,9 @@ mod integration;
 mod integration_account;
 mod integration_application;
 mod integration_expire_behavior;
+mod membership_screening;
+mod membership_screening_field;
+mod membership_screening_field_type;
 mod mfa_level;
 mod partial_guild;
 mod partial_member;
@@ -22,26 +26,34 @@ mod role_tags;
 mod status;
 mod system_channel_flags;
 mod unavailable_guild;
+mod vanity_url;
 mod verification_level;
+mod welcome_screen;
+mod welcome_screen_channel;
 mod widget;
 
 pub use self::{
     ban::Ban, default_message_notification_level::DefaultMessageNotificationLevel, emoji::Emoji,
     explicit_content_filter::ExplicitContentFilter, info::GuildInfo, integration::GuildIntegration,
     integration_account::IntegrationAccount, integration_application::IntegrationApplication,
-    integration_expire_behavior::IntegrationExpireBehavior, member::Member, mfa_level::MfaLevel,
+    integration_expire_behavior::IntegrationExpireBehavior, member::Member,
+    membership_screening::MembershipScreening,
+    membership_screening_field::MembershipScreeningField,
+    membership_screening_field_type::MembershipScreeningFieldType, mfa_level::MfaLevel,
     partial_guild::PartialGuild, partial_member::PartialMember, permissions::Permissions,
     premium_tier::PremiumTier, preview::GuildPreview, prune::GuildPrune, role::Role,
     role_tags::RoleTags, status::GuildStatus, system_channel_flags::SystemChannelFlags,
-    unavailable_guild::UnavailableGuild, verification_level::VerificationLevel,
-    widget::GuildWidget,
+    unavailable_guild::UnavailableGuild, vanity_url::GuildVanityUrl,
+    verification_level::VerificationLevel, welcome_screen::WelcomeScreen,
+    welcome_screen_channel::WelcomeScreenChannel, widget::GuildWidget,
 };
 
 use self::member::MemberListDeserializer;
 use super::gateway::presence::PresenceListDeserializer;
 use crate::{
-    channel::GuildChannel,
+    channel::{message::sticker::Sticker, GuildChannel},
     gateway::presence::Presence,
+    scheduled_event::GuildScheduledEvent,
     id::{ApplicationId, ChannelId, GuildId, UserId},
     voice::voice_state::VoiceState,
 };
@@ -69,6 +81,8 @@ pub struct Guild {
     pub emojis: Vec<Emoji>,
     pub explicit_content_filter: ExplicitContentFilter,
     pub features: Vec<String>,
+    #[serde(default)]
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
     pub icon: Option<String>,
     pub id: GuildId,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -105,6 +119,8 @@ pub struct Guild {
     pub roles: Vec<Role>,
     pub rules_channel_id: Option<ChannelId>,
     pub splash: Option<String>,
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
     #[serde(default)]
@@ -138,6 +154,7 @@ impl<'de> Deserialize<'de> for Guild {
             Emojis,
             ExplicitContentFilter,
             Features,
+            GuildScheduledEvents,
             Icon,
             Id,
             JoinedAt,
@@ -160,6 +177,7 @@ impl<'de> Deserialize<'de> for Guild {
             Region,
             Roles,
             Splash,
+            Stickers,
             SystemChannelFlags,
             SystemChannelId,
             RulesChannelId,
@@ -195,6 +213,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut emojis = None;
                 let mut explicit_content_filter = None;
                 let mut features = None;
+                let mut guild_scheduled_events = None;
                 let mut icon = None::<Option<_>>;
                 let mut id = None;
                 let mut joined_at = None::<Option<_>>;
@@ -217,6 +236,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut region = None;
                 let mut roles = None;
                 let mut splash = None::<Option<_>>;
+                let mut stickers = None;
                 let mut system_channel_id = None::<Option<_>>;
                 let mut system_channel_flags = None;
                 let mut rules_channel_id = None::<Option<_>>;
@@ -345,6 +365,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             features = Some(map.next_value()?);
                         }
+                        Field::GuildScheduledEvents => {
+                            if guild_scheduled_events.is_some() {
+                                return Err(DeError::duplicate_field("guild_scheduled_events"));
+                            }
+
+                            guild_scheduled_events = Some(map.next_value()?);
+                        }
                         Field::Icon => {
                             if icon.is_some() {
                                 return Err(DeError::duplicate_field("icon"));
@@ -503,6 +530,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             splash = Some(map.next_value()?);
                         }
+                        Field::Stickers => {
+                            if stickers.is_some() {
+                                return Err(DeError::duplicate_field("stickers"));
+                            }
+
+                            stickers = Some(map.next_value()?);
+                        }
                         Field::SystemChannelId => {
                             if system_channel_id.is_some() {
                                 return Err(DeError::duplicate_field("system_channel_id"));
@@ -598,6 +632,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let description = description.unwrap_or_default();
                 let discovery_splash = discovery_splash.unwrap_or_default();
                 let emojis = emojis.unwrap_or_default();
+                let guild_scheduled_events = guild_scheduled_events.unwrap_or_default();
                 let icon = icon.unwrap_or_default();
                 let large = large.unwrap_or_default();
                 let joined_at = joined_at.unwrap_or_default();
@@ -614,6 +649,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut presences = presences.unwrap_or_default();
                 let rules_channel_id = rules_channel_id.unwrap_or_default();
                 let splash = splash.unwrap_or_default();
+                let stickers = stickers.unwrap_or_default();
                 let system_channel_id = system_channel_id.unwrap_or_default();
                 let unavailable = unavailable.unwrap_or_default();
                 let vanity_url_code = vanity_url_code.unwrap_or_default();
@@ -635,6 +671,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?emojis,
                     ?explicit_content_filter,
                     ?features,
+                    ?guild_scheduled_events,
                     ?icon,
                     %id,
                     ?large,
@@ -662,6 +699,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?rules_channel_id,
                     ?roles,
                     ?splash,
+                    ?stickers,
                     ?system_channel_flags,
                     ?system_channel_id,
                     ?unavailable,
@@ -712,6 +750,7 @@ impl<'de> Deserialize<'de> for Guild {
                     emojis,
                     explicit_content_filter,
                     features,
+                    guild_scheduled_events,
                     icon,
                     id,
                     joined_at,
@@ -734,6 +773,7 @@ impl<'de> Deserialize<'de> for Guild {
                     region,
                     roles,
                     splash,
+                    stickers,
                     system_channel_id,
                     system_channel_flags,
                     rules_channel_id,
@@ -761,6 +801,7 @@ impl<'de> Deserialize<'de> for Guild {
             "emojis",
             "explicit_content_filter",
             "features",
+            "guild_scheduled_events",
             "icon",
             "id",
             "joined_at",
@@ -783,6 +824,7 @@ impl<'de> Deserialize<'de> for Guild {
             "region",
             "roles",
             "splash",
+            "stickers",
             "system_channel_id",
             "system_channel_flags",
             "rules_channel_id",
@@ -823,6 +865,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
             features: vec!["a feature".to_owned()],
+            guild_scheduled_events: Vec::new(),
             icon: Some("icon hash".to_owned()),
             id: GuildId(1),
             joined_at: Some("timestamp".to_owned()),
@@ -846,6 +889,7 @@ mod tests {
             roles: Vec::new(),
             rules_channel_id: Some(ChannelId(6)),
             splash: Some("splash hash".to_owned()),
+            stickers: Vec::new(),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
             system_channel_id: Some(ChannelId(7)),
             unavailable: false,
@@ -861,7 +905,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Guild",
-                    len: 44,
+                    len: 46,
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
@@ -904,6 +948,9 @@ mod tests {
                 Token::Seq { len: Some(1) },
                 Token::Str("a feature"),
                 Token::SeqEnd,
+                Token::Str("guild_scheduled_events"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("icon"),
                 Token::Some,
                 Token::Str("icon hash"),
@@ -968,6 +1015,9 @@ mod tests {
                 Token::Str("splash"),
                 Token::Some,
                 Token::Str("splash hash"),
+                Token::Str("stickers"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("system_channel_flags"),
                 Token::U64(2),
                 Token::Str("system_channel_id"),
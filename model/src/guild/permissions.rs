@@ -38,6 +38,7 @@ bitflags! {
         const MANAGE_ROLES = 0x1000_0000;
         const MANAGE_WEBHOOKS = 0x2000_0000;
         const MANAGE_EMOJIS = 0x4000_0000;
+        const MANAGE_EVENTS = 0x0002_0000_0000;
     }
 }
 
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildVanityUrl {
+    pub code: String,
+    pub uses: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildVanityUrl;
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_vanity_url() {
+        let value = GuildVanityUrl {
+            code: "twilight".to_owned(),
+            uses: 42,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildVanityUrl",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Str("twilight"),
+                Token::Str("uses"),
+                Token::U64(42),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use super::WelcomeScreenChannel;
+use serde::{Deserialize, Serialize};
+
+/// Screen shown to new members of a guild with community features.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct WelcomeScreen {
+    /// Description of the guild shown on the screen.
+    pub description: Option<String>,
+    /// Channels shown on the screen, at most 5.
+    pub welcome_channels: Vec<WelcomeScreenChannel>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WelcomeScreen, WelcomeScreenChannel};
+    use crate::id::{ChannelId, EmojiId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_welcome_screen() {
+        let value = WelcomeScreen {
+            description: Some("welcome!".to_owned()),
+            welcome_channels: vec![
+                WelcomeScreenChannel {
+                    channel_id: ChannelId(1),
+                    description: "rules".to_owned(),
+                    emoji_id: None,
+                    emoji_name: Some("📜".to_owned()),
+                },
+                WelcomeScreenChannel {
+                    channel_id: ChannelId(2),
+                    description: "chat".to_owned(),
+                    emoji_id: Some(EmojiId(3)),
+                    emoji_name: Some("chat".to_owned()),
+                },
+            ],
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "WelcomeScreen",
+                    len: 2,
+                },
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("welcome!"),
+                Token::Str("welcome_channels"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "WelcomeScreenChannel",
+                    len: 4,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::Str("description"),
+                Token::Str("rules"),
+                Token::Str("emoji_id"),
+                Token::None,
+                Token::Str("emoji_name"),
+                Token::Some,
+                Token::Str("📜"),
+                Token::StructEnd,
+                Token::Struct {
+                    name: "WelcomeScreenChannel",
+                    len: 4,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("description"),
+                Token::Str("chat"),
+                Token::Str("emoji_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "EmojiId" },
+                Token::Str("3"),
+                Token::Str("emoji_name"),
+                Token::Some,
+                Token::Str("chat"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
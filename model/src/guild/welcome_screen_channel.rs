@@ -0,0 +1,18 @@
+use crate::id::{ChannelId, EmojiId};
+use serde::{Deserialize, Serialize};
+
+/// Channel shown on a guild's [`WelcomeScreen`].
+///
+/// [`WelcomeScreen`]: super::WelcomeScreen
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct WelcomeScreenChannel {
+    /// ID of the channel.
+    pub channel_id: ChannelId,
+    /// Description shown for the channel.
+    pub description: String,
+    /// ID of the channel's emoji, if it's a custom emoji.
+    pub emoji_id: Option<EmojiId>,
+    /// Name of the channel's emoji, or the unicode character if it isn't a
+    /// custom emoji.
+    pub emoji_name: Option<String>,
+}
@@ -102,6 +102,23 @@ impl From<u64> for AuditLogEntryId {
     }
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct AutoModerationRuleId(#[serde(with = "string")] pub u64);
+
+impl Display for AutoModerationRuleId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for AutoModerationRuleId {
+    fn from(id: u64) -> Self {
+        AutoModerationRuleId(id)
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -119,6 +136,23 @@ impl From<u64> for ChannelId {
     }
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct CommandId(#[serde(with = "string")] pub u64);
+
+impl Display for CommandId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for CommandId {
+    fn from(id: u64) -> Self {
+        CommandId(id)
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -187,6 +221,23 @@ impl From<u64> for IntegrationId {
     }
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct InteractionId(#[serde(with = "string")] pub u64);
+
+impl Display for InteractionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for InteractionId {
+    fn from(id: u64) -> Self {
+        InteractionId(id)
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -221,6 +272,23 @@ impl From<u64> for RoleId {
     }
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct ScheduledEventId(#[serde(with = "string")] pub u64);
+
+impl Display for ScheduledEventId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for ScheduledEventId {
+    fn from(id: u64) -> Self {
+        ScheduledEventId(id)
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -258,8 +326,9 @@ impl From<u64> for WebhookId {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, EmojiId, GenericId, GuildId,
-        IntegrationId, MessageId, RoleId, UserId, WebhookId,
+        ApplicationId, AttachmentId, AuditLogEntryId, AutoModerationRuleId, ChannelId, CommandId,
+        EmojiId, GenericId, GuildId, IntegrationId, InteractionId, MessageId, RoleId,
+        ScheduledEventId, UserId, WebhookId,
     };
     use serde_test::Token;
 
@@ -320,6 +389,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &AutoModerationRuleId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "AutoModerationRuleId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &AutoModerationRuleId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "AutoModerationRuleId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &ChannelId(114_941_315_417_899_012),
             &[
@@ -334,6 +421,20 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &CommandId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "CommandId" },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &CommandId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "CommandId" },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &EmojiId(114_941_315_417_899_012),
             &[
@@ -394,6 +495,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &InteractionId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "InteractionId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &InteractionId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "InteractionId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &MessageId(114_941_315_417_899_012),
             &[
@@ -422,6 +541,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &UserId(114_941_315_417_899_012),
             &[
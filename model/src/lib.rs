@@ -47,11 +47,14 @@
 )]
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
+pub mod application;
 pub mod channel;
 pub mod gateway;
 pub mod guild;
 pub mod id;
 pub mod invite;
 pub mod oauth;
+pub mod scheduled_event;
+pub mod template;
 pub mod user;
 pub mod voice;
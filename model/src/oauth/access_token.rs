@@ -0,0 +1,93 @@
+use super::scope::{self, Scope};
+use serde::{Deserialize, Serialize};
+
+/// Access token granted to an application via `OAuth2`.
+///
+/// Returned when exchanging an authorization code, refreshing a token, or
+/// using the client credentials grant.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AccessToken {
+    /// Token used to make requests on behalf of the user.
+    pub access_token: String,
+    /// Number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// Token used to refresh the access token once it expires.
+    ///
+    /// Not present for tokens from the client credentials grant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Scopes granted to the access token.
+    #[serde(rename = "scope", with = "scope::space_separated")]
+    pub scopes: Vec<Scope>,
+    /// Type of the token, which is always `Bearer`.
+    pub token_type: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessToken, Scope};
+    use serde_test::Token;
+
+    #[test]
+    fn test_access_token() {
+        let value = AccessToken {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scopes: vec![Scope::Identify, Scope::GuildsJoin],
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+                Token::Str("scope"),
+                Token::Str("identify guilds.join"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_client_credentials() {
+        let value = AccessToken {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: None,
+            scopes: vec![Scope::Identify, Scope::Connections],
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 4,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("scope"),
+                Token::Str("identify connections"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
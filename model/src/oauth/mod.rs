@@ -1,6 +1,12 @@
 pub mod id;
 pub mod team;
 
+mod access_token;
 mod current_application_info;
+mod scope;
 
-pub use self::current_application_info::CurrentApplicationInfo;
+pub use self::{
+    access_token::AccessToken,
+    current_application_info::CurrentApplicationInfo,
+    scope::{Scope, ScopeParseError},
+};
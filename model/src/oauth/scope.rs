@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// Error parsing a [`Scope`] from a string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopeParseError {
+    scope: String,
+}
+
+impl ScopeParseError {
+    /// Unknown scope that couldn't be parsed.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+}
+
+impl Display for ScopeParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("scope is unknown: ")?;
+
+        f.write_str(&self.scope)
+    }
+}
+
+impl Error for ScopeParseError {}
+
+/// Scope of access granted to an application via `OAuth2`.
+///
+/// Refer to [the discord docs] for the permissions each scope grants.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#shared-resources-oauth2-scopes
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[non_exhaustive]
+pub enum Scope {
+    #[serde(rename = "activities.read")]
+    ActivitiesRead,
+    #[serde(rename = "activities.write")]
+    ActivitiesWrite,
+    #[serde(rename = "applications.builds.read")]
+    ApplicationsBuildsRead,
+    #[serde(rename = "applications.builds.upload")]
+    ApplicationsBuildsUpload,
+    #[serde(rename = "applications.commands")]
+    ApplicationsCommands,
+    #[serde(rename = "applications.commands.update")]
+    ApplicationsCommandsUpdate,
+    #[serde(rename = "applications.entitlements")]
+    ApplicationsEntitlements,
+    #[serde(rename = "applications.store.update")]
+    ApplicationsStoreUpdate,
+    #[serde(rename = "bot")]
+    Bot,
+    #[serde(rename = "connections")]
+    Connections,
+    #[serde(rename = "email")]
+    Email,
+    #[serde(rename = "gdm.join")]
+    GdmJoin,
+    #[serde(rename = "guilds")]
+    Guilds,
+    #[serde(rename = "guilds.join")]
+    GuildsJoin,
+    #[serde(rename = "identify")]
+    Identify,
+    #[serde(rename = "messages.read")]
+    MessagesRead,
+    #[serde(rename = "relationships.read")]
+    RelationshipsRead,
+    #[serde(rename = "rpc")]
+    Rpc,
+    #[serde(rename = "rpc.activities.write")]
+    RpcActivitiesWrite,
+    #[serde(rename = "rpc.notifications.read")]
+    RpcNotificationsRead,
+    #[serde(rename = "rpc.voice.read")]
+    RpcVoiceRead,
+    #[serde(rename = "rpc.voice.write")]
+    RpcVoiceWrite,
+    #[serde(rename = "webhook.incoming")]
+    WebhookIncoming,
+}
+
+impl Scope {
+    /// All known scopes.
+    const ALL: [Self; 23] = [
+        Self::ActivitiesRead,
+        Self::ActivitiesWrite,
+        Self::ApplicationsBuildsRead,
+        Self::ApplicationsBuildsUpload,
+        Self::ApplicationsCommands,
+        Self::ApplicationsCommandsUpdate,
+        Self::ApplicationsEntitlements,
+        Self::ApplicationsStoreUpdate,
+        Self::Bot,
+        Self::Connections,
+        Self::Email,
+        Self::GdmJoin,
+        Self::Guilds,
+        Self::GuildsJoin,
+        Self::Identify,
+        Self::MessagesRead,
+        Self::RelationshipsRead,
+        Self::Rpc,
+        Self::RpcActivitiesWrite,
+        Self::RpcNotificationsRead,
+        Self::RpcVoiceRead,
+        Self::RpcVoiceWrite,
+        Self::WebhookIncoming,
+    ];
+
+    /// Name of the scope as used by the API.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ActivitiesRead => "activities.read",
+            Self::ActivitiesWrite => "activities.write",
+            Self::ApplicationsBuildsRead => "applications.builds.read",
+            Self::ApplicationsBuildsUpload => "applications.builds.upload",
+            Self::ApplicationsCommands => "applications.commands",
+            Self::ApplicationsCommandsUpdate => "applications.commands.update",
+            Self::ApplicationsEntitlements => "applications.entitlements",
+            Self::ApplicationsStoreUpdate => "applications.store.update",
+            Self::Bot => "bot",
+            Self::Connections => "connections",
+            Self::Email => "email",
+            Self::GdmJoin => "gdm.join",
+            Self::Guilds => "guilds",
+            Self::GuildsJoin => "guilds.join",
+            Self::Identify => "identify",
+            Self::MessagesRead => "messages.read",
+            Self::RelationshipsRead => "relationships.read",
+            Self::Rpc => "rpc",
+            Self::RpcActivitiesWrite => "rpc.activities.write",
+            Self::RpcNotificationsRead => "rpc.notifications.read",
+            Self::RpcVoiceRead => "rpc.voice.read",
+            Self::RpcVoiceWrite => "rpc.voice.write",
+            Self::WebhookIncoming => "webhook.incoming",
+        }
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ScopeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|scope| scope.name() == s)
+            .ok_or_else(|| ScopeParseError {
+                scope: s.to_owned(),
+            })
+    }
+}
+
+/// (De)serialize a list of scopes as a string of space-separated scopes.
+pub(crate) mod space_separated {
+    use super::Scope;
+    use serde::{
+        de::{Deserializer, Error as DeError, Visitor},
+        ser::Serializer,
+    };
+    use std::fmt::{Formatter, Result as FmtResult};
+
+    struct ScopesVisitor;
+
+    impl<'de> Visitor<'de> for ScopesVisitor {
+        type Value = Vec<Scope>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("a string of space-separated scopes")
+        }
+
+        fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+            value
+                .split_whitespace()
+                .map(|scope| scope.parse().map_err(DeError::custom))
+                .collect()
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Scope>, D::Error> {
+        deserializer.deserialize_str(ScopesVisitor)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S: Serializer>(value: &Vec<Scope>, serializer: S) -> Result<S::Ok, S::Error> {
+        let names = value.iter().map(|scope| scope.name()).collect::<Vec<_>>();
+
+        serializer.serialize_str(&names.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        for scope in &Scope::ALL {
+            serde_test::assert_tokens(
+                scope,
+                &[Token::UnitVariant {
+                    name: "Scope",
+                    variant: scope.name(),
+                }],
+            );
+            assert_eq!(Ok(*scope), scope.name().parse());
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        let error = "unknown.scope".parse::<Scope>().unwrap_err();
+
+        assert_eq!("unknown.scope", error.scope());
+    }
+}
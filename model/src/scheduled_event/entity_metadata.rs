@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// Additional information about the entity of a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct EntityMetadata {
+    /// Location of the event.
+    ///
+    /// Required for events with an entity type of [`External`].
+    ///
+    /// [`External`]: super::EntityType::External
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityMetadata;
+    use serde_test::Token;
+
+    #[test]
+    fn test_entity_metadata() {
+        let value = EntityMetadata {
+            location: Some("the park".to_owned()),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("the park"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
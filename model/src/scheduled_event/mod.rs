@@ -0,0 +1,169 @@
+//! Events scheduled in a guild, such as a stage talk or a meetup.
+
+mod entity_metadata;
+mod entity_type;
+mod privacy_level;
+mod recurrence_rule;
+mod status;
+mod user;
+
+pub use self::{
+    entity_metadata::EntityMetadata,
+    entity_type::EntityType,
+    privacy_level::PrivacyLevel,
+    recurrence_rule::{
+        RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleMonth, RecurrenceRuleNWeekday,
+        RecurrenceRuleWeekday,
+    },
+    status::Status,
+    user::GuildScheduledEventUser,
+};
+
+use crate::{
+    id::{ChannelId, GenericId, GuildId, ScheduledEventId, UserId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Event scheduled in a guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEvent {
+    /// ID of the stage or voice channel the event is held in.
+    ///
+    /// `None` for events with an entity type of [`External`].
+    ///
+    /// [`External`]: EntityType::External
+    pub channel_id: Option<ChannelId>,
+    /// User who created the event.
+    ///
+    /// Not present for events created before October 25th, 2021.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+    /// ID of the user who created the event.
+    pub creator_id: Option<UserId>,
+    /// Description of the event.
+    pub description: Option<String>,
+    /// ID of the entity the event is hosted in, such as a stage instance.
+    pub entity_id: Option<GenericId>,
+    /// Additional information about the entity the event is hosted in.
+    pub entity_metadata: Option<EntityMetadata>,
+    /// Type of entity the event is hosted in.
+    pub entity_type: EntityType,
+    /// ID of the guild the event is in.
+    pub guild_id: GuildId,
+    /// ID of the event.
+    pub id: ScheduledEventId,
+    /// Hash of the cover image of the event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Name of the event.
+    pub name: String,
+    /// Who can see the event.
+    pub privacy_level: PrivacyLevel,
+    /// Rule defining how often the event recurs, if it does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence_rule: Option<RecurrenceRule>,
+    /// Timestamp of when the event is scheduled to end.
+    ///
+    /// Required for events with an entity type of [`External`].
+    ///
+    /// [`External`]: EntityType::External
+    pub scheduled_end_time: Option<String>,
+    /// Timestamp of when the event is scheduled to start.
+    pub scheduled_start_time: String,
+    /// Status of the event.
+    pub status: Status,
+    /// Number of users subscribed to the event.
+    ///
+    /// Only present when requested via the HTTP API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, Status};
+    use crate::id::{GuildId, ScheduledEventId, UserId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_external_event() {
+        let value = GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: Some(UserId(1)),
+            description: Some("bring snacks".to_owned()),
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("the park".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: GuildId(2),
+            id: ScheduledEventId(3),
+            image: None,
+            name: "picnic".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: Some("2021-11-05T20:00:00+00:00".to_owned()),
+            scheduled_start_time: "2021-11-05T18:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: Some(4),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 14,
+                },
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("creator_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("1"),
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("bring snacks"),
+                Token::Str("entity_id"),
+                Token::None,
+                Token::Str("entity_metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("the park"),
+                Token::StructEnd,
+                Token::Str("entity_type"),
+                Token::U8(3),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("2"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::Str("picnic"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("scheduled_end_time"),
+                Token::Some,
+                Token::Str("2021-11-05T20:00:00+00:00"),
+                Token::Str("scheduled_start_time"),
+                Token::Str("2021-11-05T18:00:00+00:00"),
+                Token::Str("status"),
+                Token::U8(1),
+                Token::Str("user_count"),
+                Token::Some,
+                Token::U64(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
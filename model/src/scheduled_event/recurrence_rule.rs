@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Rule defining how often a [`GuildScheduledEvent`] recurs.
+///
+/// The rule is a subset of the iCalendar recurrence rule specification.
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRule {
+    /// Specific months to recur on.
+    pub by_month: Option<Vec<RecurrenceRuleMonth>>,
+    /// Specific days of the month to recur on.
+    pub by_month_day: Option<Vec<u8>>,
+    /// Specific weeks and days of the week to recur on, such as the first
+    /// Monday of the month.
+    pub by_n_weekday: Option<Vec<RecurrenceRuleNWeekday>>,
+    /// Specific days of the week to recur on.
+    pub by_weekday: Option<Vec<RecurrenceRuleWeekday>>,
+    /// Specific days of the year to recur on.
+    pub by_year_day: Option<Vec<u16>>,
+    /// Number of times the event can recur before stopping.
+    pub count: Option<u64>,
+    /// Timestamp of when the recurrence ends.
+    pub end: Option<String>,
+    /// How often the event occurs.
+    pub frequency: RecurrenceRuleFrequency,
+    /// Spacing between the events, in units of the frequency.
+    ///
+    /// For example, a weekly frequency with an interval of 2 is every other
+    /// week.
+    pub interval: u16,
+    /// Timestamp of when the recurrence starts.
+    pub start: String,
+}
+
+/// How often a [`RecurrenceRule`] recurs.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum RecurrenceRuleFrequency {
+    Yearly = 0,
+    Monthly = 1,
+    Weekly = 2,
+    Daily = 3,
+}
+
+/// Month a [`RecurrenceRule`] recurs in.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum RecurrenceRuleMonth {
+    January = 1,
+    February = 2,
+    March = 3,
+    April = 4,
+    May = 5,
+    June = 6,
+    July = 7,
+    August = 8,
+    September = 9,
+    October = 10,
+    November = 11,
+    December = 12,
+}
+
+/// Day of a specific week a [`RecurrenceRule`] recurs on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRuleNWeekday {
+    /// Day of the week.
+    pub day: RecurrenceRuleWeekday,
+    /// Week of the month, from 1 to 5.
+    pub n: u8,
+}
+
+/// Day of the week a [`RecurrenceRule`] recurs on.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum RecurrenceRuleWeekday {
+    Monday = 0,
+    Tuesday = 1,
+    Wednesday = 2,
+    Thursday = 3,
+    Friday = 4,
+    Saturday = 5,
+    Sunday = 6,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleMonth, RecurrenceRuleNWeekday,
+        RecurrenceRuleWeekday,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&RecurrenceRuleFrequency::Yearly, &[Token::U8(0)]);
+        serde_test::assert_tokens(&RecurrenceRuleFrequency::Daily, &[Token::U8(3)]);
+        serde_test::assert_tokens(&RecurrenceRuleMonth::January, &[Token::U8(1)]);
+        serde_test::assert_tokens(&RecurrenceRuleMonth::December, &[Token::U8(12)]);
+        serde_test::assert_tokens(&RecurrenceRuleWeekday::Monday, &[Token::U8(0)]);
+        serde_test::assert_tokens(&RecurrenceRuleWeekday::Sunday, &[Token::U8(6)]);
+    }
+
+    #[test]
+    fn test_recurrence_rule() {
+        let value = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: Some(vec![RecurrenceRuleNWeekday {
+                day: RecurrenceRuleWeekday::Friday,
+                n: 1,
+            }]),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Monthly,
+            interval: 1,
+            start: "2021-11-05T18:00:00+00:00".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RecurrenceRule",
+                    len: 10,
+                },
+                Token::Str("by_month"),
+                Token::None,
+                Token::Str("by_month_day"),
+                Token::None,
+                Token::Str("by_n_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "RecurrenceRuleNWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(4),
+                Token::Str("n"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("by_weekday"),
+                Token::None,
+                Token::Str("by_year_day"),
+                Token::None,
+                Token::Str("count"),
+                Token::None,
+                Token::Str("end"),
+                Token::None,
+                Token::Str("frequency"),
+                Token::U8(1),
+                Token::Str("interval"),
+                Token::U16(1),
+                Token::Str("start"),
+                Token::Str("2021-11-05T18:00:00+00:00"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
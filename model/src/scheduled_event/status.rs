@@ -0,0 +1,40 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Status of a [`GuildScheduledEvent`].
+///
+/// Scheduled events can transition from [`Scheduled`] to [`Active`] or
+/// [`Cancelled`], and from [`Active`] to [`Completed`].
+///
+/// [`Active`]: Self::Active
+/// [`Cancelled`]: Self::Cancelled
+/// [`Completed`]: Self::Completed
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+/// [`Scheduled`]: Self::Scheduled
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum Status {
+    /// Event hasn't started yet.
+    Scheduled = 1,
+    /// Event is happening.
+    Active = 2,
+    /// Event has ended.
+    Completed = 3,
+    /// Event was cancelled before it started.
+    Cancelled = 4,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Status;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&Status::Scheduled, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Status::Active, &[Token::U8(2)]);
+        serde_test::assert_tokens(&Status::Completed, &[Token::U8(3)]);
+        serde_test::assert_tokens(&Status::Cancelled, &[Token::U8(4)]);
+    }
+}
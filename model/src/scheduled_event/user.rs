@@ -0,0 +1,16 @@
+use crate::{guild::PartialMember, id::ScheduledEventId, user::User};
+use serde::{Deserialize, Serialize};
+
+/// User subscribed to a [`GuildScheduledEvent`].
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEventUser {
+    /// ID of the event the user is subscribed to.
+    pub guild_scheduled_event_id: ScheduledEventId,
+    /// Guild member data of the user, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// User subscribed to the event.
+    pub user: User,
+}
@@ -0,0 +1,238 @@
+mod guild;
+mod role;
+
+pub use self::{guild::TemplateGuild, role::TemplateRole};
+
+use crate::{
+    id::{GuildId, UserId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Template of a guild's channels, roles, and settings, used to create new
+/// guilds.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Template {
+    pub code: String,
+    pub created_at: String,
+    pub creator: User,
+    pub creator_id: UserId,
+    pub description: Option<String>,
+    /// Whether the template has unsynced changes.
+    pub is_dirty: Option<bool>,
+    pub name: String,
+    pub serialized_source_guild: TemplateGuild,
+    pub source_guild_id: GuildId,
+    pub updated_at: String,
+    pub usage_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Template, TemplateGuild, TemplateRole};
+    use crate::{
+        channel::{ChannelType, GuildChannel, TextChannel},
+        guild::{
+            DefaultMessageNotificationLevel, ExplicitContentFilter, Permissions,
+            SystemChannelFlags, VerificationLevel,
+        },
+        id::{ChannelId, GuildId, RoleId, UserId},
+        user::{User, UserFlags},
+    };
+    use serde_test::Token;
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_template() {
+        let value = Template {
+            code: "hgM48av5Q69A".to_owned(),
+            created_at: "2020-04-02T21:10:38+00:00".to_owned(),
+            creator: User {
+                avatar: Some("79b0d9f8c340f2d43e1f49d5b1fe4a87".to_owned()),
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(132_837_293_881_950_208),
+                locale: None,
+                mfa_enabled: None,
+                name: "hoges".to_owned(),
+                premium_type: None,
+                public_flags: Some(UserFlags::empty()),
+                system: None,
+                verified: None,
+            },
+            creator_id: UserId(132_837_293_881_950_208),
+            description: None,
+            is_dirty: None,
+            name: "Friends & Family".to_owned(),
+            serialized_source_guild: TemplateGuild {
+                afk_channel_id: None,
+                afk_timeout: 300,
+                // Permission overwrites and the rate limit of channels are
+                // deserialized differently than they're serialized, so they
+                // can't be round-tripped through serde_test. The integer IDs of
+                // overwrites in templates are tested with the overwrites.
+                channels: vec![GuildChannel::Text(TextChannel {
+                    guild_id: None,
+                    id: ChannelId(1),
+                    kind: ChannelType::GuildText,
+                    last_message_id: None,
+                    last_pin_timestamp: None,
+                    name: "general".to_owned(),
+                    nsfw: false,
+                    parent_id: None,
+                    permission_overwrites: Vec::new(),
+                    position: 0,
+                    rate_limit_per_user: None,
+                    topic: None,
+                })],
+                default_message_notifications: DefaultMessageNotificationLevel::All,
+                description: None,
+                explicit_content_filter: ExplicitContentFilter::None,
+                icon_hash: None,
+                name: "Friends & Family".to_owned(),
+                preferred_locale: "en-US".to_owned(),
+                roles: vec![TemplateRole {
+                    color: 0,
+                    hoist: false,
+                    id: RoleId(0),
+                    mentionable: false,
+                    name: "@everyone".to_owned(),
+                    permissions: Permissions::from_bits_truncate(104_324_689),
+                }],
+                system_channel_flags: SystemChannelFlags::empty(),
+                system_channel_id: Some(ChannelId(1)),
+                verification_level: VerificationLevel::None,
+            },
+            source_guild_id: GuildId(678_070_694_164_299_796),
+            updated_at: "2020-05-01T17:57:38+00:00".to_owned(),
+            usage_count: 49605,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Template",
+                    len: 11,
+                },
+                Token::Str("code"),
+                Token::Str("hgM48av5Q69A"),
+                Token::Str("created_at"),
+                Token::Str("2020-04-02T21:10:38+00:00"),
+                Token::Str("creator"),
+                Token::Struct {
+                    name: "User",
+                    len: 6,
+                },
+                Token::Str("avatar"),
+                Token::Some,
+                Token::Str("79b0d9f8c340f2d43e1f49d5b1fe4a87"),
+                Token::Str("bot"),
+                Token::Bool(false),
+                Token::Str("discriminator"),
+                Token::Str("0001"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("132837293881950208"),
+                Token::Str("username"),
+                Token::Str("hoges"),
+                Token::Str("public_flags"),
+                Token::Some,
+                Token::U64(0),
+                Token::StructEnd,
+                Token::Str("creator_id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("132837293881950208"),
+                Token::Str("description"),
+                Token::None,
+                Token::Str("is_dirty"),
+                Token::None,
+                Token::Str("name"),
+                Token::Str("Friends & Family"),
+                Token::Str("serialized_source_guild"),
+                Token::Struct {
+                    name: "TemplateGuild",
+                    len: 13,
+                },
+                Token::Str("afk_channel_id"),
+                Token::None,
+                Token::Str("afk_timeout"),
+                Token::U64(300),
+                Token::Str("channels"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "TextChannel",
+                    len: 6,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::Str("type"),
+                Token::U8(0),
+                Token::Str("name"),
+                Token::Str("general"),
+                Token::Str("nsfw"),
+                Token::Bool(false),
+                Token::Str("permission_overwrites"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("position"),
+                Token::I64(0),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("default_message_notifications"),
+                Token::U8(0),
+                Token::Str("description"),
+                Token::None,
+                Token::Str("explicit_content_filter"),
+                Token::U8(0),
+                Token::Str("icon_hash"),
+                Token::None,
+                Token::Str("name"),
+                Token::Str("Friends & Family"),
+                Token::Str("preferred_locale"),
+                Token::Str("en-US"),
+                Token::Str("roles"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "TemplateRole",
+                    len: 6,
+                },
+                Token::Str("color"),
+                Token::U32(0),
+                Token::Str("hoist"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "RoleId" },
+                Token::Str("0"),
+                Token::Str("mentionable"),
+                Token::Bool(false),
+                Token::Str("name"),
+                Token::Str("@everyone"),
+                Token::Str("permissions"),
+                Token::Str("104324689"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("system_channel_flags"),
+                Token::U64(0),
+                Token::Str("system_channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::Str("verification_level"),
+                Token::U8(0),
+                Token::StructEnd,
+                Token::Str("source_guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("678070694164299796"),
+                Token::Str("updated_at"),
+                Token::Str("2020-05-01T17:57:38+00:00"),
+                Token::Str("usage_count"),
+                Token::U64(49605),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
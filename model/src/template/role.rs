@@ -0,0 +1,58 @@
+use crate::{guild::Permissions, id::RoleId};
+use serde::{Deserialize, Serialize};
+
+/// Role in the snapshot of a template's guild.
+///
+/// The IDs of roles are placeholders local to the template, starting from 0
+/// for the `@everyone` role.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct TemplateRole {
+    pub color: u32,
+    pub hoist: bool,
+    pub id: RoleId,
+    pub mentionable: bool,
+    pub name: String,
+    pub permissions: Permissions,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Permissions, RoleId, TemplateRole};
+    use serde_test::Token;
+
+    #[test]
+    fn test_template_role() {
+        let value = TemplateRole {
+            color: 0,
+            hoist: false,
+            id: RoleId(0),
+            mentionable: false,
+            name: "@everyone".to_owned(),
+            permissions: Permissions::SEND_MESSAGES,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "TemplateRole",
+                    len: 6,
+                },
+                Token::Str("color"),
+                Token::U32(0),
+                Token::Str("hoist"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "RoleId" },
+                Token::Str("0"),
+                Token::Str("mentionable"),
+                Token::Bool(false),
+                Token::Str("name"),
+                Token::Str("@everyone"),
+                Token::Str("permissions"),
+                Token::Str("2048"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -792,6 +792,10 @@ impl Standby {
 
 fn event_guild_id(event: &Event) -> Option<GuildId> {
     match event {
+        Event::AutoModerationActionExecution(e) => Some(e.guild_id),
+        Event::AutoModerationRuleCreate(e) => Some(e.guild_id),
+        Event::AutoModerationRuleDelete(e) => Some(e.guild_id),
+        Event::AutoModerationRuleUpdate(e) => Some(e.guild_id),
         Event::BanAdd(e) => Some(e.guild_id),
         Event::BanRemove(e) => Some(e.guild_id),
         Event::ChannelCreate(e) => channel_guild_id(e),
@@ -808,6 +812,12 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::GuildDelete(e) => Some(e.id),
         Event::GuildEmojisUpdate(e) => Some(e.guild_id),
         Event::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventCreate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventDelete(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
+        Event::GuildStickersUpdate(e) => Some(e.guild_id),
         Event::GuildUpdate(e) => Some(e.id),
         Event::InviteCreate(e) => Some(e.guild_id),
         Event::InviteDelete(e) => Some(e.guild_id),
@@ -837,6 +847,7 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::ShardPayload(_) => None,
         Event::ShardReconnecting(_) => None,
         Event::ShardResuming(_) => None,
+        Event::ShardStopped(_) => None,
         Event::TypingStart(e) => e.guild_id,
         Event::UnavailableGuild(e) => Some(e.id),
         Event::UserUpdate(_) => None,